@@ -13,7 +13,8 @@ use serde::{Deserialize, Serialize};
 use std::{collections::HashMap, path::Path};
 
 use crate::shared::rel_asset_to_absolute;
-use crate::{load_texture_with_repeat, material_from_path_into_scene};
+use crate::MaterialParams;
+use crate::{load_texture_with_settings, material_from_path_into_scene};
 
 // For types that require EditableMaterials, use this struct to hold necessary info
 // Path is basically the requestor for brand new entities as the current/last wont exist in a meaningful way
@@ -401,6 +402,15 @@ impl EditableMaterial {
             changed = true;
         }
 
+        // Kind params never touch the StandardMaterial but still have to reach the scene list
+        if self
+            .def
+            .as_ref()
+            .is_some_and(|old| old.kind != def.kind || old.params != def.params)
+        {
+            changed = true;
+        }
+
         if let Some(handle) = &self.handle {
             log!(
                 LogType::Editor,
@@ -424,7 +434,7 @@ impl EditableMaterial {
 
                 if let Some(path) = &def.base_color_texture {
                     if !path.is_empty() {
-                        let handle = load_texture_with_repeat(asset_server, path.clone(), true);
+                        let handle = load_texture_with_settings(asset_server, path.clone(), true);
                         existing_material.base_color_texture = Some(handle.clone());
                         changed = true;
                         if !fields.contains(&EditableMaterialField::BaseColorTexture) {
@@ -468,7 +478,7 @@ impl EditableMaterial {
                         if !fields.contains(&EditableMaterialField::MetallicRoughnessTexture) {
                             fields.push(EditableMaterialField::MetallicRoughnessTexture);
                         }
-                        let handle = load_texture_with_repeat(asset_server, path.clone(), false);
+                        let handle = load_texture_with_settings(asset_server, path.clone(), false);
                         existing_material.metallic_roughness_texture = Some(handle.clone());
 
                         changed = true;
@@ -509,7 +519,7 @@ impl EditableMaterial {
                         if !fields.contains(&EditableMaterialField::EmissiveTexture) {
                             fields.push(EditableMaterialField::EmissiveTexture);
                         }
-                        let handle = load_texture_with_repeat(asset_server, path.clone(), true);
+                        let handle = load_texture_with_settings(asset_server, path.clone(), true);
 
                         changed = true;
                         existing_material.emissive_texture = Some(handle.clone());
@@ -527,7 +537,7 @@ impl EditableMaterial {
                         if !fields.contains(&EditableMaterialField::NormalMapTexture) {
                             fields.push(EditableMaterialField::NormalMapTexture);
                         }
-                        let handle = load_texture_with_repeat(asset_server, path.clone(), false);
+                        let handle = load_texture_with_settings(asset_server, path.clone(), false);
 
                         changed = true;
                         existing_material.normal_map_texture = Some(handle.clone());
@@ -545,7 +555,7 @@ impl EditableMaterial {
                         if !fields.contains(&EditableMaterialField::OcclusionMap) {
                             fields.push(EditableMaterialField::OcclusionMap);
                         }
-                        let handle = load_texture_with_repeat(asset_server, path.clone(), false);
+                        let handle = load_texture_with_settings(asset_server, path.clone(), false);
 
                         changed = true;
                         existing_material.occlusion_texture = Some(handle.clone());
@@ -932,6 +942,14 @@ impl EditableMaterial {
 pub struct StandardMaterialDef {
     pub friendly_name: String,
 
+    // Registered material kind, None is a plain StandardMaterial
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub kind: Option<String>,
+
+    // Reflected fields of the kind by path, standard fields below stay the editor fallback
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub params: Option<MaterialParams>,
+
     #[serde(skip_serializing_if = "Option::is_none")]
     pub base_color: Option<(f32, f32, f32, f32)>,
 
@@ -1011,6 +1029,8 @@ impl Default for StandardMaterialDef {
     fn default() -> Self {
         Self {
             friendly_name: "None".to_string(),
+            kind: None,
+            params: None,
             base_color: None,
             roughness: None,
             metalness: None,
@@ -0,0 +1,379 @@
+use crate::{entities::GraniteType, load_texture_with_settings, IdentityData};
+use bevy::{
+    app::{App, Update},
+    asset::{AssetServer, Assets, Handle},
+    color::{Color, LinearRgba},
+    ecs::{
+        entity::Entity,
+        query::{Added, Changed, Or, With},
+        system::{Commands, Local, Query, Res, ResMut},
+    },
+    math::{Vec2, Vec3, Vec4},
+    mesh::Mesh3d,
+    pbr::{Material, MeshMaterial3d, StandardMaterial},
+    prelude::{Image, Reflect},
+    reflect::{GetPath, PartialReflect, ReflectRef},
+};
+use bevy_granite_logging::{log, LogCategory, LogLevel, LogType};
+use serde::{Deserialize, Serialize};
+use std::{
+    any::{Any, TypeId},
+    collections::{BTreeMap, HashMap},
+    sync::Mutex,
+};
+
+// Material files are read by plain functions without World access, so kinds live in a global
+// like custom classes do
+lazy_static::lazy_static! {
+    static ref MATERIAL_KIND_REGISTRY: Mutex<Vec<MaterialKindRegistration>> =
+        Mutex::new(Vec::new());
+}
+
+/// Reflected material fields keyed by their field path, e.g. "extension.tint"
+pub type MaterialParams = BTreeMap<String, MaterialParam>;
+
+/// A single reflected material field as stored in a .mat file
+#[derive(Reflect, Serialize, Deserialize, Debug, Clone, PartialEq)]
+pub enum MaterialParam {
+    Float(f32),
+    Int(i32),
+    UInt(u32),
+    Bool(bool),
+    /// sRGB with alpha, written to `Color` and `LinearRgba` fields
+    Color((f32, f32, f32, f32)),
+    Vec2((f32, f32)),
+    Vec3((f32, f32, f32)),
+    Vec4((f32, f32, f32, f32)),
+    /// Asset path, empty for no texture. `srgb` is off for data maps like normals
+    Texture {
+        path: String,
+        srgb: bool,
+    },
+}
+
+// StandardMaterial fields holding color data, new texture params for these start as sRGB
+const SRGB_TEXTURE_FIELDS: [&str; 2] = ["base_color_texture", "emissive_texture"];
+
+impl MaterialParam {
+    fn read(name: &str, value: &dyn PartialReflect) -> Option<Self> {
+        if let Some(value) = value.try_downcast_ref::<f32>() {
+            return Some(Self::Float(*value));
+        }
+        if let Some(value) = value.try_downcast_ref::<i32>() {
+            return Some(Self::Int(*value));
+        }
+        if let Some(value) = value.try_downcast_ref::<u32>() {
+            return Some(Self::UInt(*value));
+        }
+        if let Some(value) = value.try_downcast_ref::<bool>() {
+            return Some(Self::Bool(*value));
+        }
+        let color = value.try_downcast_ref::<Color>().copied().or_else(|| {
+            value
+                .try_downcast_ref::<LinearRgba>()
+                .map(|c| Color::from(*c))
+        });
+        if let Some(color) = color {
+            let srgba = color.to_srgba();
+            return Some(Self::Color((
+                srgba.red,
+                srgba.green,
+                srgba.blue,
+                srgba.alpha,
+            )));
+        }
+        if let Some(value) = value.try_downcast_ref::<Vec2>() {
+            return Some(Self::Vec2((value.x, value.y)));
+        }
+        if let Some(value) = value.try_downcast_ref::<Vec3>() {
+            return Some(Self::Vec3((value.x, value.y, value.z)));
+        }
+        if let Some(value) = value.try_downcast_ref::<Vec4>() {
+            return Some(Self::Vec4((value.x, value.y, value.z, value.w)));
+        }
+        if value.try_downcast_ref::<Handle<Image>>().is_some()
+            || value.try_downcast_ref::<Option<Handle<Image>>>().is_some()
+        {
+            return Some(Self::Texture {
+                path: String::new(),
+                srgb: SRGB_TEXTURE_FIELDS.contains(&name),
+            });
+        }
+        None
+    }
+
+    fn write(&self, field: &mut dyn PartialReflect, asset_server: &AssetServer) -> bool {
+        match self {
+            Self::Float(value) => set_field(field, *value),
+            Self::Int(value) => set_field(field, *value),
+            Self::UInt(value) => set_field(field, *value),
+            Self::Bool(value) => set_field(field, *value),
+            Self::Color((r, g, b, a)) => {
+                let color = Color::srgba(*r, *g, *b, *a);
+                set_field(field, color) || set_field(field, color.to_linear())
+            }
+            Self::Vec2((x, y)) => set_field(field, Vec2::new(*x, *y)),
+            Self::Vec3((x, y, z)) => set_field(field, Vec3::new(*x, *y, *z)),
+            Self::Vec4((x, y, z, w)) => set_field(field, Vec4::new(*x, *y, *z, *w)),
+            Self::Texture { path, srgb } => {
+                let handle = (!path.is_empty())
+                    .then(|| load_texture_with_settings(asset_server, path.clone(), *srgb));
+                set_field(field, handle.clone()) || set_field(field, handle.unwrap_or_default())
+            }
+        }
+    }
+}
+
+fn set_field<T: Any>(field: &mut dyn PartialReflect, value: T) -> bool {
+    field
+        .try_downcast_mut::<T>()
+        .map(|slot| *slot = value)
+        .is_some()
+}
+
+// Walk nested structs so e.g. ExtendedMaterial exposes "base.*" and "extension.*"
+fn collect_params(value: &dyn PartialReflect, prefix: &str, params: &mut MaterialParams) {
+    let ReflectRef::Struct(fields) = value.reflect_ref() else {
+        return;
+    };
+    for index in 0..fields.field_len() {
+        let (Some(name), Some(field)) = (fields.name_at(index), fields.field_at(index)) else {
+            continue;
+        };
+        let key = if prefix.is_empty() {
+            name.to_string()
+        } else {
+            format!("{}.{}", prefix, name)
+        };
+        match MaterialParam::read(name, field) {
+            Some(param) => {
+                params.insert(key, param);
+            }
+            None => collect_params(field, &key, params),
+        }
+    }
+}
+
+/// Bounds a material type needs to be used from .mat files
+pub trait GraniteMaterialKind: Material + Reflect + Default {}
+impl<T> GraniteMaterialKind for T where T: Material + Reflect + Default {}
+
+#[derive(Clone)]
+struct MaterialKindRegistration {
+    kind: &'static str,
+    type_id: TypeId,
+    defaults: fn() -> MaterialParams,
+}
+
+fn default_params<M: GraniteMaterialKind>() -> MaterialParams {
+    let mut params = MaterialParams::new();
+    collect_params(M::default().as_partial_reflect(), "", &mut params);
+    params
+}
+
+fn build_material<M: GraniteMaterialKind>(
+    kind: &str,
+    params: &MaterialParams,
+    asset_server: &AssetServer,
+) -> M {
+    let mut material = M::default();
+    for (key, param) in params {
+        let applied = material
+            .reflect_path_mut(key.as_str())
+            .is_ok_and(|field| param.write(field, asset_server));
+        if !applied {
+            log!(
+                LogType::Game,
+                LogLevel::Warning,
+                LogCategory::Asset,
+                "Material kind '{}' has no field '{}' of type {:?}",
+                kind,
+                key,
+                param
+            );
+        }
+    }
+    material
+}
+
+/// Keys of every registered material kind
+pub fn registered_material_kinds() -> Vec<&'static str> {
+    MATERIAL_KIND_REGISTRY
+        .lock()
+        .unwrap()
+        .iter()
+        .map(|registration| registration.kind)
+        .collect()
+}
+
+pub fn is_registered_material_kind(kind: &str) -> bool {
+    MATERIAL_KIND_REGISTRY
+        .lock()
+        .unwrap()
+        .iter()
+        .any(|registration| registration.kind == kind)
+}
+
+/// Every parameter a kind exposes, at the material's default values
+pub fn material_kind_defaults(kind: &str) -> Option<MaterialParams> {
+    let defaults = MATERIAL_KIND_REGISTRY
+        .lock()
+        .unwrap()
+        .iter()
+        .find(|registration| registration.kind == kind)
+        .map(|registration| registration.defaults)?;
+    Some(defaults())
+}
+
+fn kind_by_type<M: 'static>() -> Option<&'static str> {
+    MATERIAL_KIND_REGISTRY
+        .lock()
+        .unwrap()
+        .iter()
+        .find(|registration| registration.type_id == TypeId::of::<M>())
+        .map(|registration| registration.kind)
+}
+
+/// Register material types other than StandardMaterial, e.g. an ExtendedMaterial or a custom
+/// shader material, so .mat files can name them with `kind` and set their reflected fields
+/// through `params`. The material's own MaterialPlugin still has to be added by the app
+pub trait RegisterGraniteMaterial {
+    fn register_granite_material<M: GraniteMaterialKind>(
+        &mut self,
+        kind: &'static str,
+    ) -> &mut Self;
+}
+
+impl RegisterGraniteMaterial for App {
+    fn register_granite_material<M: GraniteMaterialKind>(
+        &mut self,
+        kind: &'static str,
+    ) -> &mut Self {
+        let mut registry = MATERIAL_KIND_REGISTRY.lock().unwrap();
+        if let Some(existing) = registry
+            .iter()
+            .find(|registration| registration.kind == kind)
+        {
+            if existing.type_id != TypeId::of::<M>() {
+                log!(
+                    LogType::Game,
+                    LogLevel::Error,
+                    LogCategory::Asset,
+                    "Material kind '{}' is already registered to another type, skipping",
+                    kind
+                );
+            }
+            return self;
+        }
+        registry.push(MaterialKindRegistration {
+            kind,
+            type_id: TypeId::of::<M>(),
+            defaults: default_params::<M>,
+        });
+        drop(registry);
+        log!(
+            LogType::Game,
+            LogLevel::OK,
+            LogCategory::Asset,
+            "Registered material kind '{}'",
+            kind
+        );
+        self.add_systems(Update, apply_material_kind_system::<M>)
+    }
+}
+
+// Material data lives on IdentityData, so file and editor changes show up as a change there
+type KindMaterialQuery<'w, 's, M> = Query<
+    'w,
+    's,
+    (
+        Entity,
+        &'w IdentityData,
+        Option<&'w MeshMaterial3d<StandardMaterial>>,
+        Option<&'w MeshMaterial3d<M>>,
+    ),
+    (
+        With<Mesh3d>,
+        Or<(
+            Changed<IdentityData>,
+            Added<Mesh3d>,
+            Changed<MeshMaterial3d<StandardMaterial>>,
+        )>,
+    ),
+>;
+
+/// Swaps the StandardMaterial on mesh entities whose material file uses this kind. The
+/// StandardMaterial handle stays on the EditableMaterial so switching back restores it
+fn apply_material_kind_system<M: GraniteMaterialKind>(
+    mut commands: Commands,
+    asset_server: Res<AssetServer>,
+    mut kind_materials: ResMut<Assets<M>>,
+    // Built assets by material path, with the params they were built from
+    mut built: Local<HashMap<String, (MaterialParams, Handle<M>)>>,
+    query: KindMaterialQuery<M>,
+) {
+    let Some(kind) = kind_by_type::<M>() else {
+        return;
+    };
+
+    for (entity, identity, standard, applied) in query.iter() {
+        let Some(material) = identity.class.get_material_data().map(|data| data.current) else {
+            continue;
+        };
+        let def = material
+            .def
+            .as_ref()
+            .filter(|def| def.kind.as_deref() == Some(kind));
+
+        let Some(def) = def else {
+            // Only undo what this system applied, hand added materials are left alone
+            let Some(applied) = applied else {
+                continue;
+            };
+            if !built.values().any(|(_, handle)| *handle == applied.0) {
+                continue;
+            }
+            commands.entity(entity).remove::<MeshMaterial3d<M>>();
+            let other_kind = material
+                .def
+                .as_ref()
+                .and_then(|def| def.kind.as_deref())
+                .is_some_and(is_registered_material_kind);
+            if standard.is_none() && !other_kind {
+                if let Some(handle) = &material.handle {
+                    commands
+                        .entity(entity)
+                        .insert(MeshMaterial3d(handle.clone()));
+                }
+            }
+            continue;
+        };
+
+        let params = def.params.clone().unwrap_or_default();
+        let handle = match built.get_mut(&material.path) {
+            Some((built_params, handle)) => {
+                if *built_params != params {
+                    if let Some(existing) = kind_materials.get_mut(&*handle) {
+                        *existing = build_material(kind, &params, &asset_server);
+                    }
+                    *built_params = params;
+                }
+                handle.clone()
+            }
+            None => {
+                let handle = kind_materials.add(build_material::<M>(kind, &params, &asset_server));
+                built.insert(material.path.clone(), (params, handle.clone()));
+                handle
+            }
+        };
+
+        if applied.is_none_or(|applied| applied.0 != handle) {
+            commands.entity(entity).insert(MeshMaterial3d(handle));
+        }
+        if standard.is_some() {
+            commands
+                .entity(entity)
+                .remove::<MeshMaterial3d<StandardMaterial>>();
+        }
+    }
+}
@@ -1,9 +1,6 @@
 use super::{
-    AvailableEditableMaterials, EditableMaterial, EditableMaterialError, EditableMaterialField,
-    StandardMaterialDef,
-};
-use bevy::image::{
-    ImageAddressMode, ImageFilterMode, ImageFormat, ImageFormatSetting, ImageLoaderSettings, ImageSampler, ImageSamplerDescriptor
+    load_texture_with_settings, AvailableEditableMaterials, EditableMaterial,
+    EditableMaterialError, EditableMaterialField, StandardMaterialDef,
 };
 use bevy::math::Affine2;
 use bevy::prelude::{
@@ -15,31 +12,15 @@ use bevy_granite_logging::{
     log,
 };
 
-// This was brutal to figure out and I CANNOT believe the is a .load_with_settings() method...
 /// Helper function to load textures with REPEAT address mode
-/// `is_srgb` should be true for color textures (base_color, emissive), false for data textures (normal, metallic, roughness, etc.)
-pub fn load_texture_with_repeat(asset_server: &AssetServer, path: String, is_srgb: bool) -> Handle<Image> {
-    let path_clone = path.clone();
-    asset_server.load_with_settings(path, move |settings: &mut ImageLoaderSettings| {
-        settings.is_srgb = is_srgb;
-
-        if let Some(ext) = path_clone.rsplit('.').next() {
-            settings.format = ImageFormatSetting::Format(
-                ImageFormat::from_extension(ext).unwrap_or(ImageFormat::Png)
-            );
-        }
-        
-        settings.sampler = ImageSampler::Descriptor(ImageSamplerDescriptor {
-            address_mode_u: ImageAddressMode::Repeat,
-            address_mode_v: ImageAddressMode::Repeat,
-            address_mode_w: ImageAddressMode::Repeat,
-            mag_filter: ImageFilterMode::Linear,
-            min_filter: ImageFilterMode::Linear,
-            mipmap_filter: ImageFilterMode::Linear,
-            anisotropy_clamp: 64,
-            ..Default::default()
-        });
-    })
+/// `is_srgb` should be true for color textures (base_color, emissive), false for data textures
+#[deprecated(note = "use load_texture_with_settings, which also applies texture import settings")]
+pub fn load_texture_with_repeat(
+    asset_server: &AssetServer,
+    path: String,
+    is_srgb: bool,
+) -> Handle<Image> {
+    load_texture_with_settings(asset_server, path, is_srgb)
 }
 
 /// Creates a EditableMaterial from a definition(wrapper) file and adds it to the asset system
@@ -101,7 +82,8 @@ pub fn material_from_path_into_scene(
     }
     if let Some(texture_path) = &mat_def.base_color_texture {
         if !texture_path.is_empty() {
-            let handle = load_texture_with_repeat(asset_server, texture_path.clone(), true); // sRGB for color
+            // sRGB for color
+            let handle = load_texture_with_settings(asset_server, texture_path.clone(), true);
             mat.base_color_texture = Some(handle.clone());
             available_materials
                 .image_paths
@@ -125,7 +107,8 @@ pub fn material_from_path_into_scene(
     // Metallic Roughness Texture (combined)
     if let Some(texture_path) = &mat_def.metallic_roughness_texture {
         if !texture_path.is_empty() {
-            let handle = load_texture_with_repeat(asset_server, texture_path.clone(), false); // Linear for data
+            // Linear for data
+            let handle = load_texture_with_settings(asset_server, texture_path.clone(), false);
             mat.metallic_roughness_texture = Some(handle.clone());
             available_materials
                 .image_paths
@@ -145,7 +128,8 @@ pub fn material_from_path_into_scene(
     }
     if let Some(texture_path) = &mat_def.emissive_texture {
         if !texture_path.is_empty() {
-            let handle = load_texture_with_repeat(asset_server, texture_path.clone(), true); // sRGB for emissive color
+            // sRGB for emissive color
+            let handle = load_texture_with_settings(asset_server, texture_path.clone(), true);
             mat.emissive_texture = Some(handle.clone());
             available_materials
                 .image_paths
@@ -157,7 +141,8 @@ pub fn material_from_path_into_scene(
     // Normal Map
     if let Some(texture_path) = &mat_def.normal_map_texture {
         if !texture_path.is_empty() {
-            let handle = load_texture_with_repeat(asset_server, texture_path.clone(), false); // Linear for normal data
+            // Linear for normal data
+            let handle = load_texture_with_settings(asset_server, texture_path.clone(), false);
             mat.normal_map_texture = Some(handle.clone());
             available_materials
                 .image_paths
@@ -169,7 +154,8 @@ pub fn material_from_path_into_scene(
     // Occlusion Map
     if let Some(texture_path) = &mat_def.occlusion_map {
         if !texture_path.is_empty() {
-            let handle = load_texture_with_repeat(asset_server, texture_path.clone(), false); // Linear for occlusion data
+            // Linear for occlusion data
+            let handle = load_texture_with_settings(asset_server, texture_path.clone(), false);
             mat.occlusion_texture = Some(handle.clone());
             available_materials
                 .image_paths
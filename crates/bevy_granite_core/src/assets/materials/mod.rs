@@ -1,10 +1,18 @@
 use bevy::prelude::Reflect;
 use serde::{Deserialize, Serialize};
 pub mod definition;
+pub mod kind;
 pub mod load;
+pub mod texture;
+pub mod usage;
+pub mod watch;
 
 pub use definition::*;
+pub use kind::*;
 pub use load::*;
+pub use texture::*;
+pub use usage::*;
+pub use watch::*;
 
 // Store the material path, the current material, and the last material
 #[derive(Serialize, Deserialize, Reflect, Debug, Clone, PartialEq)]
@@ -0,0 +1,408 @@
+use crate::assets_dir;
+use bevy::{
+    asset::{AssetEvent, AssetId},
+    ecs::message::{Message, MessageReader},
+    image::{
+        ImageAddressMode, ImageFilterMode, ImageFormat, ImageFormatSetting, ImageLoaderSettings,
+        ImageSampler, ImageSamplerDescriptor,
+    },
+    prelude::{
+        AssetServer, Assets, Handle, Image, Reflect, Res, ResMut, Resource, StandardMaterial,
+    },
+    render::render_resource::TextureFormat,
+};
+use bevy_granite_logging::{
+    config::{LogCategory, LogLevel, LogType},
+    log,
+};
+use ron::ser::{to_string_pretty, PrettyConfig};
+use serde::{Deserialize, Serialize};
+use std::collections::HashSet;
+
+#[derive(Reflect, Serialize, Deserialize, Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub enum TextureAddressMode {
+    #[default]
+    Repeat,
+    MirrorRepeat,
+    ClampToEdge,
+}
+
+impl TextureAddressMode {
+    pub fn all() -> Vec<Self> {
+        vec![Self::Repeat, Self::MirrorRepeat, Self::ClampToEdge]
+    }
+
+    fn to_bevy(self) -> ImageAddressMode {
+        match self {
+            Self::Repeat => ImageAddressMode::Repeat,
+            Self::MirrorRepeat => ImageAddressMode::MirrorRepeat,
+            Self::ClampToEdge => ImageAddressMode::ClampToEdge,
+        }
+    }
+}
+
+#[derive(Reflect, Serialize, Deserialize, Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub enum TextureFilterMode {
+    Nearest,
+    #[default]
+    Linear,
+}
+
+impl TextureFilterMode {
+    pub fn all() -> Vec<Self> {
+        vec![Self::Nearest, Self::Linear]
+    }
+
+    fn to_bevy(self) -> ImageFilterMode {
+        match self {
+            Self::Nearest => ImageFilterMode::Nearest,
+            Self::Linear => ImageFilterMode::Linear,
+        }
+    }
+}
+
+/// How a texture is imported, stored next to it as `<texture>.import`
+/// Shared by every material using the texture since the asset server loads a path once
+#[derive(Reflect, Serialize, Deserialize, Debug, Clone, PartialEq)]
+#[serde(default)]
+pub struct TextureImportSettings {
+    /// Also used for W, which only 3D textures sample
+    pub address_mode_u: TextureAddressMode,
+    pub address_mode_v: TextureAddressMode,
+    pub mag_filter: TextureFilterMode,
+    pub min_filter: TextureFilterMode,
+    pub mipmap_filter: TextureFilterMode,
+    /// 1 turns it off, only used when every filter is linear
+    pub anisotropy: u16,
+    /// None picks by slot, color and emissive are sRGB and everything else linear
+    pub srgb: Option<bool>,
+    pub generate_mips: bool,
+    /// For normal maps authored with the DirectX convention
+    pub flip_normal_green: bool,
+}
+
+impl Default for TextureImportSettings {
+    fn default() -> Self {
+        Self {
+            address_mode_u: TextureAddressMode::Repeat,
+            address_mode_v: TextureAddressMode::Repeat,
+            mag_filter: TextureFilterMode::Linear,
+            min_filter: TextureFilterMode::Linear,
+            mipmap_filter: TextureFilterMode::Linear,
+            anisotropy: 16,
+            srgb: None,
+            generate_mips: false,
+            flip_normal_green: false,
+        }
+    }
+}
+
+impl TextureImportSettings {
+    /// Applies everything the image loader itself handles, mips and green flip happen after load
+    pub fn apply_to_loader(&self, settings: &mut ImageLoaderSettings, default_srgb: bool) {
+        settings.is_srgb = self.srgb.unwrap_or(default_srgb);
+
+        let all_linear = [self.mag_filter, self.min_filter, self.mipmap_filter]
+            .iter()
+            .all(|filter| *filter == TextureFilterMode::Linear);
+        settings.sampler = ImageSampler::Descriptor(ImageSamplerDescriptor {
+            address_mode_u: self.address_mode_u.to_bevy(),
+            address_mode_v: self.address_mode_v.to_bevy(),
+            // Materials only use 2D textures, W follows U
+            address_mode_w: self.address_mode_u.to_bevy(),
+            mag_filter: self.mag_filter.to_bevy(),
+            min_filter: self.min_filter.to_bevy(),
+            mipmap_filter: self.mipmap_filter.to_bevy(),
+            // wgpu rejects anisotropy with any nearest filter
+            anisotropy_clamp: if all_linear {
+                self.anisotropy.clamp(1, 16)
+            } else {
+                1
+            },
+            ..Default::default()
+        });
+    }
+
+    fn needs_processing(&self) -> bool {
+        self.generate_mips || self.flip_normal_green
+    }
+}
+
+pub fn texture_import_settings_path(texture_path: &str) -> String {
+    format!("{}.import", texture_path)
+}
+
+/// Import settings saved for a texture relative to assets, None when it uses the defaults
+pub fn read_texture_import_settings(texture_path: &str) -> Option<TextureImportSettings> {
+    let sidecar = assets_dir().join(texture_import_settings_path(texture_path));
+    let contents = std::fs::read_to_string(&sidecar).ok()?;
+    match ron::from_str(&contents) {
+        Ok(settings) => Some(settings),
+        Err(e) => {
+            log!(
+                LogType::Editor,
+                LogLevel::Warning,
+                LogCategory::Asset,
+                "Ignoring bad texture import settings {:?}: {}",
+                sidecar,
+                e
+            );
+            None
+        }
+    }
+}
+
+/// Saves the sidecar, or removes it when the settings are back to the defaults
+pub fn write_texture_import_settings(texture_path: &str, settings: &TextureImportSettings) {
+    let sidecar = assets_dir().join(texture_import_settings_path(texture_path));
+    let result = if *settings == TextureImportSettings::default() {
+        match std::fs::remove_file(&sidecar) {
+            Err(e) if e.kind() != std::io::ErrorKind::NotFound => Err(e.to_string()),
+            _ => Ok(()),
+        }
+    } else {
+        to_string_pretty(settings, PrettyConfig::default())
+            .map_err(|e| e.to_string())
+            .and_then(|ron| std::fs::write(&sidecar, ron).map_err(|e| e.to_string()))
+    };
+
+    if let Err(e) = result {
+        log!(
+            LogType::Editor,
+            LogLevel::Error,
+            LogCategory::Asset,
+            "Failed to save texture import settings {:?}: {}",
+            sidecar,
+            e
+        );
+    }
+}
+
+/// Loads a material texture with its import settings
+/// `is_srgb` is the default for the slot, true for color textures (base_color, emissive),
+/// false for data textures (normal, metallic, roughness, etc.)
+pub fn load_texture_with_settings(
+    asset_server: &AssetServer,
+    path: String,
+    is_srgb: bool,
+) -> Handle<Image> {
+    let path_clone = path.clone();
+    // Runs again on every reload, so the sidecar is read here rather than captured
+    asset_server.load_with_settings(path, move |settings: &mut ImageLoaderSettings| {
+        if let Some(ext) = path_clone.rsplit('.').next() {
+            settings.format = ImageFormatSetting::Format(
+                ImageFormat::from_extension(ext).unwrap_or(ImageFormat::Png),
+            );
+        }
+        read_texture_import_settings(&path_clone)
+            .unwrap_or_default()
+            .apply_to_loader(settings, is_srgb);
+    })
+}
+
+/// Save new import settings for a texture and reload it everywhere it is used
+#[derive(Message, Clone, Debug, PartialEq)]
+pub struct RequestTextureReimport {
+    pub path: String,
+    pub settings: TextureImportSettings,
+}
+
+/// Images this crate changed itself, so their modified event is not processed twice
+#[derive(Resource, Default)]
+pub struct TextureImportState {
+    processed: HashSet<AssetId<Image>>,
+}
+
+pub fn texture_reimport_system(
+    mut reimport_reader: MessageReader<RequestTextureReimport>,
+    asset_server: Res<AssetServer>,
+) {
+    for RequestTextureReimport { path, settings } in reimport_reader.read() {
+        write_texture_import_settings(path, settings);
+        asset_server.reload(path.as_str());
+        log!(
+            LogType::Editor,
+            LogLevel::Info,
+            LogCategory::Asset,
+            "Reimporting texture: {}",
+            path
+        );
+    }
+}
+
+/// Generates mips and flips normal map green once a texture with those settings has loaded
+pub fn apply_texture_import_settings_system(
+    mut image_events: MessageReader<AssetEvent<Image>>,
+    mut state: ResMut<TextureImportState>,
+    mut images: ResMut<Assets<Image>>,
+    mut materials: ResMut<Assets<StandardMaterial>>,
+    asset_server: Res<AssetServer>,
+) {
+    // Reloads send both events in the same frame
+    let loaded: HashSet<AssetId<Image>> = image_events
+        .read()
+        .filter_map(|event| match event {
+            AssetEvent::LoadedWithDependencies { id } | AssetEvent::Modified { id } => Some(*id),
+            _ => None,
+        })
+        .collect();
+
+    let mut processed = HashSet::new();
+    for id in loaded {
+        if state.processed.remove(&id) {
+            continue;
+        }
+        let Some(path) = asset_server.get_path(id) else {
+            continue;
+        };
+        let path = path.path().to_string_lossy().replace('\\', "/");
+        let Some(settings) =
+            read_texture_import_settings(&path).filter(|settings| settings.needs_processing())
+        else {
+            continue;
+        };
+        // Borrowing mutably marks the image modified, so only do it once it will change
+        let Some(image) = images.get(id) else {
+            continue;
+        };
+
+        if !matches!(
+            image.texture_descriptor.format,
+            TextureFormat::Rgba8Unorm | TextureFormat::Rgba8UnormSrgb
+        ) || image.texture_descriptor.mip_level_count > 1
+        {
+            log!(
+                LogType::Editor,
+                LogLevel::Warning,
+                LogCategory::Asset,
+                "Mips and green flip need an 8 bit RGBA texture without mips: {}",
+                path
+            );
+            continue;
+        }
+
+        if image.data.is_none() {
+            continue;
+        }
+
+        let Some(image) = images.get_mut(id) else {
+            continue;
+        };
+        let width = image.texture_descriptor.size.width;
+        let height = image.texture_descriptor.size.height;
+        let srgb = image.texture_descriptor.format == TextureFormat::Rgba8UnormSrgb;
+        let Some(data) = image.data.as_mut() else {
+            continue;
+        };
+
+        if settings.flip_normal_green {
+            for pixel in data.chunks_exact_mut(4) {
+                pixel[1] = 255 - pixel[1];
+            }
+        }
+        if settings.generate_mips {
+            let levels = append_mip_chain(data, width, height, srgb);
+            image.texture_descriptor.mip_level_count = levels;
+        }
+
+        // Changing the image fires another modified event for it
+        state.processed.insert(id);
+        processed.insert(id);
+        log!(
+            LogType::Editor,
+            LogLevel::OK,
+            LogCategory::Asset,
+            "Applied import settings to texture: {}",
+            path
+        );
+    }
+    if processed.is_empty() {
+        return;
+    }
+
+    // Materials only re-bind their textures when they change themselves
+    let users: Vec<_> = materials
+        .iter()
+        .filter(|(_, material)| {
+            [
+                &material.base_color_texture,
+                &material.metallic_roughness_texture,
+                &material.emissive_texture,
+                &material.normal_map_texture,
+                &material.occlusion_texture,
+            ]
+            .into_iter()
+            .flatten()
+            .any(|texture| processed.contains(&texture.id()))
+        })
+        .map(|(id, _)| id)
+        .collect();
+    for id in users {
+        materials.get_mut(id);
+    }
+}
+
+fn srgb_to_linear(value: u8) -> f32 {
+    let value = value as f32 / 255.;
+    if value <= 0.04045 {
+        value / 12.92
+    } else {
+        ((value + 0.055) / 1.055).powf(2.4)
+    }
+}
+
+fn linear_to_srgb(value: f32) -> u8 {
+    let value = if value <= 0.0031308 {
+        value * 12.92
+    } else {
+        1.055 * value.powf(1.0 / 2.4) - 0.055
+    };
+    (value * 255.).round().clamp(0., 255.) as u8
+}
+
+/// Box filters RGBA8 level 0 down to 1x1, appending each level. Returns the level count
+fn append_mip_chain(data: &mut Vec<u8>, width: u32, height: u32, srgb: bool) -> u32 {
+    let mut levels = 1;
+    let mut level_start = 0;
+    let (mut width, mut height) = (width as usize, height as usize);
+
+    while width > 1 || height > 1 {
+        let next_width = (width / 2).max(1);
+        let next_height = (height / 2).max(1);
+        let mut next = Vec::with_capacity(next_width * next_height * 4);
+
+        for y in 0..next_height {
+            for x in 0..next_width {
+                for channel in 0..4 {
+                    let mut sum = 0.;
+                    let mut count = 0.;
+                    for (sx, sy) in [(0, 0), (1, 0), (0, 1), (1, 1)] {
+                        let source_x = (x * 2 + sx).min(width - 1);
+                        let source_y = (y * 2 + sy).min(height - 1);
+                        let value = data[level_start + (source_y * width + source_x) * 4 + channel];
+                        // Color averages in linear space, alpha never is sRGB
+                        sum += if srgb && channel < 3 {
+                            srgb_to_linear(value)
+                        } else {
+                            value as f32 / 255.
+                        };
+                        count += 1.;
+                    }
+                    let average = sum / count;
+                    next.push(if srgb && channel < 3 {
+                        linear_to_srgb(average)
+                    } else {
+                        (average * 255.).round() as u8
+                    });
+                }
+            }
+        }
+
+        level_start += width * height * 4;
+        data.extend_from_slice(&next);
+        width = next_width;
+        height = next_height;
+        levels += 1;
+    }
+    levels
+}
@@ -0,0 +1,123 @@
+use crate::{
+    assets_dir,
+    entities::{scene_pretty_config, SceneData},
+    GraniteType,
+};
+use bevy_granite_logging::{
+    config::{LogCategory, LogLevel, LogType},
+    log,
+};
+use ron::ser::to_string_pretty;
+use std::{collections::HashMap, path::Path};
+
+/// Every `.scene` file under assets, relative to it
+pub fn collect_scene_files() -> Vec<String> {
+    fn walk(dir: &Path, root: &Path, scenes: &mut Vec<String>) {
+        let Ok(entries) = std::fs::read_dir(dir) else {
+            return;
+        };
+        for entry in entries.flatten() {
+            let path = entry.path();
+            if path.is_dir() {
+                walk(&path, root, scenes);
+            } else if path.extension().is_some_and(|ext| ext == "scene") {
+                if let Ok(relative) = path.strip_prefix(root) {
+                    scenes.push(relative.to_string_lossy().replace('\\', "/"));
+                }
+            }
+        }
+    }
+
+    let mut scenes = Vec::new();
+    let root = assets_dir();
+    walk(&root, &root, &mut scenes);
+    scenes.sort();
+    scenes
+}
+
+fn read_scene(scene_path: &str) -> Option<SceneData> {
+    let contents = std::fs::read_to_string(assets_dir().join(scene_path)).ok()?;
+    match ron::from_str::<SceneData>(&contents) {
+        Ok(scene) => Some(scene),
+        Err(e) => {
+            log!(
+                LogType::Editor,
+                LogLevel::Warning,
+                LogCategory::Asset,
+                "Skipping material references in {}: {}",
+                scene_path,
+                e
+            );
+            None
+        }
+    }
+}
+
+/// How many entities in a scene file on disk use each material path
+pub fn scene_material_references(scene_path: &str) -> HashMap<String, usize> {
+    let mut references = HashMap::new();
+    let Some(scene) = read_scene(scene_path) else {
+        return references;
+    };
+    for entity in &scene.entities {
+        if let Some(material) = entity.identity.class.get_material_data() {
+            if !material.path.is_empty() {
+                *references.entry(material.path.clone()).or_insert(0) += 1;
+            }
+        }
+    }
+    references
+}
+
+/// Points every entity in a scene file using `from` at `to` instead and writes it back
+/// Returns how many entities changed
+pub fn reassign_scene_material(scene_path: &str, from: &str, to: &str) -> usize {
+    let Some(mut scene) = read_scene(scene_path) else {
+        return 0;
+    };
+
+    let mut changed = 0;
+    for entity in &mut scene.entities {
+        if let Some(material) = entity.identity.class.get_mut_material_data() {
+            if *material.path == from {
+                *material.path = to.to_string();
+                changed += 1;
+            }
+        }
+    }
+    if changed == 0 {
+        return 0;
+    }
+
+    let written = to_string_pretty(&scene, scene_pretty_config())
+        .map_err(|e| e.to_string())
+        .and_then(|ron| {
+            std::fs::write(assets_dir().join(scene_path), ron).map_err(|e| e.to_string())
+        });
+    match written {
+        Ok(()) => {
+            log!(
+                LogType::Editor,
+                LogLevel::OK,
+                LogCategory::Asset,
+                "Reassigned {} entities in {} from '{}' to '{}'",
+                changed,
+                scene_path,
+                from,
+                to
+            );
+            changed
+        }
+        Err(e) => {
+            log!(
+                LogType::Editor,
+                LogLevel::Error,
+                LogCategory::Asset,
+                "Failed to rewrite {}: {}",
+                scene_path,
+                e
+            );
+            0
+        }
+    }
+}
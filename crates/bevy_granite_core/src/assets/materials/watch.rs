@@ -0,0 +1,327 @@
+use super::{texture_import_settings_path, AvailableEditableMaterials, StandardMaterialDef};
+use crate::{assets_dir, material_from_path_into_scene};
+use bevy::{
+    asset::{AssetEvent, AssetId},
+    ecs::message::{Message, MessageReader, MessageWriter},
+    prelude::{AssetServer, Assets, Image, Res, ResMut, Resource, StandardMaterial, Time},
+};
+use bevy_granite_logging::{
+    config::{LogCategory, LogLevel, LogType},
+    log,
+};
+use std::{
+    collections::{HashMap, HashSet},
+    path::Path,
+    time::SystemTime,
+};
+
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum MaterialFileChange {
+    Added,
+    Modified,
+    Removed,
+}
+
+/// Sent once a change on disk has been applied to `AvailableEditableMaterials`
+#[derive(Message, Clone, Debug, PartialEq)]
+pub struct MaterialFileChangedEvent {
+    pub path: String,
+    pub change: MaterialFileChange,
+}
+
+/// Polls `.mat` files and the textures they reference so outside edits show up without a reload
+#[derive(Resource)]
+pub struct MaterialFileWatcher {
+    pub enabled: bool,
+    /// Seconds between disk scans
+    pub interval: f32,
+    elapsed: f32,
+    scanned: bool,
+    material_times: HashMap<String, SystemTime>,
+    texture_times: HashMap<String, SystemTime>,
+    // Textures asked to reload, their materials are touched once the new image lands
+    reloading: HashSet<AssetId<Image>>,
+}
+
+impl Default for MaterialFileWatcher {
+    fn default() -> Self {
+        Self {
+            // Shipped builds rarely have the loose asset folder around
+            enabled: cfg!(debug_assertions),
+            interval: 0.5,
+            elapsed: 0.,
+            scanned: false,
+            material_times: HashMap::new(),
+            texture_times: HashMap::new(),
+            reloading: HashSet::new(),
+        }
+    }
+}
+
+fn modified_time(rel_path: &str) -> Option<SystemTime> {
+    std::fs::metadata(assets_dir().join(rel_path))
+        .and_then(|metadata| metadata.modified())
+        .ok()
+}
+
+// Editing the import settings beside a texture counts as changing it
+fn texture_modified_time(rel_path: &str) -> Option<SystemTime> {
+    let texture = modified_time(rel_path)?;
+    Some(
+        modified_time(&texture_import_settings_path(rel_path))
+            .map_or(texture, |sidecar| sidecar.max(texture)),
+    )
+}
+
+// Same walk as the folder loader, but quiet since it runs every scan
+fn collect_material_times(dir: &Path, root: &Path, times: &mut HashMap<String, SystemTime>) {
+    let Ok(entries) = std::fs::read_dir(dir) else {
+        return;
+    };
+    for entry in entries.flatten() {
+        let path = entry.path();
+        if path.is_dir() {
+            collect_material_times(&path, root, times);
+        } else if path.extension().is_some_and(|ext| ext == "mat") {
+            let Ok(relative) = path.strip_prefix(root) else {
+                continue;
+            };
+            if let Ok(modified) = entry.metadata().and_then(|metadata| metadata.modified()) {
+                times.insert(relative.to_string_lossy().replace('\\', "/"), modified);
+            }
+        }
+    }
+}
+
+/// Re-reads a `.mat` file into the existing `StandardMaterial` handle
+/// Entities keep pointing at it, so they pick up the change
+fn reload_material_file(
+    path: &str,
+    materials: &mut ResMut<Assets<StandardMaterial>>,
+    available_materials: &mut ResMut<AvailableEditableMaterials>,
+    asset_server: &Res<AssetServer>,
+) -> bool {
+    let Some(existing) = available_materials.find_material_by_path(path).cloned() else {
+        return material_from_path_into_scene(path, materials, available_materials, asset_server)
+            .is_some();
+    };
+
+    let def: StandardMaterialDef = match std::fs::read_to_string(assets_dir().join(path))
+        .map_err(|e| e.to_string())
+        .and_then(|ron| ron::from_str(&ron).map_err(|e| e.to_string()))
+    {
+        Ok(def) => def,
+        Err(e) => {
+            // Half written files are common while an external tool saves, the next scan retries
+            log!(
+                LogType::Editor,
+                LogLevel::Warning,
+                LogCategory::Asset,
+                "Could not reload material {}: {}",
+                path,
+                e
+            );
+            return false;
+        }
+    };
+
+    // Our own saves land here too
+    if existing.def.as_ref() == Some(&def) {
+        return false;
+    }
+
+    let mut material = existing;
+    // Matching the name and rebuilding the field list up front keeps this from saving back to disk
+    material.friendly_name = def.friendly_name.clone();
+    material.fields = None;
+    material.disk_changes = false;
+    material.new_material = false;
+    material.update_material_handle(&def, materials, available_materials, asset_server);
+
+    if let Some(entry) = available_materials
+        .materials
+        .iter_mut()
+        .flatten()
+        .find(|entry| entry.path == path)
+    {
+        *entry = material;
+    }
+    true
+}
+
+pub fn watch_material_files_system(
+    time: Res<Time>,
+    mut watcher: ResMut<MaterialFileWatcher>,
+    mut materials: ResMut<Assets<StandardMaterial>>,
+    mut available_materials: ResMut<AvailableEditableMaterials>,
+    asset_server: Res<AssetServer>,
+    mut changed_writer: MessageWriter<MaterialFileChangedEvent>,
+) {
+    if !watcher.enabled {
+        return;
+    }
+    watcher.elapsed += time.delta_secs();
+    if watcher.elapsed < watcher.interval {
+        return;
+    }
+    watcher.elapsed = 0.;
+
+    let mut material_times = HashMap::new();
+    let root = assets_dir();
+    collect_material_times(&root.join("materials"), &root, &mut material_times);
+
+    let texture_paths: HashSet<String> = available_materials
+        .image_paths
+        .values()
+        .filter(|path| !path.is_empty())
+        .cloned()
+        .collect();
+    let texture_times: HashMap<String, SystemTime> = texture_paths
+        .into_iter()
+        .filter_map(|path| texture_modified_time(&path).map(|modified| (path, modified)))
+        .collect();
+
+    // First scan is the baseline
+    if !watcher.scanned {
+        watcher.scanned = true;
+        watcher.material_times = material_times;
+        watcher.texture_times = texture_times;
+        return;
+    }
+
+    let mut changes = Vec::new();
+    for (path, modified) in &material_times {
+        match watcher.material_times.get(path) {
+            None => changes.push((path.clone(), MaterialFileChange::Added)),
+            Some(last) if last != modified => {
+                changes.push((path.clone(), MaterialFileChange::Modified))
+            }
+            _ => {}
+        }
+    }
+    for path in watcher.material_times.keys() {
+        if !material_times.contains_key(path) {
+            changes.push((path.clone(), MaterialFileChange::Removed));
+        }
+    }
+
+    for (path, change) in changes {
+        let applied = match change {
+            MaterialFileChange::Added => {
+                available_materials.find_material_by_path(&path).is_none()
+                    && material_from_path_into_scene(
+                        &path,
+                        &mut materials,
+                        &mut available_materials,
+                        &asset_server,
+                    )
+                    .is_some()
+            }
+            MaterialFileChange::Modified => reload_material_file(
+                &path,
+                &mut materials,
+                &mut available_materials,
+                &asset_server,
+            ),
+            MaterialFileChange::Removed => {
+                let known = available_materials.find_material_by_path(&path).is_some();
+                if let Some(list) = &mut available_materials.materials {
+                    list.retain(|material| material.path != path);
+                }
+                known
+            }
+        };
+
+        if applied {
+            log!(
+                LogType::Editor,
+                LogLevel::Info,
+                LogCategory::Asset,
+                "Material file {:?} on disk: {}",
+                change,
+                path
+            );
+            changed_writer.write(MaterialFileChangedEvent { path, change });
+        }
+    }
+
+    for (path, modified) in &texture_times {
+        if watcher
+            .texture_times
+            .get(path)
+            .is_some_and(|last| last != modified)
+        {
+            // Reloading re-runs the handle's loader settings, so new import settings apply too
+            asset_server.reload(path.as_str());
+            let ids: Vec<_> = available_materials
+                .image_paths
+                .iter()
+                .filter(|(_, image_path)| *image_path == path)
+                .map(|(handle, _)| handle.id())
+                .collect();
+            watcher.reloading.extend(ids);
+            log!(
+                LogType::Editor,
+                LogLevel::Info,
+                LogCategory::Asset,
+                "Texture changed on disk: {}",
+                path
+            );
+        }
+    }
+
+    watcher.material_times = material_times;
+    watcher.texture_times = texture_times;
+}
+
+/// Touches every material using a texture that just reloaded so it re-binds the new image
+pub fn apply_reloaded_textures_system(
+    mut image_events: MessageReader<AssetEvent<Image>>,
+    mut watcher: ResMut<MaterialFileWatcher>,
+    mut materials: ResMut<Assets<StandardMaterial>>,
+    mut available_materials: ResMut<AvailableEditableMaterials>,
+    mut changed_writer: MessageWriter<MaterialFileChangedEvent>,
+) {
+    let mut reloaded = HashSet::new();
+    for event in image_events.read() {
+        if let AssetEvent::Modified { id } | AssetEvent::LoadedWithDependencies { id } = event {
+            if watcher.reloading.remove(id) {
+                reloaded.insert(*id);
+            }
+        }
+    }
+    if reloaded.is_empty() {
+        return;
+    }
+
+    let Some(list) = &mut available_materials.materials else {
+        return;
+    };
+    for material in list.iter_mut() {
+        let Some(handle) = &material.handle else {
+            continue;
+        };
+        let uses_texture = materials.get(handle).is_some_and(|existing| {
+            [
+                &existing.base_color_texture,
+                &existing.metallic_roughness_texture,
+                &existing.emissive_texture,
+                &existing.normal_map_texture,
+                &existing.occlusion_texture,
+            ]
+            .into_iter()
+            .flatten()
+            .any(|texture| reloaded.contains(&texture.id()))
+        });
+
+        if uses_texture {
+            // Marking it changed is what rebuilds the bind group
+            materials.get_mut(handle);
+            material.version += 1;
+            changed_writer.write(MaterialFileChangedEvent {
+                path: material.path.clone(),
+                change: MaterialFileChange::Modified,
+            });
+        }
+    }
+}
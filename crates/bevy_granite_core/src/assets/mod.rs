@@ -1,10 +1,19 @@
 pub mod materials;
 pub mod plugin;
 
+#[allow(deprecated)]
+pub use materials::load_texture_with_repeat;
 pub use materials::{
-    get_material_from_path, load_texture_with_repeat, material_from_path_into_scene,
-    materials_from_folder_into_scene, AvailableEditableMaterials, EditableMaterial,
-    EditableMaterialError, EditableMaterialField, MaterialData,
-    NewEditableMaterial, RequiredMaterialData, RequiredMaterialDataMut, StandardMaterialDef,
+    apply_reloaded_textures_system, apply_texture_import_settings_system, collect_scene_files,
+    get_material_from_path, is_registered_material_kind, load_texture_with_settings,
+    material_from_path_into_scene, material_kind_defaults, materials_from_folder_into_scene,
+    read_texture_import_settings, reassign_scene_material, registered_material_kinds,
+    scene_material_references, texture_import_settings_path, texture_reimport_system,
+    watch_material_files_system, write_texture_import_settings, AvailableEditableMaterials,
+    EditableMaterial, EditableMaterialError, EditableMaterialField, GraniteMaterialKind,
+    MaterialData, MaterialFileChange, MaterialFileChangedEvent, MaterialFileWatcher, MaterialParam,
+    MaterialParams, NewEditableMaterial, RegisterGraniteMaterial, RequestTextureReimport,
+    RequiredMaterialData, RequiredMaterialDataMut, StandardMaterialDef, TextureAddressMode,
+    TextureFilterMode, TextureImportSettings, TextureImportState,
 };
 pub use plugin::AssetPlugin;
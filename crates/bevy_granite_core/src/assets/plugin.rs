@@ -1,9 +1,16 @@
-use super::AvailableEditableMaterials;
+use super::{
+    apply_reloaded_textures_system, apply_texture_import_settings_system, texture_reimport_system,
+    watch_material_files_system, AvailableEditableMaterials, MaterialFileChangedEvent,
+    MaterialFileWatcher, RequestTextureReimport, TextureImportState,
+};
 use crate::EditableMaterial;
 use bevy::{
-    app::{App, Plugin, PreStartup},
+    app::{App, Plugin, PreStartup, Update},
     asset::{AssetServer, Assets, Handle},
-    ecs::system::{Res, ResMut},
+    ecs::{
+        schedule::IntoScheduleConfigs,
+        system::{Res, ResMut},
+    },
     pbr::StandardMaterial,
 };
 
@@ -43,13 +50,32 @@ pub struct AssetPlugin;
 impl Plugin for AssetPlugin {
     fn build(&self, app: &mut App) {
         app
+            //
+            // Events
+            //
+            .add_message::<MaterialFileChangedEvent>()
+            .add_message::<RequestTextureReimport>()
             //
             // Resources
             //
             .insert_resource(AvailableEditableMaterials::default())
+            .insert_resource(MaterialFileWatcher::default())
+            .insert_resource(TextureImportState::default())
             //
             // Schedule system
             //
-            .add_systems(PreStartup, preload_fallback_material);
+            .add_systems(PreStartup, preload_fallback_material)
+            .add_systems(
+                Update,
+                (watch_material_files_system, apply_reloaded_textures_system).chain(),
+            )
+            .add_systems(
+                Update,
+                (
+                    texture_reimport_system,
+                    apply_texture_import_settings_system,
+                )
+                    .chain(),
+            );
     }
 }
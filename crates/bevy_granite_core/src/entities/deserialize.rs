@@ -1,8 +1,11 @@
 use super::{ComponentEditor, EntitySaveReadyData, IdentityData, SceneData, SpawnSource};
 use crate::{
-    absolute_asset_to_rel, entities::SaveSettings, materials_from_folder_into_scene,
-    rel_asset_to_absolute, shared::is_scene_version_compatible, AvailableEditableMaterials,
-    GraniteType, TransformData,
+    absolute_asset_to_rel,
+    entities::SaveSettings,
+    materials_from_folder_into_scene, rel_asset_to_absolute,
+    shared::is_scene_version_compatible,
+    world::{SceneSelectionSets, SelectionSet},
+    AvailableEditableMaterials, GraniteType, TransformData,
 };
 use bevy::{
     ecs::{entity::Entity, system::ResMut, world::World},
@@ -37,6 +40,7 @@ pub fn deserialize_entities(
     materials: &mut ResMut<Assets<StandardMaterial>>,
     available_materials: &mut ResMut<AvailableEditableMaterials>,
     meshes: &mut ResMut<Assets<Mesh>>,
+    selection_sets: &mut ResMut<SceneSelectionSets>,
     path: impl Into<Cow<'static, str>>, //absolute or rel
     save_settings: SaveSettings,
     transform_override: Option<Transform>,
@@ -46,13 +50,19 @@ pub fn deserialize_entities(
     materials_from_folder_into_scene("materials", materials, available_materials, asset_server);
 
     // Gather file contents into a Vec<EntitySaveReadyData>
-    let deserialized_data = gather_file_contents(
+    let (deserialized_data, scene_selection_sets) = gather_file_contents(
         asset_server,
         materials,
         available_materials,
         abs_path.as_ref(),
     );
 
+    // Selection sets are keyed by the same relative source the entities are tagged with
+    selection_sets.set_for_source(
+        &absolute_asset_to_rel(abs_path.to_string()),
+        scene_selection_sets,
+    );
+
     // for id
     let mut uuid_to_entity_map: std::collections::HashMap<Uuid, Entity> =
         std::collections::HashMap::new();
@@ -157,12 +167,13 @@ pub fn deserialize_entities(
 }
 
 /// Gathers the file contents from the given path and deserializes them into EntitySaveReadyData
+/// Also returns any selection sets stored alongside the entities
 fn gather_file_contents(
     asset_server: &Res<AssetServer>,
     materials: &mut ResMut<Assets<StandardMaterial>>,
     available_materials: &mut ResMut<AvailableEditableMaterials>,
     path: &str,
-) -> Vec<EntitySaveReadyData> {
+) -> (Vec<EntitySaveReadyData>, Vec<SelectionSet>) {
     log!(
         LogType::Game,
         LogLevel::Info,
@@ -193,7 +204,7 @@ fn gather_file_contents(
                 path,
                 e
             );
-            return vec![];
+            return (vec![], vec![]);
         }
     };
 
@@ -207,7 +218,7 @@ fn gather_file_contents(
             path,
             e
         );
-        return vec![];
+        return (vec![], vec![]);
     }
 
     // Handle empty file
@@ -219,7 +230,7 @@ fn gather_file_contents(
             "No contents found in scene: {}",
             path
         );
-        return vec![];
+        return (vec![], vec![]);
     }
 
     // Handle whitespace-only files
@@ -231,7 +242,7 @@ fn gather_file_contents(
             "Only whitespace found in scene: {}",
             path
         );
-        return vec![];
+        return (vec![], vec![]);
     }
 
     // Handle empty JSON object or array
@@ -246,12 +257,12 @@ fn gather_file_contents(
         );
         // Still create materials even if no entities to deserialize
         materials_from_folder_into_scene("materials", materials, available_materials, asset_server);
-        return vec![];
+        return (vec![], vec![]);
     }
 
     // Attempt to deserialize with proper error handling
     // Try new format first (with metadata), fallback to old format (direct array)
-    let deserialized_data: Vec<EntitySaveReadyData> = if let Ok(scene_data) =
+    let (deserialized_data, selection_sets) = if let Ok(scene_data) =
         from_str::<SceneData>(&file_contents)
     {
         log!(
@@ -286,7 +297,7 @@ fn gather_file_contents(
                 );
         }
 
-        scene_data.entities
+        (scene_data.entities, scene_data.selection_sets)
     } else {
         log!(
             LogType::Game,
@@ -295,7 +306,7 @@ fn gather_file_contents(
             "Failed to deserialize data from {} - invalid format",
             path
         );
-        return vec![];
+        return (vec![], vec![]);
     };
 
     // Handle case where deserialization succeeded but resulted in empty vector
@@ -309,7 +320,7 @@ fn gather_file_contents(
         );
         // Still create materials even if no entities
         materials_from_folder_into_scene("materials", materials, available_materials, asset_server);
-        return vec![];
+        return (vec![], vec![]);
     }
 
     log!(
@@ -320,7 +331,7 @@ fn gather_file_contents(
         deserialized_data.len(),
         path
     );
-    deserialized_data
+    (deserialized_data, selection_sets)
 }
 
 /// Spawns the entity and returns the identity data and entity
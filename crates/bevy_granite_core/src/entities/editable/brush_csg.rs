@@ -0,0 +1,286 @@
+use super::brush_geometry::{BrushFace, BrushGeometry, FaceUv};
+use bevy::math::{Mat4, Vec3};
+
+const PLANE_EPSILON: f32 = 0.0005;
+
+/// Plane of a convex brush. A point is inside when normal.dot(point) <= distance
+/// Material and uv ride along so faces keep their look through CSG
+#[derive(Debug, Clone, PartialEq)]
+pub struct BrushPlane {
+    pub normal: Vec3,
+    pub distance: f32,
+    pub material: Option<String>,
+    pub uv: FaceUv,
+}
+
+impl BrushPlane {
+    pub fn new(normal: Vec3, distance: f32) -> Self {
+        Self {
+            normal,
+            distance,
+            material: None,
+            uv: FaceUv::default(),
+        }
+    }
+
+    pub fn signed_distance(&self, point: Vec3) -> f32 {
+        self.normal.dot(point) - self.distance
+    }
+
+    pub fn flipped(&self) -> Self {
+        Self {
+            normal: -self.normal,
+            distance: -self.distance,
+            ..self.clone()
+        }
+    }
+
+    fn same_as(&self, other: &BrushPlane) -> bool {
+        self.normal.dot(other.normal) > 1.0 - PLANE_EPSILON
+            && (self.distance - other.distance).abs() < PLANE_EPSILON * 10.0
+    }
+}
+
+impl BrushGeometry {
+    /// One plane per face, coplanar faces collapse into one
+    pub fn planes(&self) -> Vec<BrushPlane> {
+        let mut planes: Vec<BrushPlane> = Vec::new();
+        for (index, face) in self.faces.iter().enumerate() {
+            if face.indices.len() < 3 {
+                continue;
+            }
+            let normal = self.face_normal(index);
+            if normal == Vec3::ZERO {
+                continue;
+            }
+            let plane = BrushPlane {
+                normal,
+                distance: normal.dot(self.face_center(index)),
+                material: face.material.clone(),
+                uv: face.uv.clone(),
+            };
+            if !planes.iter().any(|existing| existing.same_as(&plane)) {
+                planes.push(plane);
+            }
+        }
+        planes
+    }
+
+    /// True when every vertex is behind every face plane
+    pub fn is_convex(&self) -> bool {
+        let planes = self.planes();
+        planes.len() >= 4
+            && self.vertices.iter().all(|vertex| {
+                planes
+                    .iter()
+                    .all(|plane| plane.signed_distance(*vertex) <= PLANE_EPSILON * 10.0)
+            })
+    }
+
+    /// Volume via the divergence theorem. Only meaningful for closed geometry
+    pub fn volume(&self) -> f32 {
+        let mut volume = 0.0;
+        for face in &self.faces {
+            if face.indices.len() < 3 {
+                continue;
+            }
+            let a = self.vertices[face.indices[0] as usize];
+            for i in 1..face.indices.len() - 1 {
+                let b = self.vertices[face.indices[i] as usize];
+                let c = self.vertices[face.indices[i + 1] as usize];
+                volume += a.dot(b.cross(c)) / 6.0;
+            }
+        }
+        volume
+    }
+
+    /// Move every vertex through a matrix. Flips winding if the matrix mirrors
+    pub fn transformed(&self, matrix: Mat4) -> BrushGeometry {
+        let mut geometry = self.clone();
+        for vertex in geometry.vertices.iter_mut() {
+            *vertex = matrix.transform_point3(*vertex);
+        }
+        if matrix.determinant() < 0.0 {
+            for face in geometry.faces.iter_mut() {
+                face.indices.reverse();
+            }
+        }
+        geometry
+    }
+
+    /// Build the convex polyhedron bounded by a set of planes
+    /// Returns None if the planes don't close a volume
+    pub fn from_planes(planes: &[BrushPlane]) -> Option<BrushGeometry> {
+        let mut vertices: Vec<Vec3> = Vec::new();
+
+        // Every triple of planes meeting in a point that is inside all other planes is a corner
+        for i in 0..planes.len() {
+            for j in (i + 1)..planes.len() {
+                for k in (j + 1)..planes.len() {
+                    let Some(point) = intersect_planes(&planes[i], &planes[j], &planes[k]) else {
+                        continue;
+                    };
+                    let inside = planes
+                        .iter()
+                        .all(|plane| plane.signed_distance(point) <= PLANE_EPSILON * 10.0);
+                    if inside
+                        && !vertices
+                            .iter()
+                            .any(|existing| existing.distance_squared(point) < PLANE_EPSILON)
+                    {
+                        vertices.push(point);
+                    }
+                }
+            }
+        }
+
+        let mut faces = Vec::new();
+        for plane in planes {
+            let mut on_plane: Vec<u32> = vertices
+                .iter()
+                .enumerate()
+                .filter(|(_, vertex)| plane.signed_distance(**vertex).abs() < PLANE_EPSILON * 10.0)
+                .map(|(index, _)| index as u32)
+                .collect();
+            if on_plane.len() < 3 {
+                continue;
+            }
+
+            // Sort counter-clockwise around the face normal
+            let center = on_plane.iter().map(|i| vertices[*i as usize]).sum::<Vec3>()
+                / on_plane.len() as f32;
+            let u = plane.normal.any_orthonormal_vector();
+            let v = plane.normal.cross(u);
+            on_plane.sort_by(|a, b| {
+                let pa = vertices[*a as usize] - center;
+                let pb = vertices[*b as usize] - center;
+                let angle_a = pa.dot(v).atan2(pa.dot(u));
+                let angle_b = pb.dot(v).atan2(pb.dot(u));
+                angle_a.total_cmp(&angle_b)
+            });
+
+            faces.push(BrushFace {
+                indices: on_plane,
+                material: plane.material.clone(),
+                uv: plane.uv.clone(),
+            });
+        }
+
+        if faces.len() < 4 {
+            return None;
+        }
+
+        let mut geometry = BrushGeometry { vertices, faces };
+        geometry.remove_unused_vertices();
+        if geometry.volume() <= PLANE_EPSILON {
+            return None;
+        }
+        Some(geometry)
+    }
+
+    /// Convex hull of a point cloud. Brute force, brushes only have a handful of points
+    pub fn convex_hull(points: &[Vec3]) -> Option<BrushGeometry> {
+        let mut planes: Vec<BrushPlane> = Vec::new();
+        for i in 0..points.len() {
+            for j in (i + 1)..points.len() {
+                for k in (j + 1)..points.len() {
+                    let normal = (points[j] - points[i])
+                        .cross(points[k] - points[i])
+                        .normalize_or_zero();
+                    if normal == Vec3::ZERO {
+                        continue;
+                    }
+                    let distance = normal.dot(points[i]);
+                    let plane = BrushPlane::new(normal, distance);
+
+                    let all_behind = points
+                        .iter()
+                        .all(|p| plane.signed_distance(*p) <= PLANE_EPSILON);
+                    let all_front = points
+                        .iter()
+                        .all(|p| plane.signed_distance(*p) >= -PLANE_EPSILON);
+
+                    let hull_plane = if all_behind {
+                        plane
+                    } else if all_front {
+                        plane.flipped()
+                    } else {
+                        continue;
+                    };
+                    if !planes.iter().any(|existing| existing.same_as(&hull_plane)) {
+                        planes.push(hull_plane);
+                    }
+                }
+            }
+        }
+        Self::from_planes(&planes)
+    }
+
+    /// Carve a convex cutter out of this convex brush
+    /// Returns None if the brushes don't overlap, otherwise the convex pieces left over
+    pub fn subtract(&self, cutter: &BrushGeometry) -> Option<Vec<BrushGeometry>> {
+        let own_planes = self.planes();
+        let cutter_planes: Vec<BrushPlane> = cutter
+            .planes()
+            .into_iter()
+            .map(|plane| BrushPlane {
+                material: None,
+                uv: FaceUv::default(),
+                ..plane
+            })
+            .collect();
+
+        let mut overlap = own_planes.clone();
+        overlap.extend(cutter_planes.iter().cloned());
+        Self::from_planes(&overlap)?;
+
+        // Peel one slab per cutter plane, the rest continues inside that plane
+        let mut remaining = own_planes;
+        let mut pieces = Vec::new();
+        for plane in cutter_planes {
+            let mut outside = remaining.clone();
+            outside.push(plane.flipped());
+            if let Some(piece) = Self::from_planes(&outside) {
+                pieces.push(piece);
+            }
+            remaining.push(plane);
+        }
+        Some(pieces)
+    }
+
+    /// Convex hull around several convex brushes. Faces that survive keep their material
+    pub fn merge(geometries: &[BrushGeometry]) -> Option<BrushGeometry> {
+        let points: Vec<Vec3> = geometries
+            .iter()
+            .flat_map(|geometry| geometry.vertices.iter().copied())
+            .collect();
+        let mut hull = Self::convex_hull(&points)?;
+
+        let source_planes: Vec<BrushPlane> = geometries
+            .iter()
+            .flat_map(|geometry| geometry.planes())
+            .collect();
+        for index in 0..hull.faces.len() {
+            let normal = hull.face_normal(index);
+            let plane = BrushPlane::new(normal, normal.dot(hull.face_center(index)));
+            if let Some(source) = source_planes.iter().find(|source| source.same_as(&plane)) {
+                hull.faces[index].material = source.material.clone();
+                hull.faces[index].uv = source.uv.clone();
+            }
+        }
+        Some(hull)
+    }
+}
+
+fn intersect_planes(a: &BrushPlane, b: &BrushPlane, c: &BrushPlane) -> Option<Vec3> {
+    let denominator = a.normal.dot(b.normal.cross(c.normal));
+    if denominator.abs() < 1e-6 {
+        return None;
+    }
+    Some(
+        (b.normal.cross(c.normal) * a.distance
+            + c.normal.cross(a.normal) * b.distance
+            + a.normal.cross(b.normal) * c.distance)
+            / denominator,
+    )
+}
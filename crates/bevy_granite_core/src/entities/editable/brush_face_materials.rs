@@ -0,0 +1,73 @@
+use crate::{
+    assets::material_from_path_into_scene, entities::TreeHiddenEntity, AvailableEditableMaterials,
+};
+use bevy::{
+    asset::{AssetServer, Assets, Handle},
+    ecs::{
+        component::Component,
+        entity::Entity,
+        system::{Commands, Res, ResMut},
+    },
+    mesh::{Mesh, Mesh3d},
+    pbr::{MeshMaterial3d, StandardMaterial},
+    prelude::{ChildOf, Name},
+    transform::components::Transform,
+};
+use bevy_granite_logging::{log, LogCategory, LogLevel, LogType};
+
+/// Child mesh that renders the faces of a brush using a material override
+/// Bevy only supports one material per mesh, so each override gets its own child
+/// These are rebuilt from class data and never serialized
+#[derive(Component)]
+pub struct BrushFaceMaterialGroup {
+    pub path: String,
+}
+
+/// Spawn one child mesh per face material override
+/// build_mesh returns the faces using a given override, so any brush shape can use this
+/// Faces whose material fails to load fall back to the brush material handle
+pub fn spawn_brush_face_material_groups(
+    commands: &mut Commands,
+    brush: Entity,
+    overrides: Vec<String>,
+    mut build_mesh: impl FnMut(&str) -> Mesh,
+    fallback: Handle<StandardMaterial>,
+    meshes: &mut ResMut<Assets<Mesh>>,
+    standard_materials: &mut ResMut<Assets<StandardMaterial>>,
+    available_materials: &mut ResMut<AvailableEditableMaterials>,
+    asset_server: &Res<AssetServer>,
+) {
+    for path in overrides {
+        let handle = match material_from_path_into_scene(
+            &path,
+            standard_materials,
+            available_materials,
+            asset_server,
+        )
+        .and_then(|material| material.handle)
+        {
+            Some(handle) => handle,
+            None => {
+                log!(
+                    LogType::Editor,
+                    LogLevel::Warning,
+                    LogCategory::Asset,
+                    "Brush face material '{}' could not be loaded, using brush material",
+                    path
+                );
+                fallback.clone()
+            }
+        };
+
+        let mesh = build_mesh(&path);
+        commands.spawn((
+            Mesh3d(meshes.add(mesh)),
+            MeshMaterial3d(handle),
+            Transform::default(),
+            BrushFaceMaterialGroup { path: path.clone() },
+            TreeHiddenEntity,
+            ChildOf(brush),
+            Name::new(format!("Brush Faces ({})", path)),
+        ));
+    }
+}
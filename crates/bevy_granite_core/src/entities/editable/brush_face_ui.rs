@@ -0,0 +1,95 @@
+use super::brush_geometry::FaceUv;
+use bevy_egui::egui;
+
+/// Material path and UV placement widgets for a single brush face
+/// Shared by every brush class that supports per face materials
+/// Returns true when anything changed
+pub fn edit_brush_face_ui(
+    ui: &mut egui::Ui,
+    id_salt: &str,
+    material: &mut Option<String>,
+    uv: &mut FaceUv,
+    drag_size: [f32; 2],
+    small_spacing: f32,
+) -> bool {
+    let mut changed = false;
+
+    // Empty path means the face uses the brush material
+    let mut path = material.clone().unwrap_or_default();
+    ui.horizontal(|ui| {
+        ui.label("Material:");
+        let response = ui.add(egui::TextEdit::singleline(&mut path).hint_text("Brush material"));
+        if response.lost_focus() {
+            let new_material = (!path.trim().is_empty()).then(|| path.trim().to_string());
+            if new_material != *material {
+                *material = new_material;
+                changed = true;
+            }
+        }
+    });
+    ui.add_space(small_spacing);
+
+    egui::Grid::new(format!("{}_face_uv_grid", id_salt))
+        .num_columns(3)
+        .spacing([1.0, 2.0])
+        .show(ui, |ui| {
+            ui.label("Offset");
+            changed |= ui
+                .add_sized(
+                    drag_size,
+                    egui::DragValue::new(&mut uv.offset.x)
+                        .speed(0.01)
+                        .fixed_decimals(2),
+                )
+                .changed();
+            changed |= ui
+                .add_sized(
+                    drag_size,
+                    egui::DragValue::new(&mut uv.offset.y)
+                        .speed(0.01)
+                        .fixed_decimals(2),
+                )
+                .changed();
+            ui.end_row();
+
+            ui.label("Scale");
+            changed |= ui
+                .add_sized(
+                    drag_size,
+                    egui::DragValue::new(&mut uv.scale.x)
+                        .speed(0.01)
+                        .fixed_decimals(2),
+                )
+                .changed();
+            changed |= ui
+                .add_sized(
+                    drag_size,
+                    egui::DragValue::new(&mut uv.scale.y)
+                        .speed(0.01)
+                        .fixed_decimals(2),
+                )
+                .changed();
+            ui.end_row();
+
+            ui.label("Rotation");
+            changed |= ui
+                .add_sized(
+                    drag_size,
+                    egui::DragValue::new(&mut uv.rotation)
+                        .speed(1.0)
+                        .suffix("°")
+                        .fixed_decimals(1),
+                )
+                .changed();
+            if ui
+                .add_sized(drag_size, egui::Button::new("Reset"))
+                .clicked()
+            {
+                *uv = FaceUv::default();
+                changed = true;
+            }
+            ui.end_row();
+        });
+
+    changed
+}
@@ -0,0 +1,455 @@
+use bevy::{
+    asset::RenderAssetUsages,
+    math::{Mat4, Vec2, Vec3},
+    mesh::{Indices, Mesh, PrimitiveTopology},
+    prelude::Reflect,
+};
+use serde::{Deserialize, Serialize};
+use std::collections::{HashMap, HashSet};
+
+/// Texture placement of a single brush face
+/// Rotation is in degrees. Scale multiplies the brush uv_scale, so bigger means more repeats
+#[derive(Serialize, Deserialize, Reflect, Debug, Clone, PartialEq)]
+pub struct FaceUv {
+    pub offset: Vec2,
+    pub scale: Vec2,
+    pub rotation: f32,
+}
+
+impl Default for FaceUv {
+    fn default() -> Self {
+        Self {
+            offset: Vec2::ZERO,
+            scale: Vec2::ONE,
+            rotation: 0.0,
+        }
+    }
+}
+
+impl FaceUv {
+    pub fn is_default(&self) -> bool {
+        *self == Self::default()
+    }
+
+    /// Apply rotation, scale and offset to projected coordinates
+    pub fn apply(&self, projected: Vec2, uv_scale: Vec2) -> Vec2 {
+        let rotated = Vec2::from_angle(self.rotation.to_radians()).rotate(projected);
+        rotated * uv_scale * self.scale + self.offset
+    }
+}
+
+/// A single polygon of a brush
+/// Indices point into BrushGeometry::vertices and wind counter-clockwise when viewed from outside
+/// Material is a relative .mat path. None uses the brush's own material
+#[derive(Serialize, Deserialize, Reflect, Debug, Clone, PartialEq, Default)]
+pub struct BrushFace {
+    pub indices: Vec<u32>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub material: Option<String>,
+    #[serde(default, skip_serializing_if = "FaceUv::is_default")]
+    pub uv: FaceUv,
+}
+
+impl BrushFace {
+    pub fn new(indices: Vec<u32>) -> Self {
+        Self {
+            indices,
+            ..Default::default()
+        }
+    }
+}
+
+/// Editable polygon geometry for brush classes
+/// Vertices are unique (welded) local positions, faces are n-gons over them
+/// This is what gets stored inside the class data once a brush has been mesh edited
+#[derive(Serialize, Deserialize, Reflect, Debug, Clone, PartialEq, Default)]
+pub struct BrushGeometry {
+    pub vertices: Vec<Vec3>,
+    pub faces: Vec<BrushFace>,
+}
+
+/// Mesh editing operations that can be applied to BrushGeometry
+#[derive(Debug, Clone, PartialEq)]
+pub enum BrushEditOperation {
+    /// Move vertices by a local space offset
+    TranslateVertices { vertices: Vec<u32>, delta: Vec3 },
+    /// Extrude a connected region of faces along their averaged normals
+    ExtrudeFaces { faces: Vec<usize>, distance: f32 },
+    /// Insert a vertex in the middle of the edge a-b on every face that uses it
+    SplitEdge { a: u32, b: u32 },
+    /// Remove faces and any vertex no longer used
+    DeleteFaces { faces: Vec<usize> },
+}
+
+impl BrushEditOperation {
+    pub fn description(&self) -> String {
+        match self {
+            Self::TranslateVertices { vertices, .. } => {
+                format!("Move {} brush vertices", vertices.len())
+            }
+            Self::ExtrudeFaces { faces, .. } => format!("Extrude {} brush faces", faces.len()),
+            Self::SplitEdge { .. } => "Split brush edge".to_string(),
+            Self::DeleteFaces { faces } => format!("Delete {} brush faces", faces.len()),
+        }
+    }
+}
+
+impl BrushGeometry {
+    /// Axis aligned box centered at origin. Matches the shape of a default RectBrush
+    pub fn from_box(size: Vec3) -> Self {
+        let h = size / 2.0;
+        let vertices = vec![
+            Vec3::new(-h.x, -h.y, h.z),
+            Vec3::new(h.x, -h.y, h.z),
+            Vec3::new(h.x, h.y, h.z),
+            Vec3::new(-h.x, h.y, h.z),
+            Vec3::new(-h.x, -h.y, -h.z),
+            Vec3::new(h.x, -h.y, -h.z),
+            Vec3::new(h.x, h.y, -h.z),
+            Vec3::new(-h.x, h.y, -h.z),
+        ];
+        let faces = [
+            [0, 1, 2, 3], // Front
+            [5, 4, 7, 6], // Back
+            [1, 5, 6, 2], // Right
+            [4, 0, 3, 7], // Left
+            [3, 2, 6, 7], // Top
+            [4, 5, 1, 0], // Bottom
+        ]
+        .into_iter()
+        .map(|indices| BrushFace::new(indices.to_vec()))
+        .collect();
+
+        Self { vertices, faces }
+    }
+
+    /// Newell normal, works for any planar-ish polygon
+    pub fn face_normal(&self, face: usize) -> Vec3 {
+        let Some(face) = self.faces.get(face) else {
+            return Vec3::ZERO;
+        };
+        let mut normal = Vec3::ZERO;
+        let count = face.indices.len();
+        for i in 0..count {
+            let current = self.vertices[face.indices[i] as usize];
+            let next = self.vertices[face.indices[(i + 1) % count] as usize];
+            normal.x += (current.y - next.y) * (current.z + next.z);
+            normal.y += (current.z - next.z) * (current.x + next.x);
+            normal.z += (current.x - next.x) * (current.y + next.y);
+        }
+        normal.normalize_or_zero()
+    }
+
+    pub fn face_center(&self, face: usize) -> Vec3 {
+        let Some(face) = self.faces.get(face) else {
+            return Vec3::ZERO;
+        };
+        if face.indices.is_empty() {
+            return Vec3::ZERO;
+        }
+        face.indices
+            .iter()
+            .map(|i| self.vertices[*i as usize])
+            .sum::<Vec3>()
+            / face.indices.len() as f32
+    }
+
+    /// Faces whose every vertex is inside the given set
+    pub fn faces_from_vertices(&self, vertices: &[u32]) -> Vec<usize> {
+        let selected: HashSet<u32> = vertices.iter().copied().collect();
+        self.faces
+            .iter()
+            .enumerate()
+            .filter(|(_, face)| {
+                !face.indices.is_empty() && face.indices.iter().all(|i| selected.contains(i))
+            })
+            .map(|(index, _)| index)
+            .collect()
+    }
+
+    /// True if a and b are neighbours on any face
+    pub fn has_edge(&self, a: u32, b: u32) -> bool {
+        self.faces.iter().any(|face| {
+            let count = face.indices.len();
+            (0..count).any(|i| {
+                let (v0, v1) = (face.indices[i], face.indices[(i + 1) % count]);
+                (v0 == a && v1 == b) || (v0 == b && v1 == a)
+            })
+        })
+    }
+
+    /// Apply an edit and return the resulting geometry. Self is left untouched
+    pub fn apply(&self, operation: &BrushEditOperation) -> Result<BrushGeometry, String> {
+        let mut geometry = self.clone();
+        match operation {
+            BrushEditOperation::TranslateVertices { vertices, delta } => {
+                geometry.translate_vertices(vertices, *delta)?
+            }
+            BrushEditOperation::ExtrudeFaces { faces, distance } => {
+                geometry.extrude_faces(faces, *distance)?
+            }
+            BrushEditOperation::SplitEdge { a, b } => {
+                geometry.split_edge(*a, *b)?;
+            }
+            BrushEditOperation::DeleteFaces { faces } => geometry.delete_faces(faces)?,
+        }
+        Ok(geometry)
+    }
+
+    fn translate_vertices(&mut self, vertices: &[u32], delta: Vec3) -> Result<(), String> {
+        if vertices.is_empty() {
+            return Err("No vertices to move".to_string());
+        }
+        let unique: HashSet<u32> = vertices.iter().copied().collect();
+        for index in unique {
+            let Some(vertex) = self.vertices.get_mut(index as usize) else {
+                return Err(format!("Vertex {} does not exist", index));
+            };
+            *vertex += delta;
+        }
+        Ok(())
+    }
+
+    fn extrude_faces(&mut self, faces: &[usize], distance: f32) -> Result<(), String> {
+        let faces: Vec<usize> = faces
+            .iter()
+            .copied()
+            .collect::<HashSet<_>>()
+            .into_iter()
+            .collect();
+        if faces.is_empty() {
+            return Err("No faces to extrude".to_string());
+        }
+        if let Some(missing) = faces.iter().find(|f| **f >= self.faces.len()) {
+            return Err(format!("Face {} does not exist", missing));
+        }
+
+        // Offset direction per vertex is the average of the selected face normals around it
+        let mut vertex_offsets: HashMap<u32, Vec3> = HashMap::new();
+        for face in &faces {
+            let normal = self.face_normal(*face);
+            for index in &self.faces[*face].indices {
+                *vertex_offsets.entry(*index).or_default() += normal;
+            }
+        }
+
+        // Duplicate every vertex used by the region
+        let mut remap: HashMap<u32, u32> = HashMap::new();
+        let mut sorted: Vec<u32> = vertex_offsets.keys().copied().collect();
+        sorted.sort();
+        for old in sorted {
+            let offset = vertex_offsets[&old].normalize_or_zero() * distance;
+            let new_index = self.vertices.len() as u32;
+            self.vertices.push(self.vertices[old as usize] + offset);
+            remap.insert(old, new_index);
+        }
+
+        // Edges shared by two selected faces are interior and get no side wall
+        let mut directed_edges: HashSet<(u32, u32)> = HashSet::new();
+        for face in &faces {
+            let indices = &self.faces[*face].indices;
+            for i in 0..indices.len() {
+                directed_edges.insert((indices[i], indices[(i + 1) % indices.len()]));
+            }
+        }
+
+        let mut side_walls = Vec::new();
+        for face in &faces {
+            let indices = self.faces[*face].indices.clone();
+            for i in 0..indices.len() {
+                let (a, b) = (indices[i], indices[(i + 1) % indices.len()]);
+                if directed_edges.contains(&(b, a)) {
+                    continue;
+                }
+                // Side walls inherit the look of the face they were pulled from
+                side_walls.push(BrushFace {
+                    indices: vec![a, b, remap[&b], remap[&a]],
+                    material: self.faces[*face].material.clone(),
+                    uv: self.faces[*face].uv.clone(),
+                });
+            }
+            self.faces[*face].indices = indices.iter().map(|i| remap[i]).collect();
+        }
+        self.faces.extend(side_walls);
+
+        Ok(())
+    }
+
+    /// Returns the index of the new vertex
+    fn split_edge(&mut self, a: u32, b: u32) -> Result<u32, String> {
+        if a == b || !self.has_edge(a, b) {
+            return Err(format!("Vertices {} and {} do not share an edge", a, b));
+        }
+
+        let midpoint = (self.vertices[a as usize] + self.vertices[b as usize]) / 2.0;
+        let new_index = self.vertices.len() as u32;
+        self.vertices.push(midpoint);
+
+        for face in self.faces.iter_mut() {
+            let count = face.indices.len();
+            let mut insert_at = None;
+            for i in 0..count {
+                let (v0, v1) = (face.indices[i], face.indices[(i + 1) % count]);
+                if (v0 == a && v1 == b) || (v0 == b && v1 == a) {
+                    insert_at = Some(i + 1);
+                    break;
+                }
+            }
+            if let Some(position) = insert_at {
+                face.indices.insert(position, new_index);
+            }
+        }
+
+        Ok(new_index)
+    }
+
+    fn delete_faces(&mut self, faces: &[usize]) -> Result<(), String> {
+        let to_remove: HashSet<usize> = faces.iter().copied().collect();
+        if to_remove.is_empty() {
+            return Err("No faces to delete".to_string());
+        }
+        if let Some(missing) = to_remove.iter().find(|f| **f >= self.faces.len()) {
+            return Err(format!("Face {} does not exist", missing));
+        }
+
+        self.faces = std::mem::take(&mut self.faces)
+            .into_iter()
+            .enumerate()
+            .filter(|(index, _)| !to_remove.contains(index))
+            .map(|(_, face)| face)
+            .collect();
+        self.remove_unused_vertices();
+        Ok(())
+    }
+
+    /// Drop vertices no face references and re-index faces
+    pub fn remove_unused_vertices(&mut self) {
+        let used: HashSet<u32> = self
+            .faces
+            .iter()
+            .flat_map(|face| face.indices.iter().copied())
+            .collect();
+
+        let mut remap: HashMap<u32, u32> = HashMap::new();
+        let mut vertices = Vec::with_capacity(used.len());
+        for (index, vertex) in self.vertices.iter().enumerate() {
+            if used.contains(&(index as u32)) {
+                remap.insert(index as u32, vertices.len() as u32);
+                vertices.push(*vertex);
+            }
+        }
+
+        for face in self.faces.iter_mut() {
+            for index in face.indices.iter_mut() {
+                *index = remap[index];
+            }
+        }
+        self.vertices = vertices;
+    }
+
+    /// Append another geometry. Used to build compound (non convex) shapes out of convex parts
+    pub fn append(&mut self, other: &BrushGeometry) {
+        let base = self.vertices.len() as u32;
+        self.vertices.extend_from_slice(&other.vertices);
+        self.faces.extend(other.faces.iter().map(|face| BrushFace {
+            indices: face.indices.iter().map(|i| i + base).collect(),
+            material: face.material.clone(),
+            uv: face.uv.clone(),
+        }));
+    }
+
+    /// Every distinct per-face material override, sorted
+    pub fn material_overrides(&self) -> Vec<String> {
+        let mut paths: Vec<String> = self
+            .faces
+            .iter()
+            .filter_map(|face| face.material.clone())
+            .collect();
+        paths.sort();
+        paths.dedup();
+        paths
+    }
+
+    /// Box projected coordinates for a local position on a face with the given normal
+    pub fn project_uv(position: Vec3, normal: Vec3) -> Vec2 {
+        let abs = normal.abs();
+        if abs.x >= abs.y && abs.x >= abs.z {
+            Vec2::new(-position.z * normal.x.signum(), -position.y)
+        } else if abs.y >= abs.z {
+            Vec2::new(position.x, position.z * normal.y.signum())
+        } else {
+            Vec2::new(position.x * normal.z.signum(), -position.y)
+        }
+    }
+
+    /// Flat shaded triangle mesh of the faces that use the brush material
+    pub fn to_mesh(&self, uv_scale: Vec2) -> Mesh {
+        self.to_mesh_for_material(uv_scale, None, Mat4::IDENTITY)
+    }
+
+    /// Flat shaded triangle mesh of the faces using a material override (None = brush material)
+    /// UVs are projected in uv_space. Identity gives local UVs, the world matrix gives locked UVs
+    pub fn to_mesh_for_material(
+        &self,
+        uv_scale: Vec2,
+        material: Option<&str>,
+        uv_space: Mat4,
+    ) -> Mesh {
+        let mut mesh = Mesh::new(
+            PrimitiveTopology::TriangleList,
+            RenderAssetUsages::default(),
+        );
+        self.write_faces_to_mesh(&mut mesh, uv_scale, material, uv_space);
+        mesh
+    }
+
+    /// Replace positions, normals, uvs and indices of an existing mesh with the brush faces
+    pub fn write_to_mesh(&self, mesh: &mut Mesh, uv_scale: Vec2) {
+        self.write_faces_to_mesh(mesh, uv_scale, None, Mat4::IDENTITY);
+    }
+
+    /// Each face gets its own vertices so normals stay sharp
+    pub fn write_faces_to_mesh(
+        &self,
+        mesh: &mut Mesh,
+        uv_scale: Vec2,
+        material: Option<&str>,
+        uv_space: Mat4,
+    ) {
+        let mut positions: Vec<[f32; 3]> = Vec::new();
+        let mut normals: Vec<[f32; 3]> = Vec::new();
+        let mut uvs: Vec<[f32; 2]> = Vec::new();
+        let mut indices: Vec<u32> = Vec::new();
+
+        for (face_index, face) in self.faces.iter().enumerate() {
+            if face.indices.len() < 3 || face.material.as_deref() != material {
+                continue;
+            }
+            let normal = self.face_normal(face_index);
+            let uv_normal = uv_space.transform_vector3(normal).normalize_or_zero();
+            let base = positions.len() as u32;
+            for index in &face.indices {
+                let position = self.vertices[*index as usize];
+                positions.push(position.to_array());
+                normals.push(normal.to_array());
+                let projected = Self::project_uv(uv_space.transform_point3(position), uv_normal);
+                uvs.push(face.uv.apply(projected, uv_scale).to_array());
+            }
+            // Fan triangulation. Fine for convex faces which is what brush editing produces
+            for i in 1..(face.indices.len() as u32 - 1) {
+                indices.extend_from_slice(&[base, base + i, base + i + 1]);
+            }
+        }
+
+        mesh.insert_attribute(Mesh::ATTRIBUTE_POSITION, positions);
+        mesh.insert_attribute(Mesh::ATTRIBUTE_NORMAL, normals);
+        mesh.insert_attribute(Mesh::ATTRIBUTE_UV_0, uvs);
+        mesh.insert_indices(Indices::U32(indices));
+
+        // Tangents are stale after a topology change
+        if mesh.contains_attribute(Mesh::ATTRIBUTE_TANGENT) {
+            mesh.remove_attribute(Mesh::ATTRIBUTE_TANGENT);
+            let _ = mesh.generate_tangents();
+        }
+    }
+}
@@ -0,0 +1,580 @@
+use super::{GraniteType, GraniteTypes, RequestEntityUpdateFromClass};
+use crate::{
+    entities::{BrushGeometry, EntitySaveReadyData},
+    AvailableEditableMaterials, ClassCategory, GraniteEditorSerdeEntity, HasRuntimeData,
+    IdentityData, PromptData, RequiredMaterialData, RequiredMaterialDataMut,
+};
+use bevy::{
+    app::App,
+    asset::{AssetServer, Assets, Handle},
+    camera::visibility::Visibility,
+    ecs::{
+        entity::Entity,
+        message::Message,
+        system::{Commands, Res, ResMut},
+    },
+    mesh::Mesh,
+    pbr::StandardMaterial,
+    prelude::{Image, Name, Reflect},
+    transform::components::Transform,
+};
+use bevy_egui::egui;
+use bevy_granite_logging::{log, LogCategory, LogLevel, LogType};
+use serde::{de::DeserializeOwned, Deserialize, Serialize};
+use std::{
+    any::{Any, TypeId},
+    fmt::Debug,
+    sync::Mutex,
+};
+use uuid::Uuid;
+
+// Registered classes live in a global so GraniteTypes::all() and scene deserialization,
+// neither of which can see the World, still know about them
+lazy_static::lazy_static! {
+    static ref CUSTOM_CLASS_REGISTRY: Mutex<Vec<CustomClassRegistration>> = Mutex::new(Vec::new());
+}
+
+/// Bounds a class type from another crate needs to be registered
+pub trait CustomGraniteClass:
+    GraniteType
+    + Serialize
+    + DeserializeOwned
+    + Default
+    + Clone
+    + PartialEq
+    + Debug
+    + Send
+    + Sync
+    + 'static
+{
+}
+impl<T> CustomGraniteClass for T where
+    T: GraniteType
+        + Serialize
+        + DeserializeOwned
+        + Default
+        + Clone
+        + PartialEq
+        + Debug
+        + Send
+        + Sync
+        + 'static
+{
+}
+
+/// Object safe side of a custom class so it can sit inside GraniteTypes
+trait ErasedGraniteClass: GraniteType + Send + Sync {
+    fn clone_box(&self) -> Box<dyn ErasedGraniteClass>;
+    fn eq_dyn(&self, other: &dyn ErasedGraniteClass) -> bool;
+    fn as_any(&self) -> &dyn Any;
+    fn as_any_mut(&mut self) -> &mut dyn Any;
+    fn to_ron(&self) -> Result<String, ron::Error>;
+    fn debug(&self) -> String;
+}
+
+impl<T: CustomGraniteClass> ErasedGraniteClass for T {
+    fn clone_box(&self) -> Box<dyn ErasedGraniteClass> {
+        Box::new(self.clone())
+    }
+
+    fn eq_dyn(&self, other: &dyn ErasedGraniteClass) -> bool {
+        other
+            .as_any()
+            .downcast_ref::<T>()
+            .is_some_and(|other| self == other)
+    }
+
+    fn as_any(&self) -> &dyn Any {
+        self
+    }
+
+    fn as_any_mut(&mut self) -> &mut dyn Any {
+        self
+    }
+
+    fn to_ron(&self) -> Result<String, ron::Error> {
+        ron::to_string(self)
+    }
+
+    fn debug(&self) -> String {
+        format!("{:?}", self)
+    }
+}
+
+#[derive(Clone)]
+struct CustomClassRegistration {
+    type_key: &'static str,
+    type_id: TypeId,
+    default: fn() -> Box<dyn ErasedGraniteClass>,
+    from_ron: fn(&str) -> Result<Box<dyn ErasedGraniteClass>, ron::error::SpannedError>,
+}
+
+fn default_erased<T: CustomGraniteClass>() -> Box<dyn ErasedGraniteClass> {
+    Box::new(T::default())
+}
+
+fn from_ron_erased<T: CustomGraniteClass>(
+    data: &str,
+) -> Result<Box<dyn ErasedGraniteClass>, ron::error::SpannedError> {
+    ron::from_str::<T>(data).map(|class| Box::new(class) as Box<dyn ErasedGraniteClass>)
+}
+
+fn registration_by_key(type_key: &str) -> Option<CustomClassRegistration> {
+    CUSTOM_CLASS_REGISTRY
+        .lock()
+        .unwrap()
+        .iter()
+        .find(|registration| registration.type_key == type_key)
+        .cloned()
+}
+
+fn registration_by_type<T: 'static>() -> Option<CustomClassRegistration> {
+    CUSTOM_CLASS_REGISTRY
+        .lock()
+        .unwrap()
+        .iter()
+        .find(|registration| registration.type_id == TypeId::of::<T>())
+        .cloned()
+}
+
+/// Register class types that live outside Granite so they can be added, edited, saved and loaded
+/// like the built in ones. The type key is what scene files store, so keep it stable between
+/// releases, e.g. "my_game::SpawnPoint"
+pub trait RegisterGraniteClass {
+    fn register_granite_class<T: CustomGraniteClass>(
+        &mut self,
+        type_key: &'static str,
+    ) -> &mut Self;
+}
+
+impl RegisterGraniteClass for App {
+    fn register_granite_class<T: CustomGraniteClass>(
+        &mut self,
+        type_key: &'static str,
+    ) -> &mut Self {
+        let mut registry = CUSTOM_CLASS_REGISTRY.lock().unwrap();
+        if let Some(existing) = registry
+            .iter()
+            .find(|registration| registration.type_key == type_key)
+        {
+            if existing.type_id != TypeId::of::<T>() {
+                log!(
+                    LogType::Game,
+                    LogLevel::Error,
+                    LogCategory::Entity,
+                    "Granite class key '{}' is already registered to another type, skipping",
+                    type_key
+                );
+            }
+            return self;
+        }
+        registry.push(CustomClassRegistration {
+            type_key,
+            type_id: TypeId::of::<T>(),
+            default: default_erased::<T>,
+            from_ron: from_ron_erased::<T>,
+        });
+        log!(
+            LogType::Game,
+            LogLevel::OK,
+            LogCategory::Entity,
+            "Registered Granite class '{}'",
+            type_key
+        );
+        drop(registry);
+        self
+    }
+}
+
+/// Sent by custom classes on UI edits. Downstream crates read it and downcast to their type
+/// with `class.downcast_ref::<T>()` to update the entity
+#[derive(Message, Clone)]
+pub struct UserUpdatedCustomClassEvent {
+    pub entity: Entity,
+    pub class: CustomClass,
+}
+
+// Scene files store the key and the class's own RON so unregistered classes still round trip
+#[derive(Serialize, Deserialize, Clone)]
+struct CustomClassData {
+    type_key: String,
+    data: String,
+}
+
+/// A class type registered from another crate, stored inside `GraniteTypes::Custom`
+#[derive(Reflect, Serialize, Deserialize)]
+#[reflect(opaque)]
+#[serde(into = "CustomClassData", from = "CustomClassData")]
+pub struct CustomClass {
+    type_key: String,
+    // None when the scene names a class this app never registered
+    class: Option<Box<dyn ErasedGraniteClass>>,
+    // Last known RON, kept so unregistered data is written back untouched
+    raw: String,
+}
+
+impl CustomClass {
+    /// Wrap a registered class. None if `T` was never registered
+    pub fn new<T: CustomGraniteClass>(class: T) -> Option<Self> {
+        let registration = registration_by_type::<T>()?;
+        Some(Self {
+            type_key: registration.type_key.to_string(),
+            class: Some(Box::new(class)),
+            raw: String::new(),
+        })
+    }
+
+    /// Every registered class in its default state, in registration order
+    pub fn all() -> Vec<CustomClass> {
+        CUSTOM_CLASS_REGISTRY
+            .lock()
+            .unwrap()
+            .iter()
+            .map(|registration| Self {
+                type_key: registration.type_key.to_string(),
+                class: Some((registration.default)()),
+                raw: String::new(),
+            })
+            .collect()
+    }
+
+    pub fn type_key(&self) -> &str {
+        &self.type_key
+    }
+
+    pub fn is_registered(&self) -> bool {
+        self.class.is_some()
+    }
+
+    pub fn downcast_ref<T: CustomGraniteClass>(&self) -> Option<&T> {
+        self.class.as_ref()?.as_any().downcast_ref::<T>()
+    }
+
+    pub fn downcast_mut<T: CustomGraniteClass>(&mut self) -> Option<&mut T> {
+        self.class.as_mut()?.as_any_mut().downcast_mut::<T>()
+    }
+
+    // Unregistered classes still get an entity holding their identity so saving writes them back
+    fn spawn_placeholder(
+        identity: IdentityData,
+        commands: &mut Commands,
+        transform: Transform,
+    ) -> Entity {
+        commands
+            .spawn((
+                Visibility::default(),
+                transform,
+                Name::new(identity.name.clone()),
+                HasRuntimeData,
+                GraniteEditorSerdeEntity,
+                identity,
+            ))
+            .id()
+    }
+}
+
+impl From<CustomClassData> for CustomClass {
+    fn from(data: CustomClassData) -> Self {
+        let class = match registration_by_key(&data.type_key) {
+            Some(registration) => match (registration.from_ron)(&data.data) {
+                Ok(class) => Some(class),
+                Err(error) => {
+                    log!(
+                        LogType::Game,
+                        LogLevel::Error,
+                        LogCategory::Entity,
+                        "Failed to read Granite class '{}': {}",
+                        data.type_key,
+                        error
+                    );
+                    None
+                }
+            },
+            None => {
+                log!(
+                    LogType::Game,
+                    LogLevel::Warning,
+                    LogCategory::Entity,
+                    "Granite class '{}' is not registered, its entities load as placeholders",
+                    data.type_key
+                );
+                None
+            }
+        };
+        Self {
+            type_key: data.type_key,
+            class,
+            raw: data.data,
+        }
+    }
+}
+
+impl From<CustomClass> for CustomClassData {
+    fn from(custom: CustomClass) -> Self {
+        let data = custom
+            .class
+            .as_ref()
+            .and_then(|class| class.to_ron().ok())
+            .unwrap_or(custom.raw);
+        Self {
+            type_key: custom.type_key,
+            data,
+        }
+    }
+}
+
+impl Clone for CustomClass {
+    fn clone(&self) -> Self {
+        Self {
+            type_key: self.type_key.clone(),
+            class: self.class.as_ref().map(|class| class.clone_box()),
+            raw: self.raw.clone(),
+        }
+    }
+}
+
+impl PartialEq for CustomClass {
+    fn eq(&self, other: &Self) -> bool {
+        if self.type_key != other.type_key {
+            return false;
+        }
+        match (&self.class, &other.class) {
+            (Some(class), Some(other_class)) => class.eq_dyn(other_class.as_ref()),
+            (None, None) => self.raw == other.raw,
+            _ => false,
+        }
+    }
+}
+
+impl Debug for CustomClass {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        let data = match &self.class {
+            Some(class) => class.debug(),
+            None => self.raw.clone(),
+        };
+        f.debug_struct("CustomClass")
+            .field("type_key", &self.type_key)
+            .field("class", &data)
+            .finish()
+    }
+}
+
+impl Default for CustomClass {
+    fn default() -> Self {
+        Self {
+            type_key: String::new(),
+            class: None,
+            raw: String::new(),
+        }
+    }
+}
+
+// Everything forwards to the registered class, unregistered ones behave like Unknown
+impl GraniteType for CustomClass {
+    fn category(&self) -> ClassCategory {
+        self.class
+            .as_ref()
+            .map_or(ClassCategory::Unknown, |class| class.category())
+    }
+
+    fn type_name(&self) -> String {
+        self.class.as_ref().map_or_else(
+            || format!("{} (unregistered)", self.type_key),
+            |class| class.type_name(),
+        )
+    }
+
+    fn type_abv(&self) -> String {
+        self.class
+            .as_ref()
+            .map_or_else(|| self.type_key.clone(), |class| class.type_abv())
+    }
+
+    fn needs_prompt(&self) -> bool {
+        self.class
+            .as_ref()
+            .is_some_and(|class| class.needs_prompt())
+    }
+
+    fn get_prompt_config(&self) -> (String, Vec<&'static str>) {
+        self.class.as_ref().map_or_else(
+            || ("".to_string(), vec!["*"]),
+            |class| class.get_prompt_config(),
+        )
+    }
+
+    fn get_embedded_icon_bytes(&self) -> Option<&'static [u8]> {
+        self.class.as_ref()?.get_embedded_icon_bytes()
+    }
+
+    fn get_icon_filename(&self) -> Option<&'static str> {
+        self.class.as_ref()?.get_icon_filename()
+    }
+
+    fn icon_handle(&self) -> Handle<Image> {
+        match &self.class {
+            Some(class) => class.icon_handle(),
+            None => Handle::default(),
+        }
+    }
+
+    fn spawn_from_new_identity(
+        &mut self,
+        commands: &mut Commands,
+        transform: Transform,
+        standard_materials: ResMut<Assets<StandardMaterial>>,
+        meshes: ResMut<Assets<Mesh>>,
+        available_materials: ResMut<AvailableEditableMaterials>,
+        asset_server: Res<AssetServer>,
+        maybe_prompt_data: Option<PromptData>,
+    ) -> Entity {
+        match self.class.as_mut() {
+            Some(class) => class.spawn_from_new_identity(
+                commands,
+                transform,
+                standard_materials,
+                meshes,
+                available_materials,
+                asset_server,
+                maybe_prompt_data,
+            ),
+            None => {
+                let identity = IdentityData {
+                    name: self.type_name(),
+                    uuid: Uuid::new_v4(),
+                    class: GraniteTypes::Custom(self.clone()),
+                };
+                Self::spawn_placeholder(identity, commands, transform)
+            }
+        }
+    }
+
+    fn spawn_from_save_data(
+        &self,
+        save_data: &EntitySaveReadyData,
+        commands: &mut Commands,
+        standard_materials: &mut ResMut<Assets<StandardMaterial>>,
+        meshes: &mut ResMut<Assets<Mesh>>,
+        available_materials: &mut ResMut<AvailableEditableMaterials>,
+        asset_server: &Res<AssetServer>,
+    ) -> Entity {
+        match self.class.as_ref() {
+            Some(class) => class.spawn_from_save_data(
+                save_data,
+                commands,
+                standard_materials,
+                meshes,
+                available_materials,
+                asset_server,
+            ),
+            None => Self::spawn_placeholder(
+                save_data.identity.clone(),
+                commands,
+                save_data.transform.to_bevy(),
+            ),
+        }
+    }
+
+    fn push_to_entity(&self, entity: Entity, request_update: &mut RequestEntityUpdateFromClass) {
+        let Some(class) = self.class.as_ref() else {
+            return;
+        };
+        request_update.custom.write(UserUpdatedCustomClassEvent {
+            entity,
+            class: self.clone(),
+        });
+        class.push_to_entity(entity, request_update);
+    }
+
+    fn edit_via_ui(&mut self, ui: &mut egui::Ui, spacing: (f32, f32, f32)) -> bool {
+        match self.class.as_mut() {
+            Some(class) => class.edit_via_ui(ui, spacing),
+            None => {
+                ui.label(format!(
+                    "'{}' isn't registered in this app, its data is kept as is",
+                    self.type_key
+                ));
+                false
+            }
+        }
+    }
+
+    fn get_material_data(&self) -> Option<RequiredMaterialData> {
+        self.class.as_ref()?.get_material_data()
+    }
+
+    fn get_mut_material_data(&mut self) -> Option<RequiredMaterialDataMut> {
+        self.class.as_mut()?.get_mut_material_data()
+    }
+
+    fn needs_unique_handle(&self) -> bool {
+        self.class
+            .as_ref()
+            .is_some_and(|class| class.needs_unique_handle())
+    }
+
+    fn creation_presets(&self) -> Vec<(String, GraniteTypes)> {
+        self.class
+            .as_ref()
+            .map_or_else(Vec::new, |class| class.creation_presets())
+    }
+
+    fn get_brush_geometry(&self) -> Option<BrushGeometry> {
+        self.class.as_ref()?.get_brush_geometry()
+    }
+
+    fn set_brush_geometry(&mut self, geometry: Option<BrushGeometry>) -> bool {
+        self.class
+            .as_mut()
+            .is_some_and(|class| class.set_brush_geometry(geometry))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::TransformData;
+    use bevy::ecs::world::{CommandQueue, World};
+
+    #[test]
+    fn test_unregistered_class_round_trips_through_placeholder() {
+        let class = CustomClass::from(CustomClassData {
+            type_key: "test::Unregistered".to_string(),
+            data: "(health:10,team:\"red\")".to_string(),
+        });
+        let original = EntitySaveReadyData {
+            identity: IdentityData {
+                uuid: Uuid::new_v4(),
+                name: "Spawn".to_string(),
+                class: GraniteTypes::Custom(class),
+            },
+            transform: TransformData::default(),
+            parent: None,
+            components: None,
+        };
+        let file = ron::to_string(&original).unwrap();
+        let loaded: EntitySaveReadyData = ron::from_str(&file).unwrap();
+
+        let mut world = World::new();
+        let mut queue = CommandQueue::default();
+        let entity = {
+            let mut commands = Commands::new(&mut queue, &world);
+            CustomClass::spawn_placeholder(
+                loaded.identity.clone(),
+                &mut commands,
+                loaded.transform.to_bevy(),
+            )
+        };
+        queue.apply(&mut world);
+
+        let identity = world.get::<IdentityData>(entity).unwrap().clone();
+        assert_eq!(identity, original.identity);
+        let saved = EntitySaveReadyData {
+            identity,
+            transform: loaded.transform,
+            parent: None,
+            components: None,
+        };
+        assert_eq!(ron::to_string(&saved).unwrap(), file);
+    }
+}
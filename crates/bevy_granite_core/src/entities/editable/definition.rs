@@ -1,6 +1,6 @@
 use super::RequestEntityUpdateFromClass;
 use crate::{
-    entities::{EntitySaveReadyData, PromptData},
+    entities::{BrushGeometry, EntitySaveReadyData, GraniteTypes, PromptData},
     AvailableEditableMaterials, ClassCategory, RequiredMaterialData, RequiredMaterialDataMut,
 };
 use bevy::{
@@ -169,4 +169,24 @@ pub trait GraniteType {
     fn needs_unique_handle(&self) -> bool {
         false
     }
+
+    /// Named variations of this class offered when adding entities, e.g. brush primitives
+    /// Each entry is shown as its own button in the add entity popup
+    /// Defaults to empty - meaning the class is offered once under its type name
+    fn creation_presets(&self) -> Vec<(String, GraniteTypes)> {
+        Vec::new()
+    }
+
+    /// Editable polygon geometry for brush like classes. Used by the vertex gizmo mesh edit mode
+    /// Defaults to None - meaning this type can't be mesh edited
+    fn get_brush_geometry(&self) -> Option<BrushGeometry> {
+        None
+    }
+
+    /// Store edited geometry inside the class data so it survives save/load
+    /// None resets back to the class's generated shape
+    /// Returns false if this type doesn't support mesh editing
+    fn set_brush_geometry(&mut self, _geometry: Option<BrushGeometry>) -> bool {
+        false
+    }
 }
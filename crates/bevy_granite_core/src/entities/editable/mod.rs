@@ -19,12 +19,24 @@ use enum_dispatch::enum_dispatch;
 use serde::{Deserialize, Serialize};
 
 // Modules
+pub mod brush_csg;
+pub mod brush_face_materials;
+pub mod brush_face_ui;
+pub mod brush_geometry;
 pub mod category;
+pub mod custom;
 pub mod definition;
 pub mod types;
 
 // Re-exports
+pub use brush_csg::BrushPlane;
+pub use brush_face_materials::{spawn_brush_face_material_groups, BrushFaceMaterialGroup};
+pub use brush_face_ui::edit_brush_face_ui;
+pub use brush_geometry::{BrushEditOperation, BrushFace, BrushGeometry, FaceUv};
 pub use category::ClassCategory;
+pub use custom::{
+    CustomClass, CustomGraniteClass, RegisterGraniteClass, UserUpdatedCustomClassEvent,
+};
 pub use definition::GraniteType;
 pub use types::*;
 
@@ -37,9 +49,16 @@ pub struct RequestEntityUpdateFromClass<'w> {
     pub directional_light: MessageWriter<'w, UserUpdatedDirectionalLightEvent>,
     pub point_light: MessageWriter<'w, UserUpdatedPointLightEvent>,
     pub spot_light: MessageWriter<'w, UserUpdatedSpotLightEvent>,
+    pub rect_light: MessageWriter<'w, UserUpdatedRectLightEvent>,
+    pub sky_environment: MessageWriter<'w, UserUpdatedSkyEnvironmentEvent>,
+    pub reflection_probe: MessageWriter<'w, UserUpdatedReflectionProbeEvent>,
+    pub irradiance_volume: MessageWriter<'w, UserUpdatedIrradianceVolumeEvent>,
     pub rectangle_brush: MessageWriter<'w, UserUpdatedRectBrushEvent>,
+    pub convex_brush: MessageWriter<'w, UserUpdatedConvexBrushEvent>,
     pub obj: MessageWriter<'w, UserUpdatedOBJEvent>,
     pub empty: MessageWriter<'w, UserUpdatedEmptyEvent>,
+    /// Classes registered from other crates all share this one
+    pub custom: MessageWriter<'w, UserUpdatedCustomClassEvent>,
 }
 
 // ---------------------------------------------------------------------------------------
@@ -47,6 +66,7 @@ pub struct RequestEntityUpdateFromClass<'w> {
 // GraniteTypes represents all Granite entity "types" that are supported
 // This should be relatively trivial to expand with new types
 // If you add a new type ensure to add it under the enum and its all function
+// Games add their own through App::register_granite_class, those live under Custom
 /// We use enum_dispatch for static polymorphism - i.e. all variants of our enum need same functions available to themselves, and exposed up a level - this saves us a tremendous amount of match arms in this enum
 #[enum_dispatch(GraniteType)]
 #[derive(Serialize, Reflect, Deserialize, PartialEq, Clone, Debug)]
@@ -55,30 +75,60 @@ pub enum GraniteTypes {
     Empty(Empty),
     PointLightData(PointLightData),
     SpotLightData(SpotLightData),
+    RectLightData(RectLightData),
     DirLight(DirLight),
+    SkyEnvironment(SkyEnvironment),
+    ReflectionProbe(ReflectionProbe),
+    IrradianceVolumeData(IrradianceVolumeData),
     Camera3D(Camera3D),
     RectBrush(RectBrush),
-    Unknown(Unknown), // Holds no real data
+    ConvexBrush(ConvexBrush),
+    Custom(CustomClass), // Registered from outside this crate
+    Unknown(Unknown),    // Holds no real data
 }
 impl GraniteTypes {
     // If you add a new custom type - add it here as well so its concretely known!!
     // Used to get all available variants, not data
     pub fn all() -> Vec<GraniteTypes> {
-        vec![
+        let mut all = vec![
             GraniteTypes::OBJ(Default::default()),
             GraniteTypes::Empty(Default::default()),
             GraniteTypes::PointLightData(Default::default()),
             GraniteTypes::SpotLightData(Default::default()),
+            GraniteTypes::RectLightData(Default::default()),
             GraniteTypes::DirLight(Default::default()),
+            GraniteTypes::SkyEnvironment(Default::default()),
+            GraniteTypes::ReflectionProbe(Default::default()),
+            GraniteTypes::IrradianceVolumeData(Default::default()),
             GraniteTypes::Camera3D(Default::default()),
             GraniteTypes::RectBrush(Default::default()),
-            GraniteTypes::Unknown(Default::default()),
-        ]
+            GraniteTypes::ConvexBrush(Default::default()),
+        ];
+        all.extend(CustomClass::all().into_iter().map(GraniteTypes::Custom));
+        all.push(GraniteTypes::Unknown(Default::default()));
+        all
     }
 
     // Check if we are a known type - helpful for UI and what not
     pub fn is_known(&self) -> bool {
-        !matches!(self, GraniteTypes::Unknown(_))
+        match self {
+            GraniteTypes::Unknown(_) => false,
+            GraniteTypes::Custom(custom) => custom.is_registered(),
+            _ => true,
+        }
+    }
+
+    /// Wrap a class registered with App::register_granite_class, e.g. for its IdentityData
+    pub fn custom<T: CustomGraniteClass>(class: T) -> Option<GraniteTypes> {
+        CustomClass::new(class).map(GraniteTypes::Custom)
+    }
+
+    /// The registered class inside, if this is one of type `T`
+    pub fn as_custom<T: CustomGraniteClass>(&self) -> Option<&T> {
+        match self {
+            GraniteTypes::Custom(custom) => custom.downcast_ref::<T>(),
+            _ => None,
+        }
     }
 
     // Return vector of all GraniteTypes variant categories. Used for UI
@@ -0,0 +1,192 @@
+use super::ConvexBrush;
+use crate::{
+    entities::{editable::spawn_brush_face_material_groups, EntitySaveReadyData},
+    AvailableEditableMaterials, GraniteEditorSerdeEntity, GraniteType, GraniteTypes,
+    HasRuntimeData, IdentityData, NeedsTangents,
+};
+use bevy::{
+    asset::{AssetServer, Assets},
+    ecs::{
+        entity::Entity,
+        system::{Commands, Res, ResMut},
+    },
+    math::Mat4,
+    mesh::{Mesh, Mesh3d},
+    pbr::{MeshMaterial3d, StandardMaterial},
+    prelude::Name,
+    transform::components::Transform,
+};
+use uuid::Uuid;
+
+impl ConvexBrush {
+    /// Extract needed info to spawn this entity via save data
+    pub fn spawn_from_save_data(
+        save_data: &EntitySaveReadyData,
+        commands: &mut Commands,
+        standard_materials: &mut ResMut<Assets<StandardMaterial>>,
+        available_materials: &mut ResMut<AvailableEditableMaterials>,
+        asset_server: &Res<AssetServer>,
+        meshes: &mut ResMut<Assets<Mesh>>,
+    ) -> Entity {
+        let identity = &save_data.identity;
+        let save_transform = &save_data.transform;
+
+        Self::spawn_from_identity(
+            commands,
+            identity,
+            save_transform.to_bevy(),
+            standard_materials,
+            available_materials,
+            asset_server,
+            meshes,
+        )
+    }
+
+    /// Take the name and class from identity to spawn
+    pub fn spawn_from_identity(
+        commands: &mut Commands,
+        identity: &IdentityData,
+        transform: Transform,
+        standard_materials: &mut ResMut<Assets<StandardMaterial>>,
+        available_materials: &mut ResMut<AvailableEditableMaterials>,
+        asset_server: &Res<AssetServer>,
+        meshes: &mut ResMut<Assets<Mesh>>,
+    ) -> Entity {
+        let mut class = Self::extract_class(identity);
+
+        class.spawn(
+            identity,
+            commands,
+            transform,
+            standard_materials,
+            available_materials,
+            asset_server,
+            meshes,
+        )
+    }
+
+    /// Generally to be used from UI popups as it gives default name
+    pub fn spawn_from_new_identity(
+        &mut self,
+        commands: &mut Commands,
+        transform: Transform,
+        standard_materials: &mut ResMut<Assets<StandardMaterial>>,
+        available_materials: &mut ResMut<AvailableEditableMaterials>,
+        asset_server: &Res<AssetServer>,
+        meshes: &mut ResMut<Assets<Mesh>>,
+    ) -> Entity {
+        let identity = IdentityData {
+            name: self.type_name(),
+            uuid: Uuid::new_v4(),
+            class: GraniteTypes::ConvexBrush(self.clone()),
+        };
+        self.spawn(
+            &identity,
+            commands,
+            transform,
+            standard_materials,
+            available_materials,
+            asset_server,
+            meshes,
+        )
+    }
+
+    /// Private core logic
+    fn spawn(
+        &mut self,
+        identity: &IdentityData,
+        commands: &mut Commands,
+        transform: Transform,
+        standard_materials: &mut ResMut<Assets<StandardMaterial>>,
+        available_materials: &mut ResMut<AvailableEditableMaterials>,
+        asset_server: &Res<AssetServer>,
+        meshes: &mut ResMut<Assets<Mesh>>,
+    ) -> Entity {
+        // Load and configure the material
+        self.load_and_configure_material(available_materials, standard_materials, asset_server);
+
+        let brush_material = self
+            .material
+            .current
+            .handle
+            .clone()
+            .expect("Default material should always have a handle");
+
+        let entity = commands
+            .spawn((
+                transform,
+                Mesh3d(meshes.add(self.geometry.to_mesh(self.uv_scale))),
+                MeshMaterial3d(brush_material.clone()),
+                Name::new(identity.name.clone()),
+                HasRuntimeData,
+                GraniteEditorSerdeEntity,
+                NeedsTangents,
+                IdentityData {
+                    name: identity.name.clone(),
+                    uuid: identity.uuid,
+                    class: GraniteTypes::ConvexBrush(self.clone()),
+                },
+            ))
+            .id();
+
+        spawn_brush_face_material_groups(
+            commands,
+            entity,
+            self.geometry.material_overrides(),
+            |path| {
+                self.geometry
+                    .to_mesh_for_material(self.uv_scale, Some(path), Mat4::IDENTITY)
+            },
+            brush_material,
+            meshes,
+            standard_materials,
+            available_materials,
+            asset_server,
+        );
+
+        entity
+    }
+
+    fn extract_class(identity: &IdentityData) -> ConvexBrush {
+        match &identity.class {
+            GraniteTypes::ConvexBrush(brush_data) => brush_data.clone(),
+            _ => panic!("Expected ConvexBrush class data, got different type from save data"),
+        }
+    }
+
+    /// Load and configure material with proper metadata
+    /// Same fallback behaviour as the rectangle brush
+    fn load_and_configure_material(
+        &mut self,
+        available_materials: &mut ResMut<AvailableEditableMaterials>,
+        standard_materials: &mut ResMut<Assets<StandardMaterial>>,
+        asset_server: &Res<AssetServer>,
+    ) {
+        let fallback_path = Self::internal_material_path();
+
+        let material_path = if !self.material.path.is_empty() {
+            self.material.path.clone()
+        } else {
+            fallback_path.clone()
+        };
+
+        self.material.current.path = material_path.clone();
+
+        let _created_new = self.material.current.material_exists_and_load(
+            available_materials,
+            standard_materials,
+            asset_server,
+            &self.type_name(),
+            &material_path,
+        );
+
+        // Fix the material metadata after loading (since loaded materials have "None" path)
+        self.material.current.path = material_path;
+        self.material.current.friendly_name = self.type_name();
+        self.material.last = self.material.current.clone();
+
+        if self.material.path.is_empty() {
+            self.material.path = fallback_path;
+        }
+    }
+}
@@ -0,0 +1,192 @@
+use crate::{
+    assets::EditableMaterial,
+    entities::{
+        editable::{
+            BrushGeometry, GraniteType, RequestEntityUpdateFromClass, RequiredMaterialData,
+            RequiredMaterialDataMut,
+        },
+        EntitySaveReadyData, GraniteTypes, PromptData,
+    },
+    AvailableEditableMaterials, ClassCategory, MaterialData,
+};
+use bevy::{
+    asset::{AssetServer, Assets},
+    ecs::{
+        entity::Entity,
+        message::Message,
+        system::{Commands, Res, ResMut},
+    },
+    math::{Vec2, Vec3},
+    mesh::Mesh,
+    pbr::StandardMaterial,
+    prelude::Reflect,
+    transform::components::Transform,
+};
+use bevy_egui::egui;
+use serde::{Deserialize, Serialize};
+
+pub mod creation;
+pub mod plugin;
+pub mod primitives;
+pub mod ui;
+pub mod update_event;
+
+pub use plugin::*;
+pub use primitives::BrushPrimitive;
+pub use update_event::*;
+
+/// Internal event thats called when user edits UI ConvexBrush variables
+#[derive(Message)]
+pub struct UserUpdatedConvexBrushEvent {
+    pub entity: Entity,
+    pub data: ConvexBrush,
+}
+
+/// Actual serialized class data thats stored inside IdentityData
+/// Geometry is the full polygon shape. Wedges and cylinders are truly convex,
+/// arches and stairs are built out of convex parts and can't be used for subtract/merge
+/// Faces without a material override use the brush material stored under MaterialData
+#[derive(Serialize, Deserialize, Reflect, Debug, Clone, PartialEq)]
+pub struct ConvexBrush {
+    pub geometry: BrushGeometry,
+    pub uv_scale: Vec2,
+    pub material: MaterialData,
+}
+
+impl ConvexBrush {
+    // Needed static access to the type name so we define them static-ly here this time
+    pub fn type_name_static() -> String {
+        "Convex Brush".to_string()
+    }
+
+    pub fn type_abv_static() -> String {
+        "Convex".to_string()
+    }
+
+    /// Shares the internal brush material with RectBrush so blockouts look the same
+    pub fn internal_material_path() -> String {
+        "materials/internal/rect_brush.mat".to_string()
+    }
+
+    pub fn from_primitive(primitive: BrushPrimitive) -> Self {
+        Self {
+            geometry: primitive.build(),
+            ..Default::default()
+        }
+    }
+}
+
+impl Default for ConvexBrush {
+    fn default() -> Self {
+        let (path, name) = (Self::internal_material_path(), Self::type_name_static());
+
+        let mut brush_material = EditableMaterial::get_new_unnamed_base_color();
+        brush_material.update_name(name.clone());
+        brush_material.update_path(path.clone());
+
+        Self {
+            geometry: BrushGeometry::from_box(Vec3::ONE),
+            uv_scale: Vec2::ONE,
+            material: MaterialData {
+                path: path.clone(),
+                current: brush_material.clone(),
+                last: brush_material.clone(),
+            },
+        }
+    }
+}
+
+impl GraniteType for ConvexBrush {
+    fn category(&self) -> ClassCategory {
+        ClassCategory::Mesh
+    }
+
+    fn type_name(&self) -> String {
+        ConvexBrush::type_name_static()
+    }
+
+    fn type_abv(&self) -> String {
+        ConvexBrush::type_abv_static()
+    }
+
+    fn spawn_from_new_identity(
+        &mut self,
+        commands: &mut Commands,
+        transform: Transform,
+        mut standard_materials: ResMut<Assets<StandardMaterial>>,
+        mut meshes: ResMut<Assets<Mesh>>,
+        mut available_materials: ResMut<AvailableEditableMaterials>,
+        asset_server: Res<AssetServer>,
+        _maybe_prompt_data: Option<PromptData>,
+    ) -> Entity {
+        self.spawn_from_new_identity(
+            commands,
+            transform,
+            &mut standard_materials,
+            &mut available_materials,
+            &asset_server,
+            &mut meshes,
+        )
+    }
+
+    fn spawn_from_save_data(
+        &self,
+        save_data: &EntitySaveReadyData,
+        commands: &mut Commands,
+        standard_materials: &mut ResMut<Assets<StandardMaterial>>,
+        meshes: &mut ResMut<Assets<Mesh>>,
+        available_materials: &mut ResMut<AvailableEditableMaterials>,
+        asset_server: &Res<AssetServer>,
+    ) -> Entity {
+        ConvexBrush::spawn_from_save_data(
+            save_data,
+            commands,
+            standard_materials,
+            available_materials,
+            asset_server,
+            meshes,
+        )
+    }
+
+    fn push_to_entity(&self, entity: Entity, request_update: &mut RequestEntityUpdateFromClass) {
+        self.push_to_entity(entity, request_update)
+    }
+
+    fn edit_via_ui(&mut self, ui: &mut egui::Ui, spacing: (f32, f32, f32)) -> bool {
+        self.edit_via_ui(ui, spacing)
+    }
+
+    fn get_material_data(&self) -> Option<RequiredMaterialData> {
+        Some(self.material.as_ref())
+    }
+
+    fn get_mut_material_data(&mut self) -> Option<RequiredMaterialDataMut> {
+        Some(self.material.as_mut())
+    }
+
+    fn needs_unique_handle(&self) -> bool {
+        true
+    }
+
+    fn creation_presets(&self) -> Vec<(String, GraniteTypes)> {
+        BrushPrimitive::all()
+            .into_iter()
+            .map(|primitive| {
+                (
+                    primitive.label().to_string(),
+                    GraniteTypes::ConvexBrush(ConvexBrush::from_primitive(primitive)),
+                )
+            })
+            .collect()
+    }
+
+    fn get_brush_geometry(&self) -> Option<BrushGeometry> {
+        Some(self.geometry.clone())
+    }
+
+    fn set_brush_geometry(&mut self, geometry: Option<BrushGeometry>) -> bool {
+        // No generated shape to fall back to, so a reset gives the default box
+        self.geometry = geometry.unwrap_or_else(|| BrushGeometry::from_box(Vec3::ONE));
+        true
+    }
+}
@@ -0,0 +1,23 @@
+use crate::ConvexBrush;
+
+use super::{update_convex_brush_system, UserUpdatedConvexBrushEvent};
+use bevy::app::{App, Plugin, Update};
+
+pub struct ConvexBrushPlugin;
+impl Plugin for ConvexBrushPlugin {
+    fn build(&self, app: &mut App) {
+        app
+            //
+            // Event
+            //
+            .add_message::<UserUpdatedConvexBrushEvent>()
+            //
+            // Register
+            //
+            .register_type::<ConvexBrush>()
+            //
+            // Schedule system
+            //
+            .add_systems(Update, update_convex_brush_system);
+    }
+}
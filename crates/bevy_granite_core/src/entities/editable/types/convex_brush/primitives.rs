@@ -0,0 +1,111 @@
+use crate::entities::editable::BrushGeometry;
+use bevy::math::Vec3;
+use std::f32::consts::PI;
+
+/// Shapes offered by the add entity popup for ConvexBrush
+/// All primitives fit inside a unit cube centered at the origin
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub enum BrushPrimitive {
+    Box,
+    Wedge,
+    Cylinder { sides: u32 },
+    Arch { segments: u32 },
+    Stairs { steps: u32 },
+}
+
+impl BrushPrimitive {
+    pub fn all() -> Vec<BrushPrimitive> {
+        vec![
+            BrushPrimitive::Box,
+            BrushPrimitive::Wedge,
+            BrushPrimitive::Cylinder { sides: 16 },
+            BrushPrimitive::Arch { segments: 8 },
+            BrushPrimitive::Stairs { steps: 4 },
+        ]
+    }
+
+    pub fn label(&self) -> &'static str {
+        match self {
+            BrushPrimitive::Box => "Box Brush",
+            BrushPrimitive::Wedge => "Wedge Brush",
+            BrushPrimitive::Cylinder { .. } => "Cylinder Brush",
+            BrushPrimitive::Arch { .. } => "Arch Brush",
+            BrushPrimitive::Stairs { .. } => "Stairs Brush",
+        }
+    }
+
+    pub fn build(&self) -> BrushGeometry {
+        match *self {
+            BrushPrimitive::Box => BrushGeometry::from_box(Vec3::ONE),
+            BrushPrimitive::Wedge => Self::wedge(),
+            BrushPrimitive::Cylinder { sides } => Self::cylinder(sides.max(3)),
+            BrushPrimitive::Arch { segments } => Self::arch(segments.max(2)),
+            BrushPrimitive::Stairs { steps } => Self::stairs(steps.max(1)),
+        }
+    }
+
+    /// Ramp rising towards -Z
+    fn wedge() -> BrushGeometry {
+        let points = [
+            Vec3::new(-0.5, -0.5, 0.5),
+            Vec3::new(0.5, -0.5, 0.5),
+            Vec3::new(0.5, -0.5, -0.5),
+            Vec3::new(-0.5, -0.5, -0.5),
+            Vec3::new(-0.5, 0.5, -0.5),
+            Vec3::new(0.5, 0.5, -0.5),
+        ];
+        BrushGeometry::convex_hull(&points).unwrap_or_else(|| BrushGeometry::from_box(Vec3::ONE))
+    }
+
+    /// Upright prism around Y
+    fn cylinder(sides: u32) -> BrushGeometry {
+        let mut points = Vec::with_capacity(sides as usize * 2);
+        for i in 0..sides {
+            let angle = i as f32 / sides as f32 * PI * 2.0;
+            let (x, z) = (angle.cos() * 0.5, angle.sin() * 0.5);
+            points.push(Vec3::new(x, -0.5, z));
+            points.push(Vec3::new(x, 0.5, z));
+        }
+        BrushGeometry::convex_hull(&points).unwrap_or_else(|| BrushGeometry::from_box(Vec3::ONE))
+    }
+
+    /// Half ring in the XY plane, one convex block per segment
+    fn arch(segments: u32) -> BrushGeometry {
+        let (inner, outer) = (0.3, 0.5);
+        let mut geometry = BrushGeometry::default();
+        for i in 0..segments {
+            let a0 = i as f32 / segments as f32 * PI;
+            let a1 = (i + 1) as f32 / segments as f32 * PI;
+            let mut points = Vec::with_capacity(8);
+            for angle in [a0, a1] {
+                for radius in [inner, outer] {
+                    // Shift down so the arch sits inside the unit cube
+                    let (x, y) = (angle.cos() * radius, angle.sin() * radius - 0.5);
+                    points.push(Vec3::new(x, y, 0.5));
+                    points.push(Vec3::new(x, y, -0.5));
+                }
+            }
+            if let Some(part) = BrushGeometry::convex_hull(&points) {
+                geometry.append(&part);
+            }
+        }
+        geometry
+    }
+
+    /// Steps climbing towards -Z, one box per step
+    fn stairs(steps: u32) -> BrushGeometry {
+        let step_size = 1.0 / steps as f32;
+        let mut geometry = BrushGeometry::default();
+        for i in 0..steps {
+            let height = step_size * (i + 1) as f32;
+            let z_front = 0.5 - step_size * i as f32;
+            let center = Vec3::new(0.0, -0.5 + height / 2.0, z_front - step_size / 2.0);
+            let mut part = BrushGeometry::from_box(Vec3::new(1.0, height, step_size));
+            for vertex in part.vertices.iter_mut() {
+                *vertex += center;
+            }
+            geometry.append(&part);
+        }
+        geometry
+    }
+}
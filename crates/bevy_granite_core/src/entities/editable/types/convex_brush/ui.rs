@@ -0,0 +1,126 @@
+use super::ConvexBrush;
+use crate::entities::editable::edit_brush_face_ui;
+use bevy::math::Vec2;
+use bevy_egui::egui;
+
+impl ConvexBrush {
+    /// Function to edit self's data via UI side panel
+    /// We have a sister system that pushes changes to world entity
+    /// It can be found inside 'update_event.rs'
+    /// When true, sends an update to propagate these vars to the world's entity
+    pub fn edit_via_ui(&mut self, ui: &mut egui::Ui, spacing: (f32, f32, f32)) -> bool {
+        let brush_data = self;
+        let small_spacing = spacing.0;
+        let large_spacing = spacing.1;
+        let mut changed = false;
+
+        let style = ui.ctx().style().clone();
+        let default_font_id = egui::FontId::default();
+        let font_id = style
+            .text_styles
+            .get(&egui::TextStyle::Button)
+            .unwrap_or(&default_font_id);
+        let btn_height = font_id.size + style.spacing.button_padding.y * 2.0;
+        let drag_size = [60., btn_height];
+
+        ui.label(egui::RichText::new("Convex Brush Data").italics());
+        ui.add_space(large_spacing);
+
+        ui.label(format!(
+            "Geometry: {} verts, {} faces{}",
+            brush_data.geometry.vertices.len(),
+            brush_data.geometry.faces.len(),
+            if brush_data.geometry.is_convex() {
+                ""
+            } else {
+                " (compound)"
+            }
+        ));
+        ui.add_space(large_spacing);
+
+        // UV Scale
+        ui.vertical(|ui| {
+            ui.label("UV:");
+            ui.add_space(small_spacing);
+            egui::Grid::new("convex_uv_grid")
+                .num_columns(3)
+                .spacing([1.0, 0.0])
+                .show(ui, |ui| {
+                    changed |= ui
+                        .add_sized(
+                            drag_size,
+                            egui::DragValue::new(&mut brush_data.uv_scale.x)
+                                .speed(0.01)
+                                .fixed_decimals(2),
+                        )
+                        .changed();
+                    changed |= ui
+                        .add_sized(
+                            drag_size,
+                            egui::DragValue::new(&mut brush_data.uv_scale.y)
+                                .speed(0.01)
+                                .fixed_decimals(2),
+                        )
+                        .changed();
+                    if ui
+                        .add_sized(drag_size, egui::Button::new("Reset"))
+                        .clicked()
+                    {
+                        brush_data.uv_scale = Vec2::ONE;
+                        changed = true;
+                    }
+                });
+        });
+
+        ui.add_space(large_spacing);
+
+        // Per face material and UV. The selected face index lives in egui memory
+        // so it survives between frames without becoming class data
+        if brush_data.geometry.faces.is_empty() {
+            return changed;
+        }
+        let face_id = ui.make_persistent_id("convex_brush_selected_face");
+        let mut face_index: usize = ui
+            .ctx()
+            .data_mut(|d| d.get_temp(face_id))
+            .unwrap_or_default();
+        face_index = face_index.min(brush_data.geometry.faces.len() - 1);
+
+        ui.vertical(|ui| {
+            ui.label("Face:");
+            ui.add_space(small_spacing);
+            ui.horizontal(|ui| {
+                egui::ComboBox::from_id_salt("convex_face_combo")
+                    .selected_text(format!("Face {}", face_index))
+                    .show_ui(ui, |ui| {
+                        for i in 0..brush_data.geometry.faces.len() {
+                            ui.selectable_value(&mut face_index, i, format!("Face {}", i));
+                        }
+                    });
+                if ui.button("Apply To All").clicked() {
+                    let source = brush_data.geometry.faces[face_index].clone();
+                    for face in brush_data.geometry.faces.iter_mut() {
+                        face.material = source.material.clone();
+                        face.uv = source.uv.clone();
+                    }
+                    changed = true;
+                }
+            });
+            ui.add_space(small_spacing);
+
+            let face = &mut brush_data.geometry.faces[face_index];
+            changed |= edit_brush_face_ui(
+                ui,
+                "convex",
+                &mut face.material,
+                &mut face.uv,
+                drag_size,
+                small_spacing,
+            );
+        });
+
+        ui.ctx().data_mut(|d| d.insert_temp(face_id, face_index));
+
+        changed
+    }
+}
@@ -0,0 +1,120 @@
+use crate::{
+    entities::editable::{
+        spawn_brush_face_material_groups, BrushFaceMaterialGroup, RequestEntityUpdateFromClass,
+    },
+    AvailableEditableMaterials,
+};
+
+use super::{ConvexBrush, UserUpdatedConvexBrushEvent};
+use bevy::{
+    asset::{AssetServer, Assets},
+    camera::primitives::{Aabb, MeshAabb},
+    ecs::{
+        message::MessageReader,
+        query::With,
+        system::{Commands, Query, Res, ResMut},
+    },
+    math::Mat4,
+    mesh::{Mesh, Mesh3d},
+    pbr::{MeshMaterial3d, StandardMaterial},
+    prelude::{Children, Entity},
+};
+use bevy_granite_logging::{log, LogCategory, LogLevel, LogType};
+
+impl ConvexBrush {
+    pub fn push_to_entity(
+        &self,
+        brush_e: Entity,
+        request_update: &mut RequestEntityUpdateFromClass,
+    ) {
+        log!(
+            LogType::Editor,
+            LogLevel::Info,
+            LogCategory::Entity,
+            "Requesting Convex Brush update"
+        );
+        request_update
+            .convex_brush
+            .write(UserUpdatedConvexBrushEvent {
+                entity: brush_e,
+                data: self.clone(),
+            });
+    }
+}
+
+pub fn update_convex_brush_system(
+    mut commands: Commands,
+    mut reader: MessageReader<UserUpdatedConvexBrushEvent>,
+    query: Query<(
+        &Mesh3d,
+        Option<&MeshMaterial3d<StandardMaterial>>,
+        Option<&Children>,
+    )>,
+    face_groups: Query<(), With<BrushFaceMaterialGroup>>,
+    mut meshes: ResMut<Assets<Mesh>>,
+    mut aabbs: Query<&mut Aabb>,
+    mut standard_materials: ResMut<Assets<StandardMaterial>>,
+    mut available_materials: ResMut<AvailableEditableMaterials>,
+    asset_server: Res<AssetServer>,
+) {
+    for UserUpdatedConvexBrushEvent {
+        entity: requested_entity,
+        data: new,
+    } in reader.read()
+    {
+        let Ok((mesh_handle, brush_material, children)) = query.get(*requested_entity) else {
+            log!(
+                LogType::Editor,
+                LogLevel::Error,
+                LogCategory::Entity,
+                "Could not find convex brush entity {}",
+                requested_entity
+            );
+            continue;
+        };
+
+        if let Some(mesh) = meshes.get_mut(mesh_handle) {
+            new.geometry.write_to_mesh(mesh, new.uv_scale);
+            if let (Some(new_mesh_aabb), Ok(mut entity_aabb)) =
+                (mesh.compute_aabb(), aabbs.get_mut(*requested_entity))
+            {
+                *entity_aabb = new_mesh_aabb;
+            }
+        }
+
+        // Face material children are cheap, rebuild them from scratch
+        if let Some(children) = children {
+            for child in children.iter() {
+                if face_groups.get(child).is_ok() {
+                    commands.entity(child).despawn();
+                }
+            }
+        }
+        spawn_brush_face_material_groups(
+            &mut commands,
+            *requested_entity,
+            new.geometry.material_overrides(),
+            |path| {
+                new.geometry
+                    .to_mesh_for_material(new.uv_scale, Some(path), Mat4::IDENTITY)
+            },
+            // Registered material kinds replace the standard material on the entity
+            brush_material.map_or_else(
+                || new.material.current.handle.clone().unwrap_or_default(),
+                |material| material.0.clone(),
+            ),
+            &mut meshes,
+            &mut standard_materials,
+            &mut available_materials,
+            &asset_server,
+        );
+
+        log!(
+            LogType::Editor,
+            LogLevel::Info,
+            LogCategory::Entity,
+            "Updated convex brush mesh for entity {}",
+            requested_entity
+        );
+    }
+}
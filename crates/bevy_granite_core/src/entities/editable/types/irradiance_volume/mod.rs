@@ -0,0 +1,134 @@
+use crate::{
+    entities::editable::{GraniteType, RequestEntityUpdateFromClass},
+    entities::EntitySaveReadyData,
+    AvailableEditableMaterials,
+};
+use crate::{ClassCategory, PromptData};
+use bevy::{
+    asset::{AssetServer, Assets, Handle},
+    ecs::{
+        component::Component,
+        entity::Entity,
+        message::Message,
+        system::{Commands, Res, ResMut},
+    },
+    image::Image,
+    math::Vec3,
+    mesh::Mesh,
+    pbr::StandardMaterial,
+    prelude::Reflect,
+    transform::components::Transform,
+};
+use bevy_egui::egui;
+use serde::{Deserialize, Serialize};
+
+use super::load_environment_image;
+
+pub mod creation;
+pub mod plugin;
+pub mod ui;
+pub mod update_event;
+
+pub use plugin::*;
+pub use update_event::*;
+
+/// Internal event thats called when user edits UI irradiance volume variable
+#[derive(Message)]
+pub struct UserUpdatedIrradianceVolumeEvent {
+    pub entity: Entity,
+    pub data: IrradianceVolumeData,
+}
+
+/// Actual serialized class data thats stored inside IdentityData
+/// Baked voxel lighting for everything inside the bounds, mostly indirect diffuse light
+#[derive(Serialize, Deserialize, Reflect, Debug, Clone, PartialEq)]
+pub struct IrradianceVolumeData {
+    /// Baked 3D .ktx2 texture, empty for none
+    pub voxels: String,
+    pub intensity: f32,
+    /// Full extents of the volume bounds, centered on the entity
+    pub size: (f32, f32, f32),
+}
+
+impl Default for IrradianceVolumeData {
+    fn default() -> Self {
+        Self {
+            voxels: String::new(),
+            intensity: 1000.0,
+            size: (10., 10., 10.),
+        }
+    }
+}
+
+/// Runtime side of a irradiance volume, synced onto its `LightProbeVolume` child
+#[derive(Component, Clone, Debug)]
+pub struct IrradianceVolumeSettings {
+    pub voxels: Option<Handle<Image>>,
+    pub intensity: f32,
+    pub size: Vec3,
+}
+
+impl IrradianceVolumeData {
+    pub fn settings(&self, asset_server: &AssetServer) -> IrradianceVolumeSettings {
+        IrradianceVolumeSettings {
+            voxels: load_environment_image(asset_server, &self.voxels),
+            intensity: self.intensity,
+            size: Vec3::new(self.size.0, self.size.1, self.size.2),
+        }
+    }
+}
+
+impl GraniteType for IrradianceVolumeData {
+    fn type_name(&self) -> String {
+        "Irradiance Volume".to_string()
+    }
+
+    fn type_abv(&self) -> String {
+        "Irr Volume".to_string()
+    }
+
+    fn category(&self) -> ClassCategory {
+        ClassCategory::Light
+    }
+
+    fn get_embedded_icon_bytes(&self) -> Option<&'static [u8]> {
+        Some(include_bytes!("IrradianceVolume.png"))
+    }
+
+    fn get_icon_filename(&self) -> Option<&'static str> {
+        Some("IrradianceVolume.png")
+    }
+
+    fn spawn_from_new_identity(
+        &mut self,
+        commands: &mut Commands,
+        transform: Transform,
+        _standard_materials: ResMut<Assets<StandardMaterial>>,
+        _meshes: ResMut<Assets<Mesh>>,
+        _available_materials: ResMut<AvailableEditableMaterials>,
+        asset_server: Res<AssetServer>,
+        _maybe_prompt_data: Option<PromptData>,
+    ) -> Entity {
+        IrradianceVolumeData::spawn_from_new_identity(self, commands, transform, &asset_server)
+    }
+
+    fn spawn_from_save_data(
+        &self,
+        save_data: &EntitySaveReadyData,
+        commands: &mut Commands,
+        _standard_materials: &mut ResMut<Assets<StandardMaterial>>,
+        _meshes: &mut ResMut<Assets<Mesh>>,
+        _available_materials: &mut ResMut<AvailableEditableMaterials>,
+        asset_server: &Res<AssetServer>,
+    ) -> Entity {
+        IrradianceVolumeData::spawn_from_save_data(save_data, commands, asset_server)
+    }
+
+    fn push_to_entity(&self, entity: Entity, request_update: &mut RequestEntityUpdateFromClass) {
+        IrradianceVolumeData::push_to_entity(self, entity, request_update);
+    }
+
+    fn edit_via_ui(&mut self, ui: &mut egui::Ui, spacing: (f32, f32, f32)) -> bool {
+        IrradianceVolumeData::edit_via_ui(self, ui, spacing)
+    }
+}
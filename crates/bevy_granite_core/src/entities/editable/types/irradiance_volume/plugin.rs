@@ -0,0 +1,34 @@
+use super::{
+    sync_irradiance_volume_system, update_irradiance_volume_system, IrradianceVolumeData,
+    UserUpdatedIrradianceVolumeEvent,
+};
+use bevy::{
+    app::{App, Plugin, Update},
+    ecs::schedule::IntoScheduleConfigs,
+};
+
+pub struct IrradianceVolumePlugin;
+impl Plugin for IrradianceVolumePlugin {
+    fn build(&self, app: &mut App) {
+        app
+            //
+            // Event
+            //
+            .add_message::<UserUpdatedIrradianceVolumeEvent>()
+            //
+            // Register
+            //
+            .register_type::<IrradianceVolumeData>()
+            //
+            // Schedule system
+            //
+            .add_systems(
+                Update,
+                (
+                    update_irradiance_volume_system,
+                    sync_irradiance_volume_system,
+                )
+                    .chain(),
+            );
+    }
+}
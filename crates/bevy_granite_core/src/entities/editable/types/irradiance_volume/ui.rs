@@ -0,0 +1,45 @@
+use super::IrradianceVolumeData;
+use crate::{
+    entities::editable::{environment_image_field, probe_size_field},
+    GraniteType,
+};
+use bevy_egui::egui;
+
+impl IrradianceVolumeData {
+    /// Function to edit self's data via UI side panel
+    /// We have a sister system that pushes changes to world entity
+    /// It can be found inside 'update_event.rs'
+    /// When true, sends an update to propagate these vars to the world's entity
+    pub fn edit_via_ui(&mut self, ui: &mut egui::Ui, spacing: (f32, f32, f32)) -> bool {
+        let type_name = self.type_name();
+        let data = self;
+        let large_spacing = spacing.1;
+        ui.label(egui::RichText::new(type_name).italics());
+        ui.add_space(large_spacing);
+
+        let mut changed = false;
+        ui.vertical(|ui| {
+            egui::Grid::new("irradiance_volume_data_grid")
+                .num_columns(2)
+                .spacing([large_spacing, large_spacing])
+                .striped(true)
+                .show(ui, |ui| {
+                    changed |=
+                        environment_image_field(ui, "Voxels:", &mut data.voxels, vec!["ktx2"]);
+
+                    ui.label("Intensity:");
+                    changed |= ui
+                        .add(
+                            egui::DragValue::new(&mut data.intensity)
+                                .range(0.0..=100_000.0)
+                                .speed(10.0),
+                        )
+                        .changed();
+                    ui.end_row();
+
+                    changed |= probe_size_field(ui, &mut data.size);
+                });
+        });
+        changed
+    }
+}
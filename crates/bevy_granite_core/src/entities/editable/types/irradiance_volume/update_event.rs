@@ -0,0 +1,108 @@
+use super::{IrradianceVolumeSettings, UserUpdatedIrradianceVolumeEvent};
+use crate::entities::editable::{
+    EnvironmentImages, LightProbeVolume, RequestEntityUpdateFromClass,
+};
+use crate::IrradianceVolumeData;
+use bevy::{
+    asset::AssetServer,
+    ecs::{
+        change_detection::{DetectChanges, Ref},
+        entity::Entity,
+        hierarchy::Children,
+        message::MessageReader,
+        query::With,
+        system::{Commands, Query, Res},
+    },
+    light::IrradianceVolume,
+    transform::components::Transform,
+};
+use bevy_granite_logging::{log, LogCategory, LogLevel, LogType};
+
+impl IrradianceVolumeData {
+    /// Request an entity update with this data
+    pub fn push_to_entity(
+        &self,
+        entity: Entity,
+        request_update: &mut RequestEntityUpdateFromClass,
+    ) {
+        log!(
+            LogType::Editor,
+            LogLevel::Info,
+            LogCategory::Entity,
+            "Requesting irradiance volume entity update"
+        );
+
+        request_update
+            .irradiance_volume
+            .write(UserUpdatedIrradianceVolumeEvent {
+                entity,
+                data: self.clone(),
+            });
+    }
+}
+
+pub fn update_irradiance_volume_system(
+    mut reader: MessageReader<UserUpdatedIrradianceVolumeEvent>,
+    mut query: Query<&mut IrradianceVolumeSettings>,
+    asset_server: Res<AssetServer>,
+) {
+    for UserUpdatedIrradianceVolumeEvent {
+        entity: requested_entity,
+        data: new,
+    } in reader.read()
+    {
+        log!(
+            LogType::Editor,
+            LogLevel::Info,
+            LogCategory::Entity,
+            "Heard irradiance volume update event: {}",
+            requested_entity
+        );
+        if let Ok(mut settings) = query.get_mut(*requested_entity) {
+            *settings = new.settings(&asset_server);
+        } else {
+            log!(
+                LogType::Editor,
+                LogLevel::Error,
+                LogCategory::Entity,
+                "Could not find irradiance volume on: {}",
+                requested_entity
+            );
+        }
+    }
+}
+
+/// Scales the volume to the bounds and binds the voxels once they are usable
+pub fn sync_irradiance_volume_system(
+    mut commands: Commands,
+    irradiance_query: Query<(Ref<IrradianceVolumeSettings>, &Children)>,
+    mut volume_query: Query<&mut Transform, With<LightProbeVolume>>,
+    environment_images: Res<EnvironmentImages>,
+) {
+    for (settings, children) in irradiance_query.iter() {
+        if !settings.is_changed() && !environment_images.is_changed() {
+            continue;
+        }
+        for &child in children {
+            let Ok(mut transform) = volume_query.get_mut(child) else {
+                continue;
+            };
+            if transform.scale != settings.size {
+                transform.scale = settings.size;
+            }
+
+            match &settings.voxels {
+                Some(voxels) if environment_images.is_ready(&settings.voxels) => {
+                    commands.entity(child).insert(IrradianceVolume {
+                        voxels: voxels.clone(),
+                        intensity: settings.intensity,
+                        ..Default::default()
+                    });
+                }
+                _ => {
+                    commands.entity(child).remove::<IrradianceVolume>();
+                }
+            }
+        }
+    }
+}
@@ -0,0 +1,196 @@
+use super::{IrradianceVolumeSettings, ReflectionProbeSettings, SkyEnvironmentSettings};
+use crate::{absolute_asset_to_rel, shared::asset_file_browser};
+use bevy::{
+    asset::{AssetEvent, AssetId, AssetServer, Assets, Handle},
+    ecs::{
+        component::Component,
+        message::MessageReader,
+        system::{Query, Res, ResMut},
+    },
+    image::Image,
+    prelude::Resource,
+    render::render_resource::{TextureDimension, TextureViewDescriptor, TextureViewDimension},
+};
+use bevy_egui::egui;
+use bevy_granite_logging::{
+    config::{LogCategory, LogLevel, LogType},
+    log,
+};
+use std::collections::HashSet;
+
+/// The child holding a probe's `LightProbe`, scaled to the class bounds so the
+/// class entity itself keeps a unit scale
+#[derive(Component, Clone, Copy, Debug, Default)]
+pub struct LightProbeVolume;
+
+/// Cubemaps and voxel textures used by the environment classes that can be bound
+/// Skyboxes and probes only get an image once it is in here, a plain 2D image fails on the GPU
+#[derive(Resource, Default)]
+pub struct EnvironmentImages {
+    ready: HashSet<AssetId<Image>>,
+    rejected: HashSet<AssetId<Image>>,
+    // Images this turned into cubes, so their modified event is not a reload
+    converted: HashSet<AssetId<Image>>,
+}
+
+impl EnvironmentImages {
+    pub fn is_ready(&self, handle: &Option<Handle<Image>>) -> bool {
+        handle
+            .as_ref()
+            .is_some_and(|handle| self.ready.contains(&handle.id()))
+    }
+}
+
+/// Loads an environment image relative to assets, None when no path is set
+pub fn load_environment_image(asset_server: &AssetServer, path: &str) -> Option<Handle<Image>> {
+    (!path.is_empty()).then(|| asset_server.load(path.to_string()))
+}
+
+fn is_cubemap(image: &Image) -> bool {
+    image
+        .texture_view_descriptor
+        .as_ref()
+        .is_some_and(|view| view.dimension == Some(TextureViewDimension::Cube))
+}
+
+fn image_path(asset_server: &AssetServer, id: AssetId<Image>) -> String {
+    asset_server
+        .get_path(id)
+        .map(|path| path.to_string())
+        .unwrap_or_else(|| format!("{:?}", id))
+}
+
+/// KTX2 cubemaps are used as is, PNGs with six faces stacked vertically are turned into cubes
+pub fn prepare_environment_images_system(
+    mut image_events: MessageReader<AssetEvent<Image>>,
+    mut images: ResMut<Assets<Image>>,
+    mut environment_images: ResMut<EnvironmentImages>,
+    asset_server: Res<AssetServer>,
+    sky_query: Query<&SkyEnvironmentSettings>,
+    probe_query: Query<&ReflectionProbeSettings>,
+    volume_query: Query<&IrradianceVolumeSettings>,
+) {
+    // A reload brings back the raw image, so it gets checked again
+    for event in image_events.read() {
+        if let AssetEvent::Modified { id } = event {
+            if !environment_images.converted.remove(id) {
+                environment_images.ready.remove(id);
+                environment_images.rejected.remove(id);
+            }
+        }
+    }
+
+    let cubemaps: HashSet<AssetId<Image>> = sky_query
+        .iter()
+        .flat_map(|sky| sky.cubemaps())
+        .chain(probe_query.iter().flat_map(|probe| probe.cubemaps()))
+        .collect();
+    for id in cubemaps {
+        if environment_images.ready.contains(&id) || environment_images.rejected.contains(&id) {
+            continue;
+        }
+        let Some(image) = images.get(id) else {
+            continue;
+        };
+        if is_cubemap(image) {
+            environment_images.ready.insert(id);
+            continue;
+        }
+
+        let size = image.texture_descriptor.size;
+        let stacked = size.depth_or_array_layers == 1 && size.height == size.width * 6;
+        let converted = stacked
+            && images.get_mut(id).is_some_and(|image| {
+                if image.reinterpret_stacked_2d_as_array(6).is_err() {
+                    return false;
+                }
+                image.texture_view_descriptor = Some(TextureViewDescriptor {
+                    dimension: Some(TextureViewDimension::Cube),
+                    ..Default::default()
+                });
+                true
+            });
+
+        if converted {
+            environment_images.converted.insert(id);
+            environment_images.ready.insert(id);
+        } else {
+            log!(
+                LogType::Game,
+                LogLevel::Warning,
+                LogCategory::Asset,
+                "{} is not a cubemap. Use a .ktx2 cubemap or six faces stacked vertically",
+                image_path(&asset_server, id)
+            );
+            environment_images.rejected.insert(id);
+        }
+    }
+
+    let volumes: HashSet<AssetId<Image>> = volume_query
+        .iter()
+        .filter_map(|volume| volume.voxels.as_ref().map(|voxels| voxels.id()))
+        .collect();
+    for id in volumes {
+        if environment_images.ready.contains(&id) || environment_images.rejected.contains(&id) {
+            continue;
+        }
+        let Some(image) = images.get(id) else {
+            continue;
+        };
+        if image.texture_descriptor.dimension == TextureDimension::D3 {
+            environment_images.ready.insert(id);
+        } else {
+            log!(
+                LogType::Game,
+                LogLevel::Warning,
+                LogCategory::Asset,
+                "{} is not a 3D texture. Irradiance volumes need a baked .ktx2 volume",
+                image_path(&asset_server, id)
+            );
+            environment_images.rejected.insert(id);
+        }
+    }
+}
+
+/// Path field with a browse button for an image, relative to assets
+pub fn environment_image_field(
+    ui: &mut egui::Ui,
+    label: &str,
+    path: &mut String,
+    extensions: Vec<&str>,
+) -> bool {
+    let mut changed = false;
+    ui.label(label);
+    ui.horizontal(|ui| {
+        changed |= ui.text_edit_singleline(path).changed();
+        ui.spacing_mut().button_padding = egui::Vec2::new(2.0, 2.0);
+        if ui.button("📁").clicked() {
+            if let Some(selected) = asset_file_browser("textures".to_string(), extensions) {
+                *path = absolute_asset_to_rel(selected).to_string();
+                changed = true;
+            }
+        }
+        if !path.is_empty() && ui.button("❌").on_hover_text("Clear").clicked() {
+            path.clear();
+            changed = true;
+        }
+    });
+    ui.end_row();
+    changed
+}
+
+/// Drag fields for probe bounds, every axis at least a centimeter
+pub fn probe_size_field(ui: &mut egui::Ui, size: &mut (f32, f32, f32)) -> bool {
+    let mut changed = false;
+    ui.label("Size:");
+    ui.horizontal(|ui| {
+        for (axis, value) in [("X", &mut size.0), ("Y", &mut size.1), ("Z", &mut size.2)] {
+            ui.label(axis);
+            changed |= ui
+                .add(egui::DragValue::new(value).range(0.01..=1000.0).speed(0.05))
+                .changed();
+        }
+    });
+    ui.end_row();
+    changed
+}
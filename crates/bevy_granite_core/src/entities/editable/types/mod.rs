@@ -1,8 +1,14 @@
 pub mod camera_3d;
+pub mod convex_brush;
 pub mod directional_light;
 pub mod empty;
+pub mod irradiance_volume;
+pub mod light_probe;
 pub mod obj;
 pub mod point_light;
+pub mod rect_light;
+pub mod reflection_probe;
+pub mod sky_environment;
 pub mod spot_light;
 pub mod unknown;
 pub mod rect_brush;
@@ -12,12 +18,33 @@ pub mod plugin;
 // Re-exports
 // Class Types
 pub use camera_3d::{Camera3D, Camera3DPlugin, UserUpdatedCamera3DEvent, VolumetricFog};
+pub use convex_brush::{
+    BrushPrimitive, ConvexBrush, ConvexBrushPlugin, UserUpdatedConvexBrushEvent,
+};
 pub use directional_light::{DirLight, DirLightPlugin, UserUpdatedDirectionalLightEvent};
 pub use empty::{Empty, EmptyPlugin, UserUpdatedEmptyEvent};
+pub use irradiance_volume::{
+    IrradianceVolumeData, IrradianceVolumePlugin, IrradianceVolumeSettings,
+    UserUpdatedIrradianceVolumeEvent,
+};
+pub use light_probe::{
+    environment_image_field, load_environment_image, prepare_environment_images_system,
+    probe_size_field, EnvironmentImages, LightProbeVolume,
+};
 pub use obj::{OBJPlugin, UserUpdatedOBJEvent, OBJ};
 pub use point_light::{PointLightData, PointLightPlugin, UserUpdatedPointLightEvent};
+pub use rect_light::{
+    RectLightData, RectLightPanel, RectLightPlugin, RectLightSample, UserUpdatedRectLightEvent,
+};
+pub use reflection_probe::{
+    ReflectionProbe, ReflectionProbePlugin, ReflectionProbeSettings,
+    UserUpdatedReflectionProbeEvent,
+};
+pub use sky_environment::{
+    SkyEnvironment, SkyEnvironmentPlugin, SkyEnvironmentSettings, UserUpdatedSkyEnvironmentEvent,
+};
 pub use spot_light::{SpotLightData, SpotLightPlugin, UserUpdatedSpotLightEvent};
 pub use unknown::Unknown;
-pub use rect_brush::{UserUpdatedRectBrushEvent, RectBrush, RectBrushPlugin};
+pub use rect_brush::{UserUpdatedRectBrushEvent, RectBrush, RectBrushFace, RectBrushPlugin};
 
 pub use plugin::ClassTypePlugin;
\ No newline at end of file
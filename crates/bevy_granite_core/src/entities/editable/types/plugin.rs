@@ -1,5 +1,6 @@
 use super::*;
-use bevy::app::{App, Plugin};
+use crate::entities::editable::UserUpdatedCustomClassEvent;
+use bevy::app::{App, Plugin, Update};
 pub struct ClassTypePlugin;
 impl Plugin for ClassTypePlugin {
     fn build(&self, app: &mut App) {
@@ -10,8 +11,20 @@ impl Plugin for ClassTypePlugin {
             .add_plugins(DirLightPlugin)
             .add_plugins(PointLightPlugin)
             .add_plugins(SpotLightPlugin)
+            .add_plugins(RectLightPlugin)
+            .add_plugins(SkyEnvironmentPlugin)
+            .add_plugins(ReflectionProbePlugin)
+            .add_plugins(IrradianceVolumePlugin)
             .add_plugins(RectBrushPlugin)
+            .add_plugins(ConvexBrushPlugin)
             .add_plugins(EmptyPlugin)
-            .add_plugins(OBJPlugin);
+            .add_plugins(OBJPlugin)
+            //
+            // Cubemaps and voxels shared by the environment classes
+            .init_resource::<EnvironmentImages>()
+            .add_systems(Update, prepare_environment_images_system)
+            //
+            // Classes registered from other crates
+            .add_message::<UserUpdatedCustomClassEvent>();
     }
 }
@@ -1,7 +1,8 @@
 use super::RectBrush;
 use crate::{
-    entities::EntitySaveReadyData, AvailableEditableMaterials, GraniteEditorSerdeEntity,
-    GraniteType, GraniteTypes, HasRuntimeData, IdentityData, NeedsTangents,
+    entities::{editable::spawn_brush_face_material_groups, EntitySaveReadyData},
+    AvailableEditableMaterials, GraniteEditorSerdeEntity, GraniteType, GraniteTypes,
+    HasRuntimeData, IdentityData, NeedsTangents,
 };
 use bevy::{
     asset::{AssetServer, Assets, RenderAssetUsages},
@@ -10,7 +11,7 @@ use bevy::{
         entity::Entity,
         system::{Commands, Res, ResMut},
     },
-    math::{Vec2, Vec3},
+    math::{Mat4, Vec2, Vec3},
     mesh::{Indices, Mesh, Mesh3d, PrimitiveTopology},
     pbr::{MeshMaterial3d, StandardMaterial},
     prelude::Name,
@@ -105,14 +106,31 @@ impl RectBrush {
         // Load and configure the material
         self.load_and_configure_material(available_materials, standard_materials, asset_server);
 
-        commands
+        let entity = commands
             .spawn(Self::get_bundle(
                 self.clone(), // Clone AFTER fixing materials
                 identity.clone(),
                 transform,
                 meshes,
             ))
-            .id()
+            .id();
+
+        // UV locked brushes get their world uvs once GlobalTransform is ready
+        if let Some(brush_material) = self.material.current.handle.clone() {
+            spawn_brush_face_material_groups(
+                commands,
+                entity,
+                self.material_overrides(),
+                |path| self.build_mesh_for_material(Some(path), Mat4::IDENTITY),
+                brush_material,
+                meshes,
+                standard_materials,
+                available_materials,
+                asset_server,
+            );
+        }
+
+        entity
     }
 
     /// Build a bundle that is ready to spawn from a rect brush
@@ -122,12 +140,7 @@ impl RectBrush {
         transform: Transform,
         meshes: &mut ResMut<Assets<Mesh>>,
     ) -> impl Bundle {
-        let rect_mesh = Self::create_mesh(
-            rectangle_brush.size.x,
-            rectangle_brush.size.y,
-            rectangle_brush.size.z,
-            rectangle_brush.uv_scale,
-        );
+        let rect_mesh = rectangle_brush.build_mesh();
         let rect_handle = meshes.add(rect_mesh);
 
         (
@@ -285,6 +298,85 @@ impl RectBrush {
         normals
     }
 
+    /// Mesh for this brush. Edited geometry wins over the size box
+    pub fn build_mesh(&self) -> Mesh {
+        self.build_mesh_for_material(None, Mat4::IDENTITY)
+    }
+
+    /// Faces of this brush using a material override (None = brush material)
+    /// uv_space is the brush's world matrix and only matters when UV lock is on
+    pub fn build_mesh_for_material(&self, material: Option<&str>, uv_space: Mat4) -> Mesh {
+        let mut mesh = Mesh::new(
+            PrimitiveTopology::TriangleList,
+            RenderAssetUsages::default(),
+        );
+        self.write_mesh_for_material(&mut mesh, material, uv_space);
+        mesh
+    }
+
+    /// Rewrite an existing mesh in place with this brush's current shape
+    pub fn apply_to_mesh(&self, mesh: &mut Mesh, uv_space: Mat4) {
+        self.write_mesh_for_material(mesh, None, uv_space);
+    }
+
+    /// Shared mesh writer. Unlocked boxes keep the stretched 0..uv_scale mapping per side,
+    /// locked or mesh edited brushes go through BrushGeometry projection
+    pub fn write_mesh_for_material(&self, mesh: &mut Mesh, material: Option<&str>, uv_space: Mat4) {
+        let uv_space = if self.uv_lock {
+            uv_space
+        } else {
+            Mat4::IDENTITY
+        };
+        if let Some(geometry) = &self.geometry {
+            geometry.write_faces_to_mesh(mesh, self.uv_scale, material, uv_space);
+            return;
+        }
+        if self.uv_lock {
+            self.styled_box()
+                .write_faces_to_mesh(mesh, self.uv_scale, material, uv_space);
+            return;
+        }
+
+        let all_vertices = Self::get_vertices(self.size.x, self.size.y, self.size.z);
+        let all_uvs = Self::get_uvs(Vec2::ONE);
+        let all_indices = Self::get_indices();
+
+        let mut vertices = Vec::new();
+        let mut uvs = Vec::new();
+        let mut indices = Vec::new();
+        for (side, face) in self.faces.iter().enumerate() {
+            if face.material.as_deref() != material {
+                continue;
+            }
+            let base = vertices.len() as u32;
+            for corner in side * 4..side * 4 + 4 {
+                vertices.push(all_vertices[corner]);
+                uvs.push(
+                    face.uv
+                        .apply(Vec2::from(all_uvs[corner]), self.uv_scale)
+                        .to_array(),
+                );
+            }
+            indices.extend(
+                all_indices[side * 6..side * 6 + 6]
+                    .iter()
+                    .map(|index| index - side as u32 * 4 + base),
+            );
+        }
+        let normals = Self::calculate_normals(&vertices, &indices);
+
+        mesh.insert_attribute(Mesh::ATTRIBUTE_POSITION, vertices);
+        mesh.insert_attribute(Mesh::ATTRIBUTE_UV_0, uvs);
+        mesh.insert_attribute(Mesh::ATTRIBUTE_NORMAL, normals);
+        mesh.insert_indices(Indices::U32(indices));
+
+        // Tangents are stale once uvs change
+        if mesh.contains_attribute(Mesh::ATTRIBUTE_TANGENT) {
+            mesh.remove_attribute(Mesh::ATTRIBUTE_TANGENT);
+            let _ = mesh.generate_tangents();
+        }
+    }
+
     pub fn create_mesh(x: f32, y: f32, z: f32, uv_scale: Vec2) -> Mesh {
         let mut mesh = Mesh::new(
             PrimitiveTopology::TriangleList,
@@ -2,7 +2,7 @@ use crate::{
     assets::EditableMaterial,
     entities::{
         editable::{
-            GraniteType, RequestEntityUpdateFromClass, RequiredMaterialData,
+            BrushGeometry, FaceUv, GraniteType, RequestEntityUpdateFromClass, RequiredMaterialData,
             RequiredMaterialDataMut,
         },
         EntitySaveReadyData, PromptData,
@@ -44,11 +44,32 @@ pub struct UserUpdatedRectBrushEvent {
 /// Size is x,y,z that actually edits the verts of this brush, not just a scale
 /// UV scale is mapped directly to the verts, so this is separate then Material editing UVs
 /// Rectangle Brushes contain materials on their surface so we pass the path, last, and current material under MaterialData
+/// Geometry is only set once the brush has been mesh edited
+/// When set it replaces the box built from size
+/// Faces hold per side material overrides and UV placement for the box
+/// Edited geometry keeps its own per face data
+/// UV lock projects UVs in world space so textures line up across brushes and don't stretch
 #[derive(Serialize, Deserialize, Reflect, Debug, Clone, PartialEq)]
 pub struct RectBrush {
     pub size: Vec3,
     pub uv_scale: Vec2,
     pub material: MaterialData,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub geometry: Option<BrushGeometry>,
+    #[serde(default, skip_serializing_if = "RectBrush::faces_are_default")]
+    pub faces: [RectBrushFace; 6],
+    #[serde(default, skip_serializing_if = "std::ops::Not::not")]
+    pub uv_lock: bool,
+}
+
+/// Material override and UV placement for one side of a rectangle brush
+/// Material is a relative .mat path. None uses the brush material
+#[derive(Serialize, Deserialize, Reflect, Debug, Clone, PartialEq, Default)]
+pub struct RectBrushFace {
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub material: Option<String>,
+    #[serde(default, skip_serializing_if = "FaceUv::is_default")]
+    pub uv: FaceUv,
 }
 
 impl RectBrush {
@@ -64,6 +85,31 @@ impl RectBrush {
     pub fn internal_material_path() -> String {
         "materials/internal/rect_brush.mat".to_string()
     }
+
+    /// Side names in face order. Matches both get_vertices and BrushGeometry::from_box
+    pub const FACE_NAMES: [&'static str; 6] = ["Front", "Back", "Right", "Left", "Top", "Bottom"];
+
+    fn faces_are_default(faces: &[RectBrushFace; 6]) -> bool {
+        faces.iter().all(|face| *face == RectBrushFace::default())
+    }
+
+    /// Box geometry built from size, carrying the per side face settings
+    pub fn styled_box(&self) -> BrushGeometry {
+        let mut geometry = BrushGeometry::from_box(self.size);
+        for (face, style) in geometry.faces.iter_mut().zip(self.faces.iter()) {
+            face.material = style.material.clone();
+            face.uv = style.uv.clone();
+        }
+        geometry
+    }
+
+    /// Every distinct per face material override in use
+    pub fn material_overrides(&self) -> Vec<String> {
+        match &self.geometry {
+            Some(geometry) => geometry.material_overrides(),
+            None => self.styled_box().material_overrides(),
+        }
+    }
 }
 
 impl Default for RectBrush {
@@ -86,6 +132,9 @@ impl Default for RectBrush {
                 current: brush_material.clone(),
                 last: brush_material.clone(),
             },
+            geometry: None,
+            faces: Default::default(),
+            uv_lock: false,
         }
     }
 }
@@ -161,4 +210,13 @@ impl GraniteType for RectBrush {
     fn needs_unique_handle(&self) -> bool {
         true
     }
+
+    fn get_brush_geometry(&self) -> Option<BrushGeometry> {
+        Some(self.geometry.clone().unwrap_or_else(|| self.styled_box()))
+    }
+
+    fn set_brush_geometry(&mut self, geometry: Option<BrushGeometry>) -> bool {
+        self.geometry = geometry;
+        true
+    }
 }
@@ -1,6 +1,7 @@
-use crate::RectBrush;
-
-use super::{update_rectangle_brush_system, UserUpdatedRectBrushEvent};
+use super::{
+    refresh_uv_locked_rect_brush_system, update_rectangle_brush_system, RectBrush, RectBrushFace,
+    UserUpdatedRectBrushEvent,
+};
 use bevy::app::{App, Plugin, Update};
 
 pub struct RectBrushPlugin;
@@ -15,9 +16,16 @@ impl Plugin for RectBrushPlugin {
             // Register
             //
             .register_type::<RectBrush>()
+            .register_type::<RectBrushFace>()
             //
             // Schedule system
             //
-            .add_systems(Update, update_rectangle_brush_system);
+            .add_systems(
+                Update,
+                (
+                    update_rectangle_brush_system,
+                    refresh_uv_locked_rect_brush_system,
+                ),
+            );
     }
 }
@@ -1,4 +1,5 @@
 use super::RectBrush;
+use crate::entities::editable::edit_brush_face_ui;
 use bevy::math::{Vec2, Vec3};
 use bevy_egui::egui;
 
@@ -25,12 +26,29 @@ impl RectBrush {
         ui.label(egui::RichText::new("Rectangle Brush Data").italics());
         ui.add_space(large_spacing);
 
+        // Mesh edited brushes ignore size, so show geometry info and a way back instead
+        if let Some(geometry) = &rect_data.geometry {
+            ui.horizontal(|ui| {
+                ui.label(format!(
+                    "Mesh Edited: {} verts, {} faces",
+                    geometry.vertices.len(),
+                    geometry.faces.len()
+                ));
+                if ui.button("Reset Geometry").clicked() {
+                    rect_data.geometry = None;
+                    changed = true;
+                }
+            });
+            ui.add_space(large_spacing);
+        }
+
         // Size
+        let size_editable = rect_data.geometry.is_none();
         ui.vertical(|ui| {
             ui.label("Brush Size:");
             ui.add_space(normal_spacing);
 
-            ui.horizontal(|ui| {
+            ui.add_enabled_ui(size_editable, |ui| {
                 egui::Grid::new("size_grid")
                     .num_columns(4)
                     .spacing([1.0, 0.0])
@@ -106,8 +124,93 @@ impl RectBrush {
                         }
                     });
             });
+            ui.add_space(small_spacing);
+            changed |= ui
+                .checkbox(&mut rect_data.uv_lock, "UV Lock (World Space)")
+                .on_hover_text("Keep textures continuous when moved or resized")
+                .changed();
+        });
+
+        ui.add_space(large_spacing);
+
+        // Per face material and UV. The selected face index lives in egui memory
+        // Mesh edited brushes edit their geometry faces, otherwise the six box sides
+        let face_id = ui.make_persistent_id("rect_brush_selected_face");
+        let mut face_index: usize = ui
+            .ctx()
+            .data_mut(|d| d.get_temp(face_id))
+            .unwrap_or_default();
+        let face_labels: Vec<String> = match &rect_data.geometry {
+            Some(geometry) => (0..geometry.faces.len())
+                .map(|i| format!("Face {}", i))
+                .collect(),
+            None => RectBrush::FACE_NAMES
+                .iter()
+                .map(|name| name.to_string())
+                .collect(),
+        };
+        if face_labels.is_empty() {
+            return changed;
+        }
+        face_index = face_index.min(face_labels.len() - 1);
+
+        ui.vertical(|ui| {
+            ui.label("Face:");
+            ui.add_space(small_spacing);
+            let mut apply_to_all = false;
+            ui.horizontal(|ui| {
+                egui::ComboBox::from_id_salt("rect_face_combo")
+                    .selected_text(face_labels[face_index].clone())
+                    .show_ui(ui, |ui| {
+                        for (i, label) in face_labels.iter().enumerate() {
+                            ui.selectable_value(&mut face_index, i, label);
+                        }
+                    });
+                apply_to_all = ui.button("Apply To All").clicked();
+            });
+            ui.add_space(small_spacing);
+
+            match &mut rect_data.geometry {
+                Some(geometry) => {
+                    if apply_to_all {
+                        let source = geometry.faces[face_index].clone();
+                        for face in geometry.faces.iter_mut() {
+                            face.material = source.material.clone();
+                            face.uv = source.uv.clone();
+                        }
+                        changed = true;
+                    }
+                    let face = &mut geometry.faces[face_index];
+                    changed |= edit_brush_face_ui(
+                        ui,
+                        "rect",
+                        &mut face.material,
+                        &mut face.uv,
+                        drag_size,
+                        small_spacing,
+                    );
+                }
+                None => {
+                    if apply_to_all {
+                        let source = rect_data.faces[face_index].clone();
+                        rect_data.faces = std::array::from_fn(|_| source.clone());
+                        changed = true;
+                    }
+                    let face = &mut rect_data.faces[face_index];
+                    changed |= edit_brush_face_ui(
+                        ui,
+                        "rect",
+                        &mut face.material,
+                        &mut face.uv,
+                        drag_size,
+                        small_spacing,
+                    );
+                }
+            }
         });
 
+        ui.ctx().data_mut(|d| d.insert_temp(face_id, face_index));
+
         changed
     }
 }
@@ -1,16 +1,28 @@
-use crate::entities::editable::RequestEntityUpdateFromClass;
+use crate::{
+    entities::editable::{
+        spawn_brush_face_material_groups, BrushFaceMaterialGroup, RequestEntityUpdateFromClass,
+    },
+    AvailableEditableMaterials, GraniteTypes, IdentityData,
+};
 
 use super::{RectBrush, UserUpdatedRectBrushEvent};
 use bevy::{
-    asset::Assets,
+    asset::{AssetServer, Assets},
     camera::primitives::{Aabb, MeshAabb},
     ecs::{
         message::MessageReader,
-        system::{Query, ResMut},
+        query::{Changed, With},
+        system::{Commands, Query, Res, ResMut},
     },
+    math::Mat4,
     mesh::Mesh3d,
+    pbr::{MeshMaterial3d, StandardMaterial},
+    transform::components::GlobalTransform,
+};
+use bevy::{
+    mesh::Mesh,
+    prelude::{Children, Entity},
 };
-use bevy::{mesh::Mesh, prelude::Entity};
 use bevy_granite_logging::{log, LogCategory, LogLevel, LogType};
 
 impl RectBrush {
@@ -35,10 +47,21 @@ impl RectBrush {
 }
 
 pub fn update_rectangle_brush_system(
+    mut commands: Commands,
     mut reader: MessageReader<UserUpdatedRectBrushEvent>,
-    mut query: Query<(Entity, &Mesh3d)>,
+    mut query: Query<(
+        Entity,
+        &Mesh3d,
+        Option<&MeshMaterial3d<StandardMaterial>>,
+        &GlobalTransform,
+        Option<&Children>,
+    )>,
+    face_groups: Query<(), With<BrushFaceMaterialGroup>>,
     mut meshes: ResMut<Assets<Mesh>>,
     mut aabbs: Query<&mut Aabb>,
+    mut standard_materials: ResMut<Assets<StandardMaterial>>,
+    mut available_materials: ResMut<AvailableEditableMaterials>,
+    asset_server: Res<AssetServer>,
 ) {
     for UserUpdatedRectBrushEvent {
         entity: requested_entity,
@@ -52,16 +75,37 @@ pub fn update_rectangle_brush_system(
             "Heard rectangle brush update event: {}",
             requested_entity
         );
-        if let Ok((_entity, mesh_handle)) = query.get_mut(*requested_entity) {
-            if let Some(mesh) = meshes.get_mut(mesh_handle) {
-                let new_vertices = RectBrush::get_vertices(new.size.x, new.size.y, new.size.z);
-                let new_uvs = RectBrush::get_uvs(new.uv_scale);
-                let indices = RectBrush::get_indices();
-                let normals = RectBrush::calculate_normals(&new_vertices, &indices);
+        if let Ok((_entity, mesh_handle, brush_material, global, children)) =
+            query.get_mut(*requested_entity)
+        {
+            let uv_space = Mat4::from(global.affine());
 
-                mesh.insert_attribute(Mesh::ATTRIBUTE_POSITION, new_vertices);
-                mesh.insert_attribute(Mesh::ATTRIBUTE_UV_0, new_uvs);
-                mesh.insert_attribute(Mesh::ATTRIBUTE_NORMAL, normals);
+            // Face material children are cheap, rebuild them from scratch
+            if let Some(children) = children {
+                for child in children.iter() {
+                    if face_groups.get(child).is_ok() {
+                        commands.entity(child).despawn();
+                    }
+                }
+            }
+            spawn_brush_face_material_groups(
+                &mut commands,
+                *requested_entity,
+                new.material_overrides(),
+                |path| new.build_mesh_for_material(Some(path), uv_space),
+                // Registered material kinds replace the standard material on the entity
+                brush_material.map_or_else(
+                    || new.material.current.handle.clone().unwrap_or_default(),
+                    |material| material.0.clone(),
+                ),
+                &mut meshes,
+                &mut standard_materials,
+                &mut available_materials,
+                &asset_server,
+            );
+
+            if let Some(mesh) = meshes.get_mut(mesh_handle) {
+                new.apply_to_mesh(mesh, uv_space);
                 // Compute the new AABB from the updated mesh
                 if let Some(new_mesh_aabb) = mesh.compute_aabb() {
                     if let Ok(mut entity_aabb) = aabbs.get_mut(*requested_entity) {
@@ -113,3 +157,35 @@ pub fn update_rectangle_brush_system(
         }
     }
 }
+
+/// UV locked brushes project in world space, so their uvs follow the transform
+/// Only uvs change here, positions and the AABB stay the same
+pub fn refresh_uv_locked_rect_brush_system(
+    brushes: Query<
+        (&IdentityData, &GlobalTransform, &Mesh3d, Option<&Children>),
+        Changed<GlobalTransform>,
+    >,
+    face_groups: Query<(&BrushFaceMaterialGroup, &Mesh3d)>,
+    mut meshes: ResMut<Assets<Mesh>>,
+) {
+    for (identity, global, mesh_handle, children) in brushes.iter() {
+        let GraniteTypes::RectBrush(brush) = &identity.class else {
+            continue;
+        };
+        if !brush.uv_lock {
+            continue;
+        }
+
+        let uv_space = Mat4::from(global.affine());
+        if let Some(mesh) = meshes.get_mut(mesh_handle) {
+            brush.apply_to_mesh(mesh, uv_space);
+        }
+        for child in children.into_iter().flat_map(|children| children.iter()) {
+            if let Ok((group, group_mesh)) = face_groups.get(child) {
+                if let Some(mesh) = meshes.get_mut(group_mesh) {
+                    brush.write_mesh_for_material(mesh, Some(&group.path), uv_space);
+                }
+            }
+        }
+    }
+}
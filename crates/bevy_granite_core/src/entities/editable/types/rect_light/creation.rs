@@ -0,0 +1,123 @@
+use super::{RectLightData, RectLightPanel, RectLightSample};
+use crate::{
+    entities::EntitySaveReadyData, GraniteEditorSerdeEntity, GraniteType, GraniteTypes,
+    HasRuntimeData, IdentityData, TreeHiddenEntity,
+};
+use bevy::{
+    camera::visibility::Visibility,
+    color::Color,
+    ecs::{bundle::Bundle, entity::Entity, system::Commands},
+    light::SpotLight,
+    prelude::Name,
+    transform::components::Transform,
+};
+use std::f32::consts::PI;
+use uuid::Uuid;
+
+// A full hemisphere cone gives the spot light shadow maps a degenerate projection, so the
+// samples stop short of it and leave the last few degrees by the panel unlit
+const SAMPLE_OUTER_ANGLE: f32 = 80.0 * PI / 180.0;
+
+impl RectLightData {
+    /// Extract needed info to spawn this entity via save data
+    pub fn spawn_from_save_data(
+        save_data: &EntitySaveReadyData,
+        commands: &mut Commands,
+    ) -> Entity {
+        let identity = &save_data.identity;
+        let save_transform = &save_data.transform;
+
+        Self::spawn_from_identity(commands, identity, save_transform.to_bevy())
+    }
+
+    /// Take the name and class from identity to spawn
+    pub fn spawn_from_identity(
+        commands: &mut Commands,
+        identity: &IdentityData,
+        transform: Transform,
+    ) -> Entity {
+        let class = Self::extract_class(&identity);
+
+        class.spawn(identity, commands, transform)
+    }
+
+    /// Generally to be used from UI popups as it gives default name
+    pub fn spawn_from_new_identity(&self, commands: &mut Commands, transform: Transform) -> Entity {
+        let identity = IdentityData {
+            name: self.type_name(),
+            uuid: Uuid::new_v4(),
+            class: GraniteTypes::RectLightData(self.clone()),
+        };
+        self.spawn(&identity, commands, transform)
+    }
+
+    /// Private core logic
+    fn spawn(
+        &self,
+        identity: &IdentityData,
+        commands: &mut Commands,
+        transform: Transform,
+    ) -> Entity {
+        let entity = commands
+            .spawn(Self::get_bundle(identity.clone(), transform))
+            .id();
+        self.spawn_samples(commands, entity);
+        entity
+    }
+
+    /// Spawns the spot light grid under the panel, facing the panel's forward
+    pub fn spawn_samples(&self, commands: &mut Commands, panel: Entity) {
+        let (columns, rows) = self.sample_count();
+        let cell_width = self.width / columns as f32;
+        let cell_height = self.height / rows as f32;
+        let intensity = self.intensity / (columns * rows) as f32;
+
+        commands.entity(panel).with_children(|panel| {
+            for column in 0..columns {
+                for row in 0..rows {
+                    let x = (column as f32 + 0.5) * cell_width - self.width * 0.5;
+                    let y = (row as f32 + 0.5) * cell_height - self.height * 0.5;
+                    panel.spawn((
+                        Transform::from_xyz(x, y, 0.0),
+                        SpotLight {
+                            color: Color::linear_rgb(self.color.0, self.color.1, self.color.2),
+                            intensity,
+                            range: self.range,
+                            radius: cell_width.min(cell_height) * 0.5,
+                            shadows_enabled: self.shadows_enabled,
+                            inner_angle: 0.0,
+                            outer_angle: SAMPLE_OUTER_ANGLE,
+                            ..Default::default()
+                        },
+                        RectLightSample,
+                        TreeHiddenEntity,
+                    ));
+                }
+            }
+        });
+    }
+
+    /// Build a bundle that is ready to spawn from a Rect Light
+    fn get_bundle(identity: IdentityData, transform: Transform) -> impl Bundle {
+        (
+            transform,
+            Visibility::default(),
+            RectLightPanel,
+            Name::new(identity.name.clone()),
+            HasRuntimeData,
+            GraniteEditorSerdeEntity,
+            IdentityData {
+                name: identity.name.clone(),
+                uuid: identity.uuid.clone(),
+                class: identity.class.clone(),
+            },
+        )
+    }
+
+    fn extract_class(identity: &IdentityData) -> RectLightData {
+        match &identity.class {
+            GraniteTypes::RectLightData(rect_light_data) => rect_light_data.clone(),
+            _ => panic!("Expected RectLightData class data, got different type from save data"),
+        }
+    }
+}
@@ -0,0 +1,142 @@
+use crate::{
+    entities::editable::{GraniteType, RequestEntityUpdateFromClass},
+    entities::EntitySaveReadyData,
+    AvailableEditableMaterials,
+};
+use crate::{ClassCategory, PromptData};
+use bevy::{
+    asset::{AssetServer, Assets},
+    ecs::{
+        component::Component,
+        entity::Entity,
+        message::Message,
+        system::{Commands, Res, ResMut},
+    },
+    mesh::Mesh,
+    pbr::StandardMaterial,
+    prelude::Reflect,
+    transform::components::Transform,
+};
+use bevy_egui::egui;
+use serde::{Deserialize, Serialize};
+
+pub mod creation;
+pub mod plugin;
+pub mod ui;
+pub mod update_event;
+
+pub use plugin::*;
+pub use update_event::*;
+
+/// Internal event thats called when user edits UI rect light variable
+#[derive(Message)]
+pub struct UserUpdatedRectLightEvent {
+    pub entity: Entity,
+    pub data: RectLightData,
+}
+
+/// Actual serialized class data thats stored inside IdentityData
+/// Bevy has no area lights, so the panel is approximated by a grid of wide spot lights
+/// that share the intensity and are sized to their cell. More samples give softer light and
+/// shadows at the cost of one shadow map per sample. The shadows are only approximate, each
+/// sample casts a hard spot light shadow and the cones stop at 80 degrees from the normal
+#[derive(Serialize, Deserialize, Reflect, Debug, Clone, PartialEq)]
+pub struct RectLightData {
+    pub color: (f32, f32, f32),
+    /// Total for the panel, split across the samples
+    pub intensity: f32,
+    pub width: f32,
+    pub height: f32,
+    pub range: f32,
+    /// Spot lights across the width and height
+    pub samples: (u32, u32),
+    pub shadows_enabled: bool,
+}
+
+impl Default for RectLightData {
+    fn default() -> Self {
+        Self {
+            color: (1., 1., 1.),
+            intensity: 400_000.0,
+            width: 2.0,
+            height: 1.0,
+            range: 20.0,
+            samples: (2, 1),
+            shadows_enabled: false,
+        }
+    }
+}
+
+impl RectLightData {
+    pub const MAX_SAMPLES: u32 = 8;
+
+    pub fn sample_count(&self) -> (u32, u32) {
+        (
+            self.samples.0.clamp(1, Self::MAX_SAMPLES),
+            self.samples.1.clamp(1, Self::MAX_SAMPLES),
+        )
+    }
+}
+
+/// Marks the parent of a rect light's spot light samples
+#[derive(Component, Clone, Copy, Debug, Default)]
+pub struct RectLightPanel;
+
+/// One spot light of a rect light, rebuilt whenever the class changes
+#[derive(Component, Clone, Copy, Debug, Default)]
+pub struct RectLightSample;
+
+impl GraniteType for RectLightData {
+    fn type_name(&self) -> String {
+        "Rect Light".to_string()
+    }
+
+    fn type_abv(&self) -> String {
+        "R.Light".to_string()
+    }
+
+    fn category(&self) -> ClassCategory {
+        ClassCategory::Light
+    }
+
+    fn get_embedded_icon_bytes(&self) -> Option<&'static [u8]> {
+        Some(include_bytes!("RectLight.png"))
+    }
+
+    fn get_icon_filename(&self) -> Option<&'static str> {
+        Some("RectLight.png")
+    }
+
+    fn spawn_from_new_identity(
+        &mut self,
+        commands: &mut Commands,
+        transform: Transform,
+        _standard_materials: ResMut<Assets<StandardMaterial>>,
+        _meshes: ResMut<Assets<Mesh>>,
+        _available_materials: ResMut<AvailableEditableMaterials>,
+        _asset_server: Res<AssetServer>,
+        _maybe_prompt_data: Option<PromptData>,
+    ) -> Entity {
+        RectLightData::spawn_from_new_identity(self, commands, transform)
+    }
+
+    fn spawn_from_save_data(
+        &self,
+        save_data: &EntitySaveReadyData,
+        commands: &mut Commands,
+        _standard_materials: &mut ResMut<Assets<StandardMaterial>>,
+        _meshes: &mut ResMut<Assets<Mesh>>,
+        _available_materials: &mut ResMut<AvailableEditableMaterials>,
+        _asset_server: &Res<AssetServer>,
+    ) -> Entity {
+        RectLightData::spawn_from_save_data(save_data, commands)
+    }
+
+    fn push_to_entity(&self, entity: Entity, request_update: &mut RequestEntityUpdateFromClass) {
+        RectLightData::push_to_entity(self, entity, request_update);
+    }
+
+    fn edit_via_ui(&mut self, ui: &mut egui::Ui, spacing: (f32, f32, f32)) -> bool {
+        RectLightData::edit_via_ui(self, ui, spacing)
+    }
+}
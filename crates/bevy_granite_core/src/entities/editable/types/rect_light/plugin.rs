@@ -0,0 +1,22 @@
+use super::{update_rect_light_system, UserUpdatedRectLightEvent};
+use crate::RectLightData;
+use bevy::app::{App, Plugin, Update};
+
+pub struct RectLightPlugin;
+impl Plugin for RectLightPlugin {
+    fn build(&self, app: &mut App) {
+        app
+            //
+            // Event
+            //
+            .add_message::<UserUpdatedRectLightEvent>()
+            //
+            // Register
+            //
+            .register_type::<RectLightData>()
+            //
+            // Schedule system
+            //
+            .add_systems(Update, update_rect_light_system);
+    }
+}
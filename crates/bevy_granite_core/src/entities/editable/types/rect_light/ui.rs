@@ -0,0 +1,109 @@
+use crate::GraniteType;
+
+use super::RectLightData;
+use bevy_egui::egui;
+
+impl RectLightData {
+    /// Function to edit self's data via UI side panel
+    /// We have a sister system that pushes changes to world entity
+    /// It can be found inside 'update_event.rs'
+    /// When true, sends an update to propagate these vars to the world's entity
+    pub fn edit_via_ui(&mut self, ui: &mut egui::Ui, spacing: (f32, f32, f32)) -> bool {
+        let type_name = self.type_name();
+        let data = self;
+        let large_spacing = spacing.1;
+        ui.label(egui::RichText::new(type_name).italics());
+        ui.add_space(large_spacing);
+
+        let mut changed = false;
+        ui.vertical(|ui| {
+            let mut color_array = [
+                (data.color.0 * 255.0) as u8,
+                (data.color.1 * 255.0) as u8,
+                (data.color.2 * 255.0) as u8,
+            ];
+
+            egui::Grid::new("rect_light_data_grid")
+                .num_columns(2)
+                .spacing([large_spacing, large_spacing])
+                .striped(true)
+                .show(ui, |ui| {
+                    ui.label("Color:");
+                    if ui.color_edit_button_srgb(&mut color_array).changed() {
+                        data.color = (
+                            color_array[0] as f32 / 255.0,
+                            color_array[1] as f32 / 255.0,
+                            color_array[2] as f32 / 255.0,
+                        );
+                        changed = true;
+                    }
+                    ui.end_row();
+
+                    ui.label("Intensity:");
+                    changed |= ui
+                        .add(
+                            egui::DragValue::new(&mut data.intensity)
+                                .range(0.0..=4_000_000.0)
+                                .speed(100.0)
+                                .suffix(" lm"),
+                        )
+                        .changed();
+                    ui.end_row();
+
+                    ui.label("Width:");
+                    changed |= ui
+                        .add(
+                            egui::DragValue::new(&mut data.width)
+                                .range(0.01..=50.0)
+                                .speed(0.01),
+                        )
+                        .changed();
+                    ui.end_row();
+
+                    ui.label("Height:");
+                    changed |= ui
+                        .add(
+                            egui::DragValue::new(&mut data.height)
+                                .range(0.01..=50.0)
+                                .speed(0.01),
+                        )
+                        .changed();
+                    ui.end_row();
+
+                    ui.label("Range:");
+                    changed |= ui
+                        .add(
+                            egui::DragValue::new(&mut data.range)
+                                .range(0.0..=200.0)
+                                .speed(0.1),
+                        )
+                        .changed();
+                    ui.end_row();
+
+                    ui.label("Samples:");
+                    ui.horizontal(|ui| {
+                        for value in [&mut data.samples.0, &mut data.samples.1] {
+                            changed |= ui
+                                .add(
+                                    egui::DragValue::new(value)
+                                        .range(1..=RectLightData::MAX_SAMPLES)
+                                        .speed(0.05),
+                                )
+                                .changed();
+                        }
+                    })
+                    .response
+                    .on_hover_text("Spot lights across the width and height");
+                    ui.end_row();
+
+                    ui.label("Shadows Enabled:");
+                    changed |= ui
+                        .checkbox(&mut data.shadows_enabled, "")
+                        .on_hover_text("Approximate, one hard spot light shadow map per sample")
+                        .changed();
+                    ui.end_row();
+                });
+        });
+        changed
+    }
+}
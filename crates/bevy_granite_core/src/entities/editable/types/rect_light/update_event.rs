@@ -0,0 +1,71 @@
+use super::{RectLightData, RectLightPanel, RectLightSample, UserUpdatedRectLightEvent};
+use crate::entities::editable::RequestEntityUpdateFromClass;
+use bevy::ecs::{
+    entity::Entity,
+    hierarchy::Children,
+    message::MessageReader,
+    query::With,
+    system::{Commands, Query},
+};
+use bevy_granite_logging::{log, LogCategory, LogLevel, LogType};
+use std::collections::HashMap;
+
+impl RectLightData {
+    /// Request an entity update with this data
+    pub fn push_to_entity(
+        &self,
+        entity: Entity,
+        request_update: &mut RequestEntityUpdateFromClass,
+    ) {
+        log!(
+            LogType::Editor,
+            LogLevel::Info,
+            LogCategory::Entity,
+            "Requesting rect light entity update"
+        );
+
+        request_update.rect_light.write(UserUpdatedRectLightEvent {
+            entity,
+            data: self.clone(),
+        });
+    }
+}
+
+/// The sample grid changes shape with the class, so it is respawned instead of patched
+/// Only the last update per entity in a frame is used, the despawns aren't applied until after
+pub fn update_rect_light_system(
+    mut commands: Commands,
+    mut reader: MessageReader<UserUpdatedRectLightEvent>,
+    query: Query<Option<&Children>, With<RectLightPanel>>,
+    sample_query: Query<(), With<RectLightSample>>,
+) {
+    let latest: HashMap<Entity, &RectLightData> = reader
+        .read()
+        .map(|event| (event.entity, &event.data))
+        .collect();
+    for (requested_entity, new) in latest.iter() {
+        log!(
+            LogType::Editor,
+            LogLevel::Info,
+            LogCategory::Entity,
+            "Heard rect light update event: {}",
+            requested_entity
+        );
+        if let Ok(children) = query.get(*requested_entity) {
+            for &child in children.into_iter().flatten() {
+                if sample_query.contains(child) {
+                    commands.entity(child).despawn();
+                }
+            }
+            new.spawn_samples(&mut commands, *requested_entity);
+        } else {
+            log!(
+                LogType::Editor,
+                LogLevel::Error,
+                LogCategory::Entity,
+                "Could not find rect light on: {}",
+                requested_entity
+            );
+        }
+    }
+}
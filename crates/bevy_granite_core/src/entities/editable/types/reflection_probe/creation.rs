@@ -0,0 +1,107 @@
+use super::{ReflectionProbe, ReflectionProbeSettings};
+use crate::{
+    entities::{editable::LightProbeVolume, EntitySaveReadyData},
+    GraniteEditorSerdeEntity, GraniteType, GraniteTypes, HasRuntimeData, IdentityData,
+    TreeHiddenEntity,
+};
+use bevy::{
+    asset::AssetServer,
+    camera::visibility::Visibility,
+    ecs::{bundle::Bundle, entity::Entity, system::Commands},
+    light::LightProbe,
+    prelude::Name,
+    transform::components::Transform,
+};
+use uuid::Uuid;
+
+impl ReflectionProbe {
+    /// Extract needed info to spawn this entity via save data
+    pub fn spawn_from_save_data(
+        save_data: &EntitySaveReadyData,
+        commands: &mut Commands,
+        asset_server: &AssetServer,
+    ) -> Entity {
+        let identity = &save_data.identity;
+        let save_transform = &save_data.transform;
+
+        Self::spawn_from_identity(commands, identity, save_transform.to_bevy(), asset_server)
+    }
+
+    /// Take the name and class from identity to spawn
+    pub fn spawn_from_identity(
+        commands: &mut Commands,
+        identity: &IdentityData,
+        transform: Transform,
+        asset_server: &AssetServer,
+    ) -> Entity {
+        let class = Self::extract_class(&identity);
+
+        class.spawn(identity, commands, transform, asset_server)
+    }
+
+    /// Generally to be used from UI popups as it gives default name
+    pub fn spawn_from_new_identity(
+        &self,
+        commands: &mut Commands,
+        transform: Transform,
+        asset_server: &AssetServer,
+    ) -> Entity {
+        let identity = IdentityData {
+            name: self.type_name(),
+            uuid: Uuid::new_v4(),
+            class: GraniteTypes::ReflectionProbe(self.clone()),
+        };
+        self.spawn(&identity, commands, transform, asset_server)
+    }
+
+    /// Private core logic
+    fn spawn(
+        &self,
+        identity: &IdentityData,
+        commands: &mut Commands,
+        transform: Transform,
+        asset_server: &AssetServer,
+    ) -> Entity {
+        let settings = self.settings(asset_server);
+        // The probe itself sits on a child so the bounds scale stays off the entity transform
+        let volume = (
+            LightProbe,
+            Transform::from_scale(settings.size),
+            Visibility::default(),
+            LightProbeVolume,
+            TreeHiddenEntity,
+        );
+        commands
+            .spawn(Self::get_bundle(settings, identity.clone(), transform))
+            .with_child(volume)
+            .id()
+    }
+
+    /// Build a bundle that is ready to spawn from a Reflection Probe
+    fn get_bundle(
+        settings: ReflectionProbeSettings,
+        identity: IdentityData,
+        transform: Transform,
+    ) -> impl Bundle {
+        (
+            transform,
+            Visibility::default(),
+            settings,
+            Name::new(identity.name.clone()),
+            HasRuntimeData,
+            GraniteEditorSerdeEntity,
+            IdentityData {
+                name: identity.name.clone(),
+                uuid: identity.uuid.clone(),
+                class: identity.class.clone(),
+            },
+        )
+    }
+
+    fn extract_class(identity: &IdentityData) -> ReflectionProbe {
+        match &identity.class {
+            GraniteTypes::ReflectionProbe(probe) => probe.clone(),
+            _ => panic!("Expected ReflectionProbe class data, got different type from save data"),
+        }
+    }
+}
@@ -0,0 +1,146 @@
+use crate::{
+    entities::editable::{GraniteType, RequestEntityUpdateFromClass},
+    entities::EntitySaveReadyData,
+    AvailableEditableMaterials,
+};
+use crate::{ClassCategory, PromptData};
+use bevy::{
+    asset::{AssetId, AssetServer, Assets, Handle},
+    ecs::{
+        component::Component,
+        entity::Entity,
+        message::Message,
+        system::{Commands, Res, ResMut},
+    },
+    image::Image,
+    math::Vec3,
+    mesh::Mesh,
+    pbr::StandardMaterial,
+    prelude::Reflect,
+    transform::components::Transform,
+};
+use bevy_egui::egui;
+use serde::{Deserialize, Serialize};
+
+use super::load_environment_image;
+
+pub mod creation;
+pub mod plugin;
+pub mod ui;
+pub mod update_event;
+
+pub use plugin::*;
+pub use update_event::*;
+
+/// Internal event thats called when user edits UI reflection probe variable
+#[derive(Message)]
+pub struct UserUpdatedReflectionProbeEvent {
+    pub entity: Entity,
+    pub data: ReflectionProbe,
+}
+
+/// Actual serialized class data thats stored inside IdentityData
+/// Baked cubemaps that replace the sky environment for everything inside the bounds
+#[derive(Serialize, Deserialize, Reflect, Debug, Clone, PartialEq)]
+pub struct ReflectionProbe {
+    pub diffuse_map: String,
+    pub specular_map: String,
+    pub intensity: f32,
+    /// Full extents of the probe bounds, centered on the entity
+    pub size: (f32, f32, f32),
+}
+
+impl Default for ReflectionProbe {
+    fn default() -> Self {
+        Self {
+            diffuse_map: String::new(),
+            specular_map: String::new(),
+            intensity: 1000.0,
+            size: (10., 10., 10.),
+        }
+    }
+}
+
+/// Runtime side of a reflection probe, synced onto its `LightProbeVolume` child
+#[derive(Component, Clone, Debug)]
+pub struct ReflectionProbeSettings {
+    pub diffuse_map: Option<Handle<Image>>,
+    pub specular_map: Option<Handle<Image>>,
+    pub intensity: f32,
+    pub size: Vec3,
+}
+
+impl ReflectionProbeSettings {
+    pub fn cubemaps(&self) -> impl Iterator<Item = AssetId<Image>> + '_ {
+        [&self.diffuse_map, &self.specular_map]
+            .into_iter()
+            .flatten()
+            .map(|handle| handle.id())
+    }
+}
+
+impl ReflectionProbe {
+    pub fn settings(&self, asset_server: &AssetServer) -> ReflectionProbeSettings {
+        ReflectionProbeSettings {
+            diffuse_map: load_environment_image(asset_server, &self.diffuse_map),
+            specular_map: load_environment_image(asset_server, &self.specular_map),
+            intensity: self.intensity,
+            size: Vec3::new(self.size.0, self.size.1, self.size.2),
+        }
+    }
+}
+
+impl GraniteType for ReflectionProbe {
+    fn type_name(&self) -> String {
+        "Reflection Probe".to_string()
+    }
+
+    fn type_abv(&self) -> String {
+        "Refl Probe".to_string()
+    }
+
+    fn category(&self) -> ClassCategory {
+        ClassCategory::Light
+    }
+
+    fn get_embedded_icon_bytes(&self) -> Option<&'static [u8]> {
+        Some(include_bytes!("ReflectionProbe.png"))
+    }
+
+    fn get_icon_filename(&self) -> Option<&'static str> {
+        Some("ReflectionProbe.png")
+    }
+
+    fn spawn_from_new_identity(
+        &mut self,
+        commands: &mut Commands,
+        transform: Transform,
+        _standard_materials: ResMut<Assets<StandardMaterial>>,
+        _meshes: ResMut<Assets<Mesh>>,
+        _available_materials: ResMut<AvailableEditableMaterials>,
+        asset_server: Res<AssetServer>,
+        _maybe_prompt_data: Option<PromptData>,
+    ) -> Entity {
+        ReflectionProbe::spawn_from_new_identity(self, commands, transform, &asset_server)
+    }
+
+    fn spawn_from_save_data(
+        &self,
+        save_data: &EntitySaveReadyData,
+        commands: &mut Commands,
+        _standard_materials: &mut ResMut<Assets<StandardMaterial>>,
+        _meshes: &mut ResMut<Assets<Mesh>>,
+        _available_materials: &mut ResMut<AvailableEditableMaterials>,
+        asset_server: &Res<AssetServer>,
+    ) -> Entity {
+        ReflectionProbe::spawn_from_save_data(save_data, commands, asset_server)
+    }
+
+    fn push_to_entity(&self, entity: Entity, request_update: &mut RequestEntityUpdateFromClass) {
+        ReflectionProbe::push_to_entity(self, entity, request_update);
+    }
+
+    fn edit_via_ui(&mut self, ui: &mut egui::Ui, spacing: (f32, f32, f32)) -> bool {
+        ReflectionProbe::edit_via_ui(self, ui, spacing)
+    }
+}
@@ -0,0 +1,30 @@
+use super::{
+    sync_reflection_probe_system, update_reflection_probe_system, ReflectionProbe,
+    UserUpdatedReflectionProbeEvent,
+};
+use bevy::{
+    app::{App, Plugin, Update},
+    ecs::schedule::IntoScheduleConfigs,
+};
+
+pub struct ReflectionProbePlugin;
+impl Plugin for ReflectionProbePlugin {
+    fn build(&self, app: &mut App) {
+        app
+            //
+            // Event
+            //
+            .add_message::<UserUpdatedReflectionProbeEvent>()
+            //
+            // Register
+            //
+            .register_type::<ReflectionProbe>()
+            //
+            // Schedule system
+            //
+            .add_systems(
+                Update,
+                (update_reflection_probe_system, sync_reflection_probe_system).chain(),
+            );
+    }
+}
@@ -0,0 +1,112 @@
+use super::{ReflectionProbeSettings, UserUpdatedReflectionProbeEvent};
+use crate::entities::editable::{
+    EnvironmentImages, LightProbeVolume, RequestEntityUpdateFromClass,
+};
+use crate::ReflectionProbe;
+use bevy::{
+    asset::AssetServer,
+    ecs::{
+        change_detection::{DetectChanges, Ref},
+        entity::Entity,
+        hierarchy::Children,
+        message::MessageReader,
+        query::With,
+        system::{Commands, Query, Res},
+    },
+    light::EnvironmentMapLight,
+    transform::components::Transform,
+};
+use bevy_granite_logging::{log, LogCategory, LogLevel, LogType};
+
+impl ReflectionProbe {
+    /// Request an entity update with this data
+    pub fn push_to_entity(
+        &self,
+        entity: Entity,
+        request_update: &mut RequestEntityUpdateFromClass,
+    ) {
+        log!(
+            LogType::Editor,
+            LogLevel::Info,
+            LogCategory::Entity,
+            "Requesting reflection probe entity update"
+        );
+
+        request_update
+            .reflection_probe
+            .write(UserUpdatedReflectionProbeEvent {
+                entity,
+                data: self.clone(),
+            });
+    }
+}
+
+pub fn update_reflection_probe_system(
+    mut reader: MessageReader<UserUpdatedReflectionProbeEvent>,
+    mut query: Query<&mut ReflectionProbeSettings>,
+    asset_server: Res<AssetServer>,
+) {
+    for UserUpdatedReflectionProbeEvent {
+        entity: requested_entity,
+        data: new,
+    } in reader.read()
+    {
+        log!(
+            LogType::Editor,
+            LogLevel::Info,
+            LogCategory::Entity,
+            "Heard reflection probe update event: {}",
+            requested_entity
+        );
+        if let Ok(mut settings) = query.get_mut(*requested_entity) {
+            *settings = new.settings(&asset_server);
+        } else {
+            log!(
+                LogType::Editor,
+                LogLevel::Error,
+                LogCategory::Entity,
+                "Could not find reflection probe on: {}",
+                requested_entity
+            );
+        }
+    }
+}
+
+/// Scales the probe volume to the bounds and binds the cubemaps once they are usable
+pub fn sync_reflection_probe_system(
+    mut commands: Commands,
+    probe_query: Query<(Ref<ReflectionProbeSettings>, &Children)>,
+    mut volume_query: Query<&mut Transform, With<LightProbeVolume>>,
+    environment_images: Res<EnvironmentImages>,
+) {
+    for (settings, children) in probe_query.iter() {
+        if !settings.is_changed() && !environment_images.is_changed() {
+            continue;
+        }
+        for &child in children {
+            let Ok(mut transform) = volume_query.get_mut(child) else {
+                continue;
+            };
+            if transform.scale != settings.size {
+                transform.scale = settings.size;
+            }
+
+            match (&settings.diffuse_map, &settings.specular_map) {
+                (Some(diffuse), Some(specular))
+                    if environment_images.is_ready(&settings.diffuse_map)
+                        && environment_images.is_ready(&settings.specular_map) =>
+                {
+                    commands.entity(child).insert(EnvironmentMapLight {
+                        diffuse_map: diffuse.clone(),
+                        specular_map: specular.clone(),
+                        intensity: settings.intensity,
+                        ..Default::default()
+                    });
+                }
+                _ => {
+                    commands.entity(child).remove::<EnvironmentMapLight>();
+                }
+            }
+        }
+    }
+}
@@ -0,0 +1,101 @@
+use super::SkyEnvironment;
+use crate::{
+    entities::EntitySaveReadyData, GraniteEditorSerdeEntity, GraniteType, GraniteTypes,
+    HasRuntimeData, IdentityData,
+};
+use bevy::{
+    asset::AssetServer,
+    camera::visibility::Visibility,
+    ecs::{bundle::Bundle, entity::Entity, system::Commands},
+    prelude::Name,
+    transform::components::Transform,
+};
+use uuid::Uuid;
+
+impl SkyEnvironment {
+    /// Extract needed info to spawn this entity via save data
+    pub fn spawn_from_save_data(
+        save_data: &EntitySaveReadyData,
+        commands: &mut Commands,
+        asset_server: &AssetServer,
+    ) -> Entity {
+        let identity = &save_data.identity;
+        let save_transform = &save_data.transform;
+
+        Self::spawn_from_identity(commands, identity, save_transform.to_bevy(), asset_server)
+    }
+
+    /// Take the name and class from identity to spawn
+    pub fn spawn_from_identity(
+        commands: &mut Commands,
+        identity: &IdentityData,
+        transform: Transform,
+        asset_server: &AssetServer,
+    ) -> Entity {
+        let class = Self::extract_class(&identity);
+
+        class.spawn(identity, commands, transform, asset_server)
+    }
+
+    /// Generally to be used from UI popups as it gives default name
+    pub fn spawn_from_new_identity(
+        &self,
+        commands: &mut Commands,
+        transform: Transform,
+        asset_server: &AssetServer,
+    ) -> Entity {
+        let identity = IdentityData {
+            name: self.type_name(),
+            uuid: Uuid::new_v4(),
+            class: GraniteTypes::SkyEnvironment(self.clone()),
+        };
+        self.spawn(&identity, commands, transform, asset_server)
+    }
+
+    /// Private core logic
+    fn spawn(
+        &self,
+        identity: &IdentityData,
+        commands: &mut Commands,
+        transform: Transform,
+        asset_server: &AssetServer,
+    ) -> Entity {
+        commands
+            .spawn(Self::get_bundle(
+                self,
+                identity.clone(),
+                transform,
+                asset_server,
+            ))
+            .id()
+    }
+
+    /// Build a bundle that is ready to spawn from a Sky Environment
+    fn get_bundle(
+        sky: &SkyEnvironment,
+        identity: IdentityData,
+        transform: Transform,
+        asset_server: &AssetServer,
+    ) -> impl Bundle {
+        (
+            transform,
+            Visibility::default(),
+            sky.settings(asset_server),
+            Name::new(identity.name.clone()),
+            HasRuntimeData,
+            GraniteEditorSerdeEntity,
+            IdentityData {
+                name: identity.name.clone(),
+                uuid: identity.uuid.clone(),
+                class: identity.class.clone(),
+            },
+        )
+    }
+
+    fn extract_class(identity: &IdentityData) -> SkyEnvironment {
+        match &identity.class {
+            GraniteTypes::SkyEnvironment(sky) => sky.clone(),
+            _ => panic!("Expected SkyEnvironment class data, got different type from save data"),
+        }
+    }
+}
@@ -0,0 +1,169 @@
+use crate::{
+    entities::editable::{GraniteType, RequestEntityUpdateFromClass},
+    entities::EntitySaveReadyData,
+    AvailableEditableMaterials,
+};
+use crate::{ClassCategory, PromptData};
+use bevy::{
+    asset::{AssetId, AssetServer, Assets, Handle},
+    color::Color,
+    ecs::{
+        component::Component,
+        entity::Entity,
+        message::Message,
+        system::{Commands, Res, ResMut},
+    },
+    image::Image,
+    math::Quat,
+    mesh::Mesh,
+    pbr::StandardMaterial,
+    prelude::Reflect,
+    transform::components::Transform,
+};
+use bevy_egui::egui;
+use serde::{Deserialize, Serialize};
+
+use super::load_environment_image;
+
+pub mod creation;
+pub mod plugin;
+pub mod ui;
+pub mod update_event;
+
+pub use plugin::*;
+pub use update_event::*;
+
+/// Internal event thats called when user edits UI sky environment variable
+#[derive(Message)]
+pub struct UserUpdatedSkyEnvironmentEvent {
+    pub entity: Entity,
+    pub data: SkyEnvironment,
+}
+
+/// Actual serialized class data thats stored inside IdentityData
+/// Scene wide lighting, applied to the ambient light and every scene camera while it exists
+#[derive(Serialize, Deserialize, Reflect, Debug, Clone, PartialEq)]
+pub struct SkyEnvironment {
+    pub ambient_color: (f32, f32, f32),
+    pub ambient_brightness: f32,
+    /// Cubemap drawn behind the scene, empty for none
+    pub skybox: String,
+    pub skybox_brightness: f32,
+    /// Prefiltered cubemaps that light the scene, both are needed
+    pub diffuse_map: String,
+    pub specular_map: String,
+    pub environment_intensity: f32,
+    /// Turns the skybox and environment around the vertical axis, in degrees
+    pub rotation: f32,
+}
+
+impl Default for SkyEnvironment {
+    fn default() -> Self {
+        Self {
+            ambient_color: (1., 1., 1.),
+            ambient_brightness: 80.0,
+            skybox: String::new(),
+            skybox_brightness: 1000.0,
+            diffuse_map: String::new(),
+            specular_map: String::new(),
+            environment_intensity: 1000.0,
+            rotation: 0.0,
+        }
+    }
+}
+
+/// Runtime side of a sky environment, read by `apply_sky_environment_system`
+#[derive(Component, Clone, Debug)]
+pub struct SkyEnvironmentSettings {
+    pub ambient_color: Color,
+    pub ambient_brightness: f32,
+    pub skybox: Option<Handle<Image>>,
+    pub skybox_brightness: f32,
+    pub diffuse_map: Option<Handle<Image>>,
+    pub specular_map: Option<Handle<Image>>,
+    pub environment_intensity: f32,
+    pub rotation: Quat,
+}
+
+impl SkyEnvironmentSettings {
+    pub fn cubemaps(&self) -> impl Iterator<Item = AssetId<Image>> + '_ {
+        [&self.skybox, &self.diffuse_map, &self.specular_map]
+            .into_iter()
+            .flatten()
+            .map(|handle| handle.id())
+    }
+}
+
+impl SkyEnvironment {
+    pub fn settings(&self, asset_server: &AssetServer) -> SkyEnvironmentSettings {
+        SkyEnvironmentSettings {
+            ambient_color: Color::linear_rgb(
+                self.ambient_color.0,
+                self.ambient_color.1,
+                self.ambient_color.2,
+            ),
+            ambient_brightness: self.ambient_brightness,
+            skybox: load_environment_image(asset_server, &self.skybox),
+            skybox_brightness: self.skybox_brightness,
+            diffuse_map: load_environment_image(asset_server, &self.diffuse_map),
+            specular_map: load_environment_image(asset_server, &self.specular_map),
+            environment_intensity: self.environment_intensity,
+            rotation: Quat::from_rotation_y(self.rotation.to_radians()),
+        }
+    }
+}
+
+impl GraniteType for SkyEnvironment {
+    fn type_name(&self) -> String {
+        "Sky Environment".to_string()
+    }
+
+    fn type_abv(&self) -> String {
+        "Sky".to_string()
+    }
+
+    fn category(&self) -> ClassCategory {
+        ClassCategory::Light
+    }
+
+    fn get_embedded_icon_bytes(&self) -> Option<&'static [u8]> {
+        Some(include_bytes!("SkyEnvironment.png"))
+    }
+
+    fn get_icon_filename(&self) -> Option<&'static str> {
+        Some("SkyEnvironment.png")
+    }
+
+    fn spawn_from_new_identity(
+        &mut self,
+        commands: &mut Commands,
+        transform: Transform,
+        _standard_materials: ResMut<Assets<StandardMaterial>>,
+        _meshes: ResMut<Assets<Mesh>>,
+        _available_materials: ResMut<AvailableEditableMaterials>,
+        asset_server: Res<AssetServer>,
+        _maybe_prompt_data: Option<PromptData>,
+    ) -> Entity {
+        SkyEnvironment::spawn_from_new_identity(self, commands, transform, &asset_server)
+    }
+
+    fn spawn_from_save_data(
+        &self,
+        save_data: &EntitySaveReadyData,
+        commands: &mut Commands,
+        _standard_materials: &mut ResMut<Assets<StandardMaterial>>,
+        _meshes: &mut ResMut<Assets<Mesh>>,
+        _available_materials: &mut ResMut<AvailableEditableMaterials>,
+        asset_server: &Res<AssetServer>,
+    ) -> Entity {
+        SkyEnvironment::spawn_from_save_data(save_data, commands, asset_server)
+    }
+
+    fn push_to_entity(&self, entity: Entity, request_update: &mut RequestEntityUpdateFromClass) {
+        SkyEnvironment::push_to_entity(self, entity, request_update);
+    }
+
+    fn edit_via_ui(&mut self, ui: &mut egui::Ui, spacing: (f32, f32, f32)) -> bool {
+        SkyEnvironment::edit_via_ui(self, ui, spacing)
+    }
+}
@@ -0,0 +1,30 @@
+use super::{
+    apply_sky_environment_system, update_sky_environment_system, SkyEnvironment,
+    UserUpdatedSkyEnvironmentEvent,
+};
+use bevy::{
+    app::{App, Plugin, Update},
+    ecs::schedule::IntoScheduleConfigs,
+};
+
+pub struct SkyEnvironmentPlugin;
+impl Plugin for SkyEnvironmentPlugin {
+    fn build(&self, app: &mut App) {
+        app
+            //
+            // Event
+            //
+            .add_message::<UserUpdatedSkyEnvironmentEvent>()
+            //
+            // Register
+            //
+            .register_type::<SkyEnvironment>()
+            //
+            // Schedule system
+            //
+            .add_systems(
+                Update,
+                (update_sky_environment_system, apply_sky_environment_system).chain(),
+            );
+    }
+}
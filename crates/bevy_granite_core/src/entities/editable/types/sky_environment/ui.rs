@@ -0,0 +1,107 @@
+use super::SkyEnvironment;
+use crate::{entities::editable::environment_image_field, GraniteType};
+use bevy_egui::egui;
+
+impl SkyEnvironment {
+    /// Function to edit self's data via UI side panel
+    /// We have a sister system that pushes changes to world entity
+    /// It can be found inside 'update_event.rs'
+    /// When true, sends an update to propagate these vars to the world's entity
+    pub fn edit_via_ui(&mut self, ui: &mut egui::Ui, spacing: (f32, f32, f32)) -> bool {
+        let type_name = self.type_name();
+        let data = self;
+        let large_spacing = spacing.1;
+        ui.label(egui::RichText::new(type_name).italics());
+        ui.add_space(large_spacing);
+
+        let mut changed = false;
+        ui.vertical(|ui| {
+            let mut color_array = [
+                (data.ambient_color.0 * 255.0) as u8,
+                (data.ambient_color.1 * 255.0) as u8,
+                (data.ambient_color.2 * 255.0) as u8,
+            ];
+
+            egui::Grid::new("sky_environment_data_grid")
+                .num_columns(2)
+                .spacing([large_spacing, large_spacing])
+                .striped(true)
+                .show(ui, |ui| {
+                    ui.label("Ambient Color:");
+                    if ui.color_edit_button_srgb(&mut color_array).changed() {
+                        data.ambient_color = (
+                            color_array[0] as f32 / 255.0,
+                            color_array[1] as f32 / 255.0,
+                            color_array[2] as f32 / 255.0,
+                        );
+                        changed = true;
+                    }
+                    ui.end_row();
+
+                    ui.label("Ambient Brightness:");
+                    changed |= ui
+                        .add(
+                            egui::DragValue::new(&mut data.ambient_brightness)
+                                .range(0.0..=20_000.0)
+                                .speed(1.0)
+                                .suffix(" cd/m²"),
+                        )
+                        .changed();
+                    ui.end_row();
+
+                    changed |= environment_image_field(
+                        ui,
+                        "Skybox:",
+                        &mut data.skybox,
+                        vec!["ktx2", "png"],
+                    );
+
+                    ui.label("Skybox Brightness:");
+                    changed |= ui
+                        .add(
+                            egui::DragValue::new(&mut data.skybox_brightness)
+                                .range(0.0..=100_000.0)
+                                .speed(10.0)
+                                .suffix(" cd/m²"),
+                        )
+                        .changed();
+                    ui.end_row();
+
+                    changed |= environment_image_field(
+                        ui,
+                        "Diffuse Map:",
+                        &mut data.diffuse_map,
+                        vec!["ktx2", "png"],
+                    );
+                    changed |= environment_image_field(
+                        ui,
+                        "Specular Map:",
+                        &mut data.specular_map,
+                        vec!["ktx2", "png"],
+                    );
+
+                    ui.label("Environment Intensity:");
+                    changed |= ui
+                        .add(
+                            egui::DragValue::new(&mut data.environment_intensity)
+                                .range(0.0..=100_000.0)
+                                .speed(10.0),
+                        )
+                        .changed();
+                    ui.end_row();
+
+                    ui.label("Rotation:");
+                    changed |= ui
+                        .add(
+                            egui::DragValue::new(&mut data.rotation)
+                                .range(-180.0..=180.0)
+                                .speed(0.5)
+                                .suffix("°"),
+                        )
+                        .changed();
+                    ui.end_row();
+                });
+        });
+        changed
+    }
+}
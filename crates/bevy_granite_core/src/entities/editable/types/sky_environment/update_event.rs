@@ -0,0 +1,203 @@
+use super::{SkyEnvironmentSettings, UserUpdatedSkyEnvironmentEvent};
+use crate::entities::editable::{EnvironmentImages, RequestEntityUpdateFromClass};
+use crate::SkyEnvironment;
+use bevy::{
+    asset::AssetServer,
+    camera::{visibility::RenderLayers, Camera3d},
+    core_pipeline::Skybox,
+    ecs::{
+        change_detection::{DetectChanges, Ref},
+        component::Component,
+        entity::Entity,
+        message::MessageReader,
+        query::{Added, Has, With},
+        system::{Commands, Local, Query, Res, ResMut},
+    },
+    light::{EnvironmentMapLight, GlobalAmbientLight},
+    prelude::RemovedComponents,
+};
+use bevy_granite_logging::{log, LogCategory, LogLevel, LogType};
+
+impl SkyEnvironment {
+    /// Request an entity update with this data
+    pub fn push_to_entity(
+        &self,
+        entity: Entity,
+        request_update: &mut RequestEntityUpdateFromClass,
+    ) {
+        log!(
+            LogType::Editor,
+            LogLevel::Info,
+            LogCategory::Entity,
+            "Requesting sky environment entity update"
+        );
+
+        request_update
+            .sky_environment
+            .write(UserUpdatedSkyEnvironmentEvent {
+                entity,
+                data: self.clone(),
+            });
+    }
+}
+
+pub fn update_sky_environment_system(
+    mut reader: MessageReader<UserUpdatedSkyEnvironmentEvent>,
+    mut query: Query<&mut SkyEnvironmentSettings>,
+    asset_server: Res<AssetServer>,
+) {
+    for UserUpdatedSkyEnvironmentEvent {
+        entity: requested_entity,
+        data: new,
+    } in reader.read()
+    {
+        log!(
+            LogType::Editor,
+            LogLevel::Info,
+            LogCategory::Entity,
+            "Heard sky environment update event: {}",
+            requested_entity
+        );
+        if let Ok(mut settings) = query.get_mut(*requested_entity) {
+            *settings = new.settings(&asset_server);
+        } else {
+            log!(
+                LogType::Editor,
+                LogLevel::Error,
+                LogCategory::Entity,
+                "Could not find sky environment on: {}",
+                requested_entity
+            );
+        }
+    }
+}
+
+// What the sky put on a camera, so removing it leaves the camera's own components alone
+#[derive(Component, Clone, Copy, Default, PartialEq)]
+struct SkyEnvironmentCamera {
+    skybox: bool,
+    environment_map: bool,
+}
+
+/// Puts the sky environment on the ambient light and every camera drawing the scene layer
+/// Editor overlay cameras and cameras with their own skybox or environment map are left alone
+pub fn apply_sky_environment_system(
+    mut commands: Commands,
+    sky_query: Query<Ref<SkyEnvironmentSettings>>,
+    mut removed: RemovedComponents<SkyEnvironmentSettings>,
+    camera_query: Query<
+        (
+            Entity,
+            Option<&RenderLayers>,
+            Option<&SkyEnvironmentCamera>,
+            Has<Skybox>,
+            Has<EnvironmentMapLight>,
+        ),
+        With<Camera3d>,
+    >,
+    added_cameras: Query<(), Added<Camera3d>>,
+    environment_images: Res<EnvironmentImages>,
+    mut ambient_light: ResMut<GlobalAmbientLight>,
+    mut applied: Local<bool>,
+) {
+    let sky_changed = sky_query.iter().any(|sky| sky.is_changed());
+    let stale = sky_changed
+        || removed.read().count() > 0
+        || !added_cameras.is_empty()
+        || environment_images.is_changed();
+    if !stale {
+        return;
+    }
+
+    if sky_changed && sky_query.iter().count() > 1 {
+        log!(
+            LogType::Game,
+            LogLevel::Warning,
+            LogCategory::Entity,
+            "More than one Sky Environment in the scene, only the first is used"
+        );
+    }
+
+    let Some(sky) = sky_query.iter().next() else {
+        if *applied {
+            *applied = false;
+            *ambient_light = GlobalAmbientLight::default();
+        }
+        for (camera, _, inserted, _, _) in camera_query.iter() {
+            let Some(inserted) = inserted else {
+                continue;
+            };
+            let mut camera = commands.entity(camera);
+            if inserted.skybox {
+                camera.remove::<Skybox>();
+            }
+            if inserted.environment_map {
+                camera.remove::<EnvironmentMapLight>();
+            }
+            camera.remove::<SkyEnvironmentCamera>();
+        }
+        return;
+    };
+
+    ambient_light.color = sky.ambient_color;
+    ambient_light.brightness = sky.ambient_brightness;
+
+    for (camera, layers, inserted, has_skybox, has_environment_map) in camera_query.iter() {
+        if layers.is_some_and(|layers| !layers.intersects(&RenderLayers::default())) {
+            continue;
+        }
+        let inserted = inserted.copied().unwrap_or_default();
+        let mut next = inserted;
+        let mut camera = commands.entity(camera);
+
+        if inserted.skybox || !has_skybox {
+            match &sky.skybox {
+                Some(image) if environment_images.is_ready(&sky.skybox) => {
+                    camera.insert(Skybox {
+                        image: image.clone(),
+                        brightness: sky.skybox_brightness,
+                        rotation: sky.rotation,
+                    });
+                    next.skybox = true;
+                }
+                _ => {
+                    if inserted.skybox {
+                        camera.remove::<Skybox>();
+                    }
+                    next.skybox = false;
+                }
+            }
+        }
+
+        if inserted.environment_map || !has_environment_map {
+            match (&sky.diffuse_map, &sky.specular_map) {
+                (Some(diffuse), Some(specular))
+                    if environment_images.is_ready(&sky.diffuse_map)
+                        && environment_images.is_ready(&sky.specular_map) =>
+                {
+                    camera.insert(EnvironmentMapLight {
+                        diffuse_map: diffuse.clone(),
+                        specular_map: specular.clone(),
+                        intensity: sky.environment_intensity,
+                        rotation: sky.rotation,
+                        ..Default::default()
+                    });
+                    next.environment_map = true;
+                }
+                _ => {
+                    if inserted.environment_map {
+                        camera.remove::<EnvironmentMapLight>();
+                    }
+                    next.environment_map = false;
+                }
+            }
+        }
+
+        if next == SkyEnvironmentCamera::default() {
+            camera.remove::<SkyEnvironmentCamera>();
+        } else if next != inserted {
+            camera.insert(next);
+        }
+    }
+    *applied = true;
+}
@@ -4,6 +4,7 @@ use crate::{
     HasRuntimeData, IdentityData,
 };
 use bevy::{
+    asset::AssetServer,
     color::Color,
     ecs::{bundle::Bundle, entity::Entity, system::Commands},
     light::SpotLight,
@@ -17,11 +18,12 @@ impl SpotLightData {
     pub fn spawn_from_save_data(
         save_data: &EntitySaveReadyData,
         commands: &mut Commands,
+        asset_server: &AssetServer,
     ) -> Entity {
         let identity = &save_data.identity;
         let save_transform = &save_data.transform;
 
-        Self::spawn_from_identity(commands, identity, save_transform.to_bevy())
+        Self::spawn_from_identity(commands, identity, save_transform.to_bevy(), asset_server)
     }
 
     /// Take the name and class from identity to spawn
@@ -29,20 +31,26 @@ impl SpotLightData {
         commands: &mut Commands,
         identity: &IdentityData,
         transform: Transform,
+        asset_server: &AssetServer,
     ) -> Entity {
         let class = Self::extract_class(&identity);
 
-        class.spawn(identity, commands, transform)
+        class.spawn(identity, commands, transform, asset_server)
     }
 
     /// Generally to be used from UI popups as it gives default name
-    pub fn spawn_from_new_identity(&self, commands: &mut Commands, transform: Transform) -> Entity {
+    pub fn spawn_from_new_identity(
+        &self,
+        commands: &mut Commands,
+        transform: Transform,
+        asset_server: &AssetServer,
+    ) -> Entity {
         let identity = IdentityData {
             name: self.type_name(),
             uuid: Uuid::new_v4(),
             class: GraniteTypes::SpotLightData(self.clone()),
         };
-        self.spawn(&identity, commands, transform)
+        self.spawn(&identity, commands, transform, asset_server)
     }
 
     /// Private core logic
@@ -51,10 +59,14 @@ impl SpotLightData {
         identity: &IdentityData,
         commands: &mut Commands,
         transform: Transform,
+        asset_server: &AssetServer,
     ) -> Entity {
-        commands
-            .spawn(Self::get_bundle(self.clone(), identity.clone(), transform))
-            .id()
+        let mut entity =
+            commands.spawn(Self::get_bundle(self.clone(), identity.clone(), transform));
+        if let Some(cookie) = self.cookie_texture(asset_server) {
+            entity.insert(cookie);
+        }
+        entity.id()
     }
 
     /// Build a bundle that is ready to spawn from a Spot Light
@@ -11,6 +11,7 @@ use bevy::{
         message::Message,
         system::{Commands, Res, ResMut},
     },
+    light::SpotLightTexture,
     mesh::Mesh,
     pbr::StandardMaterial,
     prelude::Reflect,
@@ -47,6 +48,9 @@ pub struct SpotLightData {
     pub shadow_normal_bias: f32,
     pub inner_angle: f32,
     pub outer_angle: f32,
+    /// Texture projected through the cone like a gobo, empty for none
+    #[serde(default)]
+    pub cookie: String,
 }
 
 impl Default for SpotLightData {
@@ -61,10 +65,20 @@ impl Default for SpotLightData {
             shadow_normal_bias: 1.8,
             inner_angle: 0.0,
             outer_angle: std::f32::consts::PI / 4.0, // 45 degrees
+            cookie: String::new(),
         }
     }
 }
 
+impl SpotLightData {
+    /// The cookie as a light texture, None when no path is set
+    pub fn cookie_texture(&self, asset_server: &AssetServer) -> Option<SpotLightTexture> {
+        (!self.cookie.is_empty()).then(|| SpotLightTexture {
+            image: asset_server.load(self.cookie.clone()),
+        })
+    }
+}
+
 impl GraniteType for SpotLightData {
     fn type_name(&self) -> String {
         "Spot Light".to_string()
@@ -93,10 +107,10 @@ impl GraniteType for SpotLightData {
         _standard_materials: ResMut<Assets<StandardMaterial>>,
         _meshes: ResMut<Assets<Mesh>>,
         _available_materials: ResMut<AvailableEditableMaterials>,
-        _asset_server: Res<AssetServer>,
+        asset_server: Res<AssetServer>,
         _maybe_prompt_data: Option<PromptData>,
     ) -> Entity {
-        SpotLightData::spawn_from_new_identity(self, commands, transform)
+        SpotLightData::spawn_from_new_identity(self, commands, transform, &asset_server)
     }
 
     fn spawn_from_save_data(
@@ -106,9 +120,9 @@ impl GraniteType for SpotLightData {
         _standard_materials: &mut ResMut<Assets<StandardMaterial>>,
         _meshes: &mut ResMut<Assets<Mesh>>,
         _available_materials: &mut ResMut<AvailableEditableMaterials>,
-        _asset_server: &Res<AssetServer>,
+        asset_server: &Res<AssetServer>,
     ) -> Entity {
-        SpotLightData::spawn_from_save_data(save_data, commands)
+        SpotLightData::spawn_from_save_data(save_data, commands, asset_server)
     }
 
     fn push_to_entity(&self, entity: Entity, request_update: &mut RequestEntityUpdateFromClass) {
@@ -1,4 +1,4 @@
-use crate::GraniteType;
+use crate::{entities::editable::environment_image_field, GraniteType};
 
 use super::SpotLightData;
 use bevy_egui::egui;
@@ -104,6 +104,13 @@ impl SpotLightData {
                     ui.label("Shadows Enabled:");
                     changed |= ui.checkbox(&mut data.shadows_enabled, "").changed();
                     ui.end_row();
+
+                    changed |= environment_image_field(
+                        ui,
+                        "Cookie:",
+                        &mut data.cookie,
+                        vec!["png", "jpg", "jpeg", "tga", "ktx2"],
+                    );
                 });
         });
         changed
@@ -1,9 +1,14 @@
 use crate::entities::editable::{RequestEntityUpdateFromClass, UserUpdatedSpotLightEvent};
 use super::SpotLightData;
 use bevy::{
+    asset::AssetServer,
     color::Color,
-    ecs::{entity::Entity, message::MessageReader, system::Query},
-    light::SpotLight,
+    ecs::{
+        entity::Entity,
+        message::MessageReader,
+        system::{Commands, Query, Res},
+    },
+    light::{SpotLight, SpotLightTexture},
 };
 use bevy_granite_logging::{log, LogCategory, LogLevel, LogType};
 
@@ -31,8 +36,10 @@ impl SpotLightData {
 }
 
 pub fn update_spot_light_system(
+    mut commands: Commands,
     mut reader: MessageReader<UserUpdatedSpotLightEvent>,
-    mut query: Query<(Entity, &mut SpotLight)>,
+    mut query: Query<(Entity, &mut SpotLight, Option<&SpotLightTexture>)>,
+    asset_server: Res<AssetServer>,
 ) {
     for UserUpdatedSpotLightEvent {
         entity: requested_entity,
@@ -46,7 +53,7 @@ pub fn update_spot_light_system(
             "Heard spot light update event: {}",
             requested_entity
         );
-        if let Ok((_entity, mut spot_light)) = query.get_mut(*requested_entity) {
+        if let Ok((entity, mut spot_light, current_cookie)) = query.get_mut(*requested_entity) {
             spot_light.color = Color::linear_rgb(new.color.0, new.color.1, new.color.2);
             spot_light.intensity = new.intensity;
             spot_light.range = new.range;
@@ -56,6 +63,19 @@ pub fn update_spot_light_system(
             spot_light.shadow_normal_bias = new.shadow_normal_bias;
             spot_light.inner_angle = new.inner_angle;
             spot_light.outer_angle = new.outer_angle;
+
+            // Reinserting the same path would only churn the handle
+            let cookie = new.cookie_texture(&asset_server);
+            let current = current_cookie.map(|cookie| cookie.image.id());
+            match cookie {
+                Some(cookie) if Some(cookie.image.id()) != current => {
+                    commands.entity(entity).insert(cookie);
+                }
+                None if current.is_some() => {
+                    commands.entity(entity).remove::<SpotLightTexture>();
+                }
+                _ => {}
+            }
         } else {
             log!(
                 LogType::Editor,
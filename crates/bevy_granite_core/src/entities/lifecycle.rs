@@ -1,20 +1,26 @@
 use super::{IdentityData, SpawnSource};
 use crate::events::{RequestDespawnBySource, RequestDespawnSerializableEntities};
+use crate::world::SceneSelectionSets;
 use bevy_granite_logging::{
     config::{LogCategory, LogLevel, LogType},
     log,
 };
 
-use bevy::prelude::{Commands, Entity, MessageReader, Query, With};
+use bevy::{
+    ecs::{entity_disabling::Disabled, query::Allow},
+    prelude::{Commands, Entity, MessageReader, Query, ResMut, With},
+};
 
 /// If entity has IdentityData, it is despawned
 pub fn despawn_entities_system(
     mut despawn_watcher: MessageReader<RequestDespawnSerializableEntities>,
     mut commands: Commands,
-    serializable_query: Query<Entity, With<IdentityData>>,
+    serializable_query: Query<Entity, (With<IdentityData>, Allow<Disabled>)>,
+    mut selection_sets: ResMut<SceneSelectionSets>,
 ) {
     for RequestDespawnSerializableEntities in despawn_watcher.read() {
         despawn_recursive_serializable_entities(&mut commands, &serializable_query);
+        selection_sets.by_source.clear();
 
         log!(
             LogType::Editor,
@@ -29,10 +35,12 @@ pub fn despawn_entities_system(
 pub fn despawn_entities_by_source_system(
     mut despawn_watcher: MessageReader<RequestDespawnBySource>,
     mut commands: Commands,
-    serializable_query: Query<(Entity, &SpawnSource), With<IdentityData>>,
+    serializable_query: Query<(Entity, &SpawnSource), (With<IdentityData>, Allow<Disabled>)>,
+    mut selection_sets: ResMut<SceneSelectionSets>,
 ) {
     for RequestDespawnBySource(source) in despawn_watcher.read() {
         let mut despawned_count = 0;
+        selection_sets.by_source.remove(source.as_str());
 
         for (entity, entity_source) in serializable_query.iter() {
             if entity_source.0 == *source {
@@ -55,7 +63,7 @@ pub fn despawn_entities_by_source_system(
 // Despawn recursive
 pub fn despawn_recursive_serializable_entities(
     commands: &mut Commands,
-    serializable_query: &Query<Entity, With<IdentityData>>,
+    serializable_query: &Query<Entity, (With<IdentityData>, Allow<Disabled>)>,
 ) {
     for entity in serializable_query.iter() {
         commands.entity(entity).try_despawn();
@@ -0,0 +1,151 @@
+use bevy::{
+    asset::{AssetEvent, AssetId, Assets, Handle},
+    ecs::{
+        message::MessageReader,
+        query::{Changed, Or},
+        resource::Resource,
+    },
+    mesh::{Indices, Mesh, Mesh3d, PrimitiveTopology},
+    platform::collections::{HashMap, HashSet},
+    prelude::{Commands, Entity, GlobalTransform, Query, ResMut},
+};
+use bevy_granite_logging::{
+    config::{LogCategory, LogLevel, LogType},
+    log,
+};
+
+/// Reversed winding copies of meshes used by mirrored (negative scale) entities
+/// A reflection turns every triangle around, so without this mirrored geometry renders inside-out
+#[derive(Resource, Default)]
+pub struct MirroredMeshes {
+    /// Original mesh to its reversed copy
+    flipped: HashMap<AssetId<Mesh>, Handle<Mesh>>,
+    /// Reversed copy back to the original
+    originals: HashMap<AssetId<Mesh>, Handle<Mesh>>,
+    /// Mirrored entities whose mesh hadn't loaded yet
+    pending: HashSet<Entity>,
+}
+
+impl MirroredMeshes {
+    pub fn is_flipped(&self, id: AssetId<Mesh>) -> bool {
+        self.originals.contains_key(&id)
+    }
+
+    /// The mesh a reversed copy was made from
+    pub fn original(&self, id: AssetId<Mesh>) -> Option<Handle<Mesh>> {
+        self.originals.get(&id).cloned()
+    }
+}
+
+/// Same mesh with every triangle reversed, None for non triangle list meshes
+pub fn reversed_winding(mesh: &Mesh) -> Option<Mesh> {
+    if mesh.primitive_topology() != PrimitiveTopology::TriangleList {
+        return None;
+    }
+    let indices: Vec<u32> = match mesh.indices() {
+        Some(indices) => indices.iter().map(|index| index as u32).collect(),
+        None => (0..mesh.count_vertices() as u32).collect(),
+    };
+    let reversed = indices
+        .chunks_exact(3)
+        .flat_map(|triangle| [triangle[0], triangle[2], triangle[1]])
+        .collect();
+
+    let mut flipped = mesh.clone();
+    flipped.insert_indices(Indices::U32(reversed));
+    Some(flipped)
+}
+
+/// Swap in reversed winding meshes while an entity is mirrored and back once it is not
+/// Works from the world transform, so mirrored parents and duplicates are covered too
+/// Only entities that moved, changed mesh, use an edited mesh or are waiting on a load are checked
+pub fn mirrored_winding_system(
+    mut commands: Commands,
+    mut meshes: ResMut<Assets<Mesh>>,
+    mut mirrored: ResMut<MirroredMeshes>,
+    mut mesh_events: MessageReader<AssetEvent<Mesh>>,
+    changed: Query<
+        (Entity, &Mesh3d, &GlobalTransform),
+        Or<(Changed<GlobalTransform>, Changed<Mesh3d>)>,
+    >,
+    all: Query<(Entity, &Mesh3d, &GlobalTransform)>,
+) {
+    let mut edited = HashSet::default();
+    for event in mesh_events.read() {
+        if let AssetEvent::Modified { id } = event {
+            edited.insert(*id);
+            match mirrored.originals.remove(id) {
+                // Brushes rewrite their mesh in place, an edited copy is no longer reversed
+                Some(original) => {
+                    mirrored.flipped.remove(&original.id());
+                }
+                // The original changed, so its reversed copy is out of date
+                None => {
+                    mirrored.flipped.remove(id);
+                }
+            }
+        }
+    }
+
+    let pending = std::mem::take(&mut mirrored.pending);
+    let mut revisit = Vec::new();
+    if !edited.is_empty() || !pending.is_empty() {
+        revisit.extend(all.iter().filter(|(entity, mesh_handle, _)| {
+            let id = mesh_handle.id();
+            pending.contains(entity)
+                || edited.contains(&id)
+                || mirrored
+                    .originals
+                    .get(&id)
+                    .is_some_and(|original| edited.contains(&original.id()))
+        }));
+    }
+
+    let mut seen = HashSet::default();
+    for (entity, mesh_handle, global) in changed.iter().chain(revisit) {
+        if !seen.insert(entity) {
+            continue;
+        }
+        let negative = global.affine().matrix3.determinant() < 0.0;
+        let id = mesh_handle.id();
+        if let Some(original) = mirrored.originals.get(&id) {
+            let stale = mirrored.flipped.get(&original.id()).map(Handle::id) != Some(id);
+            // Going back to the original also picks up a fresh copy next frame
+            if !negative || stale {
+                commands.entity(entity).insert(Mesh3d(original.clone()));
+            }
+            continue;
+        }
+        if !negative {
+            continue;
+        }
+
+        let flipped = match mirrored.flipped.get(&id) {
+            Some(flipped) => flipped.clone(),
+            None => {
+                // Not loaded yet, try again next frame
+                let Some(mesh) = meshes.get(id) else {
+                    mirrored.pending.insert(entity);
+                    continue;
+                };
+                let Some(reversed) = reversed_winding(mesh) else {
+                    continue;
+                };
+                let flipped = meshes.add(reversed);
+                mirrored.flipped.insert(id, flipped.clone());
+                mirrored
+                    .originals
+                    .insert(flipped.id(), mesh_handle.0.clone());
+                log!(
+                    LogType::Game,
+                    LogLevel::Info,
+                    LogCategory::Entity,
+                    "Reversed mesh winding for mirrored entity {}",
+                    entity
+                );
+                flipped
+            }
+        };
+        commands.entity(entity).insert(Mesh3d(flipped));
+    }
+}
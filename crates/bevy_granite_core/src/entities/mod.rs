@@ -17,6 +17,7 @@ pub mod deserialize;
 pub mod editable;
 pub mod generate_tangents;
 pub mod lifecycle;
+pub mod mirrored_winding;
 pub mod plugin;
 pub mod serialize;
 pub use editable::*;
@@ -176,15 +177,23 @@ pub use component_editor::{
 };
 pub use deserialize::{deserialize_entities, GraniteEditorSerdeEntity};
 pub use editable::{
-    Camera3D, DirLight, Empty, GraniteTypes, PointLightData, RectBrush, VolumetricFog, OBJ,
+    BrushEditOperation, BrushFace, BrushFaceMaterialGroup, BrushGeometry, BrushPlane,
+    BrushPrimitive, Camera3D, ConvexBrush, CustomClass, CustomGraniteClass, DirLight, Empty,
+    EnvironmentImages, FaceUv, GraniteTypes, IrradianceVolumeData, LightProbeVolume,
+    PointLightData, RectBrush, RectBrushFace, RectLightData, RectLightPanel, ReflectionProbe,
+    RegisterGraniteClass, SkyEnvironment, SpotLightData, UserUpdatedCustomClassEvent,
+    VolumetricFog, OBJ,
 };
 pub use generate_tangents::{generate_tangents_system, NeedsTangents};
 pub use lifecycle::{
     despawn_entities_by_source_system, despawn_entities_system,
     despawn_recursive_serializable_entities,
 };
+pub use mirrored_winding::{mirrored_winding_system, reversed_winding, MirroredMeshes};
 pub use plugin::EntityPlugin;
-pub use serialize::{serialize_entities, EntitySaveReadyData, SceneData, SceneMetadata};
+pub use serialize::{
+    scene_pretty_config, serialize_entities, EntitySaveReadyData, SceneData, SceneMetadata,
+};
 
 // Im adding this so you cant select the editor camera
 // and to stop a crash because you can select a gizmo that then despawns its self
@@ -1,6 +1,7 @@
 use super::{
-    despawn_entities_system, despawn_entities_by_source_system, generate_tangents_system, BridgeTag, ComponentEditor, HasRuntimeData,
-    IdentityData, InternalNote, MainCamera, SpawnSource, UICamera
+    despawn_entities_by_source_system, despawn_entities_system, generate_tangents_system,
+    mirrored_winding_system, BridgeTag, ComponentEditor, HasRuntimeData, IdentityData,
+    InternalNote, MainCamera, MirroredMeshes, SpawnSource, UICamera,
 };
 use crate::entities::{editable::ClassTypePlugin, PromptImportSettings};
 use bevy::app::{App, Plugin, Update};
@@ -30,9 +31,18 @@ impl Plugin for EntityPlugin {
             //
             .insert_resource(ComponentEditor::default())
             .insert_resource(PromptImportSettings::default())
+            .insert_resource(MirroredMeshes::default())
             //
             // Schedule system
             //
-            .add_systems(Update, (despawn_entities_system, despawn_entities_by_source_system, generate_tangents_system));
+            .add_systems(
+                Update,
+                (
+                    despawn_entities_system,
+                    despawn_entities_by_source_system,
+                    generate_tangents_system,
+                    mirrored_winding_system,
+                ),
+            );
     }
 }
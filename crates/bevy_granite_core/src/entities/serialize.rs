@@ -1,5 +1,8 @@
 use super::{IdentityData, TransformData, SaveSettings};
-use crate::{ shared::version::Version, world::WorldState};
+use crate::{
+    shared::version::Version,
+    world::{SelectionSet, WorldState},
+};
 use bevy::prelude::{Quat, Vec3};
 use bevy_granite_logging::{
     config::{LogCategory, LogLevel, LogType},
@@ -16,6 +19,16 @@ use std::{
 };
 use uuid::Uuid;
 
+/// Formatting every scene file is written with
+pub fn scene_pretty_config() -> PrettyConfig {
+    PrettyConfig::new()
+        .depth_limit(15)
+        .separate_tuple_members(false)
+        .enumerate_arrays(false)
+        .compact_arrays(true)
+        .indentor("\t".to_string())
+}
+
 #[derive(Serialize, Deserialize, Debug, Clone, Copy)]
 pub struct SceneMetadata {
     pub format_version: Version,
@@ -26,6 +39,9 @@ pub struct SceneMetadata {
 pub struct SceneData {
     pub metadata: SceneMetadata,
     pub entities: Vec<EntitySaveReadyData>,
+
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub selection_sets: Vec<SelectionSet>,
 }
 
 #[derive(Serialize, Deserialize, Debug, Clone)]
@@ -43,6 +59,7 @@ pub struct EntitySaveReadyData {
 // want set order or something? only actually save to disk if things changed. Same with editor toml
 pub fn serialize_entities(world_state: WorldState, path: Option<String>) {
     let entities_data = world_state.entity_data;
+    let selection_sets = world_state.selection_sets;
     let runtime_data_provider = world_state.component_data.unwrap_or_default();
 
     // Read original file data for PreserveDiskFull entities
@@ -153,12 +170,7 @@ pub fn serialize_entities(world_state: WorldState, path: Option<String>) {
         None => Vec::new(),
     };
 
-    let pretty_config = PrettyConfig::new()
-        .depth_limit(15)
-        .separate_tuple_members(false)
-        .enumerate_arrays(false)
-        .compact_arrays(true)
-        .indentor("\t".to_string());
+    let pretty_config = scene_pretty_config();
 
     if let Some(path) = path {
         // Create metadata with version from TOML file
@@ -171,6 +183,7 @@ pub fn serialize_entities(world_state: WorldState, path: Option<String>) {
         let scene_data = SceneData {
             metadata,
             entities: entities_to_serialize,
+            selection_sets,
         };
 
         let serialized_data = to_string_pretty(&scene_data, pretty_config).unwrap();
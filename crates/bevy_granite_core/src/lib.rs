@@ -23,11 +23,20 @@ use crate::shared::SharedPlugin;
 use crate::world::WorldPlugin;
 
 // Re-exports
+#[allow(deprecated)]
+pub use assets::load_texture_with_repeat;
 pub use assets::{
-    get_material_from_path, load_texture_with_repeat, material_from_path_into_scene,
-    materials_from_folder_into_scene, AvailableEditableMaterials, EditableMaterial,
-    EditableMaterialError, EditableMaterialField, MaterialData, NewEditableMaterial,
-    RequiredMaterialData, RequiredMaterialDataMut, StandardMaterialDef,
+    apply_reloaded_textures_system, apply_texture_import_settings_system, collect_scene_files,
+    get_material_from_path, is_registered_material_kind, load_texture_with_settings,
+    material_from_path_into_scene, material_kind_defaults, materials_from_folder_into_scene,
+    read_texture_import_settings, reassign_scene_material, registered_material_kinds,
+    scene_material_references, texture_import_settings_path, texture_reimport_system,
+    watch_material_files_system, write_texture_import_settings, AvailableEditableMaterials,
+    EditableMaterial, EditableMaterialError, EditableMaterialField, GraniteMaterialKind,
+    MaterialData, MaterialFileChange, MaterialFileChangedEvent, MaterialFileWatcher, MaterialParam,
+    MaterialParams, NewEditableMaterial, RegisterGraniteMaterial, RequestTextureReimport,
+    RequiredMaterialData, RequiredMaterialDataMut, StandardMaterialDef, TextureAddressMode,
+    TextureFilterMode, TextureImportSettings, TextureImportState,
 };
 pub use bevy_granite_macros::register_editor_components;
 
@@ -41,11 +50,15 @@ pub trait UICallableEventProvider {
 }
 
 pub use entities::{
-    BridgeTag, Camera3D, ClassCategory, ComponentEditor, DirLight, EditorIgnore,
-    GraniteEditorSerdeEntity, GraniteType, GraniteTypes, HasRuntimeData, IdentityData, MainCamera,
-    MaterialNameSource, NeedsTangents, PointLightData, PromptData, PromptImportSettings, RectBrush,
-    ReflectedComponent, SaveSettings, SpawnSource, TransformData, TreeHiddenEntity, UICamera,
-    VolumetricFog, OBJ,
+    BridgeTag, BrushEditOperation, BrushFace, BrushFaceMaterialGroup, BrushGeometry, BrushPlane,
+    BrushPrimitive, Camera3D, ClassCategory, ComponentEditor, ConvexBrush, CustomClass,
+    CustomGraniteClass, DirLight, EditorIgnore, EnvironmentImages, FaceUv,
+    GraniteEditorSerdeEntity, GraniteType, GraniteTypes, HasRuntimeData, IdentityData,
+    IrradianceVolumeData, LightProbeVolume, MainCamera, MaterialNameSource, MirroredMeshes,
+    NeedsTangents, PointLightData, PromptData, PromptImportSettings, RectBrush, RectBrushFace,
+    RectLightData, RectLightPanel, ReflectedComponent, ReflectionProbe, RegisterGraniteClass,
+    SaveSettings, SkyEnvironment, SpawnSource, SpotLightData, TransformData, TreeHiddenEntity,
+    UICamera, UserUpdatedCustomClassEvent, VolumetricFog, OBJ,
 };
 pub use events::{
     CollectRuntimeDataEvent, RequestDespawnBySource, RequestDespawnSerializableEntities,
@@ -55,9 +68,10 @@ pub use events::{
 };
 pub use setup::RegisteredTypeNames;
 pub use shared::{
-    absolute_asset_to_rel, is_scene_version_compatible, mouse_to_world_delta,
+    absolute_asset_to_rel, assets_dir, is_scene_version_compatible, mouse_to_world_delta,
     rel_asset_to_absolute, CursorWindowPos, IconEntity, IconProxy, IconType, InputTypes, UserInput,
 };
+pub use world::{SceneSelectionSets, SelectionSet};
 
 // Bevy Granite Core plugin
 pub struct BevyGraniteCore {
@@ -4,6 +4,11 @@ use std::{
     path::{Path, PathBuf},
 };
 
+/// Folder Bevy loads assets from, paths stored in scenes and materials are relative to it
+pub fn assets_dir() -> PathBuf {
+    FileAssetReader::get_base_path().join("assets")
+}
+
 pub fn rel_asset_to_absolute(rel_string: &str) -> Cow<'static, str> {
     let normalized_rel = rel_string.replace('\\', "/");
     
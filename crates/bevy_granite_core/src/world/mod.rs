@@ -2,6 +2,7 @@ pub mod open;
 pub mod plugin;
 pub mod reload;
 pub mod save;
+pub mod selection_sets;
 
 pub use open::{open_world_reader, open_world_batch_reader};
 pub use reload::reload_world_system;
@@ -9,4 +10,5 @@ pub use save::{
     collect_components_system, save_data_ready_system, save_request_system, SaveWorldRequestData,
     WorldState,
 };
-pub use plugin::WorldPlugin;
\ No newline at end of file
+pub use plugin::WorldPlugin;
+pub use selection_sets::{SceneSelectionSets, SelectionSet};
\ No newline at end of file
@@ -1,6 +1,8 @@
 use crate::events::{RequestLoadEvent, RequestLoadBatchEvent, WorldLoadSuccessEvent, WorldLoadBatchSuccessEvent};
 use crate::{absolute_asset_to_rel};
-use crate::{assets::AvailableEditableMaterials, entities::deserialize_entities};
+use crate::{
+    assets::AvailableEditableMaterials, entities::deserialize_entities, world::SceneSelectionSets,
+};
 use bevy::prelude::*;
 use bevy_granite_logging::{
     config::{LogCategory, LogLevel, LogType},
@@ -14,6 +16,7 @@ pub fn open_world_reader(
     mut materials: ResMut<Assets<StandardMaterial>>,
     mut meshes: ResMut<Assets<Mesh>>,
     mut available_materials: ResMut<AvailableEditableMaterials>,
+    mut selection_sets: ResMut<SceneSelectionSets>,
     mut world_open_reader: MessageReader<RequestLoadEvent>,
     mut world_load_success_writer: MessageWriter<WorldLoadSuccessEvent>,
 ) {
@@ -27,6 +30,7 @@ pub fn open_world_reader(
             &mut materials,
             &mut available_materials,
             &mut meshes,
+            &mut selection_sets,
             rel.clone(),
             save_settings.clone(),
             *translation,
@@ -51,6 +55,7 @@ pub fn open_world_batch_reader(
     mut materials: ResMut<Assets<StandardMaterial>>,
     mut meshes: ResMut<Assets<Mesh>>,
     mut available_materials: ResMut<AvailableEditableMaterials>,
+    mut selection_sets: ResMut<SceneSelectionSets>,
     mut world_batch_reader: MessageReader<RequestLoadBatchEvent>,
     mut world_load_batch_success_writer: MessageWriter<WorldLoadBatchSuccessEvent>,
 ) {
@@ -65,6 +70,7 @@ pub fn open_world_batch_reader(
                 &mut materials,
                 &mut available_materials,
                 &mut meshes,
+                &mut selection_sets,
                 rel.clone(),
                 save_settings.clone(),
                 *translation,
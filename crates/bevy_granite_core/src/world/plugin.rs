@@ -1,4 +1,4 @@
-use super::{open_world_reader, open_world_batch_reader, SaveWorldRequestData,
+use super::{open_world_reader, open_world_batch_reader, SaveWorldRequestData, SceneSelectionSets,
     collect_components_system, reload_world_system, save_request_system, save_data_ready_system,
 };
 use bevy::{
@@ -13,6 +13,7 @@ impl Plugin for WorldPlugin {
             // Resources
             //
             .init_resource::<SaveWorldRequestData>()
+            .init_resource::<SceneSelectionSets>()
             //
             // Schedule system
             //
@@ -3,13 +3,16 @@ use crate::{
     entities::{despawn_recursive_serializable_entities, IdentityData, SaveSettings},
     events::{RequestLoadEvent, RequestReloadEvent},
 };
-use bevy::prelude::{Commands, Entity, MessageReader, MessageWriter, Query, With};
+use bevy::{
+    ecs::{entity_disabling::Disabled, query::Allow},
+    prelude::{Commands, Entity, MessageReader, MessageWriter, Query, With},
+};
 
 /// Despawns all entities then loads the world
 pub fn reload_world_system(
     mut relead_watcher: MessageReader<RequestReloadEvent>,
     mut commands: Commands,
-    serializable_query: Query<Entity, With<IdentityData>>,
+    serializable_query: Query<Entity, (With<IdentityData>, Allow<Disabled>)>,
     mut load_world_writter: MessageWriter<RequestLoadEvent>,
 ) {
     for RequestReloadEvent(path) in relead_watcher.read() {
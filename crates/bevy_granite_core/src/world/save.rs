@@ -2,12 +2,15 @@ use crate::{
     entities::{serialize_entities, ComponentEditor, HasRuntimeData, IdentityData, SpawnSource},
     events::{CollectRuntimeDataEvent, RequestSaveEvent, RuntimeDataReadyEvent},
     shared::absolute_asset_to_rel,
+    world::{SceneSelectionSets, SelectionSet},
     WorldSaveSuccessEvent,
 };
 use bevy::{
     asset::io::file::FileAssetReader,
     ecs::entity::Entity,
-    prelude::{ChildOf, Commands, MessageReader, MessageWriter, Query, ResMut, Resource, World},
+    prelude::{
+        ChildOf, Commands, MessageReader, MessageWriter, Query, Res, ResMut, Resource, World,
+    },
     transform::components::Transform,
 };
 use bevy_granite_logging::{
@@ -28,6 +31,9 @@ pub struct WorldState {
 
     // Inside world runner, when gathered this flag gets set
     pub components_ready: bool,
+
+    // Named selection sets that belong to this source
+    pub selection_sets: Vec<SelectionSet>,
 }
 
 #[derive(Resource, Default)]
@@ -45,6 +51,7 @@ pub fn save_request_system(
     mut save_request: ResMut<SaveWorldRequestData>,
     mut event_writer: MessageWriter<CollectRuntimeDataEvent>,
     mut event_reader: MessageReader<RequestSaveEvent>,
+    selection_sets: Res<SceneSelectionSets>,
     query: Query<(
         Entity,
         &IdentityData,
@@ -113,6 +120,7 @@ pub fn save_request_system(
             entity_data: Some(entities_data),
             component_data: None,
             components_ready: false,
+            selection_sets: selection_sets.get_for_source(&spawn_source),
         };
 
         save_request
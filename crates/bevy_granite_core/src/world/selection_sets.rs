@@ -0,0 +1,78 @@
+use bevy::ecs::resource::Resource;
+use serde::{Deserialize, Serialize};
+use std::collections::HashMap;
+use uuid::Uuid;
+
+/// A named group of entity UUIDs
+/// Stored inside the scene file so the group survives save/load
+#[derive(Serialize, Deserialize, Debug, Clone, PartialEq, Default)]
+pub struct SelectionSet {
+    pub name: String,
+    pub entities: Vec<Uuid>,
+}
+
+/// All selection sets of every loaded scene
+/// Key is the relative scene source the sets were loaded from (or will be saved to)
+#[derive(Resource, Default, Debug, Clone)]
+pub struct SceneSelectionSets {
+    pub by_source: HashMap<String, Vec<SelectionSet>>,
+}
+
+impl SceneSelectionSets {
+    /// Get the sets stored for a single source
+    pub fn get_for_source(&self, source: &str) -> Vec<SelectionSet> {
+        self.by_source.get(source).cloned().unwrap_or_default()
+    }
+
+    /// Replace all sets stored for a source. Empty lists remove the source entry
+    pub fn set_for_source(&mut self, source: &str, sets: Vec<SelectionSet>) {
+        if sets.is_empty() {
+            self.by_source.remove(source);
+        } else {
+            self.by_source.insert(source.to_string(), sets);
+        }
+    }
+
+    /// Insert or overwrite a named set for a source
+    pub fn upsert(&mut self, source: &str, name: &str, entities: Vec<Uuid>) {
+        let sets = self.by_source.entry(source.to_string()).or_default();
+        if let Some(existing) = sets.iter_mut().find(|set| set.name == name) {
+            existing.entities = entities;
+        } else {
+            sets.push(SelectionSet {
+                name: name.to_string(),
+                entities,
+            });
+        }
+    }
+
+    /// Remove a named set from every source
+    pub fn remove(&mut self, name: &str) {
+        for sets in self.by_source.values_mut() {
+            sets.retain(|set| set.name != name);
+        }
+        self.by_source.retain(|_, sets| !sets.is_empty());
+    }
+
+    /// All UUIDs for a set name, merged across every source
+    pub fn uuids_for(&self, name: &str) -> Vec<Uuid> {
+        self.by_source
+            .values()
+            .flat_map(|sets| sets.iter())
+            .filter(|set| set.name == name)
+            .flat_map(|set| set.entities.iter().copied())
+            .collect()
+    }
+
+    /// Unique, sorted set names across every source. Used for UI
+    pub fn names(&self) -> Vec<String> {
+        let mut names: Vec<String> = self
+            .by_source
+            .values()
+            .flat_map(|sets| sets.iter().map(|set| set.name.clone()))
+            .collect();
+        names.sort();
+        names.dedup();
+        names
+    }
+}
@@ -2,6 +2,7 @@ use crate::utils::{load_from_toml_file, save_to_toml_file};
 use crate::{
     editor_state::EditorState,
     interface::{BottomDockState, EditorSettingsTabData, SideDockState, SideTab},
+    viewport::{ViewportPaneLayout, ViewportPanes},
 };
 use bevy::time::Time;
 use bevy::window::WindowClosing;
@@ -28,6 +29,7 @@ pub struct DockLayoutStr {
     pub right_dock_width: Option<f32>,
     pub bottom_dock_state: Option<String>,
     pub bottom_dock_height: Option<f32>,
+    pub viewport_panes: Option<ViewportPaneLayout>,
 }
 
 /// Resource to track periodic auto-saving of dock layout
@@ -51,12 +53,14 @@ pub fn save_dock_on_window_close_system(
     editor_state: Res<EditorState>,
     side_dock_res: Res<SideDockState>,
     bottom_dock_res: Res<BottomDockState>,
+    viewport_panes: Res<ViewportPanes>,
 ) {
     for _event in window_close_events.read() {
         save_dock_layout_toml(
             editor_state.deref().clone(),
             side_dock_res.clone(),
             bottom_dock_res.clone(),
+            &viewport_panes,
         );
     }
 }
@@ -67,6 +71,7 @@ pub fn auto_save_dock_layout_system(
     editor_state: Res<EditorState>,
     side_dock_res: Res<SideDockState>,
     bottom_dock_res: Res<BottomDockState>,
+    viewport_panes: Res<ViewportPanes>,
     mut tracker: ResMut<DockLayoutTracker>,
 ) {
     tracker.time_since_last_save += time.delta_secs();
@@ -78,6 +83,7 @@ pub fn auto_save_dock_layout_system(
                 editor_state.deref().clone(),
                 side_dock_res.clone(),
                 bottom_dock_res.clone(),
+                &viewport_panes,
             );
             tracker.time_since_last_save = 0.0;
         }
@@ -87,6 +93,7 @@ pub fn auto_save_dock_layout_system(
 pub fn get_dock_state_str(
     right_dock_state: SideDockState,
     bottom_dock_state: BottomDockState,
+    viewport_panes: &ViewportPanes,
 ) -> DockLayoutStr {
     let right_tree = to_string(&right_dock_state.dock_state).unwrap();
     let right_width = right_dock_state.width;
@@ -98,6 +105,7 @@ pub fn get_dock_state_str(
         right_dock_width: right_width,
         bottom_dock_state: Some(bottom_tree),
         bottom_dock_height: bottom_height,
+        viewport_panes: Some(viewport_panes.layout.clone()),
     }
 }
 
@@ -105,6 +113,7 @@ pub fn load_dock_state(
     dock_layout: &DockLayoutStr,
     right_dock_state: &mut SideDockState,
     bottom_dock_state: &mut BottomDockState,
+    viewport_panes: &mut ViewportPanes,
 ) {
     if let Some(ref right_tree) = dock_layout.right_dock_state {
         if let Ok(dock_state) = from_str(right_tree) {
@@ -122,6 +131,10 @@ pub fn load_dock_state(
 
     bottom_dock_state.height = dock_layout.bottom_dock_height;
 
+    if let Some(ref panes) = dock_layout.viewport_panes {
+        viewport_panes.set_layout(panes.clone());
+    }
+
     log!(
         LogType::Editor,
         LogLevel::OK,
@@ -134,6 +147,7 @@ fn save_dock_layout_toml(
     editor_state: EditorState,
     right_dock: SideDockState,
     bottom_dock: BottomDockState,
+    viewport_panes: &ViewportPanes,
 ) {
     let save = right_dock
         .dock_state
@@ -150,7 +164,7 @@ fn save_dock_layout_toml(
 
     let config_path_buf =
         FileAssetReader::get_base_path().join("assets/".to_string() + &editor_state.config_path);
-    let dock_layout = get_dock_state_str(right_dock, bottom_dock);
+    let dock_layout = get_dock_state_str(right_dock, bottom_dock, viewport_panes);
 
     if let Some(config_path_str) = config_path_buf.to_str() {
         match update_dock_layout_in_config(&dock_layout, config_path_str) {
@@ -1,6 +1,7 @@
 use crate::{
     editor_state::{get_dock_state_str, EditorState},
     interface::{BottomDockState, EditorSettingsTabData, SetActiveWorld, SideDockState},
+    viewport::ViewportPanes,
 };
 
 use crate::utils::{load_from_toml_file, save_to_toml_file};
@@ -125,11 +126,12 @@ pub fn save_editor_settings_from_widget_data(
     editor_settings: &mut EditorSettingsTabData,
     right_dock: SideDockState,
     bottom_dock: BottomDockState,
+    viewport_panes: &ViewportPanes,
 ) {
     let config_path_buf =
         FileAssetReader::get_base_path().join("assets/".to_string() + &editor_state.config_path);
 
-    editor_settings.dock.layout_str = get_dock_state_str(right_dock, bottom_dock);
+    editor_settings.dock.layout_str = get_dock_state_str(right_dock, bottom_dock, viewport_panes);
 
     if let Some(config_path_str) = config_path_buf.to_str() {
         match save_to_toml_file(editor_settings, config_path_str) {
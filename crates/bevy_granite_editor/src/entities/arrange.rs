@@ -0,0 +1,290 @@
+use super::bounds::get_entity_bounds_world;
+use crate::{
+    history::{queue_history_command, TransformBatchCommand},
+    interface::events::{RequestAlignSelection, RequestDistributeSelection},
+};
+use bevy::{
+    asset::Assets,
+    ecs::{
+        entity::Entity,
+        hierarchy::ChildOf,
+        message::MessageReader,
+        query::{Or, With},
+        system::{Commands, Query, Res},
+    },
+    math::Vec3,
+    mesh::{Mesh, Mesh3d},
+    transform::components::{GlobalTransform, Transform},
+};
+use bevy_granite_gizmos::{ActiveSelection, Selected};
+use bevy_granite_logging::{log, LogCategory, LogLevel, LogType};
+
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum ArrangeAxis {
+    X,
+    Y,
+    Z,
+}
+
+impl ArrangeAxis {
+    pub const ALL: [ArrangeAxis; 3] = [ArrangeAxis::X, ArrangeAxis::Y, ArrangeAxis::Z];
+
+    pub fn label(&self) -> &'static str {
+        match self {
+            ArrangeAxis::X => "X",
+            ArrangeAxis::Y => "Y",
+            ArrangeAxis::Z => "Z",
+        }
+    }
+
+    pub fn index(&self) -> usize {
+        match self {
+            ArrangeAxis::X => 0,
+            ArrangeAxis::Y => 1,
+            ArrangeAxis::Z => 2,
+        }
+    }
+}
+
+/// Which side of the bounds gets lined up
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum AlignEdge {
+    Min,
+    Center,
+    Max,
+}
+
+impl AlignEdge {
+    pub const ALL: [AlignEdge; 3] = [AlignEdge::Min, AlignEdge::Center, AlignEdge::Max];
+
+    pub fn label(&self) -> &'static str {
+        match self {
+            AlignEdge::Min => "Min",
+            AlignEdge::Center => "Center",
+            AlignEdge::Max => "Max",
+        }
+    }
+
+    fn value(&self, (min, max): (Vec3, Vec3), axis: ArrangeAxis) -> f32 {
+        let i = axis.index();
+        match self {
+            AlignEdge::Min => min[i],
+            AlignEdge::Center => (min[i] + max[i]) * 0.5,
+            AlignEdge::Max => max[i],
+        }
+    }
+}
+
+/// What the selection lines up against
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum AlignTarget {
+    Active,
+    SelectionBounds,
+}
+
+type ArrangeSelectionQuery<'w, 's> = Query<
+    'w,
+    's,
+    (Entity, &'w Transform, &'w GlobalTransform),
+    Or<(With<Selected>, With<ActiveSelection>)>,
+>;
+
+struct ArrangeItem {
+    entity: Entity,
+    transform: Transform,
+    bounds: (Vec3, Vec3),
+}
+
+/// Selected entities whose parent is not selected, with world bounds
+/// Entities without a mesh count as a point at their position
+fn collect_selection(
+    selection: &ArrangeSelectionQuery,
+    parents: &Query<&ChildOf>,
+    meshes: &Assets<Mesh>,
+    mesh_query: &Query<&Mesh3d>,
+) -> Vec<ArrangeItem> {
+    let selected: Vec<Entity> = selection.iter().map(|(entity, ..)| entity).collect();
+    selection
+        .iter()
+        .filter(|(entity, ..)| {
+            parents
+                .get(*entity)
+                .map(|parent| !selected.contains(&parent.parent()))
+                .unwrap_or(true)
+        })
+        .map(|(entity, transform, global)| ArrangeItem {
+            entity,
+            transform: *transform,
+            bounds: get_entity_bounds_world(entity, meshes, mesh_query, global)
+                .unwrap_or((global.translation(), global.translation())),
+        })
+        .collect()
+}
+
+/// World space move turned into the entity's local space
+fn moved(
+    item: &ArrangeItem,
+    world_delta: Vec3,
+    parents: &Query<&ChildOf>,
+    globals: &Query<&GlobalTransform>,
+) -> Transform {
+    let local_delta = parents
+        .get(item.entity)
+        .ok()
+        .and_then(|parent| globals.get(parent.parent()).ok())
+        .map(|parent_global| {
+            parent_global
+                .affine()
+                .inverse()
+                .transform_vector3(world_delta)
+        })
+        .unwrap_or(world_delta);
+    let mut transform = item.transform;
+    transform.translation += local_delta;
+    transform
+}
+
+fn axis_delta(axis: ArrangeAxis, amount: f32) -> Vec3 {
+    let mut delta = Vec3::ZERO;
+    delta[axis.index()] = amount;
+    delta
+}
+
+pub fn align_selection_system(
+    mut requests: MessageReader<RequestAlignSelection>,
+    mut commands: Commands,
+    selection: ArrangeSelectionQuery,
+    active: Query<Entity, With<ActiveSelection>>,
+    parents: Query<&ChildOf>,
+    globals: Query<&GlobalTransform>,
+    meshes: Res<Assets<Mesh>>,
+    mesh_query: Query<&Mesh3d>,
+) {
+    for request in requests.read() {
+        let items = collect_selection(&selection, &parents, &meshes, &mesh_query);
+        if items.len() < 2 {
+            log!(
+                LogType::Editor,
+                LogLevel::Warning,
+                LogCategory::Entity,
+                "Align needs at least two selected entities"
+            );
+            continue;
+        }
+
+        let active = active.single().ok();
+        let target = match request.target {
+            AlignTarget::Active => {
+                let Some(active_item) = items.iter().find(|item| Some(item.entity) == active)
+                else {
+                    log!(
+                        LogType::Editor,
+                        LogLevel::Warning,
+                        LogCategory::Entity,
+                        "Align to active needs the active entity in the selection"
+                    );
+                    continue;
+                };
+                request.edge.value(active_item.bounds, request.axis)
+            }
+            AlignTarget::SelectionBounds => {
+                let bounds = items.iter().fold(
+                    (Vec3::splat(f32::INFINITY), Vec3::splat(f32::NEG_INFINITY)),
+                    |(min, max), item| (min.min(item.bounds.0), max.max(item.bounds.1)),
+                );
+                request.edge.value(bounds, request.axis)
+            }
+        };
+
+        let entries: Vec<(Entity, Transform, Transform)> = items
+            .iter()
+            .filter_map(|item| {
+                let amount = target - request.edge.value(item.bounds, request.axis);
+                (amount.abs() > f32::EPSILON).then(|| {
+                    let world_delta = axis_delta(request.axis, amount);
+                    (
+                        item.entity,
+                        item.transform,
+                        moved(item, world_delta, &parents, &globals),
+                    )
+                })
+            })
+            .collect();
+        if entries.is_empty() {
+            continue;
+        }
+
+        queue_history_command(
+            &mut commands,
+            Box::new(TransformBatchCommand::new(
+                entries,
+                format!(
+                    "Align {} {} of {} entities",
+                    request.axis.label(),
+                    request.edge.label(),
+                    items.len()
+                ),
+            )),
+        );
+    }
+}
+
+/// Spaces centers evenly between the two outermost entities, which stay put
+pub fn distribute_selection_system(
+    mut requests: MessageReader<RequestDistributeSelection>,
+    mut commands: Commands,
+    selection: ArrangeSelectionQuery,
+    parents: Query<&ChildOf>,
+    globals: Query<&GlobalTransform>,
+    meshes: Res<Assets<Mesh>>,
+    mesh_query: Query<&Mesh3d>,
+) {
+    for request in requests.read() {
+        let mut items = collect_selection(&selection, &parents, &meshes, &mesh_query);
+        if items.len() < 3 {
+            log!(
+                LogType::Editor,
+                LogLevel::Warning,
+                LogCategory::Entity,
+                "Distribute needs at least three selected entities"
+            );
+            continue;
+        }
+
+        let center = |item: &ArrangeItem| AlignEdge::Center.value(item.bounds, request.axis);
+        items.sort_by(|a, b| center(a).total_cmp(&center(b)));
+        let first = center(&items[0]);
+        let step = (center(&items[items.len() - 1]) - first) / (items.len() - 1) as f32;
+
+        let entries: Vec<(Entity, Transform, Transform)> = items
+            .iter()
+            .enumerate()
+            .filter_map(|(i, item)| {
+                let amount = first + step * i as f32 - center(item);
+                (amount.abs() > f32::EPSILON).then(|| {
+                    let world_delta = axis_delta(request.axis, amount);
+                    (
+                        item.entity,
+                        item.transform,
+                        moved(item, world_delta, &parents, &globals),
+                    )
+                })
+            })
+            .collect();
+        if entries.is_empty() {
+            continue;
+        }
+
+        queue_history_command(
+            &mut commands,
+            Box::new(TransformBatchCommand::new(
+                entries,
+                format!(
+                    "Distribute {} entities along {}",
+                    items.len(),
+                    request.axis.label()
+                ),
+            )),
+        );
+    }
+}
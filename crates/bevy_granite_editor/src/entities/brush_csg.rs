@@ -0,0 +1,253 @@
+use crate::{
+    history::{queue_history_command, EntityBatchCommand},
+    interface::events::{RequestBrushMerge, RequestBrushSubtract},
+};
+use bevy::{
+    asset::{AssetServer, Assets},
+    ecs::{
+        entity::Entity,
+        hierarchy::ChildOf,
+        message::MessageReader,
+        query::{With, Without},
+        system::{Commands, Query, Res, ResMut},
+    },
+    math::Mat4,
+    mesh::Mesh,
+    pbr::StandardMaterial,
+    transform::components::{GlobalTransform, Transform},
+};
+use bevy_granite_core::{
+    entities::SpawnSource, AvailableEditableMaterials, BrushGeometry, ConvexBrush, GraniteTypes,
+    IdentityData,
+};
+use bevy_granite_gizmos::{selection::events::EntityEvents, ActiveSelection, Selected};
+use bevy_granite_logging::{log, LogCategory, LogLevel, LogType};
+
+/// Brushes that can take part in subtract and merge, as convex brush data
+/// Rectangle brushes convert so they can be carved like any other brush
+fn brush_as_convex(identity: &IdentityData) -> Option<ConvexBrush> {
+    match &identity.class {
+        GraniteTypes::ConvexBrush(brush) => Some(brush.clone()),
+        GraniteTypes::RectBrush(brush) => Some(ConvexBrush {
+            geometry: brush.geometry.clone().unwrap_or_else(|| brush.styled_box()),
+            uv_scale: brush.uv_scale,
+            material: brush.material.clone(),
+        }),
+        _ => None,
+    }
+}
+
+/// Carve the active selection out of every other selected brush
+/// Each target is replaced by the convex pieces left over, the cutter stays
+/// The whole subtract is one undo step
+pub fn brush_subtract_system(
+    mut requests: MessageReader<RequestBrushSubtract>,
+    mut commands: Commands,
+    active: Query<(&IdentityData, &GlobalTransform), With<ActiveSelection>>,
+    targets: Query<
+        (
+            Entity,
+            &IdentityData,
+            &Transform,
+            &GlobalTransform,
+            Option<&ChildOf>,
+            Option<&SpawnSource>,
+        ),
+        (With<Selected>, Without<ActiveSelection>),
+    >,
+    mut standard_materials: ResMut<Assets<StandardMaterial>>,
+    mut meshes: ResMut<Assets<Mesh>>,
+    mut available_materials: ResMut<AvailableEditableMaterials>,
+    asset_server: Res<AssetServer>,
+) {
+    for _request in requests.read() {
+        let Some((cutter, cutter_global)) = active
+            .single()
+            .ok()
+            .and_then(|(identity, global)| brush_as_convex(identity).map(|b| (b, global)))
+        else {
+            log!(
+                LogType::Editor,
+                LogLevel::Warning,
+                LogCategory::Entity,
+                "Brush subtract needs an active brush to cut with"
+            );
+            continue;
+        };
+        if !cutter.geometry.is_convex() {
+            log!(
+                LogType::Editor,
+                LogLevel::Warning,
+                LogCategory::Entity,
+                "Brush subtract needs a convex cutter"
+            );
+            continue;
+        }
+
+        let mut created = Vec::new();
+        let mut removed = Vec::new();
+        for (entity, identity, transform, global, parent, source) in targets.iter() {
+            let Some(target) = brush_as_convex(identity) else {
+                continue;
+            };
+            if !target.geometry.is_convex() {
+                log!(
+                    LogType::Editor,
+                    LogLevel::Warning,
+                    LogCategory::Entity,
+                    "Skipping '{}', only convex brushes can be subtracted from",
+                    identity.name
+                );
+                continue;
+            }
+
+            // Bring the cutter into the target's local space
+            let to_local = Mat4::from(global.affine().inverse() * cutter_global.affine());
+            let Some(pieces) = target
+                .geometry
+                .subtract(&cutter.geometry.transformed(to_local))
+            else {
+                continue;
+            };
+
+            for geometry in pieces {
+                let mut piece = ConvexBrush {
+                    geometry,
+                    ..target.clone()
+                };
+                // Pieces are in the target's local space, so they take its place as is
+                let new_entity = piece.spawn_from_new_identity(
+                    &mut commands,
+                    *transform,
+                    &mut standard_materials,
+                    &mut available_materials,
+                    &asset_server,
+                    &mut meshes,
+                );
+                if let Some(source) = source {
+                    commands.entity(new_entity).insert(source.clone());
+                }
+                if let Some(parent) = parent {
+                    commands.entity(new_entity).insert(ChildOf(parent.parent()));
+                }
+                created.push(new_entity);
+            }
+            removed.push(entity);
+        }
+
+        if removed.is_empty() {
+            continue;
+        }
+        let description = format!("Subtract brush from {} brushes", removed.len());
+        queue_history_command(
+            &mut commands,
+            Box::new(EntityBatchCommand::new(created, removed, description)),
+        );
+    }
+}
+
+/// Replace all selected brushes with the convex hull around them
+/// The active selection decides the transform, parent, name and material of the result
+pub fn brush_merge_system(
+    mut requests: MessageReader<RequestBrushMerge>,
+    mut commands: Commands,
+    active: Query<
+        (
+            &IdentityData,
+            &Transform,
+            &GlobalTransform,
+            Option<&ChildOf>,
+            Option<&SpawnSource>,
+        ),
+        With<ActiveSelection>,
+    >,
+    selected: Query<(Entity, &IdentityData, &GlobalTransform), With<Selected>>,
+    mut standard_materials: ResMut<Assets<StandardMaterial>>,
+    mut meshes: ResMut<Assets<Mesh>>,
+    mut available_materials: ResMut<AvailableEditableMaterials>,
+    asset_server: Res<AssetServer>,
+) {
+    for _request in requests.read() {
+        let Ok((active_identity, active_transform, active_global, parent, source)) =
+            active.single()
+        else {
+            continue;
+        };
+        let Some(base) = brush_as_convex(active_identity) else {
+            log!(
+                LogType::Editor,
+                LogLevel::Warning,
+                LogCategory::Entity,
+                "Brush merge needs an active brush"
+            );
+            continue;
+        };
+
+        let to_local = Mat4::from(active_global.affine().inverse());
+        let mut parts = Vec::new();
+        let mut merged_entities = Vec::new();
+        for (entity, identity, global) in selected.iter() {
+            let Some(brush) = brush_as_convex(identity) else {
+                continue;
+            };
+            if !brush.geometry.is_convex() {
+                log!(
+                    LogType::Editor,
+                    LogLevel::Warning,
+                    LogCategory::Entity,
+                    "Skipping '{}', only convex brushes can be merged",
+                    identity.name
+                );
+                continue;
+            }
+            parts.push(
+                brush
+                    .geometry
+                    .transformed(to_local * Mat4::from(global.affine())),
+            );
+            merged_entities.push(entity);
+        }
+
+        if merged_entities.len() < 2 {
+            log!(
+                LogType::Editor,
+                LogLevel::Warning,
+                LogCategory::Entity,
+                "Brush merge needs at least two convex brushes selected"
+            );
+            continue;
+        }
+        let Some(geometry) = BrushGeometry::merge(&parts) else {
+            continue;
+        };
+
+        let mut merged = ConvexBrush { geometry, ..base };
+        let new_entity = merged.spawn_from_new_identity(
+            &mut commands,
+            *active_transform,
+            &mut standard_materials,
+            &mut available_materials,
+            &asset_server,
+            &mut meshes,
+        );
+        if let Some(source) = source {
+            commands.entity(new_entity).insert(source.clone());
+        }
+        if let Some(parent) = parent {
+            commands.entity(new_entity).insert(ChildOf(parent.parent()));
+        }
+        let description = format!("Merge {} brushes", merged_entities.len());
+        queue_history_command(
+            &mut commands,
+            Box::new(EntityBatchCommand::new(
+                vec![new_entity],
+                merged_entities,
+                description,
+            )),
+        );
+        commands.trigger(EntityEvents::Select {
+            target: new_entity,
+            additive: false,
+        });
+    }
+}
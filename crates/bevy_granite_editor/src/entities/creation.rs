@@ -1,4 +1,8 @@
-use crate::{editor_state::EditorState, interface::UserRequestGraniteTypeViaPopup};
+use crate::{
+    editor_state::EditorState,
+    interface::UserRequestGraniteTypeViaPopup,
+    viewport::{ActiveGrid, CameraTarget},
+};
 use bevy::{
     asset::{AssetServer, Assets},
     ecs::{
@@ -39,6 +43,8 @@ pub fn new_entity_via_popup_system(
     mut entity_add_reader: MessageReader<UserRequestGraniteTypeViaPopup>,
     mut spawn_queue: ResMut<EntitySpawnQueue>,
     editor_state: Res<EditorState>,
+    active_grid: Res<ActiveGrid>,
+    camera_target: Res<CameraTarget>,
     mut commands: Commands,
 ) {
     if let Some(UserRequestGraniteTypeViaPopup { class }) = entity_add_reader.read().next() {
@@ -50,7 +56,12 @@ pub fn new_entity_via_popup_system(
             class
         );
 
-        let transform = Transform::default();
+        // Land on the grid under the orbit target rather than the world origin
+        let transform = if editor_state.config.viewport.grid_snap_new_entities {
+            Transform::from_translation(active_grid.snap(camera_target.position))
+        } else {
+            Transform::default()
+        };
 
         let source = editor_state
             .current_file
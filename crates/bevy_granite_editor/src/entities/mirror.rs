@@ -0,0 +1,171 @@
+use super::{arrange::ArrangeAxis, bounds::get_entity_bounds_world};
+use crate::{
+    history::{
+        execute_history_command, queue_history_command, EntityBatchCommand, TransformBatchCommand,
+    },
+    interface::events::RequestMirrorSelection,
+};
+use bevy::{
+    asset::Assets,
+    ecs::{
+        entity::Entity,
+        hierarchy::ChildOf,
+        message::MessageReader,
+        query::{Or, With},
+        system::{Commands, Query, Res},
+        world::World,
+    },
+    math::{Affine3A, Mat3, Mat4, Vec3},
+    mesh::{Mesh, Mesh3d},
+    prelude::AppTypeRegistry,
+    transform::components::{GlobalTransform, Transform},
+};
+use bevy_granite_gizmos::{selection::duplicate_with_world_transform, ActiveSelection, Selected};
+
+/// Which axes the mirror plane is built from
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum MirrorSpace {
+    World,
+    /// Axes of the active entity
+    Local,
+}
+
+impl MirrorSpace {
+    pub const ALL: [MirrorSpace; 2] = [MirrorSpace::World, MirrorSpace::Local];
+
+    pub fn label(&self) -> &'static str {
+        match self {
+            MirrorSpace::World => "World",
+            MirrorSpace::Local => "Local",
+        }
+    }
+}
+
+/// Reflection across the plane through pivot with the given normal
+fn reflection(pivot: Vec3, normal: Vec3) -> Affine3A {
+    let normal = normal.normalize();
+    let linear = Mat3::IDENTITY
+        - 2.0 * Mat3::from_cols(normal * normal.x, normal * normal.y, normal * normal.z);
+    Affine3A::from_mat3_translation(linear, 2.0 * pivot.dot(normal) * normal)
+}
+
+/// The result has a negative scale, mirrored meshes get reversed winding from core
+fn mirrored_world(reflect: Affine3A, global: &GlobalTransform) -> Transform {
+    Transform::from_matrix(Mat4::from(reflect * global.affine()))
+}
+
+type MirrorSelectionQuery<'w, 's> = Query<
+    'w,
+    's,
+    (Entity, &'w Transform, &'w GlobalTransform),
+    Or<(With<Selected>, With<ActiveSelection>)>,
+>;
+
+/// Reflect the selection roots across an axis plane through the pivot
+/// Moving and duplicating are each one undo step, duplicating leaves the originals in place
+pub fn mirror_selection_system(
+    mut requests: MessageReader<RequestMirrorSelection>,
+    mut commands: Commands,
+    selection: MirrorSelectionQuery,
+    active: Query<&GlobalTransform, With<ActiveSelection>>,
+    parents: Query<&ChildOf>,
+    globals: Query<&GlobalTransform>,
+    meshes: Res<Assets<Mesh>>,
+    mesh_query: Query<&Mesh3d>,
+    type_registry: Res<AppTypeRegistry>,
+) {
+    for request in requests.read() {
+        let selected: Vec<Entity> = selection.iter().map(|(entity, ..)| entity).collect();
+        let roots: Vec<(Entity, Transform, GlobalTransform)> = selection
+            .iter()
+            .filter(|(entity, ..)| {
+                parents
+                    .get(*entity)
+                    .map(|parent| !selected.contains(&parent.parent()))
+                    .unwrap_or(true)
+            })
+            .map(|(entity, transform, global)| (entity, *transform, *global))
+            .collect();
+        if roots.is_empty() {
+            continue;
+        }
+
+        let active = active.single().ok();
+        // Pivot on the active entity, or the middle of the selection without one
+        let pivot = match active {
+            Some(active) => active.translation(),
+            None => {
+                let (min, max) = roots.iter().fold(
+                    (Vec3::splat(f32::INFINITY), Vec3::splat(f32::NEG_INFINITY)),
+                    |(min, max), (entity, _, global)| {
+                        let (entity_min, entity_max) =
+                            get_entity_bounds_world(*entity, &meshes, &mesh_query, global)
+                                .unwrap_or((global.translation(), global.translation()));
+                        (min.min(entity_min), max.max(entity_max))
+                    },
+                );
+                (min + max) * 0.5
+            }
+        };
+        let mut normal = Vec3::ZERO;
+        normal[request.axis.index()] = 1.0;
+        if let (MirrorSpace::Local, Some(active)) = (request.space, active) {
+            normal = active.to_scale_rotation_translation().1 * normal;
+        }
+        let reflect = reflection(pivot, normal);
+
+        if request.duplicate {
+            let copies: Vec<(Entity, Transform)> = roots
+                .iter()
+                .map(|(entity, _, global)| (*entity, mirrored_world(reflect, global)))
+                .collect();
+            let registry = type_registry.clone();
+            let description = format!(
+                "Mirror duplicate {} entities across {} {}",
+                roots.len(),
+                request.space.label(),
+                request.axis.label()
+            );
+            commands.queue(move |world: &mut World| {
+                let created = copies
+                    .into_iter()
+                    .filter_map(|(entity, world_transform)| {
+                        duplicate_with_world_transform(world, entity, world_transform, &registry)
+                    })
+                    .collect();
+                execute_history_command(
+                    world,
+                    Box::new(EntityBatchCommand::new(created, Vec::new(), description)),
+                );
+            });
+            continue;
+        }
+
+        let entries: Vec<(Entity, Transform, Transform)> = roots
+            .iter()
+            .map(|(entity, transform, global)| {
+                let world_transform = GlobalTransform::from(mirrored_world(reflect, global));
+                let local = parents
+                    .get(*entity)
+                    .ok()
+                    .and_then(|parent| globals.get(parent.parent()).ok())
+                    .map(|parent_global| world_transform.reparented_to(parent_global))
+                    .unwrap_or_else(|| world_transform.compute_transform());
+                (*entity, *transform, local)
+            })
+            .collect();
+
+        queue_history_command(
+            &mut commands,
+            Box::new(TransformBatchCommand::new(
+                entries,
+                format!(
+                    "Mirror {} entities across {} {}",
+                    roots.len(),
+                    request.space.label(),
+                    request.axis.label()
+                ),
+            )),
+        );
+    }
+}
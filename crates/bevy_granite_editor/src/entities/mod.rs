@@ -1,9 +1,17 @@
+pub mod arrange;
 pub mod bounds;
+pub mod brush_csg;
 pub mod creation;
+pub mod mirror;
 pub mod relationship;
 pub mod plugin;
 
+pub use arrange::{
+    align_selection_system, distribute_selection_system, AlignEdge, AlignTarget, ArrangeAxis,
+};
 pub use bounds::{get_entity_bounds, get_entity_bounds_or_fallback};
+pub use brush_csg::{brush_merge_system, brush_subtract_system};
+pub use mirror::{mirror_selection_system, MirrorSpace};
 pub use creation::{new_entity_via_popup_system, process_entity_spawn_queue_system, EntitySpawnQueue, PendingEntitySpawn};
 pub use relationship::{child_removal_system, parent_removal_system, parent_removal_from_entities_system, parent_system, parent_from_node_tree_system};
 
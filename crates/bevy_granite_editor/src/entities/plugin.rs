@@ -1,7 +1,8 @@
 use super::{
-    child_removal_system, new_entity_via_popup_system, parent_from_node_tree_system,
-    parent_removal_from_entities_system, parent_removal_system, parent_system,
-    process_entity_spawn_queue_system, EntitySpawnQueue,
+    align_selection_system, brush_merge_system, brush_subtract_system, child_removal_system,
+    distribute_selection_system, mirror_selection_system, new_entity_via_popup_system,
+    parent_from_node_tree_system, parent_removal_from_entities_system, parent_removal_system,
+    parent_system, process_entity_spawn_queue_system, EntitySpawnQueue,
 };
 use crate::setup::is_editor_active;
 use bevy::{
@@ -30,6 +31,11 @@ impl Plugin for AssetPlugin {
                     child_removal_system,
                     parent_removal_system,
                     parent_removal_from_entities_system,
+                    brush_subtract_system,
+                    brush_merge_system,
+                    align_selection_system,
+                    distribute_selection_system,
+                    mirror_selection_system,
                 )
                     .run_if(is_editor_active),
             );
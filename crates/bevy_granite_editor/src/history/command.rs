@@ -52,6 +52,11 @@ pub trait EditorCommand: Send + Sync {
 
     /// Optional: called when command is removed from history
     fn on_discard(&mut self) {}
+
+    /// Optional: called with the world when command is removed from history
+    ///
+    /// For commands holding on to entities that only they could bring back
+    fn on_discard_with_world(&mut self, _world: &mut World) {}
 }
 
 /// A stored command with metadata
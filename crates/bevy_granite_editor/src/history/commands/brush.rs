@@ -0,0 +1,223 @@
+use crate::history::command::{CommandError, CommandResult, EditorCommand};
+use bevy::prelude::*;
+use bevy_granite_core::{
+    entities::editable::RequestEntityUpdateFromClass, BrushGeometry, GraniteType, GraniteTypes,
+    IdentityData,
+};
+
+/// Command for undoing/redoing mesh edits on brush classes
+///
+/// Geometry lives inside the class data on IdentityData, so this swaps the
+/// stored geometry and pushes the class back to the entity to rebuild its mesh.
+#[derive(Clone)]
+pub struct BrushGeometryCommand {
+    entity: Entity,
+    old_geometry: Option<BrushGeometry>,
+    new_geometry: Option<BrushGeometry>,
+    description: String,
+}
+
+impl BrushGeometryCommand {
+    /// Create a new brush geometry command
+    ///
+    /// # Arguments
+    /// * `entity` - The brush entity being edited
+    /// * `old` - The geometry before the edit. None means the class's generated shape
+    /// * `new` - The geometry after the edit
+    /// * `description` - What the edit was, shown in undo/redo logs
+    pub fn new(
+        entity: Entity,
+        old: Option<BrushGeometry>,
+        new: Option<BrushGeometry>,
+        description: String,
+    ) -> Self {
+        Self {
+            entity,
+            old_geometry: old,
+            new_geometry: new,
+            description,
+        }
+    }
+
+    /// Get the entity this command affects
+    pub fn entity(&self) -> Entity {
+        self.entity
+    }
+
+    fn apply(&self, world: &mut World, geometry: &Option<BrushGeometry>) -> CommandResult<()> {
+        let class = {
+            let mut entity = world
+                .get_entity_mut(self.entity)
+                .map_err(|_| CommandError::EntityNotFound(self.entity))?;
+            let mut identity = entity
+                .get_mut::<IdentityData>()
+                .ok_or_else(|| CommandError::ComponentNotFound("IdentityData".to_string()))?;
+            if !identity.class.set_brush_geometry(geometry.clone()) {
+                return Err(CommandError::InvalidState(format!(
+                    "{} does not support mesh editing",
+                    identity.class.type_name()
+                )));
+            }
+            identity.class.clone()
+        };
+
+        // Rebuild the mesh through the class's normal update path
+        // Skipped when the class update events aren't registered (e.g. bare test worlds)
+        let _ = world.run_system_cached_with(push_class_to_entity, (self.entity, class));
+        Ok(())
+    }
+}
+
+pub(super) fn push_class_to_entity(
+    In((entity, class)): In<(Entity, GraniteTypes)>,
+    mut request_update: RequestEntityUpdateFromClass,
+) {
+    class.push_to_entity(entity, &mut request_update);
+}
+
+impl EditorCommand for BrushGeometryCommand {
+    fn execute(&mut self, world: &mut World) -> CommandResult<()> {
+        let geometry = self.new_geometry.clone();
+        self.apply(world, &geometry)
+    }
+
+    fn undo(&mut self, world: &mut World) -> CommandResult<()> {
+        let geometry = self.old_geometry.clone();
+        self.apply(world, &geometry)
+    }
+
+    fn description(&self) -> String {
+        self.description.clone()
+    }
+
+    fn clone_command(&self) -> Box<dyn EditorCommand> {
+        Box::new(self.clone())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use bevy_granite_core::{BrushEditOperation, RectBrush};
+    use uuid::Uuid;
+
+    fn spawn_brush(world: &mut World) -> Entity {
+        world
+            .spawn(IdentityData {
+                name: "Brush".to_string(),
+                uuid: Uuid::new_v4(),
+                class: GraniteTypes::RectBrush(RectBrush::default()),
+            })
+            .id()
+    }
+
+    fn stored_geometry(world: &World, entity: Entity) -> Option<BrushGeometry> {
+        match &world.get::<IdentityData>(entity).unwrap().class {
+            GraniteTypes::RectBrush(brush) => brush.geometry.clone(),
+            _ => None,
+        }
+    }
+
+    #[test]
+    fn test_brush_geometry_command_execute_and_undo() {
+        let mut world = World::new();
+        let entity = spawn_brush(&mut world);
+
+        let base = BrushGeometry::from_box(Vec3::ONE);
+        let edited = base
+            .apply(&BrushEditOperation::TranslateVertices {
+                vertices: vec![0],
+                delta: Vec3::Y,
+            })
+            .unwrap();
+
+        let mut cmd =
+            BrushGeometryCommand::new(entity, None, Some(edited.clone()), "Move".to_string());
+
+        cmd.execute(&mut world).unwrap();
+        assert_eq!(stored_geometry(&world, entity), Some(edited));
+
+        cmd.undo(&mut world).unwrap();
+        assert_eq!(stored_geometry(&world, entity), None);
+    }
+
+    #[test]
+    fn test_brush_geometry_command_unsupported_class() {
+        let mut world = World::new();
+        let entity = world
+            .spawn(IdentityData {
+                name: "Empty".to_string(),
+                uuid: Uuid::new_v4(),
+                class: GraniteTypes::Empty(Default::default()),
+            })
+            .id();
+
+        let mut cmd = BrushGeometryCommand::new(
+            entity,
+            None,
+            Some(BrushGeometry::from_box(Vec3::ONE)),
+            "Move".to_string(),
+        );
+
+        assert!(matches!(
+            cmd.execute(&mut world),
+            Err(CommandError::InvalidState(_))
+        ));
+    }
+
+    #[test]
+    fn test_brush_geometry_command_missing_entity() {
+        let mut world = World::new();
+        let entity = Entity::from_raw_u32(999).unwrap();
+
+        let mut cmd = BrushGeometryCommand::new(entity, None, None, "Move".to_string());
+
+        assert!(matches!(
+            cmd.execute(&mut world),
+            Err(CommandError::EntityNotFound(_))
+        ));
+    }
+
+    #[test]
+    fn test_extrude_then_delete_faces() {
+        let base = BrushGeometry::from_box(Vec3::ONE);
+        let extruded = base
+            .apply(&BrushEditOperation::ExtrudeFaces {
+                faces: vec![4],
+                distance: 1.0,
+            })
+            .unwrap();
+        // Top face is pushed up and four side walls are added
+        assert_eq!(extruded.vertices.len(), 12);
+        assert_eq!(extruded.faces.len(), 10);
+        assert!((extruded.face_center(4).y - 1.5).abs() < 0.0001);
+
+        let deleted = extruded
+            .apply(&BrushEditOperation::DeleteFaces { faces: vec![4] })
+            .unwrap();
+        assert_eq!(deleted.faces.len(), 9);
+    }
+
+    #[test]
+    fn test_split_edge_requires_edge() {
+        let base = BrushGeometry::from_box(Vec3::ONE);
+        // 0 and 6 are opposite corners
+        assert!(base
+            .apply(&BrushEditOperation::SplitEdge { a: 0, b: 6 })
+            .is_err());
+
+        let split = base
+            .apply(&BrushEditOperation::SplitEdge { a: 0, b: 1 })
+            .unwrap();
+        assert_eq!(split.vertices.len(), 9);
+        // Front and bottom faces both share edge 0-1
+        assert_eq!(
+            split
+                .faces
+                .iter()
+                .filter(|face| face.indices.contains(&8))
+                .count(),
+            2
+        );
+    }
+}
@@ -0,0 +1,230 @@
+use super::brush::push_class_to_entity;
+use crate::history::command::{CommandError, CommandResult, EditorCommand};
+use bevy::prelude::*;
+use bevy_granite_core::{GraniteType, GraniteTypes, IdentityData};
+
+/// Command for undoing/redoing class data edits made outside the entity editor,
+/// like dragging a light's range or a probe's bounds in the viewport
+///
+/// Stores the whole class before and after, then pushes it back to the entity.
+#[derive(Clone)]
+pub struct ClassDataCommand {
+    entity: Entity,
+    old_class: GraniteTypes,
+    new_class: GraniteTypes,
+    description: String,
+}
+
+impl ClassDataCommand {
+    /// Create a new class data command
+    ///
+    /// # Arguments
+    /// * `entity` - The entity whose class was edited
+    /// * `old` - The class data before the edit
+    /// * `new` - The class data after the edit
+    /// * `description` - What the edit was, shown in undo/redo logs
+    pub fn new(entity: Entity, old: GraniteTypes, new: GraniteTypes, description: String) -> Self {
+        Self {
+            entity,
+            old_class: old,
+            new_class: new,
+            description,
+        }
+    }
+
+    /// Get the entity this command affects
+    pub fn entity(&self) -> Entity {
+        self.entity
+    }
+
+    fn apply(&self, world: &mut World, class: &GraniteTypes) -> CommandResult<()> {
+        {
+            let mut entity = world
+                .get_entity_mut(self.entity)
+                .map_err(|_| CommandError::EntityNotFound(self.entity))?;
+            let mut identity = entity
+                .get_mut::<IdentityData>()
+                .ok_or_else(|| CommandError::ComponentNotFound("IdentityData".to_string()))?;
+            if std::mem::discriminant(&identity.class) != std::mem::discriminant(class) {
+                return Err(CommandError::InvalidState(
+                    "Entity class changed since the edit".to_string(),
+                ));
+            }
+            identity.class = class.clone();
+        }
+
+        // Class update events don't swap materials, so the standard material follows here
+        // Registered material kinds manage their own component
+        let handle = class
+            .get_material_data()
+            .and_then(|data| data.current.handle.clone());
+        if let (Some(handle), Some(mut material)) = (
+            handle,
+            world.get_mut::<MeshMaterial3d<StandardMaterial>>(self.entity),
+        ) {
+            if material.0 != handle {
+                material.0 = handle;
+            }
+        }
+
+        // Skipped when the class update events aren't registered (e.g. bare test worlds)
+        let _ = world.run_system_cached_with(push_class_to_entity, (self.entity, class.clone()));
+        Ok(())
+    }
+}
+
+impl EditorCommand for ClassDataCommand {
+    fn execute(&mut self, world: &mut World) -> CommandResult<()> {
+        let class = self.new_class.clone();
+        self.apply(world, &class)
+    }
+
+    fn undo(&mut self, world: &mut World) -> CommandResult<()> {
+        let class = self.old_class.clone();
+        self.apply(world, &class)
+    }
+
+    fn description(&self) -> String {
+        self.description.clone()
+    }
+
+    fn clone_command(&self) -> Box<dyn EditorCommand> {
+        Box::new(self.clone())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use bevy_granite_core::{RectBrush, ReflectionProbe};
+    use uuid::Uuid;
+
+    fn probe(size: (f32, f32, f32)) -> GraniteTypes {
+        GraniteTypes::ReflectionProbe(ReflectionProbe {
+            size,
+            ..Default::default()
+        })
+    }
+
+    #[test]
+    fn test_class_data_command_execute_and_undo() {
+        let mut world = World::new();
+        let entity = world
+            .spawn(IdentityData {
+                name: "Probe".to_string(),
+                uuid: Uuid::new_v4(),
+                class: probe((10., 10., 10.)),
+            })
+            .id();
+
+        let mut cmd = ClassDataCommand::new(
+            entity,
+            probe((10., 10., 10.)),
+            probe((4., 10., 10.)),
+            "Resize".to_string(),
+        );
+
+        cmd.execute(&mut world).unwrap();
+        assert_eq!(
+            world.get::<IdentityData>(entity).unwrap().class,
+            probe((4., 10., 10.))
+        );
+
+        cmd.undo(&mut world).unwrap();
+        assert_eq!(
+            world.get::<IdentityData>(entity).unwrap().class,
+            probe((10., 10., 10.))
+        );
+    }
+
+    #[test]
+    fn test_class_data_command_swaps_material() {
+        let mut materials = Assets::<StandardMaterial>::default();
+        let old_handle = materials.add(StandardMaterial::default());
+        let new_handle = materials.add(StandardMaterial::default());
+        let brush = |handle: &Handle<StandardMaterial>| {
+            let mut brush = RectBrush::default();
+            brush.material.current.handle = Some(handle.clone());
+            GraniteTypes::RectBrush(brush)
+        };
+
+        let mut world = World::new();
+        let entity = world
+            .spawn((
+                IdentityData {
+                    name: "Brush".to_string(),
+                    uuid: Uuid::new_v4(),
+                    class: brush(&old_handle),
+                },
+                MeshMaterial3d(old_handle.clone()),
+            ))
+            .id();
+
+        let mut cmd = ClassDataCommand::new(
+            entity,
+            brush(&old_handle),
+            brush(&new_handle),
+            "Assign material".to_string(),
+        );
+
+        cmd.execute(&mut world).unwrap();
+        assert_eq!(
+            world
+                .get::<MeshMaterial3d<StandardMaterial>>(entity)
+                .unwrap()
+                .0,
+            new_handle
+        );
+
+        cmd.undo(&mut world).unwrap();
+        assert_eq!(
+            world
+                .get::<MeshMaterial3d<StandardMaterial>>(entity)
+                .unwrap()
+                .0,
+            old_handle
+        );
+    }
+
+    #[test]
+    fn test_class_data_command_class_mismatch() {
+        let mut world = World::new();
+        let entity = world
+            .spawn(IdentityData {
+                name: "Empty".to_string(),
+                uuid: Uuid::new_v4(),
+                class: GraniteTypes::Empty(Default::default()),
+            })
+            .id();
+
+        let mut cmd = ClassDataCommand::new(
+            entity,
+            probe((10., 10., 10.)),
+            probe((4., 10., 10.)),
+            "Resize".to_string(),
+        );
+
+        assert!(matches!(
+            cmd.execute(&mut world),
+            Err(CommandError::InvalidState(_))
+        ));
+    }
+
+    #[test]
+    fn test_class_data_command_missing_entity() {
+        let mut world = World::new();
+        let entity = Entity::from_raw_u32(999).unwrap();
+
+        let mut cmd = ClassDataCommand::new(
+            entity,
+            probe((10., 10., 10.)),
+            probe((4., 10., 10.)),
+            "Resize".to_string(),
+        );
+
+        assert!(matches!(
+            cmd.execute(&mut world),
+            Err(CommandError::EntityNotFound(_))
+        ));
+    }
+}
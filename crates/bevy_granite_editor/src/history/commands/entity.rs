@@ -1,5 +1,6 @@
 use crate::history::command::{CommandError, CommandResult, EditorCommand};
-use bevy::prelude::*;
+use bevy::{ecs::entity_disabling::Disabled, prelude::*};
+use bevy_granite_gizmos::{ActiveSelection, Selected};
 
 /// Command for creating a new entity
 ///
@@ -145,6 +146,121 @@ impl EditorCommand for EntityDeleteCommand {
     }
 }
 
+/// Command for entities an editor tool spawned and removed in one go, like brush CSG
+///
+/// The entities already exist when this is recorded. Removed entities are disabled
+/// instead of despawned, so undo brings them back with every component and child.
+/// Whatever is disabled when the command leaves the history is despawned for good.
+#[derive(Clone)]
+pub struct EntityBatchCommand {
+    created: Vec<Entity>,
+    removed: Vec<Entity>,
+    description: String,
+    applied: bool,
+}
+
+impl EntityBatchCommand {
+    /// Create a new entity batch command
+    ///
+    /// # Arguments
+    /// * `created` - Entities the tool spawned, disabled again on undo
+    /// * `removed` - Entities the tool replaces, disabled on execute
+    /// * `description` - What the tool did, shown in undo/redo logs
+    pub fn new(created: Vec<Entity>, removed: Vec<Entity>, description: String) -> Self {
+        Self {
+            created,
+            removed,
+            description,
+            applied: false,
+        }
+    }
+
+    /// Get the entities the tool spawned
+    pub fn created(&self) -> &[Entity] {
+        &self.created
+    }
+
+    /// Get the entities the tool removed
+    pub fn removed(&self) -> &[Entity] {
+        &self.removed
+    }
+
+    fn apply(&mut self, world: &mut World, applied: bool) -> CommandResult<()> {
+        let (enable, disable) = if applied {
+            (&self.created, &self.removed)
+        } else {
+            (&self.removed, &self.created)
+        };
+        if let Some(missing) = enable
+            .iter()
+            .chain(disable.iter())
+            .find(|entity| world.get_entity(**entity).is_err())
+        {
+            return Err(CommandError::EntityNotFound(*missing));
+        }
+
+        for entity in enable {
+            set_entity_enabled(world, *entity, true);
+        }
+        for entity in disable {
+            set_entity_enabled(world, *entity, false);
+        }
+        self.applied = applied;
+        Ok(())
+    }
+}
+
+/// Disable or re-enable an entity and everything below it
+/// Disabled entities drop out of the selection so they don't come back selected
+fn set_entity_enabled(world: &mut World, entity: Entity, enabled: bool) {
+    let mut stack = vec![entity];
+    while let Some(current) = stack.pop() {
+        let Ok(mut entity_mut) = world.get_entity_mut(current) else {
+            continue;
+        };
+        if let Some(children) = entity_mut.get::<Children>() {
+            stack.extend(children.iter());
+        }
+        if enabled {
+            entity_mut.remove::<Disabled>();
+        } else {
+            entity_mut.remove::<(ActiveSelection, Selected)>();
+            entity_mut.insert(Disabled);
+        }
+    }
+}
+
+impl EditorCommand for EntityBatchCommand {
+    fn execute(&mut self, world: &mut World) -> CommandResult<()> {
+        self.apply(world, true)
+    }
+
+    fn undo(&mut self, world: &mut World) -> CommandResult<()> {
+        self.apply(world, false)
+    }
+
+    fn description(&self) -> String {
+        self.description.clone()
+    }
+
+    fn clone_command(&self) -> Box<dyn EditorCommand> {
+        Box::new(self.clone())
+    }
+
+    fn on_discard_with_world(&mut self, world: &mut World) {
+        let disabled = if self.applied {
+            &self.removed
+        } else {
+            &self.created
+        };
+        for entity in disabled {
+            if let Ok(entity_mut) = world.get_entity_mut(*entity) {
+                entity_mut.despawn();
+            }
+        }
+    }
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -236,6 +352,58 @@ mod tests {
         }
     }
 
+    fn is_disabled(world: &World, entity: Entity) -> bool {
+        world.get::<Disabled>(entity).is_some()
+    }
+
+    #[test]
+    fn test_entity_batch_command_execute_and_undo() {
+        let mut world = World::new();
+        let removed = world.spawn((Name::new("Original"), Selected)).id();
+        let child = world.spawn((Name::new("Child"), ChildOf(removed))).id();
+        let created = world.spawn(Name::new("Result")).id();
+
+        let mut cmd = EntityBatchCommand::new(vec![created], vec![removed], "CSG".to_string());
+        cmd.execute(&mut world).unwrap();
+        assert!(is_disabled(&world, removed));
+        assert!(is_disabled(&world, child));
+        assert!(!is_disabled(&world, created));
+        assert!(world.get::<Selected>(removed).is_none());
+
+        cmd.undo(&mut world).unwrap();
+        assert!(!is_disabled(&world, removed));
+        assert!(!is_disabled(&world, child));
+        assert!(is_disabled(&world, created));
+        // The original comes back under the same parent link
+        assert_eq!(world.get::<ChildOf>(child).unwrap().parent(), removed);
+    }
+
+    #[test]
+    fn test_entity_batch_command_discard_despawns_disabled() {
+        let mut world = World::new();
+        let removed = world.spawn(Name::new("Original")).id();
+        let created = world.spawn(Name::new("Result")).id();
+
+        let mut cmd = EntityBatchCommand::new(vec![created], vec![removed], "CSG".to_string());
+        cmd.execute(&mut world).unwrap();
+        cmd.on_discard_with_world(&mut world);
+        assert!(world.get_entity(removed).is_err());
+        assert!(world.get_entity(created).is_ok());
+    }
+
+    #[test]
+    fn test_entity_batch_command_missing_entity() {
+        let mut world = World::new();
+        let entity = Entity::from_raw_u32(999).unwrap();
+
+        let mut cmd = EntityBatchCommand::new(vec![entity], Vec::new(), "CSG".to_string());
+
+        assert!(matches!(
+            cmd.execute(&mut world),
+            Err(CommandError::EntityNotFound(_))
+        ));
+    }
+
     #[test]
     fn test_entity_create_description() {
         let name = Name::new("MyEntity");
@@ -3,8 +3,12 @@
 //! This module contains all the different command types that can be executed,
 //! undone, and redone in the editor.
 
+pub mod brush;
+pub mod class;
 pub mod entity;
 pub mod transform;
 
-pub use entity::{EntityCreateCommand, EntityDeleteCommand};
-pub use transform::TransformCommand;
+pub use brush::BrushGeometryCommand;
+pub use class::ClassDataCommand;
+pub use entity::{EntityBatchCommand, EntityCreateCommand, EntityDeleteCommand};
+pub use transform::{TransformBatchCommand, TransformCommand};
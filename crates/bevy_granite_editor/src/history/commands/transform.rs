@@ -109,6 +109,74 @@ impl EditorCommand for TransformCommand {
     }
 }
 
+/// Command for undoing/redoing transform changes on several entities at once
+///
+/// Align, distribute and similar layout tools move many entities in one go,
+/// this keeps the whole move as a single undo step.
+#[derive(Clone)]
+pub struct TransformBatchCommand {
+    entries: Vec<(Entity, Transform, Transform)>,
+    description: String,
+}
+
+impl TransformBatchCommand {
+    /// Create a new batch transform command
+    ///
+    /// # Arguments
+    /// * `entries` - (entity, old transform, new transform) for every entity moved
+    /// * `description` - What the change was, shown in undo/redo logs
+    pub fn new(
+        entries: Vec<(Entity, Transform, Transform)>,
+        description: impl Into<String>,
+    ) -> Self {
+        Self {
+            entries,
+            description: description.into(),
+        }
+    }
+
+    /// Get the entities this command affects
+    pub fn entities(&self) -> impl Iterator<Item = Entity> + '_ {
+        self.entries.iter().map(|(entity, _, _)| *entity)
+    }
+
+    fn apply(&self, world: &mut World, use_new: bool) -> CommandResult<()> {
+        // Check everything first so a missing entity doesn't leave a half applied batch
+        for (entity, _, _) in &self.entries {
+            let entity_ref = world
+                .get_entity(*entity)
+                .map_err(|_| CommandError::EntityNotFound(*entity))?;
+            if !entity_ref.contains::<Transform>() {
+                return Err(CommandError::ComponentNotFound("Transform".to_string()));
+            }
+        }
+        for (entity, old, new) in &self.entries {
+            if let Some(mut transform) = world.get_mut::<Transform>(*entity) {
+                *transform = if use_new { *new } else { *old };
+            }
+        }
+        Ok(())
+    }
+}
+
+impl EditorCommand for TransformBatchCommand {
+    fn execute(&mut self, world: &mut World) -> CommandResult<()> {
+        self.apply(world, true)
+    }
+
+    fn undo(&mut self, world: &mut World) -> CommandResult<()> {
+        self.apply(world, false)
+    }
+
+    fn description(&self) -> String {
+        self.description.clone()
+    }
+
+    fn clone_command(&self) -> Box<dyn EditorCommand> {
+        Box::new(self.clone())
+    }
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -175,4 +243,52 @@ mod tests {
 
         assert!(desc.contains("position"));
     }
+
+    #[test]
+    fn test_transform_batch_command_undo() {
+        let mut world = World::new();
+        let a = world.spawn(Transform::default()).id();
+        let b = world.spawn(Transform::from_xyz(1.0, 0.0, 0.0)).id();
+
+        let mut cmd = TransformBatchCommand::new(
+            vec![
+                (a, Transform::default(), Transform::from_xyz(0.0, 2.0, 0.0)),
+                (
+                    b,
+                    Transform::from_xyz(1.0, 0.0, 0.0),
+                    Transform::from_xyz(1.0, 2.0, 0.0),
+                ),
+            ],
+            "Align",
+        );
+
+        cmd.execute(&mut world).unwrap();
+        assert_eq!(world.get::<Transform>(a).unwrap().translation.y, 2.0);
+        assert_eq!(world.get::<Transform>(b).unwrap().translation.y, 2.0);
+
+        cmd.undo(&mut world).unwrap();
+        assert_eq!(world.get::<Transform>(a).unwrap().translation, Vec3::ZERO);
+        assert_eq!(
+            world.get::<Transform>(b).unwrap().translation,
+            Vec3::new(1.0, 0.0, 0.0)
+        );
+    }
+
+    #[test]
+    fn test_transform_batch_command_missing_entity_applies_nothing() {
+        let mut world = World::new();
+        let a = world.spawn(Transform::default()).id();
+        let missing = Entity::from_raw_u32(999).unwrap();
+
+        let mut cmd = TransformBatchCommand::new(
+            vec![
+                (a, Transform::default(), Transform::from_xyz(3.0, 0.0, 0.0)),
+                (missing, Transform::default(), Transform::default()),
+            ],
+            "Align",
+        );
+
+        assert!(cmd.execute(&mut world).is_err());
+        assert_eq!(world.get::<Transform>(a).unwrap().translation, Vec3::ZERO);
+    }
 }
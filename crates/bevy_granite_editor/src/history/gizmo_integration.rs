@@ -8,10 +8,21 @@
 //! When a user updates a transform (via gizmo or inspector), a UserUpdatedTransformEvent
 //! is fired. This system listens to those events and queues TransformCommand entries
 //! that can be undone/redone.
+//!
+//! Mesh edits requested from the vertex gizmo (RequestBrushEditEvent) are resolved
+//! against the brush's current geometry and queued as BrushGeometryCommand entries.
+//!
+//! Class data dragged with viewport handles is queued on release as ClassDataCommand entries.
+//!
+//! Copies made by the array and scatter tools (DuplicatedEntitiesEvent) are recorded as one
+//! EntityBatchCommand each.
 
 use bevy::ecs::message::{MessageReader, MessageWriter};
 use bevy::prelude::*;
-use bevy_granite_gizmos::GizmoTransformAppliedEvent;
+use bevy_granite_core::{BrushGeometry, GraniteType, GraniteTypes, IdentityData};
+use bevy_granite_gizmos::{
+    DuplicatedEntitiesEvent, GizmoTransformAppliedEvent, RequestBrushEditEvent,
+};
 use bevy_granite_logging::{
     config::{LogCategory, LogLevel, LogType},
     log,
@@ -20,7 +31,10 @@ use bevy_granite_logging::{
 use crate::interface::events::UserUpdatedTransformEvent;
 use crate::interface::tabs::entity_editor::widgets::EntityGlobalTransformData;
 
-use super::{CommandHistory, TransformCommand};
+use super::{
+    queue_history_command, BrushGeometryCommand, ClassDataCommand, CommandHistory,
+    EntityBatchCommand, TransformCommand,
+};
 
 /// Resource that queues pending transform commands to be processed by the exclusive system
 #[derive(Resource, Default)]
@@ -151,6 +165,255 @@ pub fn process_pending_transform_commands(world: &mut World) {
     }
 }
 
+/// A resolved brush edit waiting to be executed through the history
+pub struct PendingBrushEdit {
+    pub entity: Entity,
+    pub old_geometry: Option<BrushGeometry>,
+    pub new_geometry: Option<BrushGeometry>,
+    pub description: String,
+}
+
+/// Resource that queues pending brush geometry commands to be processed by the exclusive system
+#[derive(Resource, Default)]
+pub struct PendingBrushEditCommands {
+    pub commands: Vec<PendingBrushEdit>,
+}
+
+impl PendingBrushEditCommands {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    pub fn take_all(&mut self) -> Vec<PendingBrushEdit> {
+        std::mem::take(&mut self.commands)
+    }
+}
+
+/// Record copies the gizmo tools already spawned, so one undo removes them all
+pub fn record_duplicated_entities(
+    mut commands: Commands,
+    mut reader: MessageReader<DuplicatedEntitiesEvent>,
+) {
+    for DuplicatedEntitiesEvent {
+        entities,
+        description,
+    } in reader.read()
+    {
+        if entities.is_empty() {
+            continue;
+        }
+        queue_history_command(
+            &mut commands,
+            Box::new(EntityBatchCommand::new(
+                entities.clone(),
+                Vec::new(),
+                description.clone(),
+            )),
+        );
+    }
+}
+
+/// System that resolves RequestBrushEditEvent against the current class geometry
+/// Edits that chain in the same frame build on each other
+pub fn record_brush_edit_requests(
+    mut reader: MessageReader<RequestBrushEditEvent>,
+    identities: Query<&IdentityData>,
+    mut queue: ResMut<PendingBrushEditCommands>,
+) {
+    for RequestBrushEditEvent { entity, operation } in reader.read() {
+        let Ok(identity) = identities.get(*entity) else {
+            log!(
+                LogType::Editor,
+                LogLevel::Warning,
+                LogCategory::System,
+                "Could not find brush entity {:?} for mesh edit",
+                entity
+            );
+            continue;
+        };
+
+        let (old_geometry, current) = match queue
+            .commands
+            .iter()
+            .rev()
+            .find(|pending| pending.entity == *entity)
+        {
+            Some(pending) => (pending.new_geometry.clone(), pending.new_geometry.clone()),
+            None => {
+                let current = identity.class.get_brush_geometry();
+                // Unedited brushes store None, kept so undo gives back the generated shape
+                let mut unedited = identity.class.clone();
+                unedited.set_brush_geometry(None);
+                let stored = if unedited == identity.class {
+                    None
+                } else {
+                    current.clone()
+                };
+                (stored, current)
+            }
+        };
+
+        let Some(current) = current else {
+            log!(
+                LogType::Editor,
+                LogLevel::Warning,
+                LogCategory::System,
+                "{} does not support mesh editing",
+                identity.class.type_name()
+            );
+            continue;
+        };
+
+        match current.apply(operation) {
+            Ok(new_geometry) => queue.commands.push(PendingBrushEdit {
+                entity: *entity,
+                old_geometry,
+                new_geometry: Some(new_geometry),
+                description: operation.description(),
+            }),
+            Err(e) => {
+                log!(
+                    LogType::Editor,
+                    LogLevel::Warning,
+                    LogCategory::System,
+                    "Mesh edit failed: {}",
+                    e
+                );
+            }
+        }
+    }
+}
+
+/// Exclusive system that processes queued brush edits and adds them to history
+pub fn process_pending_brush_edit_commands(world: &mut World) {
+    let commands = {
+        let mut queue = world
+            .get_resource_mut::<PendingBrushEditCommands>()
+            .unwrap();
+        queue.take_all()
+    };
+
+    for pending in commands {
+        let description = pending.description.clone();
+        let command = BrushGeometryCommand::new(
+            pending.entity,
+            pending.old_geometry,
+            pending.new_geometry,
+            pending.description,
+        );
+
+        world.resource_scope(
+            |world, mut history: bevy::ecs::change_detection::Mut<CommandHistory>| match history
+                .execute(Box::new(command), world)
+            {
+                Ok(()) => {
+                    log!(
+                        LogType::Editor,
+                        LogLevel::OK,
+                        LogCategory::System,
+                        "{}",
+                        description
+                    );
+                }
+                Err(e) => {
+                    log!(
+                        LogType::Editor,
+                        LogLevel::Warning,
+                        LogCategory::System,
+                        "Failed to apply mesh edit: {}",
+                        e
+                    );
+                }
+            },
+        );
+    }
+}
+
+/// A finished class data edit waiting to be added to the history
+pub struct PendingClassEdit {
+    pub entity: Entity,
+    pub old_class: GraniteTypes,
+    pub new_class: GraniteTypes,
+    pub description: String,
+}
+
+/// Resource that queues pending class data commands to be processed by the exclusive system
+#[derive(Resource, Default)]
+pub struct PendingClassEditCommands {
+    pub commands: Vec<PendingClassEdit>,
+}
+
+impl PendingClassEditCommands {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    pub fn queue_class_edit(
+        &mut self,
+        entity: Entity,
+        old_class: GraniteTypes,
+        new_class: GraniteTypes,
+        description: String,
+    ) {
+        self.commands.push(PendingClassEdit {
+            entity,
+            old_class,
+            new_class,
+            description,
+        });
+    }
+
+    pub fn take_all(&mut self) -> Vec<PendingClassEdit> {
+        std::mem::take(&mut self.commands)
+    }
+}
+
+/// Exclusive system that processes queued class edits and adds them to history
+/// The edit is already on the entity, executing it again just pushes the same data
+pub fn process_pending_class_edit_commands(world: &mut World) {
+    let commands = {
+        let mut queue = world
+            .get_resource_mut::<PendingClassEditCommands>()
+            .unwrap();
+        queue.take_all()
+    };
+
+    for pending in commands {
+        let description = pending.description.clone();
+        let command = ClassDataCommand::new(
+            pending.entity,
+            pending.old_class,
+            pending.new_class,
+            pending.description,
+        );
+
+        world.resource_scope(
+            |world, mut history: bevy::ecs::change_detection::Mut<CommandHistory>| match history
+                .execute(Box::new(command), world)
+            {
+                Ok(()) => {
+                    log!(
+                        LogType::Editor,
+                        LogLevel::OK,
+                        LogCategory::System,
+                        "{}",
+                        description
+                    );
+                }
+                Err(e) => {
+                    log!(
+                        LogType::Editor,
+                        LogLevel::Warning,
+                        LogCategory::System,
+                        "Failed to record class edit: {}",
+                        e
+                    );
+                }
+            },
+        );
+    }
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -184,4 +447,22 @@ mod tests {
         assert_eq!(commands.len(), 1);
         assert!(queue.commands.is_empty());
     }
+
+    #[test]
+    fn test_pending_class_edit_commands_take_all() {
+        let mut queue = PendingClassEditCommands::new();
+        let entity = Entity::from_raw_u32(1).unwrap();
+
+        queue.queue_class_edit(
+            entity,
+            GraniteTypes::Empty(Default::default()),
+            GraniteTypes::Empty(Default::default()),
+            "Edit".to_string(),
+        );
+        assert_eq!(queue.commands.len(), 1);
+
+        let commands = queue.take_all();
+        assert_eq!(commands.len(), 1);
+        assert!(queue.commands.is_empty());
+    }
 }
@@ -1,5 +1,10 @@
 use super::command::{CommandError, CommandResult, EditorCommand};
+use bevy::ecs::change_detection::Mut;
 use bevy::prelude::*;
+use bevy_granite_logging::{
+    config::{LogCategory, LogLevel, LogType},
+    log,
+};
 use std::collections::VecDeque;
 
 const MAX_HISTORY_SIZE: usize = 100;
@@ -62,11 +67,15 @@ impl CommandHistory {
         while self.undo_stack.len() > self.max_size {
             if let Some(mut discarded) = self.undo_stack.pop_front() {
                 discarded.on_discard();
+                discarded.on_discard_with_world(world);
             }
         }
 
         // Clear redo stack (new action invalidates redo)
-        self.redo_stack.clear();
+        for mut discarded in self.redo_stack.drain(..) {
+            discarded.on_discard();
+            discarded.on_discard_with_world(world);
+        }
 
         Ok(())
     }
@@ -139,10 +148,12 @@ impl CommandHistory {
         self.redo_stack.len()
     }
 
-    /// Clear all history
-    pub fn clear(&mut self) {
-        self.undo_stack.clear();
-        self.redo_stack.clear();
+    /// Clear all history, discarding every dropped command
+    pub fn clear(&mut self, world: &mut World) {
+        for mut discarded in self.undo_stack.drain(..).chain(self.redo_stack.drain(..)) {
+            discarded.on_discard();
+            discarded.on_discard_with_world(world);
+        }
     }
 
     /// Begin a transaction (group multiple commands)
@@ -180,6 +191,40 @@ impl Default for CommandHistory {
     }
 }
 
+/// Run a command through the history from a regular system
+/// The command executes once commands are applied, so it lands as one undo step
+pub fn queue_history_command(commands: &mut Commands, command: Box<dyn EditorCommand>) {
+    commands.queue(move |world: &mut World| execute_history_command(world, command));
+}
+
+/// Run a command through the history from inside a world closure
+pub fn execute_history_command(world: &mut World, command: Box<dyn EditorCommand>) {
+    let description = command.description();
+    world.resource_scope(|world, mut history: Mut<CommandHistory>| {
+        match history.execute(command, world) {
+            Ok(()) => {
+                log!(
+                    LogType::Editor,
+                    LogLevel::OK,
+                    LogCategory::System,
+                    "{}",
+                    description
+                );
+            }
+            Err(e) => {
+                log!(
+                    LogType::Editor,
+                    LogLevel::Warning,
+                    LogCategory::System,
+                    "{} failed: {}",
+                    description,
+                    e
+                );
+            }
+        }
+    });
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -302,7 +347,7 @@ mod tests {
             history.execute(cmd, &mut world).unwrap();
         }
 
-        history.clear();
+        history.clear(&mut world);
         assert!(!history.can_undo());
         assert_eq!(history.undo_count(), 0);
     }
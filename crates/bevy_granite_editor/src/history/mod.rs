@@ -28,8 +28,11 @@ pub mod plugin;
 
 // Re-export main types for convenience
 pub use command::{CommandError, CommandResult, EditorCommand, StoredCommand};
-pub use commands::{EntityCreateCommand, EntityDeleteCommand, TransformCommand};
-pub use history::CommandHistory;
+pub use commands::{
+    BrushGeometryCommand, ClassDataCommand, EntityBatchCommand, EntityCreateCommand,
+    EntityDeleteCommand, TransformBatchCommand, TransformCommand,
+};
+pub use history::{execute_history_command, queue_history_command, CommandHistory};
 pub use plugin::CommandHistoryPlugin;
 
 #[cfg(test)]
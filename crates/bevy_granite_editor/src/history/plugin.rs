@@ -8,8 +8,10 @@ use bevy_granite_logging::{
 };
 
 use super::gizmo_integration::{
-    convert_gizmo_transform_events, process_pending_transform_commands,
-    record_user_transform_changes, PendingTransformCommands,
+    convert_gizmo_transform_events, process_pending_brush_edit_commands,
+    process_pending_class_edit_commands, process_pending_transform_commands,
+    record_brush_edit_requests, record_duplicated_entities, record_user_transform_changes,
+    PendingBrushEditCommands, PendingClassEditCommands, PendingTransformCommands,
 };
 use super::history::CommandHistory;
 
@@ -25,10 +27,18 @@ impl Plugin for CommandHistoryPlugin {
             .insert_resource(UndoRedoQueue::new())
             // Insert pending transform commands queue
             .insert_resource(PendingTransformCommands::new())
+            // Insert pending brush mesh edit queue
+            .insert_resource(PendingBrushEditCommands::new())
+            // Insert pending class data edit queue (viewport handles)
+            .insert_resource(PendingClassEditCommands::new())
             // Add systems in order
             .add_systems(Update, convert_gizmo_transform_events)
             .add_systems(Update, record_user_transform_changes)
             .add_systems(Update, process_pending_transform_commands)
+            .add_systems(Update, record_brush_edit_requests)
+            .add_systems(Update, process_pending_brush_edit_commands)
+            .add_systems(Update, process_pending_class_edit_commands)
+            .add_systems(Update, record_duplicated_entities)
             .add_systems(Update, queue_undo_redo_requests)
             .add_systems(Update, process_undo_redo_queue_exclusive);
     }
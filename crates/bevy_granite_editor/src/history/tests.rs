@@ -6,8 +6,8 @@
 #[cfg(test)]
 mod tests {
     use crate::history::{
-        CommandHistory, CommandResult, EditorCommand, EntityCreateCommand, EntityDeleteCommand,
-        TransformCommand,
+        CommandHistory, CommandResult, EditorCommand, EntityBatchCommand, EntityCreateCommand,
+        EntityDeleteCommand, TransformCommand,
     };
     use bevy::prelude::*;
 
@@ -214,7 +214,7 @@ mod tests {
         assert!(history.can_undo());
         assert!(history.can_redo());
 
-        history.clear();
+        history.clear(&mut world);
 
         assert!(!history.can_undo());
         assert_eq!(history.undo_count(), 0);
@@ -317,6 +317,41 @@ mod tests {
         history.redo(&mut world).unwrap();
     }
 
+    /// Test that a new action despawns entities only the dropped redo could bring back
+    #[test]
+    fn test_entity_batch_redo_cleared() {
+        let mut world = World::new();
+        let mut history = CommandHistory::new();
+
+        let original = world.spawn(Name::new("Brush")).id();
+        let piece = world.spawn(Name::new("Piece")).id();
+        let batch = EntityBatchCommand::new(vec![piece], vec![original], "Subtract".to_string());
+        history.execute(Box::new(batch), &mut world).unwrap();
+
+        history.undo(&mut world).unwrap();
+        assert!(world.get_entity(piece).is_ok());
+
+        let cmd = EntityCreateCommand::new(Name::new("Other"), Transform::default());
+        history.execute(Box::new(cmd), &mut world).unwrap();
+        assert!(world.get_entity(piece).is_err());
+        assert!(world.get_entity(original).is_ok());
+    }
+
+    /// Test that clearing the history despawns entities it kept disabled
+    #[test]
+    fn test_clear_despawns_disabled_entities() {
+        let mut world = World::new();
+        let mut history = CommandHistory::new();
+
+        let removed = world.spawn(Name::new("Brush")).id();
+        let batch = EntityBatchCommand::new(vec![], vec![removed], "Delete".to_string());
+        history.execute(Box::new(batch), &mut world).unwrap();
+        assert!(world.get_entity(removed).is_ok());
+
+        history.clear(&mut world);
+        assert!(world.get_entity(removed).is_err());
+    }
+
     /// Test error handling - undo with empty history
     #[test]
     fn test_undo_empty_history() {
@@ -1,5 +1,5 @@
 pub mod shortcuts;
 pub mod plugin;
 
-pub use shortcuts::shortcuts_system;
+pub use shortcuts::{camera_bookmark_shortcuts_system, shortcuts_system};
 pub use plugin::InputPlugin;
\ No newline at end of file
@@ -1,5 +1,9 @@
-use super::shortcuts_system;
-use bevy::app::{App, Plugin, Update};
+use super::{camera_bookmark_shortcuts_system, shortcuts_system};
+use crate::setup::is_editor_active;
+use bevy::{
+    app::{App, Plugin, Update},
+    ecs::schedule::IntoScheduleConfigs,
+};
 
 pub struct InputPlugin;
 impl Plugin for InputPlugin {
@@ -15,6 +19,10 @@ impl Plugin for InputPlugin {
                     // allows editor/toggle
                     shortcuts_system,
                 ),
+            )
+            .add_systems(
+                Update,
+                camera_bookmark_shortcuts_system.run_if(is_editor_active),
             );
     }
 }
@@ -1,13 +1,15 @@
 use bevy::{
+    input::{keyboard::KeyCode, ButtonInput},
     math::Vec2,
-    prelude::{Children, Commands, Entity, Query, Res},
+    prelude::{Children, Commands, Entity, MessageWriter, Query, Res},
 };
+use bevy_egui::EguiContexts;
 use bevy_granite_core::{
     entities::SaveSettings,
     events::{RequestRedoEvent, RequestUndoEvent},
     RequestLoadEvent, RequestReloadEvent, RequestSaveEvent, UserInput,
 };
-use bevy_granite_gizmos::{selection::events::EntityEvents, Selected};
+use bevy_granite_gizmos::{selection::events::EntityEvents, NumericDragInput, Selected};
 use bevy_granite_logging::{log, LogCategory, LogLevel, LogType};
 use native_dialog::FileDialog;
 
@@ -15,14 +17,27 @@ use crate::{
     editor_state::EditorState,
     interface::{
         events::{
-            PopupMenuRequestedEvent, RequestCameraEntityFrame, RequestEditorToggle,
-            RequestToggleCameraSync,
+            PopupMenuRequestedEvent, RequestCameraBookmark, RequestCameraEntityFrame,
+            RequestEditorToggle, RequestToggleCameraSync,
         },
         popups::PopupType,
         EditorEvents,
     },
+    viewport::CameraBookmarkAction,
 };
 
+const BOOKMARK_KEYS: [KeyCode; 9] = [
+    KeyCode::Digit1,
+    KeyCode::Digit2,
+    KeyCode::Digit3,
+    KeyCode::Digit4,
+    KeyCode::Digit5,
+    KeyCode::Digit6,
+    KeyCode::Digit7,
+    KeyCode::Digit8,
+    KeyCode::Digit9,
+];
+
 pub fn shortcuts_system(
     mut commands: Commands,
     input: Res<UserInput>,
@@ -250,6 +265,26 @@ fn handle_shortcuts(
         });
     }
 
+    // Shft-S
+    // Select by query / selection sets
+    if input.shift_left.pressed
+        && !input.ctrl_left.pressed
+        && input.key_s.just_pressed
+        && !input.mouse_over_egui
+        && !input.mouse_right.any
+    {
+        log!(
+            LogType::Editor,
+            LogLevel::Info,
+            LogCategory::Input,
+            "(shortcut) Opening Select By popup"
+        );
+        events.popup.write(PopupMenuRequestedEvent {
+            popup: PopupType::SelectBy,
+            mouse_pos: input.mouse_pos,
+        });
+    }
+
     // Ctrl+Z
     // Undo
     if input.ctrl_left.pressed && input.key_z.just_pressed && !input.mouse_right.any {
@@ -278,3 +313,43 @@ fn handle_shortcuts(
         commands.write_message(RequestRedoEvent);
     }
 }
+
+// 1-9 recall a camera bookmark, Ctrl + 1-9 saves the current view into that slot
+pub fn camera_bookmark_shortcuts_system(
+    keys: Res<ButtonInput<KeyCode>>,
+    input: Res<UserInput>,
+    numeric_input: Res<NumericDragInput>,
+    mut contexts: EguiContexts,
+    mut bookmark_writer: MessageWriter<RequestCameraBookmark>,
+) {
+    // Digits belong to text fields and typed gizmo values first
+    let egui_wants_keys = contexts
+        .ctx_mut()
+        .map(|ctx| ctx.wants_keyboard_input())
+        .unwrap_or(false);
+    if egui_wants_keys || numeric_input.is_typing() || input.mouse_left.any || input.mouse_right.any
+    {
+        return;
+    }
+
+    for (index, key) in BOOKMARK_KEYS.iter().enumerate() {
+        if !keys.just_pressed(*key) {
+            continue;
+        }
+        let slot = index + 1;
+        let action = if input.ctrl_left.pressed {
+            CameraBookmarkAction::Save
+        } else {
+            CameraBookmarkAction::Recall
+        };
+        log!(
+            LogType::Editor,
+            LogLevel::Info,
+            LogCategory::Input,
+            "(shortcut) Camera bookmark {} {:?}",
+            slot,
+            action
+        );
+        bookmark_writer.write(RequestCameraBookmark { slot, action });
+    }
+}
@@ -1,7 +1,9 @@
+use crate::entities::{AlignEdge, AlignTarget, ArrangeAxis, MirrorSpace};
 use crate::interface::popups::PopupType;
 use crate::interface::tabs::entity_editor::{
     EntityGlobalTransformData, EntityIdentityData, EntityRegisteredData,
 };
+use crate::viewport::CameraBookmarkAction;
 use bevy::ecs::message::MessageWriter;
 use bevy::ecs::system::SystemParam;
 use bevy::prelude::{Entity, Message, Vec2};
@@ -9,6 +11,10 @@ use bevy_granite_core::RequestDespawnBySource;
 use bevy_granite_core::RequestDespawnSerializableEntities;
 use bevy_granite_core::{EditableMaterial, GraniteTypes};
 use bevy_granite_core::{RequestLoadEvent, RequestReloadEvent, RequestSaveEvent};
+use bevy_granite_gizmos::{
+    RequestArrayDuplicateEvent, RequestDeleteSelectionSetEvent, RequestSaveSelectionSetEvent,
+    RequestScatterEvent, RequestSelectByEvent,
+};
 
 #[derive(SystemParam)]
 pub struct EditorEvents<'w> {
@@ -27,6 +33,17 @@ pub struct EditorEvents<'w> {
     pub despawn_all: MessageWriter<'w, RequestDespawnSerializableEntities>,
     pub despawn_by_source: MessageWriter<'w, RequestDespawnBySource>,
     pub set_active_world: MessageWriter<'w, SetActiveWorld>,
+    pub select_by: MessageWriter<'w, RequestSelectByEvent>,
+    pub save_selection_set: MessageWriter<'w, RequestSaveSelectionSetEvent>,
+    pub delete_selection_set: MessageWriter<'w, RequestDeleteSelectionSetEvent>,
+    pub brush_subtract: MessageWriter<'w, RequestBrushSubtract>,
+    pub brush_merge: MessageWriter<'w, RequestBrushMerge>,
+    pub align: MessageWriter<'w, RequestAlignSelection>,
+    pub distribute: MessageWriter<'w, RequestDistributeSelection>,
+    pub mirror: MessageWriter<'w, RequestMirrorSelection>,
+    pub array: MessageWriter<'w, RequestArrayDuplicateEvent>,
+    pub scatter: MessageWriter<'w, RequestScatterEvent>,
+    pub camera_bookmark: MessageWriter<'w, RequestCameraBookmark>,
 }
 
 // Internal Events
@@ -78,6 +95,22 @@ pub struct MaterialHandleUpdateEvent {
 #[derive(Message)]
 pub struct MaterialDeleteEvent {
     pub path: String,
+    /// Material path users move to, None falls back to the None material
+    pub replacement: Option<String>,
+}
+
+/// Actions from the Materials tab, run outside the UI pass
+#[derive(Message, Clone, Debug, PartialEq)]
+pub enum RequestMaterialBrowserAction {
+    Duplicate(String),
+    /// Changes the friendly name only, the file keeps its path so scenes still find it
+    Rename {
+        path: String,
+        name: String,
+    },
+    Delete(String),
+    /// Assign to whatever entity is under the cursor in the viewport
+    AssignUnderCursor(String),
 }
 
 // User callable events
@@ -96,6 +129,13 @@ pub struct RequestViewportCameraOverride {
     pub camera: Option<Entity>,
 }
 
+/// Save, recall, rename or clear the editor camera bookmark in a 1-9 slot
+#[derive(Message, Clone, Debug, PartialEq)]
+pub struct RequestCameraBookmark {
+    pub slot: usize,
+    pub action: CameraBookmarkAction,
+}
+
 #[derive(Message)]
 pub struct RequestNewParent;
 
@@ -109,3 +149,32 @@ pub struct RequestRemoveParentsFromEntities {
 
 #[derive(Message)]
 pub struct RequestRemoveChildren;
+
+#[derive(Message)]
+pub struct RequestBrushSubtract;
+
+#[derive(Message)]
+pub struct RequestBrushMerge;
+
+/// Line the selection up on one axis, as one undo step
+#[derive(Message, Clone, Copy, Debug, PartialEq)]
+pub struct RequestAlignSelection {
+    pub axis: ArrangeAxis,
+    pub edge: AlignEdge,
+    pub target: AlignTarget,
+}
+
+/// Space the selection evenly along one axis, as one undo step
+#[derive(Message, Clone, Copy, Debug, PartialEq)]
+pub struct RequestDistributeSelection {
+    pub axis: ArrangeAxis,
+}
+
+/// Reflect the selection across the plane through the pivot facing an axis
+#[derive(Message, Clone, Copy, Debug, PartialEq)]
+pub struct RequestMirrorSelection {
+    pub axis: ArrangeAxis,
+    pub space: MirrorSpace,
+    /// Mirror copies and keep the originals
+    pub duplicate: bool,
+}
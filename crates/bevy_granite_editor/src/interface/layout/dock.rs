@@ -9,7 +9,10 @@ use crate::{
         },
         EditorEvents, SettingsTab,
     },
-    viewport::{EditorViewportCamera, ViewportCameraState},
+    viewport::{
+        EditorViewportCamera, MeasureTool, ViewModeState, ViewportCameraState,
+        ViewportPaneCamera, ViewportPanes,
+    },
 };
 
 use bevy::{
@@ -65,17 +68,22 @@ pub fn dock_ui_system(
         Option<&EditorViewportCamera>,
         Option<&UICamera>,
         Option<&GizmoCamera>,
+        Option<&ViewportPaneCamera>,
     )>,
     viewport_camera_state: Res<ViewportCameraState>,
+    mut viewport_panes: ResMut<ViewportPanes>,
+    mut view_mode: ResMut<ViewModeState>,
+    mut measure: ResMut<MeasureTool>,
 ) {
     let mut camera_options: Vec<(Entity, String)> = camera_query
         .iter()
         .filter_map(
-            |(entity, name, camera, camera3d, editor_camera, ui_camera, gizmo_camera)| {
+            |(entity, name, camera, camera3d, editor, ui_camera, gizmo, pane)| {
                 if camera3d.is_some()
-                    && editor_camera.is_none()
+                    && editor.is_none()
                     && ui_camera.is_none()
-                    && gizmo_camera.is_none()
+                    && gizmo.is_none()
+                    && pane.is_none()
                     && matches!(camera.target, RenderTarget::Window(_))
                 {
                     let label = name
@@ -115,6 +123,9 @@ pub fn dock_ui_system(
                     &mut commands,
                     &camera_options,
                     viewport_camera_state.as_ref(),
+                    &mut viewport_panes,
+                    &mut view_mode,
+                    &mut measure,
                 );
             });
         });
@@ -2,20 +2,26 @@ use crate::{
     editor_state::EditorState,
     interface::{
         events::{
-            PopupMenuRequestedEvent, RequestCameraEntityFrame, RequestEditorToggle,
-            RequestToggleCameraSync, RequestViewportCameraOverride, SetActiveWorld,
+            PopupMenuRequestedEvent, RequestBrushMerge, RequestBrushSubtract,
+            RequestCameraBookmark, RequestCameraEntityFrame, RequestEditorToggle,
+            RequestToggleCameraSync,
+            RequestViewportCameraOverride, SetActiveWorld,
         },
         panels::{
             bottom_panel::{BottomDockState, BottomTab}, right_panel::{SideDockState, SideTab}, BottomTabType, SideTabType
         },
         popups::PopupType,
+        shared::widgets::arrange_menu_ui,
         tabs::{
             debug::ui::DebugTabData, log::LogTabData, EditorSettingsTabData, EntityEditorTabData,
             EventsTabData,
         },
         EditorEvents, NodeTreeTabData,
     },
-    viewport::ViewportCameraState,
+    viewport::{
+        CameraBookmarkAction, MeasureTool, ViewMode, ViewModeState, ViewportCameraState,
+        ViewportLayout, ViewportPanes, CAMERA_BOOKMARK_SLOTS,
+    },
     UI_CONFIG,
 };
 use bevy::{ecs::{entity::Entity, system::Commands}, prelude::ResMut};
@@ -37,6 +43,9 @@ pub fn top_bar_ui(
     commands: &mut Commands,
     camera_options: &[(Entity, String)],
     viewport_camera_state: &ViewportCameraState,
+    viewport_panes: &mut ViewportPanes,
+    view_mode: &mut ViewModeState,
+    measure: &mut MeasureTool,
 ) {
     let active_camera_label = if viewport_camera_state.is_using_editor() {
         "Editor Camera".to_string()
@@ -206,6 +215,7 @@ pub fn top_bar_ui(
                     (BottomTabType::Log, "Log"),
                     (BottomTabType::Debug, "Debug"),
                     (BottomTabType::Events, "Events"),
+                    (BottomTabType::Materials, "Materials"),
                 ] {
                     let tab = bottom_dock.dock_state.find_tab_from(|tab| tab.get_type() == tab_type);
                     let mut show = tab.is_some();
@@ -224,6 +234,45 @@ pub fn top_bar_ui(
                     }
                 }
             });
+
+            ui.menu_button("Brush", |ui| {
+                if ui
+                    .button("Subtract Active From Selected")
+                    .on_hover_text("Carve the active brush out of the other selected brushes")
+                    .clicked()
+                {
+                    events.brush_subtract.write(RequestBrushSubtract);
+                    ui.close();
+                }
+                if ui
+                    .button("Merge Selected")
+                    .on_hover_text("Replace the selected brushes with one convex brush")
+                    .clicked()
+                {
+                    events.brush_merge.write(RequestBrushMerge);
+                    ui.close();
+                }
+            });
+
+            ui.menu_button("Arrange", |ui| {
+                if let Some(request) = arrange_menu_ui(ui) {
+                    request.send(events);
+                }
+                ui.separator();
+                if ui
+                    .button("Array / Scatter...")
+                    .on_hover_text(
+                        "Make stepped copies of the selection or scatter it over the active mesh",
+                    )
+                    .clicked()
+                {
+                    events.popup.write(PopupMenuRequestedEvent {
+                        popup: PopupType::ArrayScatter,
+                        mouse_pos: user_input.mouse_pos,
+                    });
+                    ui.close();
+                }
+            });
         });
 
         ui.separator();
@@ -245,6 +294,13 @@ pub fn top_bar_ui(
                 });
             }
             ui.separator();
+            if ui.button("Select By (Shft + S) ").clicked() {
+                events.popup.write(PopupMenuRequestedEvent {
+                    popup: PopupType::SelectBy,
+                    mouse_pos: user_input.mouse_pos,
+                });
+            }
+            ui.separator();
             if ui.button("Show Help (F1) ").clicked() {
                 events.popup.write(PopupMenuRequestedEvent {
                     popup: PopupType::Help,
@@ -292,6 +348,43 @@ pub fn top_bar_ui(
                 }
             });
 
+            ui.menu_button("Viewports", |ui| {
+                for layout in ViewportLayout::all() {
+                    let selected = viewport_panes.layout.layout == layout;
+                    if ui.selectable_label(selected, layout.label()).clicked() {
+                        viewport_panes.layout.layout = layout;
+                        ui.close();
+                    }
+                }
+            });
+
+            ui.menu_button(format!("View: {}", view_mode.mode.label()), |ui| {
+                for mode in ViewMode::all() {
+                    if ui
+                        .selectable_label(view_mode.mode == mode, mode.label())
+                        .on_hover_text(mode.description())
+                        .clicked()
+                    {
+                        view_mode.set_mode(mode);
+                        ui.close();
+                    }
+                }
+            });
+
+            ui.menu_button("Bookmarks", |ui| {
+                camera_bookmarks_menu_ui(ui, events, editor_state);
+            });
+
+            ui.separator();
+            if ui
+                .selectable_label(measure.active, "Measure")
+                .on_hover_text("Click points in the viewport to measure distance, angle or height")
+                .clicked()
+            {
+                let active = !measure.active;
+                measure.set_active(active);
+            }
+
             ui.separator();
             if ui.button("Frame Active (F) ").clicked() {
                 events.frame.write(RequestCameraEntityFrame);
@@ -306,3 +399,76 @@ pub fn top_bar_ui(
         ui.add_space(spacing);
     });
 }
+
+// Number keys recall, Ctrl + number saves. The menu does the same plus naming and clearing
+fn camera_bookmarks_menu_ui(
+    ui: &mut egui::Ui,
+    events: &mut EditorEvents,
+    editor_state: &EditorState,
+) {
+    for slot in 1..=CAMERA_BOOKMARK_SLOTS {
+        let bookmark = editor_state
+            .config
+            .camera_bookmarks
+            .iter()
+            .find(|bookmark| bookmark.slot == slot);
+
+        ui.horizontal(|ui| {
+            let Some(bookmark) = bookmark else {
+                ui.weak(format!("{}  (empty)", slot));
+                if ui.small_button("Save View").clicked() {
+                    events.camera_bookmark.write(RequestCameraBookmark {
+                        slot,
+                        action: CameraBookmarkAction::Save,
+                    });
+                    ui.close();
+                }
+                return;
+            };
+
+            if ui
+                .button(format!("{}", slot))
+                .on_hover_text("Go to this bookmark")
+                .clicked()
+            {
+                events.camera_bookmark.write(RequestCameraBookmark {
+                    slot,
+                    action: CameraBookmarkAction::Recall,
+                });
+                ui.close();
+            }
+
+            // Edited in egui memory so the config is only written once the name is done
+            let name_id = ui.id().with(("camera_bookmark_name", slot));
+            let mut name = ui
+                .data_mut(|data| data.get_temp::<String>(name_id))
+                .unwrap_or_else(|| bookmark.name.clone());
+            let response = ui.add(egui::TextEdit::singleline(&mut name).desired_width(120.));
+            if response.lost_focus() {
+                ui.data_mut(|data| data.remove::<String>(name_id));
+                if name != bookmark.name {
+                    events.camera_bookmark.write(RequestCameraBookmark {
+                        slot,
+                        action: CameraBookmarkAction::Rename(name),
+                    });
+                }
+            } else if response.has_focus() {
+                ui.data_mut(|data| data.insert_temp(name_id, name));
+            }
+
+            if ui.small_button("Update").on_hover_text("Save the current view here").clicked() {
+                events.camera_bookmark.write(RequestCameraBookmark {
+                    slot,
+                    action: CameraBookmarkAction::Save,
+                });
+                ui.close();
+            }
+            if ui.small_button("Clear").clicked() {
+                events.camera_bookmark.write(RequestCameraBookmark {
+                    slot,
+                    action: CameraBookmarkAction::Clear,
+                });
+            }
+        });
+    }
+}
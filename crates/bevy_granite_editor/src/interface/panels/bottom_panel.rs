@@ -4,7 +4,8 @@ use egui_dock::{DockState, NodeIndex, TabViewer};
 use serde::{Deserialize, Serialize};
 
 use crate::interface::tabs::{
-    debug_tab_ui, events_tab_ui, log_tab_ui, DebugTabData, EventsTabData, LogTabData,
+    debug_tab_ui, events_tab_ui, log_tab_ui, materials_tab_ui, DebugTabData, EventsTabData,
+    LogTabData, MaterialsTabData,
 };
 
 #[derive(Resource, Clone)]
@@ -24,6 +25,9 @@ impl Default for BottomDockState {
         let events_tab = BottomTab::Events {
             data: EventsTabData::default(),
         };
+        let materials_tab = BottomTab::Materials {
+            data: MaterialsTabData::default(),
+        };
 
         let mut dock_state = DockState::new(vec![debug_tab]);
 
@@ -31,7 +35,8 @@ impl Default for BottomDockState {
 
         let [_debug_node, remaining] =
             surface.split_right(NodeIndex::root(), 0.33, vec![events_tab]);
-        let [_events_node, _log_node] = surface.split_right(remaining, 0.5, vec![log_tab]);
+        let [_events_node, _log_node] =
+            surface.split_right(remaining, 0.5, vec![log_tab, materials_tab]);
 
         Self { dock_state, height: None }
     }
@@ -42,6 +47,7 @@ pub enum BottomTabType {
     Log,
     Debug,
     Events,
+    Materials,
 }
 
 #[derive(Clone, PartialEq, Serialize, Deserialize)]
@@ -58,6 +64,10 @@ pub enum BottomTab {
         #[serde(skip)]
         data: EventsTabData,
     },
+    Materials {
+        #[serde(skip)]
+        data: MaterialsTabData,
+    },
 }
 
 impl BottomTab {
@@ -66,6 +76,7 @@ impl BottomTab {
             BottomTab::Log { .. } => BottomTabType::Log,
             BottomTab::Debug { .. } => BottomTabType::Debug,
             BottomTab::Events { .. } => BottomTabType::Events,
+            BottomTab::Materials { .. } => BottomTabType::Materials,
         }
     }
 
@@ -80,6 +91,9 @@ impl BottomTab {
             BottomTabType::Events => BottomTab::Events {
                 data: Default::default(),
             },
+            BottomTabType::Materials => BottomTab::Materials {
+                data: Default::default(),
+            },
         }
     }
 }
@@ -94,6 +108,7 @@ impl TabViewer for BottomTabViewer {
             BottomTab::Log { data, .. } => log_tab_ui(ui, data),
             BottomTab::Debug { data, .. } => debug_tab_ui(ui, data),
             BottomTab::Events { data, .. } => events_tab_ui(ui, data),
+            BottomTab::Materials { data, .. } => materials_tab_ui(ui, data),
         }
     }
 
@@ -102,6 +117,7 @@ impl TabViewer for BottomTabViewer {
             BottomTab::Log { .. } => "Log".into(),
             BottomTab::Debug { .. } => "Debug".into(),
             BottomTab::Events { .. } => "Events".into(),
+            BottomTab::Materials { .. } => "Materials".into(),
         }
     }
 }
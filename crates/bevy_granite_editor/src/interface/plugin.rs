@@ -2,7 +2,10 @@ use super::{
     cache::update_entity_cache_system,
     events::{
         MaterialDeleteEvent, MaterialHandleUpdateEvent, PopupMenuRequestedEvent,
-        RequestCameraEntityFrame, RequestEditorToggle, RequestNewParent, RequestRemoveChildren,
+        RequestAlignSelection, RequestBrushMerge, RequestMaterialBrowserAction,
+        RequestDistributeSelection, RequestMirrorSelection,
+        RequestBrushSubtract, RequestCameraBookmark, RequestCameraEntityFrame, RequestEditorToggle,
+        RequestNewParent, RequestRemoveChildren,
         RequestRemoveParents, RequestToggleCameraSync, RequestViewportCameraOverride,
         SetActiveWorld, UserRequestGraniteTypeViaPopup, UserUpdatedComponentsEvent,
         UserUpdatedIdentityEvent, UserUpdatedTransformEvent,
@@ -10,11 +13,16 @@ use super::{
     layout::dock_ui_system,
     popups::{handle_popup_requests_system, show_active_popups_system},
     tabs::{
-        handle_material_deletion_system, send_queued_events_system, update_debug_tab_ui_system,
+        assign_dropped_material_system, handle_material_browser_requests_system,
+        handle_material_deletion_system, send_queued_events_system,
+        sync_material_file_changes_system, update_debug_tab_ui_system,
         update_editor_settings_tab_system, update_entity_editor_tab_system,
         update_entity_with_new_components_system, update_entity_with_new_identity_system,
         update_entity_with_new_transform_system, update_log_tab_system,
-        update_material_handle_system, update_node_tree_tabs_system, RequestReparentEntityEvent,
+        update_material_handle_system, update_material_previews_system,
+        update_material_usage_index_system, MaterialUsageIndex,
+        update_materials_tab_system, update_node_tree_tabs_system, MaterialPreviews,
+        RequestReparentEntityEvent,
     },
     BottomDockState, EntityUIDataCache, PopupState, SideDockState,
 };
@@ -46,8 +54,15 @@ impl Plugin for InterfacePlugin {
             .add_message::<RequestNewParent>()
             .add_message::<RequestRemoveChildren>()
             .add_message::<RequestRemoveParents>()
+            .add_message::<RequestBrushSubtract>()
+            .add_message::<RequestBrushMerge>()
+            .add_message::<RequestAlignSelection>()
+            .add_message::<RequestDistributeSelection>()
+            .add_message::<RequestMirrorSelection>()
             .add_message::<SetActiveWorld>()
             .add_message::<RequestViewportCameraOverride>()
+            .add_message::<RequestCameraBookmark>()
+            .add_message::<RequestMaterialBrowserAction>()
             // need to rework
             .add_message::<RequestReparentEntityEvent>()
             .add_message::<RequestRemoveParentsFromEntities>()
@@ -64,6 +79,8 @@ impl Plugin for InterfacePlugin {
             .insert_resource(PopupState::default())
             .insert_resource(SideDockState::default())
             .insert_resource(BottomDockState::default())
+            .insert_resource(MaterialPreviews::default())
+            .insert_resource(MaterialUsageIndex::default())
             //
             // Schedule systems
             //
@@ -80,7 +97,10 @@ impl Plugin for InterfacePlugin {
                     // Actual entity updates from UI changes
                     //
                     update_entity_cache_system,
+                    sync_material_file_changes_system,
                     update_material_handle_system,
+                    handle_material_browser_requests_system,
+                    assign_dropped_material_system,
                     handle_material_deletion_system,
                     //
                     // Layout and Popups
@@ -90,10 +110,12 @@ impl Plugin for InterfacePlugin {
                     // Interface tabs UI
                     //
                     update_node_tree_tabs_system,
+                    update_material_usage_index_system,
                     update_entity_editor_tab_system,
                     update_editor_settings_tab_system,
                     update_log_tab_system,
                     update_debug_tab_ui_system,
+                    update_materials_tab_system,
                     update_node_tree_tabs_system,
                 )
                     .chain()
@@ -103,7 +125,8 @@ impl Plugin for InterfacePlugin {
                 EguiPrimaryContextPass,
                 (show_active_popups_system, dock_ui_system).run_if(is_editor_active),
             )
-            .add_systems(Update, send_queued_events_system.run_if(is_editor_active));
+            .add_systems(Update, send_queued_events_system.run_if(is_editor_active))
+            .add_systems(Update, update_material_previews_system.run_if(is_editor_active));
     }
 }
 
@@ -92,6 +92,22 @@ pub fn add_entity_ui(
                             if let GraniteTypes::Unknown(_) = *entity_type {
                                 continue;
                             }
+
+                            // Classes with presets offer one button per preset instead
+                            let presets = entity_type.creation_presets();
+                            if !presets.is_empty() {
+                                for (label, preset) in presets {
+                                    if ui.button(label).clicked() {
+                                        entity_add_request.write(UserRequestGraniteTypeViaPopup {
+                                            class: preset,
+                                        });
+                                        should_close = true;
+                                    }
+                                    ui.add_space(small_spacing);
+                                }
+                                continue;
+                            }
+
                             if ui.button(entity_type.type_name()).clicked() {
                                 entity_add_request.write(UserRequestGraniteTypeViaPopup {
                                     class: entity_type.clone(),
@@ -0,0 +1,249 @@
+use crate::{
+    interface::{shared::widgets::make_frame_solid_via_context, EditorEvents},
+    UI_CONFIG,
+};
+use bevy::prelude::{Vec2, Vec3};
+use bevy_egui::{
+    egui::{self, Window},
+    EguiContexts,
+};
+use bevy_granite_gizmos::{ArrayLayout, RequestArrayDuplicateEvent, RequestScatterEvent};
+
+#[derive(Clone, Copy, PartialEq, Default)]
+enum ArrayScatterMode {
+    #[default]
+    Linear,
+    Radial,
+    Scatter,
+}
+
+impl ArrayScatterMode {
+    fn all() -> [Self; 3] {
+        [Self::Linear, Self::Radial, Self::Scatter]
+    }
+
+    fn label(&self) -> &'static str {
+        match self {
+            Self::Linear => "Linear Array",
+            Self::Radial => "Radial Array",
+            Self::Scatter => "Scatter",
+        }
+    }
+}
+
+// Popup state lives in egui memory so settings survive between uses
+#[derive(Clone)]
+struct ArrayScatterPopupState {
+    mode: ArrayScatterMode,
+    count: u32,
+    offset: Vec3,
+    rotation: Vec3,
+    scale: Vec3,
+    pivot: Vec3,
+    axis: Vec3,
+    step_degrees: f32,
+    density: f32,
+    max_count: u32,
+    align_to_normal: bool,
+    rotation_range: Vec3,
+    scale_range: (f32, f32),
+    seed: u64,
+}
+
+impl Default for ArrayScatterPopupState {
+    fn default() -> Self {
+        Self {
+            mode: ArrayScatterMode::default(),
+            count: 4,
+            offset: Vec3::new(2., 0., 0.),
+            rotation: Vec3::ZERO,
+            scale: Vec3::ONE,
+            pivot: Vec3::ZERO,
+            axis: Vec3::Y,
+            step_degrees: 90.,
+            density: 1.,
+            max_count: 1000,
+            align_to_normal: true,
+            rotation_range: Vec3::new(0., 180., 0.),
+            scale_range: (0.8, 1.2),
+            seed: 0,
+        }
+    }
+}
+
+fn vec3_row(ui: &mut egui::Ui, label: &str, value: &mut Vec3, speed: f64) {
+    ui.label(label);
+    ui.add(egui::DragValue::new(&mut value.x).speed(speed).prefix("x "));
+    ui.add(egui::DragValue::new(&mut value.y).speed(speed).prefix("y "));
+    ui.add(egui::DragValue::new(&mut value.z).speed(speed).prefix("z "));
+    ui.end_row();
+}
+
+pub fn array_scatter_ui(
+    contexts: &mut EguiContexts,
+    position: Vec2,
+    events: &mut EditorEvents,
+    active_position: Option<Vec3>,
+) -> bool {
+    let mut should_close = false;
+
+    let spacing = UI_CONFIG.spacing;
+    let _response = Window::new("Array / Scatter")
+        .resizable(false)
+        .title_bar(false)
+        .fixed_pos([position.x, position.y])
+        // call this to ensure the window is not transparent when theme transparency is selected
+        .frame(make_frame_solid_via_context(
+            egui::Frame::window(&contexts.ctx_mut().expect("Egui context to exist").style()),
+            contexts.ctx_mut().expect("Egui context to exist"),
+        ))
+        .show(contexts.ctx_mut().expect("Egui context to exist"), |ui| {
+            let state_id = egui::Id::new("array_scatter_popup_state");
+            let mut state = ui.memory(|mem| {
+                mem.data
+                    .get_temp::<ArrayScatterPopupState>(state_id)
+                    .unwrap_or_default()
+            });
+
+            ui.vertical(|ui| {
+                ui.set_max_width(320.);
+                ui.horizontal(|ui| {
+                    for mode in ArrayScatterMode::all() {
+                        ui.selectable_value(&mut state.mode, mode, mode.label());
+                    }
+                });
+                ui.add_space(spacing);
+
+                match state.mode {
+                    ArrayScatterMode::Linear | ArrayScatterMode::Radial => {
+                        egui::Grid::new("array_settings_grid")
+                            .num_columns(4)
+                            .spacing([4.0, 2.0])
+                            .show(ui, |ui| {
+                                ui.label("Copies");
+                                ui.add(egui::DragValue::new(&mut state.count).range(1..=1000));
+                                ui.end_row();
+                                vec3_row(ui, "Offset", &mut state.offset, 0.05);
+                                vec3_row(ui, "Rotation °", &mut state.rotation, 1.0);
+                                vec3_row(ui, "Scale ×", &mut state.scale, 0.01);
+                                if state.mode == ArrayScatterMode::Radial {
+                                    vec3_row(ui, "Pivot", &mut state.pivot, 0.05);
+                                    vec3_row(ui, "Axis", &mut state.axis, 0.01);
+                                    ui.label("Step °");
+                                    ui.add(
+                                        egui::DragValue::new(&mut state.step_degrees).speed(1.0),
+                                    );
+                                    if ui
+                                        .button("Fill 360°")
+                                        .on_hover_text(
+                                            "Spread the original and copies around a full circle",
+                                        )
+                                        .clicked()
+                                    {
+                                        state.step_degrees = 360. / (state.count + 1) as f32;
+                                    }
+                                    ui.end_row();
+                                }
+                            });
+                        if state.mode == ArrayScatterMode::Radial {
+                            if let Some(active_position) = active_position {
+                                if ui.button("Pivot At Active").clicked() {
+                                    state.pivot = active_position;
+                                }
+                            }
+                        }
+                        ui.add_space(spacing);
+
+                        if ui.button("Create Copies").clicked() {
+                            let layout = match state.mode {
+                                ArrayScatterMode::Radial => ArrayLayout::Radial {
+                                    pivot: state.pivot,
+                                    axis: state.axis,
+                                    step_degrees: state.step_degrees,
+                                },
+                                _ => ArrayLayout::Linear,
+                            };
+                            events.array.write(RequestArrayDuplicateEvent {
+                                count: state.count,
+                                layout,
+                                offset: state.offset,
+                                rotation: state.rotation,
+                                scale: state.scale,
+                            });
+                            should_close = true;
+                        }
+                    }
+                    ArrayScatterMode::Scatter => {
+                        ui.weak("Copies the selection onto the active entity's surface");
+                        ui.add_space(spacing);
+                        egui::Grid::new("scatter_settings_grid")
+                            .num_columns(4)
+                            .spacing([4.0, 2.0])
+                            .show(ui, |ui| {
+                                ui.label("Density");
+                                ui.add(
+                                    egui::DragValue::new(&mut state.density)
+                                        .speed(0.01)
+                                        .range(0.0..=1000.0)
+                                        .suffix(" / unit²"),
+                                );
+                                ui.end_row();
+                                ui.label("Max Copies");
+                                ui.add(
+                                    egui::DragValue::new(&mut state.max_count).range(1..=100000),
+                                );
+                                ui.end_row();
+                                vec3_row(ui, "Random Rot ±°", &mut state.rotation_range, 1.0);
+                                ui.label("Scale");
+                                ui.add(
+                                    egui::DragValue::new(&mut state.scale_range.0)
+                                        .speed(0.01)
+                                        .prefix("min "),
+                                );
+                                ui.add(
+                                    egui::DragValue::new(&mut state.scale_range.1)
+                                        .speed(0.01)
+                                        .prefix("max "),
+                                );
+                                ui.end_row();
+                                ui.label("Seed");
+                                ui.add(egui::DragValue::new(&mut state.seed));
+                                if ui.button("New").clicked() {
+                                    state.seed = state
+                                        .seed
+                                        .wrapping_mul(6364136223846793005)
+                                        .wrapping_add(1442695040888963407);
+                                }
+                                ui.end_row();
+                            });
+                        ui.checkbox(&mut state.align_to_normal, "Align to surface normal");
+                        ui.add_space(spacing);
+
+                        if ui.button("Scatter").clicked() {
+                            events.scatter.write(RequestScatterEvent {
+                                density: state.density,
+                                max_count: state.max_count,
+                                align_to_normal: state.align_to_normal,
+                                rotation_range: state.rotation_range,
+                                scale_range: (
+                                    state.scale_range.0.min(state.scale_range.1),
+                                    state.scale_range.0.max(state.scale_range.1),
+                                ),
+                                seed: state.seed,
+                            });
+                            should_close = true;
+                        }
+                    }
+                }
+                ui.add_space(spacing);
+            });
+
+            ui.memory_mut(|mem| mem.data.insert_temp(state_id, state));
+        });
+
+    let ctx = contexts.ctx_mut().expect("Egui context to exist");
+    if ctx.input(|i| i.pointer.any_click()) && !ctx.is_pointer_over_area() {
+        should_close = true;
+    }
+    should_close
+}
@@ -1,9 +1,13 @@
 pub mod add_entity_ui;
+pub mod array_scatter_ui;
 pub mod relationship_ui;
 pub mod popup_requested_system;
 pub mod help_ui;
+pub mod select_by_ui;
 
 pub use add_entity_ui::*;
+pub use array_scatter_ui::*;
 pub use relationship_ui::*;
 pub use popup_requested_system::*;
-pub use help_ui::*;
\ No newline at end of file
+pub use help_ui::*;
+pub use select_by_ui::*;
\ No newline at end of file
@@ -2,13 +2,15 @@ use bevy::{
     ecs::{
         message::{MessageReader, MessageWriter},
         query::With,
-        system::{Query, ResMut},
+        system::{Query, Res, ResMut},
     },
     math::Vec2,
-    prelude::Resource,
+    prelude::{GlobalTransform, Resource},
     window::{PrimaryWindow, Window},
 };
 use bevy_egui::EguiContexts;
+use bevy_granite_core::{AvailableEditableMaterials, RegisteredTypeNames, SceneSelectionSets};
+use bevy_granite_gizmos::ActiveSelection;
 use bevy_granite_logging::{
     config::{LogCategory, LogLevel, LogType},
     log,
@@ -17,7 +19,7 @@ use bevy_granite_logging::{
 use crate::{
     editor_state::EditorState,
     interface::{
-        popups::{add_entity_ui, help_ui, relationship_ui},
+        popups::{add_entity_ui, array_scatter_ui, help_ui, relationship_ui, select_by_ui},
         EditorEvents, PopupMenuRequestedEvent, UserRequestGraniteTypeViaPopup,
     },
 };
@@ -27,6 +29,8 @@ pub enum PopupType {
     AddRelationship,
     AddEntity,
     Help,
+    SelectBy,
+    ArrayScatter,
 }
 
 #[derive(Default, Resource)]
@@ -55,10 +59,14 @@ pub fn handle_popup_requests_system(
 pub fn show_active_popups_system(
     mut contexts: EguiContexts,
     mut popup_state: ResMut<PopupState>,
-    events: EditorEvents,
+    mut events: EditorEvents,
     entity_add_writer: MessageWriter<UserRequestGraniteTypeViaPopup>,
     window_query: Query<&Window, With<PrimaryWindow>>,
     editor_state: ResMut<EditorState>,
+    available_materials: Res<AvailableEditableMaterials>,
+    type_names: Res<RegisteredTypeNames>,
+    selection_sets: Res<SceneSelectionSets>,
+    active_selection: Query<&GlobalTransform, With<ActiveSelection>>,
 ) {
     if let Some(popup_type) = &popup_state.active_popup {
         let should_close = match popup_type {
@@ -68,6 +76,29 @@ pub fn show_active_popups_system(
             PopupType::AddRelationship => {
                 relationship_ui(&mut contexts, popup_state.popup_position, events)
             }
+            PopupType::SelectBy => {
+                let mut loaded_sources: Vec<String> =
+                    editor_state.loaded_sources.iter().cloned().collect();
+                loaded_sources.sort();
+                select_by_ui(
+                    &mut contexts,
+                    popup_state.popup_position,
+                    &mut events,
+                    loaded_sources,
+                    &available_materials,
+                    &type_names,
+                    &selection_sets,
+                )
+            }
+            PopupType::ArrayScatter => array_scatter_ui(
+                &mut contexts,
+                popup_state.popup_position,
+                &mut events,
+                active_selection
+                    .single()
+                    .ok()
+                    .map(|global| global.translation()),
+            ),
             PopupType::Help => {
                 if let Ok(window) = window_query.single() {
                     help_ui(&mut contexts, window, editor_state)
@@ -0,0 +1,257 @@
+use crate::{
+    interface::{shared::widgets::make_frame_solid_via_context, EditorEvents},
+    UI_CONFIG,
+};
+use bevy::prelude::Vec2;
+use bevy_egui::{
+    egui::{self, Window},
+    EguiContexts,
+};
+use bevy_granite_core::{
+    AvailableEditableMaterials, GraniteType, GraniteTypes, RegisteredTypeNames, SceneSelectionSets,
+};
+use bevy_granite_gizmos::{
+    RequestDeleteSelectionSetEvent, RequestSaveSelectionSetEvent, RequestSelectByEvent,
+    SelectionQuery,
+};
+
+#[derive(Clone, Copy, PartialEq, Default)]
+enum SelectByMode {
+    #[default]
+    Class,
+    Component,
+    Material,
+    Source,
+    Name,
+}
+
+impl SelectByMode {
+    fn all() -> [Self; 5] {
+        [
+            Self::Class,
+            Self::Component,
+            Self::Material,
+            Self::Source,
+            Self::Name,
+        ]
+    }
+
+    fn label(&self) -> &'static str {
+        match self {
+            Self::Class => "Class",
+            Self::Component => "Component",
+            Self::Material => "Material",
+            Self::Source => "Spawn Source",
+            Self::Name => "Name Pattern",
+        }
+    }
+}
+
+// Popup state lives in egui memory so it survives between frames like the add entity category
+#[derive(Clone, Default)]
+struct SelectByPopupState {
+    mode: SelectByMode,
+    value: String,
+    additive: bool,
+    new_set_name: String,
+}
+
+pub fn select_by_ui(
+    contexts: &mut EguiContexts,
+    position: Vec2,
+    events: &mut EditorEvents,
+    loaded_sources: Vec<String>,
+    available_materials: &AvailableEditableMaterials,
+    type_names: &RegisteredTypeNames,
+    selection_sets: &SceneSelectionSets,
+) -> bool {
+    let mut should_close = false;
+
+    let spacing = UI_CONFIG.spacing;
+    let large_spacing = UI_CONFIG.large_spacing;
+    let _response = Window::new("Select By")
+        .resizable(false)
+        .title_bar(false)
+        .fixed_pos([position.x, position.y])
+        // call this to ensure the window is not transparent when theme transparency is selected
+        .frame(make_frame_solid_via_context(
+            egui::Frame::window(&contexts.ctx_mut().expect("Egui context to exist").style()),
+            contexts.ctx_mut().expect("Egui context to exist"),
+        ))
+        .show(contexts.ctx_mut().expect("Egui context to exist"), |ui| {
+            let state_id = egui::Id::new("select_by_popup_state");
+            let mut state = ui.memory(|mem| {
+                mem.data
+                    .get_temp::<SelectByPopupState>(state_id)
+                    .unwrap_or_default()
+            });
+
+            ui.vertical(|ui| {
+                ui.set_max_width(300.);
+                ui.label("Select By:");
+                ui.add_space(spacing);
+
+                egui::ComboBox::from_id_salt("select_by_mode")
+                    .selected_text(state.mode.label())
+                    .show_ui(ui, |ui| {
+                        for mode in SelectByMode::all() {
+                            if ui
+                                .selectable_value(&mut state.mode, mode, mode.label())
+                                .changed()
+                            {
+                                state.value.clear();
+                            }
+                        }
+                    });
+                ui.add_space(spacing);
+
+                // Value picker for the chosen mode
+                let options: Option<Vec<(String, String)>> = match state.mode {
+                    SelectByMode::Class => Some(
+                        GraniteTypes::all()
+                            .into_iter()
+                            .filter(|class| class.is_known())
+                            .map(|class| (class.type_name(), class.type_name()))
+                            .collect(),
+                    ),
+                    SelectByMode::Component => Some(
+                        type_names
+                            .names
+                            .iter()
+                            .map(|name| (name.to_string(), short_type_name(name)))
+                            .collect(),
+                    ),
+                    SelectByMode::Material => Some(
+                        available_materials
+                            .materials
+                            .iter()
+                            .flatten()
+                            .filter(|material| !material.path.is_empty())
+                            .map(|material| (material.path.clone(), material.path.clone()))
+                            .collect(),
+                    ),
+                    SelectByMode::Source => Some(
+                        loaded_sources
+                            .iter()
+                            .map(|source| (source.clone(), source.clone()))
+                            .collect(),
+                    ),
+                    SelectByMode::Name => None,
+                };
+
+                match options {
+                    Some(options) => {
+                        let selected_label = options
+                            .iter()
+                            .find(|(value, _)| *value == state.value)
+                            .map(|(_, label)| label.clone())
+                            .unwrap_or_else(|| "(choose)".to_string());
+                        egui::ComboBox::from_id_salt("select_by_value")
+                            .selected_text(selected_label)
+                            .show_ui(ui, |ui| {
+                                for (value, label) in options {
+                                    ui.selectable_value(&mut state.value, value, label);
+                                }
+                            });
+                    }
+                    None => {
+                        ui.add(
+                            egui::TextEdit::singleline(&mut state.value).hint_text("e.g. Fence*"),
+                        );
+                    }
+                }
+
+                ui.add_space(spacing);
+                ui.checkbox(&mut state.additive, "Add to selection");
+                ui.add_space(spacing);
+
+                let can_select = !state.value.is_empty();
+                if ui
+                    .add_enabled(can_select, egui::Button::new("Select"))
+                    .clicked()
+                {
+                    let value = state.value.clone();
+                    let query = match state.mode {
+                        SelectByMode::Class => SelectionQuery::Class(value),
+                        SelectByMode::Component => SelectionQuery::Component(value),
+                        SelectByMode::Material => SelectionQuery::MaterialPath(value),
+                        SelectByMode::Source => SelectionQuery::SpawnSource(value),
+                        SelectByMode::Name => SelectionQuery::NamePattern(value),
+                    };
+                    events.select_by.write(RequestSelectByEvent {
+                        query,
+                        additive: state.additive,
+                    });
+                    should_close = true;
+                }
+
+                ui.add_space(large_spacing);
+                ui.separator();
+                ui.add_space(spacing);
+
+                // Saved selection sets
+                ui.label("Selection Sets:");
+                ui.add_space(spacing);
+
+                let set_names = selection_sets.names();
+                if set_names.is_empty() {
+                    ui.weak("(No selection sets saved)");
+                }
+                for name in set_names {
+                    ui.horizontal(|ui| {
+                        if ui.button("Select").clicked() {
+                            events.select_by.write(RequestSelectByEvent {
+                                query: SelectionQuery::Set(name.clone()),
+                                additive: state.additive,
+                            });
+                            should_close = true;
+                        }
+                        if ui.button("Delete").clicked() {
+                            events
+                                .delete_selection_set
+                                .write(RequestDeleteSelectionSetEvent { name: name.clone() });
+                        }
+                        ui.label(&name);
+                    });
+                }
+
+                ui.add_space(spacing);
+                ui.horizontal(|ui| {
+                    ui.add(
+                        egui::TextEdit::singleline(&mut state.new_set_name)
+                            .desired_width(150.)
+                            .hint_text("New set name"),
+                    );
+                    let can_save = !state.new_set_name.trim().is_empty();
+                    if ui
+                        .add_enabled(can_save, egui::Button::new("Save Selection"))
+                        .clicked()
+                    {
+                        events
+                            .save_selection_set
+                            .write(RequestSaveSelectionSetEvent {
+                                name: state.new_set_name.trim().to_string(),
+                            });
+                        state.new_set_name.clear();
+                    }
+                });
+                ui.add_space(spacing);
+            });
+
+            ui.memory_mut(|mem| mem.data.insert_temp(state_id, state));
+        });
+
+    let ctx = contexts.ctx_mut().expect("Egui context to exist");
+    if ctx.input(|i| i.pointer.any_click()) && !ctx.is_pointer_over_area() {
+        should_close = true;
+    }
+    should_close
+}
+
+fn short_type_name(type_path: &str) -> String {
+    type_path
+        .rsplit("::")
+        .next()
+        .unwrap_or(type_path)
+        .to_string()
+}
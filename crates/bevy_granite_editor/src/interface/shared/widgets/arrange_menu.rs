@@ -0,0 +1,102 @@
+use crate::{
+    entities::{AlignEdge, AlignTarget, ArrangeAxis, MirrorSpace},
+    interface::{
+        events::{RequestAlignSelection, RequestDistributeSelection, RequestMirrorSelection},
+        EditorEvents,
+    },
+};
+use bevy_egui::egui;
+
+/// Picked from an arrange menu, sent once the menu closes
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub enum ArrangeRequest {
+    Align(RequestAlignSelection),
+    Distribute(RequestDistributeSelection),
+    Mirror(RequestMirrorSelection),
+}
+
+impl ArrangeRequest {
+    pub fn send(self, events: &mut EditorEvents) {
+        match self {
+            ArrangeRequest::Align(request) => {
+                events.align.write(request);
+            }
+            ArrangeRequest::Distribute(request) => {
+                events.distribute.write(request);
+            }
+            ArrangeRequest::Mirror(request) => {
+                events.mirror.write(request);
+            }
+        }
+    }
+}
+
+/// Align, distribute and mirror entries for the current selection
+/// Shared by the top bar and the entity context menu
+pub fn arrange_menu_ui(ui: &mut egui::Ui) -> Option<ArrangeRequest> {
+    let mut picked = None;
+
+    for (target, label) in [
+        (AlignTarget::Active, "Align To Active"),
+        (AlignTarget::SelectionBounds, "Align To Selection Bounds"),
+    ] {
+        ui.menu_button(label, |ui| {
+            for axis in ArrangeAxis::ALL {
+                ui.horizontal(|ui| {
+                    ui.label(axis.label());
+                    for edge in AlignEdge::ALL {
+                        if ui.button(edge.label()).clicked() {
+                            picked = Some(ArrangeRequest::Align(RequestAlignSelection {
+                                axis,
+                                edge,
+                                target,
+                            }));
+                        }
+                    }
+                });
+            }
+        });
+    }
+
+    ui.menu_button("Distribute", |ui| {
+        for axis in ArrangeAxis::ALL {
+            if ui
+                .button(format!("Along {}", axis.label()))
+                .on_hover_text("Even spacing between the two outermost entities")
+                .clicked()
+            {
+                picked = Some(ArrangeRequest::Distribute(RequestDistributeSelection {
+                    axis,
+                }));
+            }
+        }
+    });
+
+    for (duplicate, label) in [(false, "Mirror"), (true, "Mirror Duplicate")] {
+        ui.menu_button(label, |ui| {
+            for space in MirrorSpace::ALL {
+                ui.horizontal(|ui| {
+                    ui.label(space.label());
+                    for axis in ArrangeAxis::ALL {
+                        if ui
+                            .button(axis.label())
+                            .on_hover_text("Reflect across this axis through the active entity")
+                            .clicked()
+                        {
+                            picked = Some(ArrangeRequest::Mirror(RequestMirrorSelection {
+                                axis,
+                                space,
+                                duplicate,
+                            }));
+                        }
+                    }
+                });
+            }
+        });
+    }
+
+    if picked.is_some() {
+        ui.close();
+    }
+    picked
+}
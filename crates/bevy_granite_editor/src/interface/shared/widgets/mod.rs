@@ -1,5 +1,7 @@
+pub mod arrange_menu;
 pub mod combobox;
 pub mod solid_frame;
 
+pub use arrange_menu::*;
 pub use combobox::*;
 pub use solid_frame::*;
\ No newline at end of file
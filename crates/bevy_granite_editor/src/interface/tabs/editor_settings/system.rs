@@ -10,7 +10,7 @@ use crate::{
         themes::{SerializableTextStyle, ThemeState},
         EditorEvents, PopupMenuRequestedEvent, PopupType,
     },
-    viewport::{DebugRenderer, SelectionRenderer, ViewportState},
+    viewport::{CameraBookmark, DebugRenderer, SelectionRenderer, ViewportPanes, ViewportState},
 };
 
 use bevy::{gizmos::config::GizmoConfigStore, math::Vec2, prelude::ResMut};
@@ -53,6 +53,9 @@ pub struct EditorSettingsTabData {
     pub dock: DockState,
     pub show_help_on_start: bool,
 
+    #[serde(default)]
+    pub camera_bookmarks: Vec<CameraBookmark>,
+
     #[serde(skip)]
     pub save_requested: bool,
 }
@@ -73,6 +76,7 @@ impl Default for EditorSettingsTabData {
             },
             show_help_on_start: true,
             viewport: ViewportState::default(),
+            camera_bookmarks: Vec::new(),
         }
     }
 }
@@ -85,6 +89,7 @@ pub fn update_editor_settings_tab_system(
     mut gizmo_config_store: ResMut<GizmoConfigStore>,
     mut prompt_import_settings: ResMut<PromptImportSettings>,
     mut scene_light_state: ResMut<crate::viewport::SceneLightState>,
+    mut viewport_panes: ResMut<ViewportPanes>,
     mut events: EditorEvents,
 ) {
     let ctx = contexts.ctx_mut().expect("Egui context to exist");
@@ -93,7 +98,12 @@ pub fn update_editor_settings_tab_system(
     if editor_state.config_loaded && !editor_state.layout_loaded {
         let config = editor_state.config.clone();
         let dock_layout = config.dock.layout_str.clone();
-        load_dock_state(&dock_layout, &mut side_dock, &mut bottom_dock);
+        load_dock_state(
+            &dock_layout,
+            &mut side_dock,
+            &mut bottom_dock,
+            &mut viewport_panes,
+        );
 
         // Show help popup on start
         editor_state.layout_loaded = true;
@@ -166,12 +176,18 @@ pub fn update_editor_settings_tab_system(
                 debug_config.line.width = data.viewport.visualizers.debug_line_thickness;
             }
 
+            // Bookmarks are written straight to the config, don't save a stale copy over them
+            if data.camera_bookmarks != editor_state.config.camera_bookmarks {
+                data.camera_bookmarks = editor_state.config.camera_bookmarks.clone();
+            }
+
             if data.save_requested {
                 save_editor_settings_from_widget_data(
                     &mut editor_state,
                     data,
                     side_dock_clone,
                     bottom_dock.clone(),
+                    &viewport_panes,
                 );
                 data.save_requested = false;
             }
@@ -3,7 +3,7 @@ use crate::{
     interface::{
         layout::SidePanelPosition, tabs::editor_settings::ImportState, themes::ThemeState,
     },
-    viewport::ViewportState,
+    viewport::{GridPlane, ViewportState},
 };
 use bevy_egui::egui::{self, SliderClamping, UiBuilder};
 use bevy_granite_core::MaterialNameSource;
@@ -274,6 +274,14 @@ fn build_debug_gizmos_section(ui: &mut egui::Ui, viewport: &mut ViewportState) {
                         Some("Show lines between Parent/Child relationships"),
                     );
 
+                    ui.add_space(spacing);
+                    changed |= labeled_checkbox_columns(
+                        ui,
+                        "Shadow Cascades:",
+                        &mut vis.debug_shadow_cascades,
+                        Some("Show the main camera's shadow cascades for directional lights"),
+                    );
+
                     ui.add_space(spacing);
                     changed |= labeled_color_picker_columns(
                         ui,
@@ -520,7 +528,65 @@ fn build_grid_section(ui: &mut egui::Ui, viewport: &mut ViewportState) {
                         0.1,
                         1,
                         None,
-                        Some("Size of grid"),
+                        Some(
+                            "Size of a major grid cell. While move snapping is on the fine lines \
+                             follow the snap value instead",
+                        ),
+                    );
+
+                    ui.add_space(spacing);
+                    changed |= labeled_slider_columns(
+                        ui,
+                        "Subdivisions:",
+                        &mut viewport.grid_subdivisions,
+                        1..=10,
+                        1,
+                        0,
+                        None,
+                        Some("Fainter lines per major cell"),
+                    );
+
+                    ui.add_space(spacing);
+                    changed |= labeled_combo_columns(
+                        ui,
+                        "Plane:",
+                        &mut viewport.grid_plane,
+                        &GridPlane::all(),
+                        "grid_plane",
+                        Some("Which world plane the grid lies on"),
+                    );
+
+                    ui.add_space(spacing);
+                    ui.columns(2, |columns| {
+                        columns[0]
+                            .label("Origin:")
+                            .on_hover_text("Point the grid lines pass through");
+                        columns[1].horizontal(|ui| {
+                            for value in viewport.grid_origin.iter_mut() {
+                                changed |= ui
+                                    .add(egui::DragValue::new(value).speed(0.1).max_decimals(3))
+                                    .changed();
+                            }
+                        });
+                    });
+
+                    ui.add_space(spacing);
+                    changed |= labeled_checkbox_columns(
+                        ui,
+                        "Align To Ortho Views:",
+                        &mut viewport.grid_align_to_ortho,
+                        Some("Orthographic panes turn the grid to face the camera"),
+                    );
+
+                    ui.add_space(spacing);
+                    changed |= labeled_checkbox_columns(
+                        ui,
+                        "Snap New Entities:",
+                        &mut viewport.grid_snap_new_entities,
+                        Some(
+                            "Place entities from the add entity popup on the grid under the \
+                             camera target instead of the world origin",
+                        ),
                     );
 
                     ui.add_space(spacing);
@@ -2,6 +2,7 @@ use std::borrow::Cow;
 
 use super::widgets::{
     EntityGlobalTransformData, EntityIdentityData, EntityRegisteredData, MaterialTab,
+    MaterialUsageData, TextureImportData,
 };
 use bevy::prelude::Entity;
 use bevy_granite_core::{AvailableEditableMaterials, ComponentEditor, NewEditableMaterial};
@@ -25,6 +26,8 @@ pub struct EntityEditorTabData {
     pub component_search_filter: String,
     pub available_materials: AvailableEditableMaterials,
     pub material_delete_requested: bool,
+    pub material_usage: MaterialUsageData,
+    pub texture_imports: TextureImportData,
     pub init: bool, //FIX:, proper on init not bool
 }
 
@@ -48,6 +51,8 @@ impl Default for EntityEditorTabData {
             component_search_filter: String::new(),
             available_materials: Default::default(),
             material_delete_requested: false,
+            material_usage: Default::default(),
+            texture_imports: Default::default(),
             init: false,
         }
     }
@@ -491,10 +491,14 @@ fn handle_component_update(
 pub fn handle_material_deletion_system(
     mut material_delete_reader: MessageReader<MaterialDeleteEvent>,
     available_materials: Res<AvailableEditableMaterials>,
-    mut identity_query: Query<(Entity, &mut IdentityData)>,
-    mut commands: Commands,
+    mut identity_query: Query<(
+        Entity,
+        &mut IdentityData,
+        Option<&mut MeshMaterial3d<StandardMaterial>>,
+    )>,
+    mut request_writer: RequestEntityUpdateFromClass,
 ) {
-    for MaterialDeleteEvent { path } in material_delete_reader.read() {
+    for MaterialDeleteEvent { path, replacement } in material_delete_reader.read() {
         log!(
             LogType::Editor,
             LogLevel::Info,
@@ -525,23 +529,33 @@ pub fn handle_material_deletion_system(
             continue;
         };
 
-        for (entity, mut identity) in identity_query.iter_mut() {
+        let replacement_material = replacement
+            .as_deref()
+            .and_then(|replacement| available_materials.find_material_by_path(replacement));
+        let fallback = replacement_material.unwrap_or(&none_material);
+
+        for (entity, mut identity, standard_material) in identity_query.iter_mut() {
             if let Some(material_data) = identity.class.get_mut_material_data() {
                 if material_data.current.path == *path {
                     log!(
                         LogType::Editor,
                         LogLevel::Info,
                         LogCategory::Asset,
-                        "Resetting entity material from '{}' to 'None'",
-                        path
+                        "Resetting entity material from '{}' to '{}'",
+                        path,
+                        fallback.friendly_name
                     );
 
-                    *material_data.current = none_material.clone();
-                    *material_data.path = none_material.path.clone();
+                    *material_data.current = fallback.clone();
+                    *material_data.path = fallback.path.clone();
 
-                    commands
-                        .entity(entity)
-                        .insert(MeshMaterial3d::<StandardMaterial>::default());
+                    // Registered material kinds swap their own material once the class changes
+                    if let Some(mut standard_material) = standard_material {
+                        standard_material.0 = replacement_material
+                            .and_then(|material| material.handle.clone())
+                            .unwrap_or_default();
+                    }
+                    identity.class.push_to_entity(entity, &mut request_writer);
                 }
             }
         }
@@ -1,9 +1,16 @@
-use crate::interface::events::MaterialHandleUpdateEvent;
+use crate::interface::events::{MaterialDeleteEvent, MaterialHandleUpdateEvent};
 use bevy::{
-    ecs::{entity::Entity, message::MessageReader, system::Query},
-    prelude::ResMut,
+    ecs::{
+        entity::Entity,
+        message::{MessageReader, MessageWriter},
+        system::Query,
+    },
+    prelude::{Res, ResMut},
+};
+use bevy_granite_core::{
+    entities::GraniteType, AvailableEditableMaterials, EditableMaterial, IdentityData,
+    MaterialFileChange, MaterialFileChangedEvent,
 };
-use bevy_granite_core::{entities::GraniteType, AvailableEditableMaterials, IdentityData};
 use bevy_granite_logging::{
     config::{LogCategory, LogLevel, LogType},
     log,
@@ -53,3 +60,37 @@ pub fn update_material_handle_system(
         }
     }
 }
+
+/// Pushes materials the file watcher reloaded to every entity using them
+/// This also refreshes the material editor
+pub fn sync_material_file_changes_system(
+    mut material_file_reader: MessageReader<MaterialFileChangedEvent>,
+    available_materials: Res<AvailableEditableMaterials>,
+    mut material_handle_update_writer: MessageWriter<MaterialHandleUpdateEvent>,
+    mut material_delete_writer: MessageWriter<MaterialDeleteEvent>,
+) {
+    for MaterialFileChangedEvent { path, change } in material_file_reader.read() {
+        match change {
+            MaterialFileChange::Removed => {
+                material_delete_writer.write(MaterialDeleteEvent {
+                    path: path.clone(),
+                    replacement: None,
+                });
+            }
+            MaterialFileChange::Added | MaterialFileChange::Modified => {
+                let Some(material) = available_materials.find_material_by_path(path) else {
+                    continue;
+                };
+                material_handle_update_writer.write(MaterialHandleUpdateEvent {
+                    skip_entity: Entity::PLACEHOLDER,
+                    path: path.clone(),
+                    version: material.version,
+                    material: EditableMaterial {
+                        disk_changes: true,
+                        ..material.clone()
+                    },
+                });
+            }
+        }
+    }
+}
@@ -0,0 +1,102 @@
+use bevy::{
+    ecs::{
+        entity::Entity,
+        query::Changed,
+        system::{Query, ResMut},
+    },
+    prelude::{RemovedComponents, Resource},
+};
+use bevy_granite_core::{
+    collect_scene_files, entities::GraniteType, scene_material_references, IdentityData,
+    SpawnSource,
+};
+use std::collections::{HashMap, HashSet};
+
+/// Which entities use which material, keyed by the material path stored on the class
+#[derive(Resource, Default)]
+pub struct MaterialUsageIndex {
+    loaded: HashMap<String, Vec<Entity>>,
+    /// Scene files that are not loaded, with how many of their entities use the material
+    unloaded: HashMap<String, Vec<(String, usize)>>,
+    /// Also read every `.scene` under assets, off by default since big projects make this slow
+    pub scan_scene_files: bool,
+    scenes_dirty: bool,
+    loaded_sources: HashSet<String>,
+}
+
+impl MaterialUsageIndex {
+    pub fn users(&self, path: &str) -> &[Entity] {
+        self.loaded.get(path).map_or(&[], |users| users.as_slice())
+    }
+
+    pub fn unloaded_users(&self, path: &str) -> &[(String, usize)] {
+        self.unloaded
+            .get(path)
+            .map_or(&[], |users| users.as_slice())
+    }
+
+    /// Re-read scene files on the next update
+    pub fn rescan_scene_files(&mut self) {
+        self.scenes_dirty = true;
+    }
+}
+
+pub fn update_material_usage_index_system(
+    mut index: ResMut<MaterialUsageIndex>,
+    changed_query: Query<(), Changed<IdentityData>>,
+    mut removed: RemovedComponents<IdentityData>,
+    identity_query: Query<(Entity, &IdentityData, Option<&SpawnSource>)>,
+) {
+    let entities_changed = !changed_query.is_empty() || removed.read().count() > 0;
+    if !entities_changed && !(index.scan_scene_files && index.scenes_dirty) {
+        return;
+    }
+
+    if entities_changed {
+        let mut loaded: HashMap<String, Vec<Entity>> = HashMap::new();
+        let mut sources = HashSet::new();
+        for (entity, identity, source) in identity_query.iter() {
+            if let Some(source) = source {
+                sources.insert(source.str_ref().to_string());
+            }
+            if let Some(material) = identity.class.get_material_data() {
+                if !material.path.is_empty() {
+                    loaded
+                        .entry(material.path.clone())
+                        .or_default()
+                        .push(entity);
+                }
+            }
+        }
+        index.loaded = loaded;
+
+        // Loading or unloading a world moves its entities between the two halves
+        if sources != index.loaded_sources {
+            index.loaded_sources = sources;
+            index.scenes_dirty = true;
+        }
+    }
+
+    if !index.scan_scene_files {
+        index.unloaded.clear();
+        return;
+    }
+    if !index.scenes_dirty {
+        return;
+    }
+    index.scenes_dirty = false;
+
+    let mut unloaded: HashMap<String, Vec<(String, usize)>> = HashMap::new();
+    for scene in collect_scene_files() {
+        if index.loaded_sources.contains(&scene) {
+            continue;
+        }
+        for (material, count) in scene_material_references(&scene) {
+            unloaded
+                .entry(material)
+                .or_default()
+                .push((scene.clone(), count));
+        }
+    }
+    index.unloaded = unloaded;
+}
@@ -1,7 +1,9 @@
 pub mod entity_updates;
 pub mod material_sync;
+pub mod material_usage;
 pub mod tab_updates;
 
 pub use entity_updates::*;
 pub use material_sync::*;
+pub use material_usage::*;
 pub use tab_updates::*;
\ No newline at end of file
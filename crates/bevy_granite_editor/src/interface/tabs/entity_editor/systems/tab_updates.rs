@@ -18,9 +18,12 @@ use bevy::ecs::{
     system::{Query, Res, ResMut},
 };
 use bevy_granite_core::{
-    entities::GraniteType, AvailableEditableMaterials, ComponentEditor, RegisteredTypeNames,
+    entities::GraniteType, reassign_scene_material, AvailableEditableMaterials, ComponentEditor,
+    RegisteredTypeNames, RequestTextureReimport,
 };
-use bevy_granite_gizmos::ActiveSelection;
+use bevy_granite_gizmos::{ActiveSelection, RequestSelectByEvent, SelectionQuery};
+
+use super::MaterialUsageIndex;
 
 // Every frame we check the tab for staleness
 pub fn update_entity_editor_tab_system(
@@ -33,6 +36,9 @@ pub fn update_entity_editor_tab_system(
     mut transform_updated_writer: MessageWriter<UserUpdatedTransformEvent>,
     mut component_updated_writer: MessageWriter<UserUpdatedComponentsEvent>,
     mut material_delete_writer: MessageWriter<MaterialDeleteEvent>,
+    mut select_by_writer: MessageWriter<RequestSelectByEvent>,
+    mut usage_index: ResMut<MaterialUsageIndex>,
+    mut texture_reimport_writer: MessageWriter<RequestTextureReimport>,
     global_component_editor: ResMut<ComponentEditor>,
 ) {
     for (_, tab) in right_dock.dock_state.iter_all_tabs_mut() {
@@ -59,24 +65,76 @@ pub fn update_entity_editor_tab_system(
 
                     temp_material.delete_from_disk_and_memory(&mut available_materials);
 
-                    // Reset current material to "None" (index 0)
+                    let replacement = Some(data.material_usage.reassign_path.clone())
+                        .filter(|path| !path.is_empty());
+                    if let Some(replacement) = &replacement {
+                        if data.material_usage.reassign_scene_files {
+                            for (scene, _) in usage_index.unloaded_users(&current_path).to_vec() {
+                                reassign_scene_material(&scene, &current_path, replacement);
+                            }
+                            usage_index.rescan_scene_files();
+                        }
+                    }
+                    data.material_usage.reassign_path.clear();
+                    data.material_usage.reassign_scene_files = false;
+
+                    // Reset current material to the replacement, or "None" (index 0)
                     if let Some(materials) = &available_materials.materials {
                         if !materials.is_empty() {
+                            let fallback = replacement
+                                .as_deref()
+                                .and_then(|path| available_materials.find_material_by_path(path))
+                                .unwrap_or(&materials[0]);
                             if let Some(mat_data) =
                                 data.identity_data.class_data.get_mut_material_data()
                             {
-                                *mat_data.current = materials[0].clone();
-                                *mat_data.path = materials[0].path.clone();
+                                *mat_data.current = fallback.clone();
+                                *mat_data.path = fallback.path.clone();
                             }
 
                             // Send deletion event to notify other systems
-                            material_delete_writer
-                                .write(MaterialDeleteEvent { path: current_path });
+                            material_delete_writer.write(MaterialDeleteEvent {
+                                path: current_path,
+                                replacement,
+                            });
                         }
                     }
                 }
             }
 
+            // Usage of the open material, the index itself is kept by its own system
+            usage_index.scan_scene_files = data.material_usage.scan_scene_files;
+            if data.material_usage.rescan_requested {
+                data.material_usage.rescan_requested = false;
+                usage_index.rescan_scene_files();
+            }
+            let material_path = data
+                .identity_data
+                .class_data
+                .get_material_data()
+                .map(|material| material.path.clone())
+                .unwrap_or_default();
+            data.material_usage.loaded = usage_index.users(&material_path).len();
+            data.material_usage.unloaded = usage_index.unloaded_users(&material_path).to_vec();
+            if data.material_usage.select_users_requested {
+                data.material_usage.select_users_requested = false;
+                if !material_path.is_empty() {
+                    select_by_writer.write(RequestSelectByEvent {
+                        query: SelectionQuery::MaterialPath(material_path),
+                        additive: false,
+                    });
+                }
+            }
+
+            for path in std::mem::take(&mut data.texture_imports.reimport_requested) {
+                if let Some(settings) = data.texture_imports.edited.get(&path) {
+                    texture_reimport_writer.write(RequestTextureReimport {
+                        path,
+                        settings: settings.clone(),
+                    });
+                }
+            }
+
             let has_selected = cache.data.entity;
             let identity_data = &mut data.identity_data;
             let components_data = &mut data.registered_data;
@@ -94,6 +152,8 @@ pub fn update_entity_editor_tab_system(
                     *active = None;
                 }
                 data.last_selected_entity = has_selected;
+                // Sidecars may have changed on disk since they were read
+                data.texture_imports.clear();
             }
 
             if has_selected.is_some() {
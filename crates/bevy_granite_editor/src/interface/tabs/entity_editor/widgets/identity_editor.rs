@@ -7,8 +7,8 @@ use bevy_granite_logging::{
 
 use crate::interface::tabs::{
     entity_editor::widgets::material_editor::{
-        display_material_creation, display_material_edit, display_material_selector_field,
-        display_material_settings,
+        display_material_creation, display_material_delete_confirm, display_material_edit,
+        display_material_selector_field, display_material_settings, display_material_usage,
     },
     EntityEditorTabData,
 };
@@ -115,14 +115,36 @@ fn display_material_data(
             );
             changed = material_changed;
             
-            // Handle delete request
+            // Deleting waits for the confirm below, which shows who still uses it
             if delete_clicked && !current_material.is_empty() && current_material.friendly_name != "None" {
-                // Set a flag to indicate deletion was requested
-                // We'll handle this in the tab update system
-                tab_data.material_delete_requested = true;
+                tab_data.material_usage.confirm_delete = true;
+                tab_data.material_usage.reassign_path.clear();
+                tab_data.material_usage.reassign_scene_files = false;
             }
         });
 
+        if !current_material.is_empty() {
+            ui.add_space(spacing);
+            display_material_usage(ui, &mut tab_data.material_usage);
+        }
+
+        if tab_data.material_usage.confirm_delete {
+            ui.add_space(spacing);
+            let (confirmed, cancelled) = display_material_delete_confirm(
+                ui,
+                available_materials,
+                &current_material.path,
+                &mut tab_data.material_usage,
+            );
+            if confirmed {
+                // Handled in the tab update system
+                tab_data.material_delete_requested = true;
+            }
+            if confirmed || cancelled {
+                tab_data.material_usage.confirm_delete = false;
+            }
+        }
+
         ui.add_space(large_spacing);
         ui.vertical(|ui| {
             if *material_builder_open {
@@ -140,7 +162,11 @@ fn display_material_data(
                 .open(Some(surface_open))
                 .show(ui, |ui| {
                     ui.set_max_width(ui.available_width());
-                    edit_changes |= display_material_edit(ui, current_material);
+                    edit_changes |= display_material_edit(
+                        ui,
+                        current_material,
+                        &mut tab_data.texture_imports,
+                    );
                     if edit_changes {
                         current_material.disk_changes = edit_changes;
                         changed = true;
@@ -2,14 +2,17 @@ use crate::interface::shared::material_selector_combo;
 use bevy::pbr::StandardMaterial;
 use bevy_egui::egui;
 use bevy_granite_core::{
-    AvailableEditableMaterials, EditableMaterial, EditableMaterialField, NewEditableMaterial,
-    StandardMaterialDef,
+    material_kind_defaults, read_texture_import_settings, registered_material_kinds,
+    AvailableEditableMaterials, EditableMaterial, EditableMaterialField, MaterialParam,
+    MaterialParams, NewEditableMaterial, StandardMaterialDef, TextureAddressMode,
+    TextureFilterMode, TextureImportSettings,
 };
 use bevy_granite_logging::{
     config::{LogCategory, LogLevel, LogType},
     log,
 };
 use native_dialog::FileDialog;
+use std::collections::HashMap;
 
 pub fn display_add_material_field_dropdown(
     ui: &mut egui::Ui,
@@ -198,8 +201,10 @@ pub fn display_material_settings(ui: &mut egui::Ui, material: &mut EditableMater
 
                     ui.label("Path");
                     ui.label(material.path.to_string());
+                    ui.end_row();
 
                     changed = field_changed;
+                    changed |= display_material_kind_field(ui, def);
                 }
             });
 
@@ -208,7 +213,11 @@ pub fn display_material_settings(ui: &mut egui::Ui, material: &mut EditableMater
     changed
 }
 
-pub fn display_material_edit(ui: &mut egui::Ui, material: &mut EditableMaterial) -> bool {
+pub fn display_material_edit(
+    ui: &mut egui::Ui,
+    material: &mut EditableMaterial,
+    imports: &mut TextureImportData,
+) -> bool {
     let large_spacing = crate::UI_CONFIG.large_spacing;
     let small_spacing = crate::UI_CONFIG.small_spacing;
     let mut changed = false;
@@ -223,6 +232,7 @@ pub fn display_material_edit(ui: &mut egui::Ui, material: &mut EditableMaterial)
             .show(ui, |ui| {
                 // ui.spacing_mut().button_padding = egui::Vec2::new(2.0, 2.0);
                 if let Some(def) = &mut material.def {
+                    changed |= display_material_kind_params(ui, def);
                     if let Some(fields) = material.fields.as_mut() {
                         for field in fields.iter() {
                             changed |= display_standard_material_field(
@@ -230,6 +240,7 @@ pub fn display_material_edit(ui: &mut egui::Ui, material: &mut EditableMaterial)
                                 field,
                                 def,
                                 &StandardMaterial::default(),
+                                imports,
                             );
                         }
                     }
@@ -347,6 +358,274 @@ pub fn display_material_selector_field(
     (changed, delete_clicked)
 }
 
+/// Who uses the material open in the editor, filled from `MaterialUsageIndex`
+#[derive(Debug, Clone, Default, PartialEq)]
+pub struct MaterialUsageData {
+    /// Loaded entities using it
+    pub loaded: usize,
+    /// Scene files on disk that are not loaded, and how many of their entities use it
+    pub unloaded: Vec<(String, usize)>,
+    pub scan_scene_files: bool,
+    pub rescan_requested: bool,
+    pub select_users_requested: bool,
+    pub confirm_delete: bool,
+    /// Where users go on delete, empty for None
+    pub reassign_path: String,
+    pub reassign_scene_files: bool,
+}
+
+impl MaterialUsageData {
+    pub fn unloaded_count(&self) -> usize {
+        self.unloaded.iter().map(|(_, count)| count).sum()
+    }
+}
+
+pub fn display_material_usage(ui: &mut egui::Ui, usage: &mut MaterialUsageData) {
+    ui.horizontal(|ui| {
+        let label = if usage.unloaded.is_empty() {
+            format!("Used by {} entities", usage.loaded)
+        } else {
+            format!(
+                "Used by {} entities, {} more in {} other scenes",
+                usage.loaded,
+                usage.unloaded_count(),
+                usage.unloaded.len()
+            )
+        };
+        let response = ui.label(label);
+        if !usage.unloaded.is_empty() {
+            let scenes: Vec<String> = usage
+                .unloaded
+                .iter()
+                .map(|(scene, count)| format!("{} ({})", scene, count))
+                .collect();
+            response.on_hover_text(scenes.join("\n"));
+        }
+
+        ui.with_layout(egui::Layout::right_to_left(egui::Align::Center), |ui| {
+            if ui
+                .add_enabled(usage.loaded > 0, egui::Button::new("Select all"))
+                .on_hover_text("Select every loaded entity using this material")
+                .clicked()
+            {
+                usage.select_users_requested = true;
+            }
+        });
+    });
+    ui.horizontal(|ui| {
+        if ui
+            .checkbox(&mut usage.scan_scene_files, "Include scene files")
+            .on_hover_text("Also count entities in every .scene under assets that is not loaded")
+            .changed()
+            && usage.scan_scene_files
+        {
+            usage.rescan_requested = true;
+        }
+        if usage.scan_scene_files
+            && ui
+                .small_button("🔄")
+                .on_hover_text("Rescan scene files")
+                .clicked()
+        {
+            usage.rescan_requested = true;
+        }
+    });
+}
+
+/// Warns about users before a delete and picks where they go. Returns (confirmed, cancelled)
+pub fn display_material_delete_confirm(
+    ui: &mut egui::Ui,
+    available_materials: &AvailableEditableMaterials,
+    deleting_path: &str,
+    usage: &mut MaterialUsageData,
+) -> (bool, bool) {
+    let spacing = crate::UI_CONFIG.spacing;
+    let mut confirmed = false;
+    let mut cancelled = false;
+
+    ui.group(|ui| {
+        ui.set_width(ui.available_width());
+        ui.label(format!("Delete '{}' from disk?", deleting_path));
+        ui.add_space(spacing);
+
+        let unloaded = usage.unloaded_count();
+        if usage.loaded + unloaded == 0 {
+            ui.label("Nothing uses it.");
+        } else {
+            ui.colored_label(
+                ui.visuals().warn_fg_color,
+                format!(
+                    "{} loaded entities and {} in other scenes still use it",
+                    usage.loaded, unloaded
+                ),
+            );
+            ui.horizontal(|ui| {
+                ui.label("Reassign users to");
+                let selected = available_materials
+                    .find_material_by_path(&usage.reassign_path)
+                    .filter(|_| !usage.reassign_path.is_empty())
+                    .map_or("None".to_string(), |material| {
+                        material.friendly_name.clone()
+                    });
+                egui::ComboBox::from_id_salt("material_delete_reassign")
+                    .selected_text(selected)
+                    .show_ui(ui, |ui| {
+                        ui.selectable_value(&mut usage.reassign_path, String::new(), "None");
+                        for material in available_materials.materials.iter().flatten() {
+                            if material.is_empty() || material.path == deleting_path {
+                                continue;
+                            }
+                            ui.selectable_value(
+                                &mut usage.reassign_path,
+                                material.path.clone(),
+                                &material.friendly_name,
+                            )
+                            .on_hover_text(&material.path);
+                        }
+                    });
+            });
+            if unloaded > 0 {
+                ui.add_enabled(
+                    !usage.reassign_path.is_empty(),
+                    egui::Checkbox::new(
+                        &mut usage.reassign_scene_files,
+                        format!("Also rewrite {} scene files", usage.unloaded.len()),
+                    ),
+                );
+            }
+        }
+        if !usage.scan_scene_files {
+            ui.small("Scene files that are not loaded were not checked");
+        }
+
+        ui.add_space(spacing);
+        ui.horizontal(|ui| {
+            if ui.button("Delete").clicked() {
+                confirmed = true;
+            }
+            if ui.button("Cancel").clicked() {
+                cancelled = true;
+            }
+        });
+    });
+
+    (confirmed, cancelled)
+}
+
+// Only shown once the app registers a material kind, plain projects keep the standard editor
+fn display_material_kind_field(ui: &mut egui::Ui, def: &mut StandardMaterialDef) -> bool {
+    let kinds = registered_material_kinds();
+    if kinds.is_empty() && def.kind.is_none() {
+        return false;
+    }
+
+    let mut changed = false;
+    let selected = match def.kind.as_deref() {
+        Some(kind) if kinds.contains(&kind) => kind.to_string(),
+        Some(kind) => format!("{} (not registered)", kind),
+        None => "Standard".to_string(),
+    };
+
+    ui.label("Kind");
+    egui::ComboBox::from_id_salt("material_kind_dropdown")
+        .selected_text(selected)
+        .width(ui.available_width())
+        .show_ui(ui, |ui| {
+            if ui
+                .selectable_label(def.kind.is_none(), "Standard")
+                .clicked()
+                && def.kind.is_some()
+            {
+                def.kind = None;
+                def.params = None;
+                changed = true;
+            }
+            for kind in kinds {
+                let is_selected = def.kind.as_deref() == Some(kind);
+                if ui.selectable_label(is_selected, kind).clicked() && !is_selected {
+                    log!(
+                        LogType::Editor,
+                        LogLevel::Info,
+                        LogCategory::UI,
+                        "User set material kind: '{}'",
+                        kind
+                    );
+                    def.kind = Some(kind.to_string());
+                    def.params = Some(MaterialParams::new());
+                    changed = true;
+                }
+            }
+        });
+    ui.end_row();
+
+    changed
+}
+
+// Parameters come from reflecting the kind's default material, so any registered type works
+fn display_material_kind_params(ui: &mut egui::Ui, def: &mut StandardMaterialDef) -> bool {
+    let Some(kind) = def.kind.clone() else {
+        return false;
+    };
+    let defaults = material_kind_defaults(&kind).unwrap_or_default();
+    let params = def.params.get_or_insert_with(MaterialParams::new);
+    let mut changed = false;
+    let mut removed = None;
+
+    for (key, param) in params.iter_mut() {
+        ui.label(key);
+        changed |= display_material_param(ui, param);
+
+        ui.horizontal(|ui| {
+            ui.with_layout(egui::Layout::right_to_left(egui::Align::Center), |ui| {
+                if ui.button("❌").on_hover_text("Clear value").clicked() {
+                    removed = Some(key.clone());
+                }
+                if let Some(default) = defaults.get(key) {
+                    if ui.button("🔄").on_hover_text("Reset to default").clicked() {
+                        *param = default.clone();
+                        changed = true;
+                    }
+                }
+            });
+        });
+        ui.end_row();
+    }
+
+    if let Some(key) = removed {
+        log!(
+            LogType::Editor,
+            LogLevel::Info,
+            LogCategory::UI,
+            "User Removed: {:?}",
+            key
+        );
+        params.remove(&key);
+        changed = true;
+    }
+
+    let available: Vec<_> = defaults
+        .iter()
+        .filter(|(key, _)| !params.contains_key(*key))
+        .collect();
+    if !available.is_empty() {
+        let width = ui.available_width();
+        egui::ComboBox::from_id_salt("add_material_param_dropdown")
+            .selected_text("Add parameter...")
+            .width(width)
+            .show_ui(ui, |ui| {
+                for (key, param) in available {
+                    if ui.button(key).clicked() {
+                        params.insert(key.clone(), param.clone());
+                        changed = true;
+                    }
+                }
+            });
+        ui.end_row();
+    }
+
+    changed
+}
+
 // -------------------------------------------------------------------------------------------------------------
 // Material Input Types
 // -------------------------------------------------------------------------------------------------------------
@@ -633,30 +912,8 @@ fn display_text_field(
 
                 ui.spacing_mut().button_padding = egui::Vec2::new(2.0, 2.0);
                 if ui.button("📁").clicked() {
-                    let current_dir = std::env::current_dir().unwrap();
-                    let assets_dir = current_dir.join("assets");
-                    let tex_path = assets_dir.join("textures");
-
-                    // Use textures dir if it exists or can be created, otherwise use current dir
-                    let dialog_path =
-                        if tex_path.exists() || std::fs::create_dir_all(&tex_path).is_ok() {
-                            tex_path
-                        } else {
-                            current_dir.clone()
-                        };
-
-                    if let Ok(Some(path)) = FileDialog::new()
-                        .add_filter("Texture Files", &["png", "jpg", "jpeg"])
-                        .set_location(&dialog_path)
-                        .show_open_single_file()
-                    {
-                        let relative_path = if let Ok(rel_path) = path.strip_prefix(&assets_dir) {
-                            rel_path.to_string_lossy().to_string().replace("\\", "/")
-                        } else {
-                            path.to_string_lossy().to_string()
-                        };
+                    if let Some(relative_path) = pick_texture_file() {
                         *val = relative_path;
-
                         changed = true;
                     }
                     ui.close();
@@ -702,6 +959,273 @@ fn display_text_field(
     changed
 }
 
+// Texture path relative to assets when the picked file lives inside it
+fn pick_texture_file() -> Option<String> {
+    let current_dir = std::env::current_dir().unwrap();
+    let assets_dir = current_dir.join("assets");
+    let tex_path = assets_dir.join("textures");
+
+    // Use textures dir if it exists or can be created, otherwise use current dir
+    let dialog_path = if tex_path.exists() || std::fs::create_dir_all(&tex_path).is_ok() {
+        tex_path
+    } else {
+        current_dir.clone()
+    };
+
+    let Ok(Some(path)) = FileDialog::new()
+        .add_filter("Texture Files", &["png", "jpg", "jpeg"])
+        .set_location(&dialog_path)
+        .show_open_single_file()
+    else {
+        return None;
+    };
+
+    let relative_path = if let Ok(rel_path) = path.strip_prefix(&assets_dir) {
+        rel_path.to_string_lossy().to_string().replace("\\", "/")
+    } else {
+        path.to_string_lossy().to_string()
+    };
+    Some(relative_path)
+}
+
+/// Import settings for the textures of the material open in the editor
+#[derive(Debug, Clone, Default, PartialEq)]
+pub struct TextureImportData {
+    /// Settings being edited, read from the sidecar the first time a texture shows up
+    pub edited: HashMap<String, TextureImportSettings>,
+    /// What the sidecar held when last read or applied
+    pub saved: HashMap<String, TextureImportSettings>,
+    /// Textures to save and reload, handled in the tab update system
+    pub reimport_requested: Vec<String>,
+}
+
+impl TextureImportData {
+    /// Forget cached settings so they are read from disk again
+    pub fn clear(&mut self) {
+        self.edited.clear();
+        self.saved.clear();
+    }
+}
+
+fn import_combo<T: Copy + PartialEq + std::fmt::Debug>(
+    ui: &mut egui::Ui,
+    id: String,
+    value: &mut T,
+    options: Vec<T>,
+) {
+    egui::ComboBox::from_id_salt(id)
+        .selected_text(format!("{:?}", value))
+        .show_ui(ui, |ui| {
+            for option in options {
+                ui.selectable_value(value, option, format!("{:?}", option));
+            }
+        });
+}
+
+// Collapsed row under a texture field, shared by every material using the same file
+fn display_texture_import_field(
+    ui: &mut egui::Ui,
+    path: &Option<String>,
+    default_srgb: bool,
+    imports: &mut TextureImportData,
+) {
+    let Some(path) = path.as_ref().filter(|path| !path.is_empty()) else {
+        return;
+    };
+    if !imports.edited.contains_key(path) {
+        let saved = read_texture_import_settings(path).unwrap_or_default();
+        imports.saved.insert(path.clone(), saved.clone());
+        imports.edited.insert(path.clone(), saved);
+    }
+    let saved = imports.saved.get(path).cloned().unwrap_or_default();
+    let mut apply = false;
+
+    ui.label("");
+    egui::CollapsingHeader::new("Import")
+        .id_salt(format!("texture_import_{}", path))
+        .show(ui, |ui| {
+            let Some(settings) = imports.edited.get_mut(path) else {
+                return;
+            };
+            egui::Grid::new(format!("texture_import_grid_{}", path))
+                .num_columns(2)
+                .show(ui, |ui| {
+                    ui.label("Address U");
+                    import_combo(
+                        ui,
+                        format!("{}_address_u", path),
+                        &mut settings.address_mode_u,
+                        TextureAddressMode::all(),
+                    );
+                    ui.end_row();
+
+                    ui.label("Address V");
+                    import_combo(
+                        ui,
+                        format!("{}_address_v", path),
+                        &mut settings.address_mode_v,
+                        TextureAddressMode::all(),
+                    );
+                    ui.end_row();
+
+                    ui.label("Mag Filter");
+                    import_combo(
+                        ui,
+                        format!("{}_mag", path),
+                        &mut settings.mag_filter,
+                        TextureFilterMode::all(),
+                    );
+                    ui.end_row();
+
+                    ui.label("Min Filter");
+                    import_combo(
+                        ui,
+                        format!("{}_min", path),
+                        &mut settings.min_filter,
+                        TextureFilterMode::all(),
+                    );
+                    ui.end_row();
+
+                    ui.label("Mip Filter");
+                    import_combo(
+                        ui,
+                        format!("{}_mip", path),
+                        &mut settings.mipmap_filter,
+                        TextureFilterMode::all(),
+                    );
+                    ui.end_row();
+
+                    let all_linear = [
+                        settings.mag_filter,
+                        settings.min_filter,
+                        settings.mipmap_filter,
+                    ]
+                    .iter()
+                    .all(|filter| *filter == TextureFilterMode::Linear);
+                    ui.label("Anisotropy");
+                    ui.add_enabled(
+                        all_linear,
+                        egui::DragValue::new(&mut settings.anisotropy).range(1..=16),
+                    )
+                    .on_disabled_hover_text("Needs every filter set to Linear");
+                    ui.end_row();
+
+                    ui.label("Color Space");
+                    let slot_default = if default_srgb { "sRGB" } else { "Linear" };
+                    let label = |srgb: Option<bool>| match srgb {
+                        None => format!("Slot default ({})", slot_default),
+                        Some(true) => "sRGB".to_string(),
+                        Some(false) => "Linear".to_string(),
+                    };
+                    egui::ComboBox::from_id_salt(format!("{}_srgb", path))
+                        .selected_text(label(settings.srgb))
+                        .show_ui(ui, |ui| {
+                            for option in [None, Some(true), Some(false)] {
+                                ui.selectable_value(&mut settings.srgb, option, label(option));
+                            }
+                        });
+                    ui.end_row();
+
+                    ui.label("Generate Mips");
+                    ui.checkbox(&mut settings.generate_mips, "")
+                        .on_hover_text("Build a mip chain on load for files that have none");
+                    ui.end_row();
+
+                    ui.label("Flip Green");
+                    ui.checkbox(&mut settings.flip_normal_green, "")
+                        .on_hover_text("For normal maps authored for DirectX");
+                    ui.end_row();
+                });
+
+            ui.horizontal(|ui| {
+                if ui
+                    .add_enabled(*settings != saved, egui::Button::new("Apply"))
+                    .on_hover_text("Save next to the texture and reload it")
+                    .clicked()
+                {
+                    apply = true;
+                }
+                if ui
+                    .add_enabled(
+                        *settings != TextureImportSettings::default(),
+                        egui::Button::new("🔄"),
+                    )
+                    .on_hover_text("Reset to default")
+                    .clicked()
+                {
+                    *settings = TextureImportSettings::default();
+                }
+            });
+        });
+    ui.end_row();
+
+    if apply {
+        if let Some(settings) = imports.edited.get(path) {
+            imports.saved.insert(path.clone(), settings.clone());
+        }
+        imports.reimport_requested.push(path.clone());
+    }
+}
+
+fn display_material_param(ui: &mut egui::Ui, param: &mut MaterialParam) -> bool {
+    let drag = |ui: &mut egui::Ui, value: &mut f32| {
+        ui.add(egui::DragValue::new(value).speed(0.01)).changed()
+    };
+
+    match param {
+        MaterialParam::Float(value) => drag(ui, value),
+        MaterialParam::Int(value) => ui.add(egui::DragValue::new(value)).changed(),
+        MaterialParam::UInt(value) => ui.add(egui::DragValue::new(value)).changed(),
+        MaterialParam::Bool(value) => ui.checkbox(value, "").changed(),
+        MaterialParam::Color(color) => {
+            let mut egui_color = egui::Color32::from_rgba_unmultiplied(
+                (color.0 * 255.0) as u8,
+                (color.1 * 255.0) as u8,
+                (color.2 * 255.0) as u8,
+                (color.3 * 255.0) as u8,
+            );
+            let changed = ui.color_edit_button_srgba(&mut egui_color).changed();
+            if changed {
+                let [r, g, b, a] = egui_color.to_srgba_unmultiplied();
+                *color = (
+                    r as f32 / 255.0,
+                    g as f32 / 255.0,
+                    b as f32 / 255.0,
+                    a as f32 / 255.0,
+                );
+            }
+            changed
+        }
+        MaterialParam::Vec2((x, y)) => ui.horizontal(|ui| drag(ui, x) | drag(ui, y)).inner,
+        MaterialParam::Vec3((x, y, z)) => {
+            ui.horizontal(|ui| drag(ui, x) | drag(ui, y) | drag(ui, z))
+                .inner
+        }
+        MaterialParam::Vec4((x, y, z, w)) => {
+            ui.horizontal(|ui| drag(ui, x) | drag(ui, y) | drag(ui, z) | drag(ui, w))
+                .inner
+        }
+        MaterialParam::Texture { path, srgb } => {
+            ui.horizontal(|ui| {
+                let mut changed = ui.text_edit_singleline(path).changed();
+                ui.spacing_mut().button_padding = egui::Vec2::new(2.0, 2.0);
+                if ui.button("📁").clicked() {
+                    if let Some(relative_path) = pick_texture_file() {
+                        *path = relative_path;
+                        changed = true;
+                    }
+                }
+                changed |= ui
+                    .checkbox(srgb, "sRGB")
+                    .on_hover_text("Off for data maps like normals and roughness")
+                    .changed();
+                changed
+            })
+            .inner
+        }
+    }
+}
+
 fn display_uv_scale_field(
     ui: &mut egui::Ui,
     uv_transform: &mut Option<[[f32; 3]; 3]>,
@@ -764,6 +1288,7 @@ pub fn display_standard_material_field(
     field: &EditableMaterialField,
     def: &mut StandardMaterialDef,
     defaults: &StandardMaterial,
+    imports: &mut TextureImportData,
 ) -> bool {
     let mut changed = false;
 
@@ -787,6 +1312,7 @@ pub fn display_standard_material_field(
                 true,
                 true,
             );
+            display_texture_import_field(ui, &def.base_color_texture, true, imports);
         }
 
         EditableMaterialField::Roughness => {
@@ -820,6 +1346,7 @@ pub fn display_standard_material_field(
                 true,
                 true,
             );
+            display_texture_import_field(ui, &def.metallic_roughness_texture, false, imports);
         }
 
         EditableMaterialField::Emissive => {
@@ -844,6 +1371,7 @@ pub fn display_standard_material_field(
                 true,
                 true,
             );
+            display_texture_import_field(ui, &def.emissive_texture, true, imports);
         }
 
         EditableMaterialField::EmissiveExposureWeight => {
@@ -870,6 +1398,7 @@ pub fn display_standard_material_field(
                 true,
                 true,
             );
+            display_texture_import_field(ui, &def.normal_map_texture, false, imports);
         }
 
         EditableMaterialField::OcclusionMap => {
@@ -881,6 +1410,7 @@ pub fn display_standard_material_field(
                 true,
                 true,
             );
+            display_texture_import_field(ui, &def.occlusion_map, false, imports);
         }
 
         EditableMaterialField::Thickness => {
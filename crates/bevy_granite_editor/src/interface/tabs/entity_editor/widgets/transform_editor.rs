@@ -248,7 +248,7 @@ fn display_rotation_ui(
                         Some(GizmoAxis::X) => i == 0,
                         Some(GizmoAxis::Y) => i == 1,
                         Some(GizmoAxis::Z) => i == 2,
-                        Some(GizmoAxis::All) | Some(GizmoAxis::None) | None => true,
+                        Some(_) | None => true,
                     };
 
                     // Map back to X, Y, Z order for UI display
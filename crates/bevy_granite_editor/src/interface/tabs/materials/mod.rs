@@ -0,0 +1,7 @@
+pub mod previews;
+pub mod system;
+pub mod ui;
+
+pub use previews::*;
+pub use system::*;
+pub use ui::*;
@@ -0,0 +1,266 @@
+use super::MaterialPreviewShape;
+use crate::{
+    interface::{BottomDockState, BottomTab},
+    viewport::camera::LAYER_MATERIAL_PREVIEW,
+};
+use bevy::{
+    asset::{AssetId, Assets, Handle, RenderAssetUsages},
+    camera::{visibility::RenderLayers, Camera, Camera3d, ClearColorConfig, RenderTarget},
+    color::Color,
+    ecs::{
+        component::Component,
+        entity::Entity,
+        query::With,
+        system::{Commands, Query, Res, ResMut},
+    },
+    image::Image,
+    light::{DirectionalLight, NotShadowCaster},
+    math::{primitives::Cuboid, primitives::Sphere, EulerRot, Quat, Vec3},
+    mesh::{Mesh, Mesh3d, Meshable},
+    pbr::{MeshMaterial3d, StandardMaterial},
+    picking::Pickable,
+    platform::collections::HashMap,
+    prelude::{Name, Resource, Transform},
+    render::render_resource::{Extent3d, TextureDimension, TextureFormat, TextureUsages},
+};
+use bevy_egui::{egui, EguiContexts, EguiTextureHandle};
+use bevy_granite_core::{AvailableEditableMaterials, TreeHiddenEntity};
+
+const PREVIEW_SIZE: u32 = 128;
+// Previews sit side by side, far enough apart that each camera only frames its own model
+const PREVIEW_SPACING: f32 = 10.0;
+const PREVIEW_CAMERA_DISTANCE: f32 = 2.8;
+// Well below any sane scene, the layer already hides it but this keeps bounds sane
+const PREVIEW_STUDIO_ORIGIN: Vec3 = Vec3::new(0.0, -10000.0, 0.0);
+// Changed previews keep rendering a little while so textures still loading land in them
+const PREVIEW_RENDER_FRAMES: u32 = 30;
+
+#[derive(Component)]
+pub struct MaterialPreviewCamera;
+
+struct MaterialPreview {
+    slot: usize,
+    camera: Entity,
+    model: Entity,
+    image: Handle<Image>,
+    texture: egui::TextureId,
+    material: AssetId<StandardMaterial>,
+    version: u32,
+    frames_left: u32,
+}
+
+/// Offscreen thumbnails for the Materials tab, keyed by material path
+#[derive(Resource, Default)]
+pub struct MaterialPreviews {
+    previews: HashMap<String, MaterialPreview>,
+    free_slots: Vec<usize>,
+    next_slot: usize,
+    shape: MaterialPreviewShape,
+    meshes: Option<(Handle<Mesh>, Handle<Mesh>)>,
+    light: Option<Entity>,
+}
+
+impl MaterialPreviews {
+    pub fn texture(&self, path: &str) -> Option<egui::TextureId> {
+        self.previews.get(path).map(|preview| preview.texture)
+    }
+
+    fn remove(&mut self, path: &str, commands: &mut Commands, contexts: &mut EguiContexts) {
+        let Some(preview) = self.previews.remove(path) else {
+            return;
+        };
+        commands.entity(preview.camera).despawn();
+        commands.entity(preview.model).despawn();
+        contexts.remove_image(&preview.image);
+        self.free_slots.push(preview.slot);
+    }
+
+    fn next_slot(&mut self) -> usize {
+        self.free_slots.pop().unwrap_or_else(|| {
+            self.next_slot += 1;
+            self.next_slot - 1
+        })
+    }
+}
+
+fn preview_target_image() -> Image {
+    let size = Extent3d {
+        width: PREVIEW_SIZE,
+        height: PREVIEW_SIZE,
+        ..Default::default()
+    };
+    let mut image = Image::new_fill(
+        size,
+        TextureDimension::D2,
+        &[0, 0, 0, 0],
+        TextureFormat::Bgra8UnormSrgb,
+        RenderAssetUsages::default(),
+    );
+    image.texture_descriptor.usage =
+        TextureUsages::TEXTURE_BINDING | TextureUsages::COPY_DST | TextureUsages::RENDER_ATTACHMENT;
+    image
+}
+
+fn preview_origin(slot: usize) -> Vec3 {
+    PREVIEW_STUDIO_ORIGIN + Vec3::X * slot as f32 * PREVIEW_SPACING
+}
+
+pub fn update_material_previews_system(
+    mut commands: Commands,
+    mut contexts: EguiContexts,
+    mut previews: ResMut<MaterialPreviews>,
+    bottom_dock: Res<BottomDockState>,
+    available_materials: Res<AvailableEditableMaterials>,
+    mut images: ResMut<Assets<Image>>,
+    mut meshes: ResMut<Assets<Mesh>>,
+    mut camera_query: Query<&mut Camera, With<MaterialPreviewCamera>>,
+) {
+    let shape = bottom_dock
+        .dock_state
+        .iter_all_tabs()
+        .find_map(|(_, tab)| match tab {
+            BottomTab::Materials { data } => Some(data.preview_shape),
+            _ => None,
+        });
+
+    // Nothing is kept around while the tab is closed
+    let Some(shape) = shape else {
+        let paths: Vec<String> = previews.previews.keys().cloned().collect();
+        for path in paths {
+            previews.remove(&path, &mut commands, &mut contexts);
+        }
+        return;
+    };
+
+    let (sphere, cube) = previews
+        .meshes
+        .get_or_insert_with(|| {
+            (
+                meshes.add(Sphere::new(1.0).mesh().uv(48, 24)),
+                meshes.add(Cuboid::from_length(1.3)),
+            )
+        })
+        .clone();
+    let model_mesh = match shape {
+        MaterialPreviewShape::Sphere => sphere,
+        MaterialPreviewShape::Cube => cube,
+    };
+    let shape_changed = previews.shape != shape;
+    previews.shape = shape;
+
+    if previews.light.is_none() {
+        let light = commands
+            .spawn((
+                Name::new("Material Preview Light"),
+                DirectionalLight {
+                    illuminance: 6000.,
+                    shadows_enabled: false,
+                    ..Default::default()
+                },
+                Transform::from_xyz(1.0, 1.5, 2.0).looking_at(Vec3::ZERO, Vec3::Y),
+                RenderLayers::layer(LAYER_MATERIAL_PREVIEW),
+                TreeHiddenEntity,
+            ))
+            .id();
+        previews.light = Some(light);
+    }
+
+    let wanted: Vec<_> = available_materials
+        .materials
+        .iter()
+        .flatten()
+        .filter(|material| !material.is_empty() && material.handle.is_some())
+        .collect();
+
+    let stale: Vec<String> = previews
+        .previews
+        .keys()
+        .filter(|path| !wanted.iter().any(|material| material.path == **path))
+        .cloned()
+        .collect();
+    for path in stale {
+        previews.remove(&path, &mut commands, &mut contexts);
+    }
+
+    for material in wanted {
+        let Some(handle) = &material.handle else {
+            continue;
+        };
+
+        if let Some(preview) = previews.previews.get_mut(&material.path) {
+            if shape_changed
+                || preview.material != handle.id()
+                || preview.version != material.version
+            {
+                commands
+                    .entity(preview.model)
+                    .insert((Mesh3d(model_mesh.clone()), MeshMaterial3d(handle.clone())));
+                preview.material = handle.id();
+                preview.version = material.version;
+                preview.frames_left = PREVIEW_RENDER_FRAMES;
+            }
+            continue;
+        }
+
+        let slot = previews.next_slot();
+        let origin = preview_origin(slot);
+        let image = images.add(preview_target_image());
+        let camera = commands
+            .spawn((
+                Name::new("Material Preview Camera"),
+                MaterialPreviewCamera,
+                Camera3d::default(),
+                Camera {
+                    target: RenderTarget::Image(image.clone().into()),
+                    order: -10,
+                    clear_color: ClearColorConfig::Custom(Color::srgb(0.11, 0.11, 0.12)),
+                    ..Default::default()
+                },
+                Transform::from_translation(origin + Vec3::new(0.0, 0.0, PREVIEW_CAMERA_DISTANCE))
+                    .looking_at(origin, Vec3::Y),
+                RenderLayers::layer(LAYER_MATERIAL_PREVIEW),
+                TreeHiddenEntity,
+            ))
+            .id();
+        let model = commands
+            .spawn((
+                Name::new("Material Preview Model"),
+                Mesh3d(model_mesh.clone()),
+                MeshMaterial3d(handle.clone()),
+                Transform::from_translation(origin).with_rotation(Quat::from_euler(
+                    EulerRot::YXZ,
+                    0.6,
+                    0.45,
+                    0.0,
+                )),
+                RenderLayers::layer(LAYER_MATERIAL_PREVIEW),
+                NotShadowCaster,
+                Pickable::IGNORE,
+                TreeHiddenEntity,
+            ))
+            .id();
+        let texture = contexts.add_image(EguiTextureHandle::Strong(image.clone()));
+
+        previews.previews.insert(
+            material.path.clone(),
+            MaterialPreview {
+                slot,
+                camera,
+                model,
+                image,
+                texture,
+                material: handle.id(),
+                version: material.version,
+                frames_left: PREVIEW_RENDER_FRAMES,
+            },
+        );
+    }
+
+    // Untouched previews keep their last frame instead of re-rendering every tick
+    for preview in previews.previews.values_mut() {
+        if let Ok(mut camera) = camera_query.get_mut(preview.camera) {
+            camera.is_active = preview.frames_left > 0;
+        }
+        preview.frames_left = preview.frames_left.saturating_sub(1);
+    }
+}
@@ -0,0 +1,301 @@
+use super::{MaterialBrowserEntry, MaterialPreviews};
+use crate::{
+    history::{queue_history_command, ClassDataCommand},
+    interface::{
+        events::{MaterialDeleteEvent, MaterialHandleUpdateEvent, RequestMaterialBrowserAction},
+        BottomDockState, BottomTab,
+    },
+    viewport::{ViewportCameraState, ViewportPanes},
+};
+use bevy::{
+    asset::{AssetServer, Assets},
+    camera::Camera,
+    ecs::{
+        entity::Entity,
+        hierarchy::ChildOf,
+        message::{MessageReader, MessageWriter},
+        system::{Commands, Query, Res, ResMut},
+    },
+    pbr::StandardMaterial,
+    picking::mesh_picking::ray_cast::{MeshRayCast, MeshRayCastSettings},
+    prelude::With,
+    transform::components::GlobalTransform,
+    window::{PrimaryWindow, Window},
+};
+use bevy_granite_core::{
+    material_from_path_into_scene, rel_asset_to_absolute, AvailableEditableMaterials,
+    EditableMaterial, EditableMaterialError, EditorIgnore, GraniteType, IconProxy, IdentityData,
+    UserInput,
+};
+use bevy_granite_gizmos::GizmoMesh;
+use bevy_granite_logging::{
+    config::{LogCategory, LogLevel, LogType},
+    log,
+};
+use std::path::Path;
+
+pub fn update_materials_tab_system(
+    mut bottom_dock: ResMut<BottomDockState>,
+    available_materials: Res<AvailableEditableMaterials>,
+    previews: Res<MaterialPreviews>,
+    mut request_writer: MessageWriter<RequestMaterialBrowserAction>,
+) {
+    for (_, tab) in bottom_dock.dock_state.iter_all_tabs_mut() {
+        if let BottomTab::Materials { ref mut data } = tab {
+            for request in data.requests.drain(..) {
+                request_writer.write(request);
+            }
+
+            data.entries = available_materials
+                .materials
+                .iter()
+                .flatten()
+                .filter(|material| !material.is_empty())
+                .map(|material| MaterialBrowserEntry {
+                    path: material.path.clone(),
+                    friendly_name: material.friendly_name.clone(),
+                    folder: Path::new(&material.path)
+                        .parent()
+                        .map(|folder| folder.to_string_lossy().replace('\\', "/"))
+                        .unwrap_or_default(),
+                    texture: previews.texture(&material.path),
+                })
+                .collect();
+        }
+    }
+}
+
+// Next free "<name>_copy.mat" beside the original
+fn duplicate_path(path: &str, available_materials: &AvailableEditableMaterials) -> String {
+    let source = Path::new(path);
+    let folder = source
+        .parent()
+        .map(|folder| folder.to_string_lossy().replace('\\', "/"))
+        .unwrap_or_default();
+    let stem = source
+        .file_stem()
+        .map(|stem| stem.to_string_lossy().to_string())
+        .unwrap_or_default();
+
+    let mut index = 1;
+    loop {
+        let suffix = if index == 1 {
+            String::new()
+        } else {
+            index.to_string()
+        };
+        let candidate = format!("{}/{}_copy{}.mat", folder, stem, suffix);
+        if available_materials
+            .find_material_by_path(&candidate)
+            .is_none()
+            && !Path::new(rel_asset_to_absolute(&candidate).as_ref()).exists()
+        {
+            return candidate;
+        }
+        index += 1;
+    }
+}
+
+pub fn handle_material_browser_requests_system(
+    mut request_reader: MessageReader<RequestMaterialBrowserAction>,
+    mut available_materials: ResMut<AvailableEditableMaterials>,
+    mut materials: ResMut<Assets<StandardMaterial>>,
+    asset_server: Res<AssetServer>,
+    mut material_handle_update_writer: MessageWriter<MaterialHandleUpdateEvent>,
+    mut material_delete_writer: MessageWriter<MaterialDeleteEvent>,
+) {
+    for request in request_reader.read() {
+        match request {
+            RequestMaterialBrowserAction::Duplicate(path) => {
+                let Some(source) = available_materials.find_material_by_path(path).cloned() else {
+                    continue;
+                };
+                let mut copy = source.clone();
+                copy.update_path(duplicate_path(path, &available_materials));
+                copy.update_name(format!("{} Copy", source.friendly_name));
+                copy.new_material = true;
+                copy.save_to_file();
+                if copy.error == EditableMaterialError::PathExists {
+                    continue;
+                }
+
+                // Loading it back gives the copy its own StandardMaterial handle
+                if material_from_path_into_scene(
+                    &copy.path,
+                    &mut materials,
+                    &mut available_materials,
+                    &asset_server,
+                )
+                .is_some()
+                {
+                    log!(
+                        LogType::Editor,
+                        LogLevel::OK,
+                        LogCategory::Asset,
+                        "Duplicated material '{}' to '{}'",
+                        path,
+                        copy.path
+                    );
+                }
+            }
+            RequestMaterialBrowserAction::Rename { path, name } => {
+                let Some(material) = available_materials
+                    .materials
+                    .iter_mut()
+                    .flatten()
+                    .find(|material| material.path == *path)
+                else {
+                    continue;
+                };
+                material.update_name(name.clone());
+                material.save_to_file();
+
+                // Entities keep their own copy of the material, so push the new name to them
+                material_handle_update_writer.write(MaterialHandleUpdateEvent {
+                    skip_entity: Entity::PLACEHOLDER,
+                    path: path.clone(),
+                    version: material.version,
+                    material: EditableMaterial {
+                        disk_changes: true,
+                        ..material.clone()
+                    },
+                });
+                log!(
+                    LogType::Editor,
+                    LogLevel::OK,
+                    LogCategory::Asset,
+                    "Renamed material '{}' to '{}'",
+                    path,
+                    name
+                );
+            }
+            RequestMaterialBrowserAction::Delete(path) => {
+                let Some(material) = available_materials.find_material_by_path(path).cloned()
+                else {
+                    continue;
+                };
+                if material.delete_from_disk_and_memory(&mut available_materials) {
+                    material_delete_writer.write(MaterialDeleteEvent {
+                        path: path.clone(),
+                        replacement: None,
+                    });
+                }
+            }
+            RequestMaterialBrowserAction::AssignUnderCursor(_) => {}
+        }
+    }
+}
+
+// Entity whose class owns the material for a mesh hit, children of brushes and OBJs included
+fn material_owner(
+    entity: Entity,
+    identity_query: &Query<&IdentityData>,
+    parent_query: &Query<&ChildOf>,
+) -> Option<Entity> {
+    let mut current = entity;
+    loop {
+        if let Ok(identity) = identity_query.get(current) {
+            return identity.class.get_material_data().map(|_| current);
+        }
+        current = parent_query.get(current).ok()?.parent();
+    }
+}
+
+pub fn assign_dropped_material_system(
+    mut request_reader: MessageReader<RequestMaterialBrowserAction>,
+    available_materials: Res<AvailableEditableMaterials>,
+    user_input: Res<UserInput>,
+    mut ray_cast: MeshRayCast,
+    camera_query: Query<(&Camera, &GlobalTransform)>,
+    panes: Res<ViewportPanes>,
+    viewport_camera_state: Res<ViewportCameraState>,
+    window_query: Query<&Window, With<PrimaryWindow>>,
+    identity_query: Query<&IdentityData>,
+    parent_query: Query<&ChildOf>,
+    skip_query: Query<(
+        Option<&EditorIgnore>,
+        Option<&GizmoMesh>,
+        Option<&IconProxy>,
+    )>,
+    mut commands: Commands,
+) {
+    for request in request_reader.read() {
+        let RequestMaterialBrowserAction::AssignUnderCursor(path) = request else {
+            continue;
+        };
+        let Some(material) = available_materials.find_material_by_path(path).cloned() else {
+            continue;
+        };
+        let Ok(window) = window_query.single() else {
+            continue;
+        };
+
+        // Dropped on whichever pane is under the cursor, not just the active one
+        let pane = panes
+            .pane_at(user_input.mouse_pos * window.scale_factor())
+            .unwrap_or(panes.active);
+        let Some((camera, camera_transform)) = panes
+            .camera(pane, &viewport_camera_state)
+            .and_then(|entity| camera_query.get(entity).ok())
+        else {
+            continue;
+        };
+        let Ok(ray) = camera.viewport_to_world(camera_transform, user_input.mouse_pos) else {
+            continue;
+        };
+
+        let target = {
+            let filter = |entity: Entity| {
+                let skipped = skip_query.get(entity).is_ok_and(|(ignore, gizmo, icon)| {
+                    ignore.is_some_and(|ignore| ignore.contains(EditorIgnore::PICKING))
+                        || gizmo.is_some()
+                        || icon.is_some()
+                });
+                !skipped && material_owner(entity, &identity_query, &parent_query).is_some()
+            };
+            let settings = MeshRayCastSettings::default().with_filter(&filter);
+            ray_cast
+                .cast_ray(ray, &settings)
+                .first()
+                .and_then(|(hit, _)| material_owner(*hit, &identity_query, &parent_query))
+        };
+        let Some(target) = target else {
+            log!(
+                LogType::Editor,
+                LogLevel::Info,
+                LogCategory::Asset,
+                "No entity with a material under the cursor"
+            );
+            continue;
+        };
+
+        let Ok(identity) = identity_query.get(target) else {
+            continue;
+        };
+        let mut class = identity.class.clone();
+        let Some(material_data) = class.get_mut_material_data() else {
+            continue;
+        };
+        *material_data.last = material_data.current.clone();
+        *material_data.current = material.clone();
+        *material_data.path = material.path.clone();
+        // The command pushes the class to the entity and swaps its material
+        queue_history_command(
+            &mut commands,
+            Box::new(ClassDataCommand::new(
+                target,
+                identity.class.clone(),
+                class,
+                format!("Assign material '{}'", material.friendly_name),
+            )),
+        );
+        log!(
+            LogType::Editor,
+            LogLevel::OK,
+            LogCategory::Asset,
+            "Assigned material '{}' to {}",
+            material.friendly_name,
+            target
+        );
+    }
+}
@@ -0,0 +1,276 @@
+use crate::interface::events::RequestMaterialBrowserAction;
+use bevy_egui::egui;
+use std::collections::BTreeMap;
+
+#[derive(Clone, Copy, Debug, Default, PartialEq)]
+pub enum MaterialPreviewShape {
+    #[default]
+    Sphere,
+    Cube,
+}
+
+impl MaterialPreviewShape {
+    pub fn all() -> Vec<Self> {
+        vec![Self::Sphere, Self::Cube]
+    }
+
+    pub fn label(&self) -> &'static str {
+        match self {
+            Self::Sphere => "Sphere",
+            Self::Cube => "Cube",
+        }
+    }
+}
+
+#[derive(Clone, Debug, PartialEq)]
+pub struct MaterialBrowserEntry {
+    pub path: String,
+    pub friendly_name: String,
+    /// Directory under assets, used to group the grid
+    pub folder: String,
+    /// Rendered preview, None until the preview camera has been set up
+    pub texture: Option<egui::TextureId>,
+}
+
+#[derive(Clone, Debug, PartialEq)]
+pub struct MaterialsTabData {
+    pub entries: Vec<MaterialBrowserEntry>,
+    pub search: String,
+    pub preview_shape: MaterialPreviewShape,
+    pub thumbnail_size: f32,
+    /// Path and the name being typed
+    pub renaming: Option<(String, String)>,
+    pub confirm_delete: Option<String>,
+    pub requests: Vec<RequestMaterialBrowserAction>,
+}
+
+impl Default for MaterialsTabData {
+    fn default() -> Self {
+        Self {
+            entries: Vec::new(),
+            search: String::new(),
+            preview_shape: MaterialPreviewShape::default(),
+            thumbnail_size: 72.,
+            renaming: None,
+            confirm_delete: None,
+            requests: Vec::new(),
+        }
+    }
+}
+
+pub fn materials_tab_ui(ui: &mut egui::Ui, data: &mut MaterialsTabData) {
+    let spacing = crate::UI_CONFIG.spacing;
+    let large_spacing = crate::UI_CONFIG.large_spacing;
+
+    ui.horizontal(|ui| {
+        ui.add(
+            egui::TextEdit::singleline(&mut data.search)
+                .hint_text("Search...")
+                .desired_width(160.),
+        );
+        ui.add_space(spacing);
+        egui::ComboBox::from_id_salt("material_preview_shape")
+            .selected_text(data.preview_shape.label())
+            .show_ui(ui, |ui| {
+                for shape in MaterialPreviewShape::all() {
+                    ui.selectable_value(&mut data.preview_shape, shape, shape.label());
+                }
+            });
+        ui.add_space(spacing);
+        ui.add(egui::Slider::new(&mut data.thumbnail_size, 48.0..=160.0).show_value(false))
+            .on_hover_text("Thumbnail size");
+    });
+    ui.add_space(spacing);
+    ui.separator();
+
+    let search = data.search.to_lowercase();
+    let mut folders: BTreeMap<&str, Vec<&MaterialBrowserEntry>> = BTreeMap::new();
+    for entry in &data.entries {
+        if search.is_empty()
+            || entry.friendly_name.to_lowercase().contains(&search)
+            || entry.path.to_lowercase().contains(&search)
+        {
+            folders
+                .entry(entry.folder.as_str())
+                .or_default()
+                .push(entry);
+        }
+    }
+
+    let size = data.thumbnail_size;
+    let mut actions = Vec::new();
+    let mut start_rename = None;
+    let mut start_delete = None;
+    egui::ScrollArea::vertical()
+        .auto_shrink([false, false])
+        .show(ui, |ui| {
+            if folders.is_empty() {
+                ui.label("No materials match");
+            }
+            for (folder, entries) in &folders {
+                egui::CollapsingHeader::new(format!("{} ({})", folder, entries.len()))
+                    .id_salt(("material_folder", *folder))
+                    .default_open(true)
+                    .show(ui, |ui| {
+                        ui.horizontal_wrapped(|ui| {
+                            for entry in entries {
+                                match material_tile(ui, entry, size) {
+                                    Some(TileAction::Assign) => actions.push(
+                                        RequestMaterialBrowserAction::AssignUnderCursor(
+                                            entry.path.clone(),
+                                        ),
+                                    ),
+                                    Some(TileAction::Duplicate) => actions.push(
+                                        RequestMaterialBrowserAction::Duplicate(entry.path.clone()),
+                                    ),
+                                    Some(TileAction::Rename) => {
+                                        start_rename =
+                                            Some((entry.path.clone(), entry.friendly_name.clone()))
+                                    }
+                                    Some(TileAction::Delete) => {
+                                        start_delete = Some(entry.path.clone())
+                                    }
+                                    None => {}
+                                }
+                            }
+                        });
+                    });
+                ui.add_space(large_spacing);
+            }
+        });
+
+    data.requests.extend(actions);
+    if start_rename.is_some() {
+        data.renaming = start_rename;
+    }
+    if start_delete.is_some() {
+        data.confirm_delete = start_delete;
+    }
+
+    rename_window(ui.ctx(), data);
+    delete_window(ui.ctx(), data);
+}
+
+enum TileAction {
+    Assign,
+    Duplicate,
+    Rename,
+    Delete,
+}
+
+fn material_tile(ui: &mut egui::Ui, entry: &MaterialBrowserEntry, size: f32) -> Option<TileAction> {
+    let mut action = None;
+    ui.vertical(|ui| {
+        ui.set_width(size);
+        let drag = ui.dnd_drag_source(
+            egui::Id::new(("material_tile", &entry.path)),
+            entry.path.clone(),
+            |ui| match entry.texture {
+                Some(texture) => {
+                    ui.add(egui::Image::new((texture, egui::vec2(size, size))));
+                }
+                None => {
+                    let (rect, _) =
+                        ui.allocate_exact_size(egui::vec2(size, size), egui::Sense::hover());
+                    ui.painter()
+                        .rect_filled(rect, 4.0, ui.visuals().faint_bg_color);
+                }
+            },
+        );
+        // Released over the viewport rather than over any panel
+        if drag.response.drag_stopped() && !ui.ctx().is_pointer_over_area() {
+            action = Some(TileAction::Assign);
+        }
+
+        let label = ui
+            .add(
+                egui::Label::new(&entry.friendly_name)
+                    .truncate()
+                    .sense(egui::Sense::click()),
+            )
+            .on_hover_text(format!(
+                "{}\nDrag onto an entity to assign, right click for more",
+                entry.path
+            ));
+        label.context_menu(|ui| {
+            if ui.button("Duplicate").clicked() {
+                action = Some(TileAction::Duplicate);
+                ui.close();
+            }
+            if ui.button("Rename").clicked() {
+                action = Some(TileAction::Rename);
+                ui.close();
+            }
+            if ui.button("Delete").clicked() {
+                action = Some(TileAction::Delete);
+                ui.close();
+            }
+        });
+    });
+    action
+}
+
+fn rename_window(ctx: &egui::Context, data: &mut MaterialsTabData) {
+    let Some((path, name)) = data.renaming.as_mut() else {
+        return;
+    };
+    let mut open = true;
+    let mut done = false;
+    egui::Window::new("Rename Material")
+        .collapsible(false)
+        .resizable(false)
+        .open(&mut open)
+        .show(ctx, |ui| {
+            ui.label(path.as_str());
+            let response = ui.text_edit_singleline(name);
+            let submitted =
+                response.lost_focus() && ui.input(|input| input.key_pressed(egui::Key::Enter));
+            ui.horizontal(|ui| {
+                let valid = !name.trim().is_empty();
+                if (ui.add_enabled(valid, egui::Button::new("Rename")).clicked() || submitted)
+                    && valid
+                {
+                    data.requests.push(RequestMaterialBrowserAction::Rename {
+                        path: path.clone(),
+                        name: name.trim().to_string(),
+                    });
+                    done = true;
+                }
+                if ui.button("Cancel").clicked() {
+                    done = true;
+                }
+            });
+        });
+    if done || !open {
+        data.renaming = None;
+    }
+}
+
+fn delete_window(ctx: &egui::Context, data: &mut MaterialsTabData) {
+    let Some(path) = data.confirm_delete.clone() else {
+        return;
+    };
+    let mut open = true;
+    let mut done = false;
+    egui::Window::new("Delete Material")
+        .collapsible(false)
+        .resizable(false)
+        .open(&mut open)
+        .show(ctx, |ui| {
+            ui.label(format!("Delete '{}' from disk?", path));
+            ui.label("Entities using it fall back to None.");
+            ui.horizontal(|ui| {
+                if ui.button("Delete").clicked() {
+                    data.requests
+                        .push(RequestMaterialBrowserAction::Delete(path.clone()));
+                    done = true;
+                }
+                if ui.button("Cancel").clicked() {
+                    done = true;
+                }
+            });
+        });
+    if done || !open {
+        data.confirm_delete = None;
+    }
+}
@@ -1,5 +1,7 @@
 use super::data::{HierarchyEntry, NodeTreeTabData};
-use crate::interface::tabs::node_tree::data::PendingContextAction;
+use crate::interface::{
+    shared::widgets::arrange_menu_ui, tabs::node_tree::data::PendingContextAction,
+};
 use bevy::prelude::Entity;
 use bevy_egui::egui;
 use bevy_granite_logging::{log, LogCategory, LogLevel, LogType};
@@ -30,6 +32,14 @@ pub fn show_entity_context_menu(
 
             ui.close();
         }
+
+        ui.separator();
+        ui.menu_button("Arrange Selection", |ui| {
+            if let Some(request) = arrange_menu_ui(ui) {
+                data.pending_context_actions
+                    .push(PendingContextAction::Arrange(request));
+            }
+        });
     });
 
     menu_shown
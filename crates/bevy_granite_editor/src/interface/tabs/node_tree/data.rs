@@ -1,3 +1,4 @@
+use crate::interface::shared::widgets::ArrangeRequest;
 use bevy::{
     ecs::message::Message,
     prelude::{Entity, Event},
@@ -10,6 +11,7 @@ pub enum PendingContextAction {
     SetActiveScene(String),
     ReloadScene(String),
     DespawnScene(String),
+    Arrange(ArrangeRequest),
 }
 
 /// Core data structures for the node tree system
@@ -156,6 +156,9 @@ fn process_context_actions(
                     .despawn_by_source
                     .write(RequestDespawnBySource(scene_path));
             }
+            PendingContextAction::Arrange(request) => {
+                request.send(events);
+            }
         }
     }
 }
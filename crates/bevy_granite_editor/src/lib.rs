@@ -24,7 +24,7 @@ pub use editor_state::{
 pub use entities::get_entity_bounds_or_fallback;
 pub use history::{
     CommandError, CommandHistory, CommandResult, EditorCommand, EntityCreateCommand,
-    EntityDeleteCommand, TransformCommand,
+    EntityDeleteCommand, TransformBatchCommand, TransformCommand,
 };
 pub use interface::events::{
     RequestCameraEntityFrame, RequestEditorToggle, RequestNewParent, RequestRemoveChildren,
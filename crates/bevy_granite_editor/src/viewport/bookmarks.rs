@@ -0,0 +1,221 @@
+use crate::{
+    editor_state::{update_editor_config_field, EditorState},
+    interface::events::RequestCameraBookmark,
+    viewport::camera::CameraTarget,
+};
+use bevy::prelude::{
+    MessageReader, Quat, Query, Res, ResMut, Resource, Time, Transform, Vec3, With,
+};
+use bevy_granite_core::{UICamera, UserInput};
+use bevy_granite_logging::{log, LogCategory, LogLevel, LogType};
+use serde::{Deserialize, Serialize};
+
+/// Number keys 1-9
+pub const CAMERA_BOOKMARK_SLOTS: usize = 9;
+pub const CAMERA_TRANSITION_SECONDS: f32 = 0.4;
+
+/// Saved editor viewpoint, stored in the editor config
+#[derive(Clone, Debug, PartialEq, Serialize, Deserialize)]
+pub struct CameraBookmark {
+    pub slot: usize,
+    pub name: String,
+    pub position: [f32; 3],
+    pub rotation: [f32; 4],
+    pub target: [f32; 3],
+}
+
+impl CameraBookmark {
+    pub fn new(slot: usize, name: String, transform: &Transform, target: Vec3) -> Self {
+        Self {
+            slot,
+            name,
+            position: transform.translation.to_array(),
+            rotation: transform.rotation.to_array(),
+            target: target.to_array(),
+        }
+    }
+
+    pub fn transform(&self) -> Transform {
+        Transform::from_translation(Vec3::from_array(self.position))
+            .with_rotation(Quat::from_array(self.rotation).normalize())
+    }
+
+    pub fn target(&self) -> Vec3 {
+        Vec3::from_array(self.target)
+    }
+}
+
+#[derive(Clone, Debug, PartialEq)]
+pub enum CameraBookmarkAction {
+    Save,
+    Recall,
+    Rename(String),
+    Clear,
+}
+
+/// Eases the UI camera and orbit target from one viewpoint to another
+#[derive(Resource, Default)]
+pub struct CameraTransition {
+    from: Transform,
+    to: Transform,
+    target_from: Vec3,
+    target_to: Vec3,
+    elapsed: f32,
+    duration: f32,
+    active: bool,
+}
+
+impl CameraTransition {
+    pub fn start(&mut self, from: Transform, to: Transform, target_from: Vec3, target_to: Vec3) {
+        self.from = from;
+        self.to = to;
+        self.target_from = target_from;
+        self.target_to = target_to;
+        self.elapsed = 0.;
+        self.duration = CAMERA_TRANSITION_SECONDS;
+        self.active = true;
+    }
+
+    pub fn is_active(&self) -> bool {
+        self.active
+    }
+
+    pub fn cancel(&mut self) {
+        self.active = false;
+    }
+}
+
+pub fn camera_bookmark_system(
+    mut bookmark_reader: MessageReader<RequestCameraBookmark>,
+    mut editor_state: ResMut<EditorState>,
+    mut transition: ResMut<CameraTransition>,
+    camera_query: Query<&Transform, With<UICamera>>,
+    camera_target: Res<CameraTarget>,
+) {
+    for RequestCameraBookmark { slot, action } in bookmark_reader.read() {
+        let slot = *slot;
+        if slot == 0 || slot > CAMERA_BOOKMARK_SLOTS {
+            continue;
+        }
+        let Ok(camera_transform) = camera_query.single() else {
+            return;
+        };
+        let existing = editor_state
+            .config
+            .camera_bookmarks
+            .iter()
+            .find(|bookmark| bookmark.slot == slot)
+            .cloned();
+
+        match action {
+            CameraBookmarkAction::Recall => {
+                let Some(bookmark) = existing else {
+                    log!(
+                        LogType::Editor,
+                        LogLevel::Warning,
+                        LogCategory::Input,
+                        "No camera bookmark in slot {}, Ctrl + {} saves one",
+                        slot,
+                        slot
+                    );
+                    continue;
+                };
+                transition.start(
+                    *camera_transform,
+                    bookmark.transform(),
+                    camera_target.position,
+                    bookmark.target(),
+                );
+                log!(
+                    LogType::Editor,
+                    LogLevel::Info,
+                    LogCategory::Input,
+                    "Recalled camera bookmark '{}'",
+                    bookmark.name
+                );
+            }
+            CameraBookmarkAction::Save => {
+                // Overwriting a slot keeps the name it was given
+                let name = existing
+                    .map(|bookmark| bookmark.name)
+                    .unwrap_or_else(|| format!("Bookmark {}", slot));
+                let bookmark =
+                    CameraBookmark::new(slot, name, camera_transform, camera_target.position);
+                let _ = update_editor_config_field(&mut editor_state, |config| {
+                    config.camera_bookmarks.retain(|saved| saved.slot != slot);
+                    config.camera_bookmarks.push(bookmark);
+                    config.camera_bookmarks.sort_by_key(|saved| saved.slot);
+                });
+                log!(
+                    LogType::Editor,
+                    LogLevel::OK,
+                    LogCategory::Input,
+                    "Saved camera bookmark {}",
+                    slot
+                );
+            }
+            CameraBookmarkAction::Rename(name) => {
+                if existing.is_none() || name.trim().is_empty() {
+                    continue;
+                }
+                let name = name.trim().to_string();
+                let _ = update_editor_config_field(&mut editor_state, |config| {
+                    for saved in config.camera_bookmarks.iter_mut() {
+                        if saved.slot == slot {
+                            saved.name = name.clone();
+                        }
+                    }
+                });
+            }
+            CameraBookmarkAction::Clear => {
+                if existing.is_none() {
+                    continue;
+                }
+                let _ = update_editor_config_field(&mut editor_state, |config| {
+                    config.camera_bookmarks.retain(|saved| saved.slot != slot);
+                });
+            }
+        }
+    }
+}
+
+pub fn camera_transition_system(
+    time: Res<Time>,
+    user_input: Res<UserInput>,
+    mut transition: ResMut<CameraTransition>,
+    mut camera_query: Query<&mut Transform, With<UICamera>>,
+    mut camera_target: ResMut<CameraTarget>,
+) {
+    if !transition.active {
+        return;
+    }
+
+    // Grabbing the camera mid flight hands control straight back
+    if user_input.mouse_right.any || user_input.mouse_middle.any {
+        transition.cancel();
+        return;
+    }
+
+    let Ok(mut camera_transform) = camera_query.single_mut() else {
+        transition.cancel();
+        return;
+    };
+
+    transition.elapsed += time.delta_secs();
+    let t = (transition.elapsed / transition.duration.max(f32::EPSILON)).clamp(0., 1.);
+    let eased = t * t * (3. - 2. * t);
+
+    camera_transform.translation = transition
+        .from
+        .translation
+        .lerp(transition.to.translation, eased);
+    camera_transform.rotation = transition
+        .from
+        .rotation
+        .slerp(transition.to.rotation, eased);
+    camera_target.position = transition.target_from.lerp(transition.target_to, eased);
+
+    if t >= 1. {
+        transition.active = false;
+    }
+}
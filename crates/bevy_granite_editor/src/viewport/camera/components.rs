@@ -57,3 +57,9 @@ impl ViewportCameraState {
             .map(|(entity, _)| *entity)
     }
 }
+
+/// Extra editor camera that draws one of the split viewport panes after the first.
+#[derive(Component)]
+pub struct ViewportPaneCamera {
+    pub index: usize,
+}
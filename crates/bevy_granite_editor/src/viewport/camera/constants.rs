@@ -1,8 +1,12 @@
 use bevy::camera::visibility::RenderLayers;
 
 pub const LAYER_SCENE: usize = 0;
+// Editor view mode proxies, never seen by runtime cameras
+pub const LAYER_VIEWMODE: usize = 12;
 pub const LAYER_GRID: usize = 13;
 pub const LAYER_GIZMO: usize = 14;
+// Offscreen material thumbnails, only their own cameras and light live here
+pub const LAYER_MATERIAL_PREVIEW: usize = 15;
 pub const LAYER_UI: usize = 31;
 
 pub fn scene_layers() -> RenderLayers {
@@ -4,20 +4,24 @@ use crate::{
     interface::events::{
         RequestCameraEntityFrame, RequestToggleCameraSync, RequestViewportCameraOverride,
     },
-    viewport::camera::{
-        handle_movement, handle_zoom, rotate_camera_towards, ViewportCameraState, LAYER_GIZMO,
-        LAYER_GRID, LAYER_SCENE,
+    viewport::{
+        camera::{
+            handle_movement, handle_zoom, rotate_camera_towards, ViewportCameraState,
+            ViewportPaneCamera, LAYER_GIZMO, LAYER_GRID, LAYER_SCENE,
+        },
+        bookmarks::CameraTransition,
+        panes::ViewportPanes,
     },
 };
 use bevy::{
     asset::Assets,
-    camera::{visibility::RenderLayers, Camera, Camera3d, RenderTarget, Viewport},
+    camera::{visibility::RenderLayers, Camera, Camera3d, Projection, RenderTarget, Viewport},
     ecs::{entity::Entity, system::Commands},
     input::mouse::{MouseMotion, MouseWheel},
     mesh::{Mesh, Mesh3d},
     prelude::{
-        Local, MessageReader, Query, Res, ResMut, Resource, Time, Transform, UVec2, Vec2, Vec3,
-        Window, With, Without,
+        Local, MessageReader, Query, Rect, Res, ResMut, Resource, Time, Transform, UVec2, Vec2,
+        Vec3, Window, With, Without,
     },
     transform::components::GlobalTransform,
     window::{CursorGrabMode, CursorOptions, PrimaryWindow},
@@ -86,19 +90,21 @@ pub fn sync_cameras_system(
     >,
     mut camera_state: ResMut<CameraSyncState>,
     mut viewport_camera_state: ResMut<ViewportCameraState>,
+    panes: Res<ViewportPanes>,
 ) {
     let Ok(mut ui_camera_transform) = ui_camera_query.single_mut() else {
         return;
     };
 
-    let Some(active_camera_entity) = viewport_camera_state.active_camera() else {
+    let Some(active_camera_entity) = panes.controlled_camera(&viewport_camera_state) else {
         return;
     };
 
     let mut active_camera_transform = match active_camera_query.get_mut(active_camera_entity) {
         Ok(transform) => transform,
         Err(_) => {
-            if viewport_camera_state.active_override.is_some() {
+            // Pane cameras come and go with the layout, only the main one falls back
+            if panes.active == 0 && viewport_camera_state.active_override.is_some() {
                 if let Some((stored_entity, stored_layers)) =
                     viewport_camera_state.take_override_render_layers()
                 {
@@ -164,7 +170,12 @@ pub fn enforce_viewport_camera_state(
     viewport_camera_state: Res<ViewportCameraState>,
     mut camera_query: Query<
         (Entity, &mut Camera),
-        (With<Camera3d>, Without<UICamera>, Without<GizmoCamera>),
+        (
+            With<Camera3d>,
+            Without<UICamera>,
+            Without<GizmoCamera>,
+            Without<ViewportPaneCamera>,
+        ),
     >,
 ) {
     let Some(active_camera_entity) = viewport_camera_state.active_camera() else {
@@ -197,7 +208,12 @@ pub fn restore_runtime_camera_state(
     >,
     mut camera_query: Query<
         (Entity, &mut Camera),
-        (With<Camera3d>, Without<UICamera>, Without<GizmoCamera>),
+        (
+            With<Camera3d>,
+            Without<UICamera>,
+            Without<GizmoCamera>,
+            Without<ViewportPaneCamera>,
+        ),
     >,
     main_camera_entities: Query<Entity, With<MainCamera>>,
 ) {
@@ -278,7 +294,10 @@ pub fn handle_viewport_camera_override_requests(
         &RenderLayers,
         (With<Camera3d>, Without<UICamera>, Without<GizmoCamera>),
     >,
+    panes: Res<ViewportPanes>,
 ) {
+    // The ui camera only follows the main camera while its pane is the active one
+    let drives_main_pane = panes.active == 0;
     for RequestViewportCameraOverride { camera } in requests.read() {
         let Ok(mut ui_transform) = ui_camera_query.single_mut() else {
             continue;
@@ -336,8 +355,10 @@ pub fn handle_viewport_camera_override_requests(
 
             match camera_transform_query.get_mut(*target_entity) {
                 Ok(target_transform) => {
-                    ui_transform.translation = target_transform.translation;
-                    ui_transform.rotation = target_transform.rotation;
+                    if drives_main_pane {
+                        ui_transform.translation = target_transform.translation;
+                        ui_transform.rotation = target_transform.rotation;
+                    }
                 }
                 Err(_) => {
                     log!(
@@ -382,8 +403,10 @@ pub fn handle_viewport_camera_override_requests(
             camera_sync_state.ui_camera_old_position = None;
 
             if let Some(stored_transform) = viewport_camera_state.take_stored_editor_transform() {
-                ui_transform.translation = stored_transform.translation;
-                ui_transform.rotation = stored_transform.rotation;
+                if drives_main_pane {
+                    ui_transform.translation = stored_transform.translation;
+                    ui_transform.rotation = stored_transform.rotation;
+                }
 
                 if let Some(editor_entity) = viewport_camera_state.editor_camera {
                     if let Ok(mut editor_transform) = camera_transform_query.get_mut(editor_entity)
@@ -400,6 +423,7 @@ pub fn update_viewport_camera_viewports_system(
     mut contexts: EguiContexts,
     editor_state: Res<EditorState>,
     viewport_camera_state: Res<ViewportCameraState>,
+    mut panes: ResMut<ViewportPanes>,
     mut camera_query: Query<&mut Camera, (With<Camera3d>, Without<UICamera>, Without<GizmoCamera>)>,
     mut gizmo_camera_query: Query<&mut Camera, With<GizmoCamera>>,
     primary_window_query: Query<&Window, With<PrimaryWindow>>,
@@ -413,31 +437,29 @@ pub fn update_viewport_camera_viewports_system(
         return;
     }
 
-    let mut clear_viewports = || {
+    let pane_cameras: Vec<Option<Entity>> = (0..panes.pane_count())
+        .map(|index| panes.camera(index, &viewport_camera_state))
+        .collect();
+    let mut clear_viewports = |panes: &mut ViewportPanes| {
+        for camera in pane_cameras.iter().flatten() {
+            if let Ok(mut camera) = camera_query.get_mut(*camera) {
+                camera.viewport = None;
+            }
+        }
         if let Ok(mut gizmo) = gizmo_camera_query.single_mut() {
             gizmo.viewport = None;
         }
+        panes.rects.clear();
     };
 
-    let Some(active_camera) = viewport_camera_state.active_camera() else {
-        clear_viewports();
-        return;
-    };
-
-    if !editor_state.active {
-        if let Ok(mut camera) = camera_query.get_mut(active_camera) {
-            camera.viewport = None;
-        }
-        clear_viewports();
+    if viewport_camera_state.active_camera().is_none() || !editor_state.active {
+        clear_viewports(&mut panes);
         return;
     }
 
     let available_rect = ctx.available_rect();
     if available_rect.width() <= 1.0 || available_rect.height() <= 1.0 {
-        if let Ok(mut camera) = camera_query.get_mut(active_camera) {
-            camera.viewport = None;
-        }
-        clear_viewports();
+        clear_viewports(&mut panes);
         return;
     }
 
@@ -452,46 +474,60 @@ pub fn update_viewport_camera_viewports_system(
     max_px.y = max_px.y.clamp(min_px.y, surface_height);
 
     if max_px.x - min_px.x < 1.0 || max_px.y - min_px.y < 1.0 {
-        if let Ok(mut camera) = camera_query.get_mut(active_camera) {
-            camera.viewport = None;
-        }
-        clear_viewports();
+        clear_viewports(&mut panes);
         return;
     }
 
-    let viewport = Viewport {
-        physical_position: UVec2::new(min_px.x as u32, min_px.y as u32),
-        physical_size: UVec2::new((max_px.x - min_px.x) as u32, (max_px.y - min_px.y) as u32),
-        ..Default::default()
-    };
-
-    if viewport.physical_size.x == 0 || viewport.physical_size.y == 0 {
-        if let Ok(mut camera) = camera_query.get_mut(active_camera) {
-            camera.viewport = None;
-        }
-        clear_viewports();
+    let pane_rects = panes.layout.layout.split(Rect::new(min_px.x, min_px.y, max_px.x, max_px.y));
+    let viewports: Vec<Viewport> = pane_rects
+        .iter()
+        .map(|rect| Viewport {
+            physical_position: UVec2::new(rect.min.x.round() as u32, rect.min.y.round() as u32),
+            physical_size: UVec2::new(
+                (rect.max.x.round() - rect.min.x.round()) as u32,
+                (rect.max.y.round() - rect.min.y.round()) as u32,
+            ),
+            ..Default::default()
+        })
+        .collect();
+
+    if viewports
+        .iter()
+        .any(|viewport| viewport.physical_size.x == 0 || viewport.physical_size.y == 0)
+    {
+        clear_viewports(&mut panes);
         return;
     }
 
-    if let Ok(mut camera) = camera_query.get_mut(active_camera) {
-        camera.viewport = Some(viewport.clone());
+    for (camera, viewport) in pane_cameras.iter().zip(viewports.iter()) {
+        if let Some(Ok(mut camera)) = camera.map(|camera| camera_query.get_mut(camera)) {
+            camera.viewport = Some(viewport.clone());
+        }
     }
+    // Gizmos draw and pick in the pane being worked in
     if let Ok(mut gizmo_camera) = gizmo_camera_query.single_mut() {
-        gizmo_camera.viewport = Some(viewport);
+        gizmo_camera.viewport = viewports.get(panes.active).or(viewports.first()).cloned();
     }
+    panes.rects = pane_rects;
 }
 
 pub fn sync_gizmo_camera_state(
     viewport_camera_state: Res<ViewportCameraState>,
+    panes: Res<ViewportPanes>,
     editor_state: Res<EditorState>,
     gizmo_visibility: Res<GizmoVisibilityState>,
-    mut gizmo_camera_query: Query<(&mut Camera, &mut Transform), With<GizmoCamera>>,
+    mut gizmo_camera_query: Query<
+        (&mut Camera, &mut Transform, &mut Projection),
+        With<GizmoCamera>,
+    >,
     active_camera_query: Query<
-        &Transform,
+        (&Transform, &Projection),
         (With<Camera3d>, Without<UICamera>, Without<GizmoCamera>),
     >,
 ) {
-    let Ok((mut gizmo_camera, mut gizmo_transform)) = gizmo_camera_query.single_mut() else {
+    let Ok((mut gizmo_camera, mut gizmo_transform, mut gizmo_projection)) =
+        gizmo_camera_query.single_mut()
+    else {
         return;
     };
 
@@ -503,21 +539,45 @@ pub fn sync_gizmo_camera_state(
         return;
     }
 
-    let Some(active_entity) = viewport_camera_state.active_camera() else {
+    let Some(active_entity) = panes.controlled_camera(&viewport_camera_state) else {
         gizmo_camera.is_active = false;
         gizmo_camera.viewport = None;
         return;
     };
 
-    if let Ok(active_transform) = active_camera_query.get(active_entity) {
+    if let Ok((active_transform, active_projection)) = active_camera_query.get(active_entity) {
         *gizmo_transform = active_transform.clone();
+        // Orthographic panes need a matching overlay or handles drift from their targets
+        *gizmo_projection = active_projection.clone();
     }
 }
 
+/// Frame moves ease in through the same transition as camera bookmarks
+fn start_frame_transition(
+    transition: &mut CameraTransition,
+    camera_transform: &Transform,
+    target_from: Vec3,
+    center: Vec3,
+    distance: f32,
+    pitch_rad: f32,
+) {
+    let rel = camera_transform.translation - center;
+    let yaw = rel.z.atan2(rel.x);
+    let dir_x = pitch_rad.cos() * yaw.cos();
+    let dir_y = pitch_rad.sin();
+    let dir_z = pitch_rad.cos() * yaw.sin();
+    let final_direction = Vec3::new(dir_x, dir_y, dir_z).normalize();
+    let mut goal = *camera_transform;
+    goal.translation = center + final_direction * distance;
+    rotate_camera_towards(&mut goal, center, 1.0);
+    transition.start(*camera_transform, goal, target_from, center);
+}
+
 pub fn camera_frame_system(
     transform_query: Query<&GlobalTransform, Without<UICamera>>,
-    mut camera_query: Query<&mut Transform, With<UICamera>>,
-    mut camera_target: ResMut<CameraTarget>,
+    camera_query: Query<&Transform, With<UICamera>>,
+    camera_target: Res<CameraTarget>,
+    mut transition: ResMut<CameraTransition>,
     mut frame_reader: MessageReader<RequestCameraEntityFrame>,
     _user_input: Res<UserInput>,
     selected_query: Query<Entity, With<Selected>>,
@@ -559,16 +619,15 @@ pub fn camera_frame_system(
                 let max_distance = radius * max_factor;
                 distance = distance.min(max_distance);
                 distance *= margin; // Add margin
-                camera_target.position = center;
-                for mut camera_transform in camera_query.iter_mut() {
-                    let rel = camera_transform.translation - center;
-                    let yaw = rel.z.atan2(rel.x);
-                    let dir_x = camera_frame_pitch_rad.cos() * yaw.cos();
-                    let dir_y = camera_frame_pitch_rad.sin();
-                    let dir_z = camera_frame_pitch_rad.cos() * yaw.sin();
-                    let final_direction = Vec3::new(dir_x, dir_y, dir_z).normalize();
-                    camera_transform.translation = center + final_direction * distance;
-                    rotate_camera_towards(&mut camera_transform, center, 1.0);
+                for camera_transform in camera_query.iter() {
+                    start_frame_transition(
+                        &mut transition,
+                        camera_transform,
+                        camera_target.position,
+                        center,
+                        distance,
+                        camera_frame_pitch_rad,
+                    );
                 }
                 log!(
                     LogType::Editor,
@@ -592,16 +651,15 @@ pub fn camera_frame_system(
                     let max_distance = radius * max_factor;
                     distance = distance.min(max_distance);
                     distance *= margin;
-                    camera_target.position = center;
-                    for mut camera_transform in camera_query.iter_mut() {
-                        let rel = camera_transform.translation - center;
-                        let yaw = rel.z.atan2(rel.x);
-                        let dir_x = camera_frame_pitch_rad.cos() * yaw.cos();
-                        let dir_y = camera_frame_pitch_rad.sin();
-                        let dir_z = camera_frame_pitch_rad.cos() * yaw.sin();
-                        let final_direction = Vec3::new(dir_x, dir_y, dir_z).normalize();
-                        camera_transform.translation = center + final_direction * distance;
-                        rotate_camera_towards(&mut camera_transform, center, 1.0);
+                    for camera_transform in camera_query.iter() {
+                        start_frame_transition(
+                            &mut transition,
+                            camera_transform,
+                            camera_target.position,
+                            center,
+                            distance,
+                            camera_frame_pitch_rad,
+                        );
                     }
                     log!(
                         LogType::Editor,
@@ -619,17 +677,15 @@ pub fn camera_frame_system(
         if selected_count > 0 {
             let entity = active_query.iter().next().unwrap();
             if let Ok(target_transform) = transform_query.get(entity) {
-                camera_target.position = target_transform.translation();
-                for mut camera_transform in camera_query.iter_mut() {
-                    let rel = camera_transform.translation - camera_target.position;
-                    let yaw = rel.z.atan2(rel.x);
-                    let dir_x = camera_frame_pitch_rad.cos() * yaw.cos();
-                    let dir_y = camera_frame_pitch_rad.sin();
-                    let dir_z = camera_frame_pitch_rad.cos() * yaw.sin();
-                    let final_direction = Vec3::new(dir_x, dir_y, dir_z).normalize();
-                    camera_transform.translation =
-                        camera_target.position + final_direction * base_distance;
-                    rotate_camera_towards(&mut camera_transform, camera_target.position, 1.0);
+                for camera_transform in camera_query.iter() {
+                    start_frame_transition(
+                        &mut transition,
+                        camera_transform,
+                        camera_target.position,
+                        target_transform.translation(),
+                        base_distance,
+                        camera_frame_pitch_rad,
+                    );
                 }
                 log!(
                     LogType::Editor,
@@ -669,13 +725,17 @@ pub fn mouse_button_iter(
     user_input: Res<UserInput>,
     movement_speed: Local<f32>,
     drag_state: Res<DragState>,
+    panes: Res<ViewportPanes>,
+    viewport_camera_state: Res<ViewportCameraState>,
 ) {
     if user_input.mouse_over_egui || drag_state.dragging {
         return;
     }
+    // Top, front and right panes only pan and zoom
+    let axis_locked = panes.active_view(&viewport_camera_state).is_axis_locked();
 
     if let Ok((mut window, mut cursor_options)) = primary_window.single_mut() {
-        if user_input.mouse_right.just_pressed {
+        if user_input.mouse_right.just_pressed && !axis_locked {
             cursor_options.visible = false;
             cursor_options.grab_mode = CursorGrabMode::Locked;
             input_state.initial_cursor_pos = window.cursor_position();
@@ -697,10 +757,11 @@ pub fn mouse_button_iter(
             &mut mouse_motion_events,
             &mut target_pos,
             time.delta_secs(),
+            axis_locked,
         );
     }
 
-    if user_input.mouse_right.pressed {
+    if user_input.mouse_right.pressed && !axis_locked {
         handle_movement(
             &mut query,
             &user_input,
@@ -723,6 +784,7 @@ fn handle_pan_or_rotation(
     mouse_motion_events: &mut MessageReader<MouseMotion>,
     target_pos: &mut ResMut<CameraTarget>,
     delta_time: f32,
+    pan_only: bool,
 ) {
     let pan_sensitivity = INPUT_CONFIG.pan_camera_sensitivity * delta_time;
     let rotate_sensitivity = INPUT_CONFIG.obit_camera_sensitivity * delta_time;
@@ -736,7 +798,7 @@ fn handle_pan_or_rotation(
         }
 
         if accumulated_delta.length_squared() > 0.0 {
-            if user_input.shift_left.pressed {
+            if user_input.shift_left.pressed || pan_only {
                 let right = camera_transform.right() * -accumulated_delta.x * pan_sensitivity;
                 let up = camera_transform.up() * accumulated_delta.y * pan_sensitivity;
 
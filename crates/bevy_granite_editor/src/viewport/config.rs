@@ -12,6 +12,8 @@ pub struct VisualizationConfig {
     pub debug_enabled: bool,
     pub debug_selected_only: bool,
     pub debug_relationship_lines: bool,
+    #[serde(default)]
+    pub debug_shadow_cascades: bool,
     pub debug_color: [f32; 3],
     pub debug_line_thickness: f32,
 
@@ -36,6 +38,7 @@ impl Default for VisualizationConfig {
             debug_enabled: true,
             debug_selected_only: true,
             debug_relationship_lines: true,
+            debug_shadow_cascades: false,
             debug_color: [0.8, 1.0, 0.0],
             debug_line_thickness: 0.75,
             icons_enabled: true,
@@ -1,12 +1,18 @@
 use super::DebugRenderer;
 use crate::editor_state::EditorState;
 use bevy::{
-    color::Color,
-    ecs::{entity::Entity, system::Query},
+    camera::{PerspectiveProjection, Projection},
+    color::{Alpha, Color},
+    ecs::{entity::Entity, hierarchy::ChildOf, system::Query},
     gizmos::gizmos::Gizmos,
-    light::{DirectionalLight, PointLight},
+    light::{CascadeShadowConfig, DirectionalLight, PointLight, SpotLight, SpotLightTexture},
+    math::{Isometry3d, Quat, Vec2, Vec3},
     prelude::{Res, With},
-    transform::components::GlobalTransform,
+    transform::components::{GlobalTransform, Transform},
+};
+use bevy_granite_core::{
+    entities::{LightProbeVolume, RectLightPanel},
+    GraniteTypes, IdentityData, MainCamera,
 };
 use bevy_granite_gizmos::Selected;
 
@@ -70,3 +76,275 @@ pub fn show_point_light_range_system(
         gizmos.sphere(pos, range, color);
     }
 }
+
+pub fn show_light_probe_bounds_system(
+    query: Query<(&ChildOf, &GlobalTransform), With<LightProbeVolume>>,
+    active_query: Query<Entity, With<Selected>>,
+    mut gizmos: Gizmos<DebugRenderer>,
+    editor_state: Res<EditorState>,
+) {
+    if !editor_state.active {
+        return;
+    }
+    let config = editor_state.config.viewport.visualizers;
+    if !config.debug_enabled {
+        return;
+    }
+    for (child_of, global_transform) in query.iter() {
+        if config.debug_selected_only {
+            match active_query.single() {
+                Ok(selected_entity) if selected_entity != child_of.parent() => continue,
+                Err(_) => return,
+                _ => {}
+            }
+        }
+        // The volume child is a unit cube scaled to the probe bounds
+        let color = Color::srgb_from_array(config.debug_color);
+        gizmos.cuboid(*global_transform, color);
+    }
+}
+
+pub fn show_rect_light_panel_system(
+    query: Query<(Entity, &GlobalTransform, &IdentityData), With<RectLightPanel>>,
+    active_query: Query<Entity, With<Selected>>,
+    mut gizmos: Gizmos<DebugRenderer>,
+    editor_state: Res<EditorState>,
+) {
+    if !editor_state.active {
+        return;
+    }
+    let config = editor_state.config.viewport.visualizers;
+    if !config.debug_enabled {
+        return;
+    }
+    for (entity, global_transform, identity_data) in query.iter() {
+        if config.debug_selected_only {
+            match active_query.single() {
+                Ok(selected_entity) if selected_entity != entity => continue,
+                Err(_) => return,
+                _ => {}
+            }
+        }
+        let GraniteTypes::RectLightData(rect_light) = &identity_data.class else {
+            continue;
+        };
+        let half = Vec2::new(rect_light.width, rect_light.height) * 0.5;
+        let corners = [
+            Vec3::new(-half.x, -half.y, 0.0),
+            Vec3::new(half.x, -half.y, 0.0),
+            Vec3::new(half.x, half.y, 0.0),
+            Vec3::new(-half.x, half.y, 0.0),
+        ]
+        .map(|corner| global_transform.transform_point(corner));
+        let color = Color::srgb_from_array(config.debug_color);
+        gizmos.linestrip(corners.into_iter().chain([corners[0]]), color);
+
+        // The panel only lights what is in front of it
+        let forward = global_transform.forward() * 0.3;
+        for corner in corners {
+            gizmos.line(corner, corner + forward, color);
+        }
+        let center = global_transform.translation();
+        gizmos.arrow(center, center + global_transform.forward() * 1.0, color);
+    }
+}
+
+pub fn show_spot_light_cookie_system(
+    query: Query<(Entity, &GlobalTransform, &SpotLight), With<SpotLightTexture>>,
+    active_query: Query<Entity, With<Selected>>,
+    mut gizmos: Gizmos<DebugRenderer>,
+    editor_state: Res<EditorState>,
+) {
+    if !editor_state.active {
+        return;
+    }
+    let config = editor_state.config.viewport.visualizers;
+    if !config.debug_enabled {
+        return;
+    }
+    for (entity, global_transform, spot_light) in query.iter() {
+        if config.debug_selected_only {
+            match active_query.single() {
+                Ok(selected_entity) if selected_entity != entity => continue,
+                Err(_) => return,
+                _ => {}
+            }
+        }
+        // The cookie covers the square around the outer cone at the end of the range
+        let (_, rotation, apex) = global_transform.to_scale_rotation_translation();
+        let distance = spot_light.range;
+        let half = spot_light.outer_angle.tan() * distance;
+        let corners = [
+            Vec3::new(-half, -half, -distance),
+            Vec3::new(half, -half, -distance),
+            Vec3::new(half, half, -distance),
+            Vec3::new(-half, half, -distance),
+        ]
+        .map(|corner| apex + rotation * corner);
+        let color = Color::srgb_from_array(config.debug_color);
+        gizmos.linestrip(corners.into_iter().chain([corners[0]]), color);
+        for corner in corners {
+            gizmos.line(apex, corner, color);
+        }
+    }
+}
+
+pub fn show_spot_light_cone_system(
+    query: Query<(Entity, &GlobalTransform, &SpotLight)>,
+    active_query: Query<Entity, With<Selected>>,
+    mut gizmos: Gizmos<DebugRenderer>,
+    editor_state: Res<EditorState>,
+) {
+    if !editor_state.active {
+        return;
+    }
+    let config = editor_state.config.viewport.visualizers;
+    if !config.debug_enabled {
+        return;
+    }
+    for (entity, global_transform, spot_light) in query.iter() {
+        if config.debug_selected_only {
+            match active_query.single() {
+                Ok(selected_entity) if selected_entity != entity => continue,
+                Err(_) => return,
+                _ => {}
+            }
+        }
+        let (_, rotation, apex) = global_transform.to_scale_rotation_translation();
+        let color = Color::srgb_from_array(config.debug_color);
+        draw_spot_cone(
+            &mut gizmos,
+            apex,
+            rotation,
+            spot_light.range,
+            spot_light.outer_angle,
+            color,
+        );
+        if spot_light.inner_angle > 0.0 && spot_light.inner_angle < spot_light.outer_angle {
+            draw_spot_cone(
+                &mut gizmos,
+                apex,
+                rotation,
+                spot_light.range,
+                spot_light.inner_angle,
+                color.with_alpha(0.4),
+            );
+        }
+        gizmos.line(
+            apex,
+            apex + rotation * Vec3::NEG_Z * spot_light.range,
+            color,
+        );
+    }
+}
+
+// Cone out to the range along the light's forward, closed by a circle where it ends
+fn draw_spot_cone(
+    gizmos: &mut Gizmos<DebugRenderer>,
+    apex: Vec3,
+    rotation: Quat,
+    range: f32,
+    angle: f32,
+    color: Color,
+) {
+    let length = range * angle.cos();
+    let radius = range * angle.sin();
+    let center = apex + rotation * Vec3::new(0.0, 0.0, -length);
+    gizmos.circle(Isometry3d::new(center, rotation), radius, color);
+    for direction in [Vec3::X, Vec3::NEG_X, Vec3::Y, Vec3::NEG_Y] {
+        gizmos.line(apex, center + rotation * direction * radius, color);
+    }
+}
+
+const CASCADE_COLORS: [Color; 4] = [
+    Color::srgb(1.0, 0.35, 0.35),
+    Color::srgb(0.35, 1.0, 0.35),
+    Color::srgb(0.35, 0.55, 1.0),
+    Color::srgb(1.0, 0.9, 0.35),
+];
+
+/// Splits the main camera's view into the directional light's cascades, and boxes roughly
+/// the area each cascade's shadow map covers
+pub fn show_directional_light_cascades_system(
+    light_query: Query<(
+        Entity,
+        &GlobalTransform,
+        &DirectionalLight,
+        &CascadeShadowConfig,
+    )>,
+    camera_query: Query<(&GlobalTransform, &Projection), With<MainCamera>>,
+    active_query: Query<Entity, With<Selected>>,
+    mut gizmos: Gizmos<DebugRenderer>,
+    editor_state: Res<EditorState>,
+) {
+    if !editor_state.active {
+        return;
+    }
+    let config = editor_state.config.viewport.visualizers;
+    if !config.debug_enabled || !config.debug_shadow_cascades {
+        return;
+    }
+    let Ok((camera_transform, Projection::Perspective(perspective))) = camera_query.single() else {
+        return;
+    };
+    for (entity, light_transform, directional_light, cascades) in light_query.iter() {
+        if !directional_light.shadows_enabled {
+            continue;
+        }
+        if config.debug_selected_only {
+            match active_query.single() {
+                Ok(selected_entity) if selected_entity != entity => continue,
+                Err(_) => return,
+                _ => {}
+            }
+        }
+        let light_rotation = light_transform.to_scale_rotation_translation().1;
+        for (index, far) in cascades.bounds.iter().enumerate() {
+            let near = match index {
+                0 => cascades.minimum_distance,
+                _ => cascades.bounds[index - 1] * (1.0 - cascades.overlap_proportion),
+            };
+            let near_corners = view_slice_corners(camera_transform, perspective, near);
+            let far_corners = view_slice_corners(camera_transform, perspective, *far);
+            let color = CASCADE_COLORS[index % CASCADE_COLORS.len()];
+
+            gizmos.linestrip(far_corners.into_iter().chain([far_corners[0]]), color);
+            for (near_corner, far_corner) in near_corners.iter().zip(far_corners) {
+                gizmos.line(*near_corner, far_corner, color.with_alpha(0.4));
+            }
+
+            // Shadow maps are fit around the slice's bounding sphere, facing the light
+            let corners = near_corners.iter().chain(far_corners.iter());
+            let center = corners.clone().sum::<Vec3>() / 8.0;
+            let diameter = corners
+                .map(|corner| corner.distance(center) * 2.0)
+                .fold(0.0, f32::max)
+                .ceil();
+            gizmos.cuboid(
+                Transform {
+                    translation: center,
+                    rotation: light_rotation,
+                    scale: Vec3::splat(diameter),
+                },
+                color.with_alpha(0.6),
+            );
+        }
+    }
+}
+
+// Corners of the camera's view at a depth, in world space
+fn view_slice_corners(
+    camera_transform: &GlobalTransform,
+    perspective: &PerspectiveProjection,
+    depth: f32,
+) -> [Vec3; 4] {
+    let half_height = (perspective.fov * 0.5).tan() * depth;
+    let half_width = half_height * perspective.aspect_ratio;
+    [
+        Vec3::new(-half_width, -half_height, -depth),
+        Vec3::new(half_width, -half_height, -depth),
+        Vec3::new(half_width, half_height, -depth),
+        Vec3::new(-half_width, half_height, -depth),
+    ]
+    .map(|corner| camera_transform.transform_point(corner))
+}
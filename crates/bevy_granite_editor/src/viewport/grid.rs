@@ -1,22 +1,111 @@
-use crate::{editor_state::EditorState, viewport::camera::LAYER_GRID};
+use crate::{
+    editor_state::EditorState,
+    viewport::{
+        camera::{ViewportCameraState, LAYER_GRID},
+        panes::ViewportPanes,
+    },
+};
 use bevy::{
-    color::Color,
+    color::{Alpha, Color},
     gizmos::gizmos::Gizmos,
     math::Vec3,
     prelude::{
-        Commands, Component, GlobalTransform, Name, Query, Res,
+        Commands, Component, GlobalTransform, Name, Query, Res, ResMut, Resource,
         Transform, Visibility, With,
     },
 };
 use bevy_granite_core::{TreeHiddenEntity, UICamera};
+use bevy_granite_gizmos::GizmoSnap;
+use serde::{Deserialize, Serialize};
 
 #[derive(Component)]
 pub struct ViewportGrid;
 
 const MIN_CELL_SIZE: f32 = 0.0001;
-const GRID_EPSILON: f32 = 0.0001;
-const GRID_HEIGHT_OFFSET: f32 = 0.0;
 const LINE_SEGMENT_LENGTH: f32 = 10.0; // Break lines into segments to avoid thickness issues
+// Past this many lines per direction the fine lines are just noise, so only majors are drawn
+const MAX_LINES_PER_AXIS: f32 = 800.0;
+const MINOR_LINE_ALPHA: f32 = 0.45;
+
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq, Serialize, Deserialize)]
+pub enum GridPlane {
+    #[default]
+    XZ,
+    XY,
+    YZ,
+}
+
+impl GridPlane {
+    pub fn all() -> Vec<Self> {
+        vec![Self::XZ, Self::XY, Self::YZ]
+    }
+
+    pub fn normal(&self) -> Vec3 {
+        match self {
+            Self::XZ => Vec3::Y,
+            Self::XY => Vec3::Z,
+            Self::YZ => Vec3::X,
+        }
+    }
+
+    /// The two in-plane directions grid lines run along
+    pub fn axes(&self) -> (Vec3, Vec3) {
+        match self {
+            Self::XZ => (Vec3::X, Vec3::Z),
+            Self::XY => (Vec3::X, Vec3::Y),
+            Self::YZ => (Vec3::Z, Vec3::Y),
+        }
+    }
+
+    /// Plane most square on to a view direction
+    pub fn facing(direction: Vec3) -> Self {
+        let abs = direction.abs();
+        if abs.y >= abs.x && abs.y >= abs.z {
+            Self::XZ
+        } else if abs.z >= abs.x {
+            Self::XY
+        } else {
+            Self::YZ
+        }
+    }
+}
+
+/// The grid the viewport is showing this frame, shared with anything that snaps to it
+#[derive(Resource, Clone, Copy, Debug)]
+pub struct ActiveGrid {
+    pub plane: GridPlane,
+    pub origin: Vec3,
+    /// Finest line spacing, the snap increment while snapping is on
+    pub spacing: f32,
+    /// Fine cells per major cell
+    pub subdivisions: u32,
+}
+
+impl Default for ActiveGrid {
+    fn default() -> Self {
+        Self {
+            plane: GridPlane::XZ,
+            origin: Vec3::ZERO,
+            spacing: 1.,
+            subdivisions: 1,
+        }
+    }
+}
+
+impl ActiveGrid {
+    pub fn project(&self, point: Vec3) -> Vec3 {
+        let normal = self.plane.normal();
+        point - normal * (point - self.origin).dot(normal)
+    }
+
+    /// Nearest grid intersection on the plane
+    pub fn snap(&self, point: Vec3) -> Vec3 {
+        let (u, v) = self.plane.axes();
+        let relative = point - self.origin;
+        let snap_axis = |axis: Vec3| (relative.dot(axis) / self.spacing).round() * self.spacing;
+        self.origin + u * snap_axis(u) + v * snap_axis(v)
+    }
+}
 
 pub fn spawn_viewport_grid(
     mut commands: Commands,
@@ -37,83 +126,127 @@ pub fn update_grid_system(
     mut grid_query: Query<&mut Visibility, With<ViewportGrid>>,
     camera_query: Query<&bevy::transform::components::GlobalTransform, With<UICamera>>,
     editor_state: Res<EditorState>,
+    gizmo_snap: Res<GizmoSnap>,
+    panes: Res<ViewportPanes>,
+    viewport_camera_state: Res<ViewportCameraState>,
+    mut active_grid: ResMut<ActiveGrid>,
 ) {
     let Ok(mut visibility) = grid_query.single_mut() else {
         return;
     };
 
-    if !editor_state.active || !editor_state.config.viewport.grid {
+    let Ok(camera_transform) = camera_query.single() else {
         *visibility = Visibility::Hidden;
         return;
-    }
+    };
 
-    let Ok(camera_transform) = camera_query.single() else {
+    let viewport = &editor_state.config.viewport;
+    let orthographic = panes.active_view(&viewport_camera_state).is_orthographic();
+    let subdivisions = viewport.grid_subdivisions.max(1);
+
+    // While snapping the fine lines sit on the snap increment, so the grid shows where a drag lands
+    let snap = gizmo_snap.transform_value;
+    let spacing = if snap > MIN_CELL_SIZE {
+        snap
+    } else {
+        viewport.grid_size.max(MIN_CELL_SIZE) / subdivisions as f32
+    };
+    let plane = if viewport.grid_align_to_ortho && orthographic {
+        GridPlane::facing(camera_transform.forward().as_vec3())
+    } else {
+        viewport.grid_plane
+    };
+    *active_grid = ActiveGrid {
+        plane,
+        origin: Vec3::from_array(viewport.grid_origin),
+        spacing,
+        subdivisions,
+    };
+
+    if !editor_state.active || !viewport.grid {
         *visibility = Visibility::Hidden;
         return;
-    };
+    }
 
-    let max_distance = editor_state.config.viewport.grid_distance.max(MIN_CELL_SIZE);
-    let cell_size = editor_state
-        .config
-        .viewport
-        .grid_size
-        .max(MIN_CELL_SIZE);
-    let color = editor_state.config.viewport.grid_color;
+    let max_distance = viewport.grid_distance.max(MIN_CELL_SIZE);
+    let color = viewport.grid_color;
     let grid_color = Color::linear_rgba(color[0], color[1], color[2], color[3]);
 
     *visibility = Visibility::Visible;
 
-    draw_grid_lines(&mut gizmos, camera_transform, max_distance, cell_size, grid_color);
+    draw_grid_lines(
+        &mut gizmos,
+        camera_transform,
+        &active_grid,
+        max_distance,
+        grid_color,
+        orthographic,
+    );
 }
 
 fn draw_grid_lines(
     gizmos: &mut Gizmos,
     camera_transform: &bevy::transform::components::GlobalTransform,
+    grid: &ActiveGrid,
     max_distance: f32,
-    cell_size: f32,
     color: Color,
+    orthographic: bool,
 ) {
+    let (u, v) = grid.plane.axes();
     let camera_pos = camera_transform.translation();
-    let center_x = camera_pos.x;
-    let center_z = camera_pos.z;
-
-    let start_x = center_x - max_distance;
-    let end_x = center_x + max_distance;
-    let start_z = center_z - max_distance;
-    let end_z = center_z + max_distance;
-
-    let first_x = (start_x / cell_size).floor() * cell_size;
-    let first_z = (start_z / cell_size).floor() * cell_size;
+    let relative = camera_pos - grid.origin;
+    let center_u = relative.dot(u);
+    let center_v = relative.dot(v);
+    // Ortho cameras sit far back, their height would cull the whole grid
+    let height = if orthographic {
+        0.0
+    } else {
+        relative.dot(grid.plane.normal())
+    };
 
-    let y = GRID_HEIGHT_OFFSET;
+    let (step, major_every) = line_spacing(grid.spacing, grid.subdivisions, max_distance);
+    let minor_color = color.with_alpha(color.alpha() * MINOR_LINE_ALPHA);
 
-    let mut x = first_x;
-    while x <= end_x + GRID_EPSILON {
-        let distance = (camera_pos - Vec3::new(x, 0.0, center_z)).length();
-        if distance <= max_distance + cell_size {
+    // Lines along v at each step of u, then the other way around
+    let directions = [(u, v, center_u, center_v), (v, u, center_v, center_u)];
+    for (axis, other, center, other_center) in directions {
+        let first = ((center - max_distance) / step).floor() as i64;
+        let last = ((center + max_distance) / step).ceil() as i64;
+        for index in first..=last {
+            let offset = index as f32 * step;
+            let distance = ((offset - center).powi(2) + height * height).sqrt();
+            if distance > max_distance + step {
+                continue;
+            }
+            let line_color = if index % major_every == 0 {
+                color
+            } else {
+                minor_color
+            };
+            let base = grid.origin + axis * offset;
             render_grid_line(
                 gizmos,
-                Vec3::new(x, y, start_z),
-                Vec3::new(x, y, end_z),
-                color,
+                base + other * (other_center - max_distance),
+                base + other * (other_center + max_distance),
+                line_color,
             );
         }
-        x += cell_size;
     }
+}
 
-    let mut z = first_z;
-    while z <= end_z + GRID_EPSILON {
-        let distance = (camera_pos - Vec3::new(center_x, 0.0, z)).length();
-        if distance <= max_distance + cell_size {
-            render_grid_line(
-                gizmos,
-                Vec3::new(start_x, y, z),
-                Vec3::new(end_x, y, z),
-                color,
-            );
-        }
-        z += cell_size;
+// Step between drawn lines and how many steps apart the major lines are
+// Too dense a grid drops to the majors, then keeps doubling until under the line cap
+fn line_spacing(spacing: f32, subdivisions: u32, max_distance: f32) -> (f32, i64) {
+    let mut step = spacing.max(MIN_CELL_SIZE);
+    let mut major_every = subdivisions.max(1) as i64;
+    if 2.0 * max_distance / step > MAX_LINES_PER_AXIS {
+        step *= major_every as f32;
+        major_every = 1;
+    }
+    while 2.0 * max_distance / step > MAX_LINES_PER_AXIS {
+        step *= 2.0;
     }
+    (step, major_every)
 }
 
 // Render line using segments to avoid thickness issues
@@ -125,9 +258,32 @@ fn render_grid_line(gizmos: &mut Gizmos, start: Vec3, end: Vec3, color: Color) {
     while current_distance < total_length {
         let segment_start = start + direction * current_distance;
         let segment_end = start + direction * (current_distance + LINE_SEGMENT_LENGTH).min(total_length);
-        
+
         gizmos.line(segment_start, segment_end, color);
-        
+
         current_distance += LINE_SEGMENT_LENGTH;
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_line_spacing_keeps_subdivisions_under_cap() {
+        assert_eq!(line_spacing(0.25, 4, 50.0), (0.25, 4));
+    }
+
+    #[test]
+    fn test_line_spacing_drops_to_majors() {
+        assert_eq!(line_spacing(0.5, 4, 250.0), (2.0, 1));
+    }
+
+    #[test]
+    fn test_line_spacing_doubles_without_subdivisions() {
+        let (step, major_every) = line_spacing(0.001, 1, 100.0);
+        assert_eq!(major_every, 1);
+        assert!(2.0 * 100.0 / step <= MAX_LINES_PER_AXIS);
+        assert!(2.0 * 100.0 / (step / 2.0) > MAX_LINES_PER_AXIS);
+    }
+}
@@ -0,0 +1,327 @@
+use crate::{
+    editor_state::EditorState,
+    history::gizmo_integration::PendingClassEditCommands,
+    viewport::{
+        camera::ViewportCameraState, measure::MeasureTool, panes::ViewportPanes, DebugRenderer,
+    },
+};
+use bevy::{
+    camera::Camera,
+    color::Color,
+    gizmos::gizmos::Gizmos,
+    math::{Isometry3d, Ray3d, Vec3},
+    prelude::{Entity, Query, Res, ResMut, Resource, With},
+    transform::components::GlobalTransform,
+};
+use bevy_granite_core::{
+    entities::editable::RequestEntityUpdateFromClass, GraniteType, GraniteTypes, IdentityData,
+    SpotLightData, UserInput,
+};
+use bevy_granite_gizmos::{ActiveSelection, ClickSelectionState};
+use std::f32::consts::FRAC_PI_2;
+
+/// How close (in screen pixels) the cursor has to be to grab a handle
+pub const CLASS_HANDLE_PICK_PIXELS: f32 = 10.0;
+// Handles keep roughly the same on screen size at any distance
+const HANDLE_SCALE: f32 = 0.01;
+// Probe bounds can't be dragged smaller than this
+const MIN_PROBE_SIZE: f32 = 0.01;
+const MIN_SPOT_RANGE: f32 = 0.1;
+
+const SPOT_RANGE_HANDLE: usize = 0;
+const SPOT_OUTER_HANDLE: usize = 1;
+const SPOT_INNER_HANDLE: usize = 2;
+
+const HANDLE_COLOR: Color = Color::srgb(0.3, 0.8, 1.0);
+const HANDLE_ACTIVE_COLOR: Color = Color::srgb(1.0, 0.85, 0.0);
+
+/// A point on the active entity that drags one class value along a world axis
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub struct ClassHandle {
+    pub id: usize,
+    /// Where the value is measured from
+    pub anchor: Vec3,
+    /// Direction the handle moves in, normalized
+    pub axis: Vec3,
+    /// World units per unit of the class value
+    pub scale: f32,
+    pub value: f32,
+}
+
+impl ClassHandle {
+    pub fn position(&self) -> Vec3 {
+        self.anchor + self.axis * self.value * self.scale
+    }
+}
+
+/// Handles for the classes that have any, empty for the rest
+pub fn class_handles(class: &GraniteTypes, transform: &GlobalTransform) -> Vec<ClassHandle> {
+    match class {
+        GraniteTypes::ReflectionProbe(probe) => bounds_handles(probe.size, transform),
+        GraniteTypes::IrradianceVolumeData(volume) => bounds_handles(volume.size, transform),
+        GraniteTypes::SpotLightData(spot_light) => spot_light_handles(spot_light, transform),
+        _ => Vec::new(),
+    }
+}
+
+/// Writes a dragged handle value back into the class, false when the handle doesn't belong to it
+pub fn set_class_handle_value(class: &mut GraniteTypes, id: usize, value: f32) -> bool {
+    match class {
+        GraniteTypes::ReflectionProbe(probe) => set_bounds_value(&mut probe.size, id, value),
+        GraniteTypes::IrradianceVolumeData(volume) => set_bounds_value(&mut volume.size, id, value),
+        GraniteTypes::SpotLightData(spot_light) => set_spot_light_value(spot_light, id, value),
+        _ => false,
+    }
+}
+
+// One handle per face, the value is half the size on that axis since bounds stay centered
+fn bounds_handles(size: (f32, f32, f32), transform: &GlobalTransform) -> Vec<ClassHandle> {
+    let half = Vec3::new(size.0, size.1, size.2) * 0.5;
+    let anchor = transform.translation();
+    (0..6)
+        .filter_map(|id| {
+            let axis_index = id / 2;
+            let sign = if id % 2 == 0 { 1.0 } else { -1.0 };
+            let direction = transform
+                .affine()
+                .transform_vector3(Vec3::AXES[axis_index] * sign);
+            let scale = direction.length();
+            (scale > f32::EPSILON).then(|| ClassHandle {
+                id,
+                anchor,
+                axis: direction / scale,
+                scale,
+                value: half[axis_index],
+            })
+        })
+        .collect()
+}
+
+fn set_bounds_value(size: &mut (f32, f32, f32), id: usize, value: f32) -> bool {
+    let axis = match id / 2 {
+        0 => &mut size.0,
+        1 => &mut size.1,
+        2 => &mut size.2,
+        _ => return false,
+    };
+    *axis = (value * 2.0).max(MIN_PROBE_SIZE);
+    true
+}
+
+// Range along the forward, then the outer and inner angle on the circle the cone is drawn with
+// That circle sits at range·cos(angle) with radius range·sin(angle), the radius is the value
+fn spot_light_handles(spot_light: &SpotLightData, transform: &GlobalTransform) -> Vec<ClassHandle> {
+    let (_, rotation, position) = transform.to_scale_rotation_translation();
+    let range = spot_light.range.max(MIN_SPOT_RANGE);
+    let angle_handle = |id: usize, axis: Vec3, angle: f32| {
+        let angle = angle.clamp(0.0, FRAC_PI_2);
+        ClassHandle {
+            id,
+            anchor: position + rotation * Vec3::NEG_Z * range * angle.cos(),
+            axis: rotation * axis,
+            scale: 1.0,
+            value: range * angle.sin(),
+        }
+    };
+    vec![
+        ClassHandle {
+            id: SPOT_RANGE_HANDLE,
+            anchor: position,
+            axis: rotation * Vec3::NEG_Z,
+            scale: 1.0,
+            value: range,
+        },
+        angle_handle(SPOT_OUTER_HANDLE, Vec3::Y, spot_light.outer_angle),
+        angle_handle(SPOT_INNER_HANDLE, Vec3::X, spot_light.inner_angle),
+    ]
+}
+
+fn set_spot_light_value(spot_light: &mut SpotLightData, id: usize, value: f32) -> bool {
+    let range = spot_light.range.max(MIN_SPOT_RANGE);
+    let angle = (value / range).clamp(0.0, 1.0).asin();
+    match id {
+        SPOT_RANGE_HANDLE => spot_light.range = value.max(MIN_SPOT_RANGE),
+        // The inner cone can't grow past the outer one
+        SPOT_OUTER_HANDLE => {
+            spot_light.outer_angle = angle;
+            spot_light.inner_angle = spot_light.inner_angle.min(angle);
+        }
+        SPOT_INNER_HANDLE => spot_light.inner_angle = angle.min(spot_light.outer_angle),
+        _ => return false,
+    }
+    true
+}
+
+/// Where the cursor ray passes closest to the handle's axis, as a distance from the anchor
+pub fn distance_along_axis(ray: Ray3d, anchor: Vec3, axis: Vec3) -> Option<f32> {
+    let offset = anchor - ray.origin;
+    let alignment = axis.dot(*ray.direction);
+    let denominator = 1.0 - alignment * alignment;
+    // Looking straight down the axis, there's nothing to drag along
+    if denominator < 1e-4 {
+        return None;
+    }
+    Some((alignment * ray.direction.dot(offset) - axis.dot(offset)) / denominator)
+}
+
+pub struct ClassHandleDrag {
+    pub entity: Entity,
+    pub handle: ClassHandle,
+    pub start_class: GraniteTypes,
+    // Keeps the handle from jumping to the cursor when grabbed off center
+    grab_offset: f32,
+}
+
+/// Hovered and dragged handle on the active entity
+#[derive(Resource, Default)]
+pub struct ClassHandleState {
+    pub hovered: Option<usize>,
+    pub drag: Option<ClassHandleDrag>,
+}
+
+/// Records a finished drag in the history if it changed anything
+fn finish_class_handle_drag(
+    drag: ClassHandleDrag,
+    class: &GraniteTypes,
+    pending: &mut PendingClassEditCommands,
+) {
+    if *class != drag.start_class {
+        pending.queue_class_edit(
+            drag.entity,
+            drag.start_class,
+            class.clone(),
+            format!("Drag {} handle", class.type_name()),
+        );
+    }
+}
+
+/// Draws the active entity's class handles and drags them with the left mouse button
+/// Every move goes to the entity right away, the whole drag is one undo step on release
+pub fn class_handle_system(
+    mut state: ResMut<ClassHandleState>,
+    measure_tool: Res<MeasureTool>,
+    mut click_selection: ResMut<ClickSelectionState>,
+    user_input: Res<UserInput>,
+    panes: Res<ViewportPanes>,
+    viewport_camera_state: Res<ViewportCameraState>,
+    camera_query: Query<(&Camera, &GlobalTransform)>,
+    active_query: Query<Entity, With<ActiveSelection>>,
+    mut identity_query: Query<(&GlobalTransform, &mut IdentityData)>,
+    mut request_update: RequestEntityUpdateFromClass,
+    mut pending: ResMut<PendingClassEditCommands>,
+    mut gizmos: Gizmos<DebugRenderer>,
+    editor_state: Res<EditorState>,
+) {
+    state.hovered = None;
+    let visualizers = editor_state.config.viewport.visualizers;
+    let blocked = measure_tool.active || !visualizers.debug_enabled;
+    let active = active_query.single().ok();
+    let drag_lost = state
+        .drag
+        .as_ref()
+        .is_some_and(|drag| Some(drag.entity) != active);
+    // A drag cut short still keeps what it changed as one undo step
+    if blocked || drag_lost {
+        if let Some(drag) = state.drag.take() {
+            if let Ok((_, identity)) = identity_query.get(drag.entity) {
+                finish_class_handle_drag(drag, &identity.class, &mut pending);
+            }
+        }
+    }
+    if blocked {
+        return;
+    }
+    let Some(entity) = active else {
+        return;
+    };
+    let Ok((transform, mut identity)) = identity_query.get_mut(entity) else {
+        return;
+    };
+    let handles = class_handles(&identity.class, transform);
+    if handles.is_empty() {
+        if let Some(drag) = state.drag.take() {
+            finish_class_handle_drag(drag, &identity.class, &mut pending);
+        }
+        return;
+    }
+    let Some((camera, camera_transform)) = panes
+        .controlled_camera(&viewport_camera_state)
+        .and_then(|camera| camera_query.get(camera).ok())
+    else {
+        return;
+    };
+    let ray = camera
+        .viewport_to_world(camera_transform, user_input.mouse_pos)
+        .ok();
+
+    if let Some(drag) = state.drag.take() {
+        if user_input.mouse_left.pressed {
+            let distance =
+                ray.and_then(|ray| distance_along_axis(ray, drag.handle.anchor, drag.handle.axis));
+            if let Some(distance) = distance {
+                let value = (distance - drag.grab_offset) / drag.handle.scale;
+                let mut class = identity.class.clone();
+                if set_class_handle_value(&mut class, drag.handle.id, value)
+                    && class != identity.class
+                {
+                    identity.class = class;
+                    identity.class.push_to_entity(entity, &mut request_update);
+                }
+            }
+            state.drag = Some(drag);
+        } else {
+            finish_class_handle_drag(drag, &identity.class, &mut pending);
+        }
+    } else if !user_input.mouse_over_egui {
+        state.hovered = handles
+            .iter()
+            .filter_map(|handle| {
+                let screen = camera
+                    .world_to_viewport(camera_transform, handle.position())
+                    .ok()?;
+                let pixels = screen.distance(user_input.mouse_pos);
+                (pixels <= CLASS_HANDLE_PICK_PIXELS).then_some((handle, pixels))
+            })
+            .min_by(|a, b| a.1.total_cmp(&b.1))
+            .map(|(handle, _)| handle.id);
+
+        if user_input.mouse_left.just_pressed {
+            let grabbed = handles
+                .iter()
+                .find(|handle| Some(handle.id) == state.hovered);
+            if let Some(handle) = grabbed {
+                let distance = ray
+                    .and_then(|ray| distance_along_axis(ray, handle.anchor, handle.axis))
+                    .unwrap_or(handle.value * handle.scale);
+                state.drag = Some(ClassHandleDrag {
+                    entity,
+                    handle: *handle,
+                    start_class: identity.class.clone(),
+                    grab_offset: distance - handle.value * handle.scale,
+                });
+            }
+        }
+    }
+
+    // Selection only gives clicks up while a handle is under the cursor or held
+    if (state.hovered.is_some() || state.drag.is_some()) && click_selection.enabled {
+        click_selection.enabled = false;
+    }
+
+    let camera_position = camera_transform.translation();
+    let active = state
+        .drag
+        .as_ref()
+        .map(|drag| drag.handle.id)
+        .or(state.hovered);
+    for handle in class_handles(&identity.class, transform) {
+        let position = handle.position();
+        let radius = camera_position.distance(position) * HANDLE_SCALE;
+        let color = if Some(handle.id) == active {
+            HANDLE_ACTIVE_COLOR
+        } else {
+            HANDLE_COLOR
+        };
+        gizmos.sphere(Isometry3d::from_translation(position), radius, color);
+    }
+}
@@ -0,0 +1,645 @@
+use crate::viewport::{
+    camera::ViewportCameraState, grid::ActiveGrid, panes::ViewportPanes, DebugRenderer,
+};
+use bevy::{
+    asset::Assets,
+    camera::Camera,
+    color::{Alpha, Color},
+    gizmos::gizmos::Gizmos,
+    math::{primitives::InfinitePlane3d, Isometry3d, Vec2, Vec3},
+    mesh::{Mesh, Mesh3d},
+    picking::{hover::HoverMap, pointer::PointerId},
+    prelude::{Entity, KeyCode, Query, Res, ResMut, Resource, With},
+    transform::components::GlobalTransform,
+    window::{PrimaryWindow, Window},
+};
+use bevy_egui::{egui, EguiContexts};
+use bevy_granite_core::{EditorIgnore, IconProxy, InputTypes, UserInput};
+use bevy_granite_gizmos::{gizmos::vertex::VertexMarker, ClickSelectionState, GizmoMesh};
+use bevy_granite_logging::{log, LogCategory, LogLevel, LogType};
+
+/// How close (in screen pixels) the cursor has to be to a mesh vertex to snap onto it
+pub const MEASURE_SNAP_PIXELS: f32 = 12.0;
+// Point markers keep roughly the same on screen size at any distance
+const POINT_MARKER_SCALE: f32 = 0.008;
+
+const RESULT_COLOR: Color = Color::srgb(1.0, 0.75, 0.2);
+const PREVIEW_COLOR: Color = Color::srgb(1.0, 0.95, 0.6);
+const VERTEX_SNAP_COLOR: Color = Color::srgb(1.0, 0.85, 0.0);
+const SURFACE_SNAP_COLOR: Color = Color::WHITE;
+const GRID_SNAP_COLOR: Color = Color::srgb(0.3, 0.8, 1.0);
+
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq)]
+pub enum MeasureMode {
+    #[default]
+    Distance,
+    Angle,
+    Height,
+}
+
+impl MeasureMode {
+    pub fn all() -> Vec<Self> {
+        vec![Self::Distance, Self::Angle, Self::Height]
+    }
+
+    pub fn label(&self) -> &'static str {
+        match self {
+            Self::Distance => "Distance",
+            Self::Angle => "Angle",
+            Self::Height => "Height",
+        }
+    }
+
+    pub fn point_count(&self) -> usize {
+        match self {
+            Self::Angle => 3,
+            Self::Distance | Self::Height => 2,
+        }
+    }
+
+    pub fn hint(&self) -> &'static str {
+        match self {
+            Self::Distance => "Click a start and an end point",
+            Self::Angle => "Click the first arm, the corner, then the second arm",
+            Self::Height => "Click a low and a high point",
+        }
+    }
+}
+
+/// What a measure point landed on
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum MeasureSnap {
+    Vertex,
+    Surface,
+    Grid,
+}
+
+impl MeasureSnap {
+    pub fn label(&self) -> &'static str {
+        match self {
+            Self::Vertex => "Vertex",
+            Self::Surface => "Surface",
+            Self::Grid => "Grid",
+        }
+    }
+
+    fn color(&self) -> Color {
+        match self {
+            Self::Vertex => VERTEX_SNAP_COLOR,
+            Self::Surface => SURFACE_SNAP_COLOR,
+            Self::Grid => GRID_SNAP_COLOR,
+        }
+    }
+}
+
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub struct MeasurePoint {
+    pub position: Vec3,
+    pub snap: MeasureSnap,
+}
+
+#[derive(Clone, Debug, PartialEq)]
+pub struct Measurement {
+    pub mode: MeasureMode,
+    pub points: Vec<Vec3>,
+}
+
+impl Measurement {
+    fn first(&self) -> Vec3 {
+        self.points.first().copied().unwrap_or_default()
+    }
+
+    fn last(&self) -> Vec3 {
+        self.points.last().copied().unwrap_or_default()
+    }
+
+    /// Straight line from the first to the last point
+    pub fn distance(&self) -> f32 {
+        self.first().distance(self.last())
+    }
+
+    /// Rise from the first to the last point, negative when it drops
+    pub fn height(&self) -> f32 {
+        self.last().y - self.first().y
+    }
+
+    /// Distance ignoring height, what a jump has to clear
+    pub fn horizontal(&self) -> f32 {
+        let delta = self.last() - self.first();
+        Vec2::new(delta.x, delta.z).length()
+    }
+
+    /// Angle at the middle point, in degrees
+    pub fn angle_degrees(&self) -> f32 {
+        if self.points.len() < 3 {
+            return 0.;
+        }
+        let first_arm = self.points[0] - self.points[1];
+        let second_arm = self.points[2] - self.points[1];
+        first_arm.angle_between(second_arm).to_degrees()
+    }
+
+    pub fn summary(&self) -> String {
+        match self.mode {
+            MeasureMode::Distance => {
+                let delta = self.last() - self.first();
+                format!(
+                    "{:.3} m  (x {:.3}, y {:.3}, z {:.3})",
+                    self.distance(),
+                    delta.x,
+                    delta.y,
+                    delta.z
+                )
+            }
+            MeasureMode::Angle => format!("{:.1}°", self.angle_degrees()),
+            MeasureMode::Height => format!(
+                "{:.3} m high, {:.3} m across",
+                self.height(),
+                self.horizontal()
+            ),
+        }
+    }
+
+    fn short_label(&self) -> String {
+        match self.mode {
+            MeasureMode::Distance => format!("{:.3} m", self.distance()),
+            MeasureMode::Angle => format!("{:.1}°", self.angle_degrees()),
+            MeasureMode::Height => format!("↕ {:.3} m", self.height()),
+        }
+    }
+
+    // Where the overlay label sits
+    fn label_anchor(&self) -> Vec3 {
+        match self.mode {
+            MeasureMode::Angle if self.points.len() >= 2 => self.points[1],
+            _ => (self.first() + self.last()) * 0.5,
+        }
+    }
+}
+
+/// Ruler for checking gameplay metrics in the viewport. While active, left clicks place
+/// points instead of selecting
+#[derive(Resource)]
+pub struct MeasureTool {
+    pub active: bool,
+    pub mode: MeasureMode,
+    pub snap_to_vertices: bool,
+    /// Falls back to the grid plane when the cursor is over empty space
+    pub snap_to_grid: bool,
+    pub points: Vec<MeasurePoint>,
+    pub hover: Option<MeasurePoint>,
+    pub results: Vec<Measurement>,
+}
+
+impl Default for MeasureTool {
+    fn default() -> Self {
+        Self {
+            active: false,
+            mode: MeasureMode::Distance,
+            snap_to_vertices: true,
+            snap_to_grid: true,
+            points: Vec::new(),
+            hover: None,
+            results: Vec::new(),
+        }
+    }
+}
+
+impl MeasureTool {
+    pub fn set_active(&mut self, active: bool) {
+        self.active = active;
+        self.points.clear();
+        self.hover = None;
+    }
+
+    pub fn set_mode(&mut self, mode: MeasureMode) {
+        if self.mode != mode {
+            self.mode = mode;
+            self.points.clear();
+        }
+    }
+
+    /// The measurement being placed, following the cursor for its next point
+    pub fn preview(&self) -> Option<Measurement> {
+        if self.points.is_empty() {
+            return None;
+        }
+        let mut points: Vec<Vec3> = self.points.iter().map(|point| point.position).collect();
+        if let Some(hover) = self.hover {
+            points.push(hover.position);
+        }
+        if points.len() < 2 {
+            return None;
+        }
+        Some(Measurement {
+            mode: self.mode,
+            points,
+        })
+    }
+}
+
+pub fn measure_tool_system(
+    mut tool: ResMut<MeasureTool>,
+    mut click_selection: ResMut<ClickSelectionState>,
+    user_input: Res<UserInput>,
+    hover_map: Res<HoverMap>,
+    panes: Res<ViewportPanes>,
+    viewport_camera_state: Res<ViewportCameraState>,
+    active_grid: Res<ActiveGrid>,
+    meshes: Res<Assets<Mesh>>,
+    camera_query: Query<(&Camera, &GlobalTransform)>,
+    marker_query: Query<&GlobalTransform, With<VertexMarker>>,
+    mesh_query: Query<(&Mesh3d, &GlobalTransform)>,
+    skip_query: Query<(
+        Option<&EditorIgnore>,
+        Option<&GizmoMesh>,
+        Option<&IconProxy>,
+    )>,
+) {
+    // Selection only gives clicks up while measuring
+    if click_selection.enabled == tool.active {
+        click_selection.enabled = !tool.active;
+    }
+    if !tool.active {
+        return;
+    }
+
+    if user_input
+        .current_button_inputs
+        .contains(&InputTypes::Button(KeyCode::Escape))
+    {
+        tool.points.clear();
+    }
+
+    let camera = panes
+        .controlled_camera(&viewport_camera_state)
+        .and_then(|entity| camera_query.get(entity).ok());
+
+    let hover = if user_input.mouse_over_egui {
+        None
+    } else {
+        find_measure_point(
+            &tool,
+            &user_input,
+            &hover_map,
+            camera,
+            &active_grid,
+            &meshes,
+            &marker_query,
+            &mesh_query,
+            &skip_query,
+        )
+    };
+    tool.hover = hover;
+
+    if !user_input.mouse_left.just_pressed || user_input.mouse_over_egui {
+        return;
+    }
+    let Some(point) = tool.hover else {
+        return;
+    };
+    tool.points.push(point);
+
+    if tool.points.len() >= tool.mode.point_count() {
+        let measurement = Measurement {
+            mode: tool.mode,
+            points: tool.points.drain(..).map(|point| point.position).collect(),
+        };
+        log!(
+            LogType::Editor,
+            LogLevel::Info,
+            LogCategory::Input,
+            "Measured {}: {}",
+            measurement.mode.label(),
+            measurement.summary()
+        );
+        tool.results.push(measurement);
+    }
+}
+
+// Vertex markers win, then the nearest surface (pulled onto a close vertex), then the grid
+fn find_measure_point(
+    tool: &MeasureTool,
+    user_input: &UserInput,
+    hover_map: &HoverMap,
+    camera: Option<(&Camera, &GlobalTransform)>,
+    active_grid: &ActiveGrid,
+    meshes: &Assets<Mesh>,
+    marker_query: &Query<&GlobalTransform, With<VertexMarker>>,
+    mesh_query: &Query<(&Mesh3d, &GlobalTransform)>,
+    skip_query: &Query<(
+        Option<&EditorIgnore>,
+        Option<&GizmoMesh>,
+        Option<&IconProxy>,
+    )>,
+) -> Option<MeasurePoint> {
+    let mut surface: Option<(Entity, f32, Vec3)> = None;
+    if let Some(hits) = hover_map.get(&PointerId::Mouse) {
+        for (entity, hit) in hits.iter() {
+            if let Ok(marker_transform) = marker_query.get(*entity) {
+                return Some(MeasurePoint {
+                    position: marker_transform.translation(),
+                    snap: MeasureSnap::Vertex,
+                });
+            }
+            let Some(position) = hit.position else {
+                continue;
+            };
+            if let Ok((ignore, gizmo, icon)) = skip_query.get(*entity) {
+                let ignored = ignore.is_some_and(|ignore| ignore.contains(EditorIgnore::PICKING));
+                if ignored || gizmo.is_some() || icon.is_some() {
+                    continue;
+                }
+            }
+            if surface.is_none_or(|(_, depth, _)| hit.depth < depth) {
+                surface = Some((*entity, hit.depth, position));
+            }
+        }
+    }
+
+    if let Some((entity, _, position)) = surface {
+        if tool.snap_to_vertices {
+            if let Some(vertex) =
+                nearest_mesh_vertex(entity, user_input.mouse_pos, camera, meshes, mesh_query)
+            {
+                return Some(MeasurePoint {
+                    position: vertex,
+                    snap: MeasureSnap::Vertex,
+                });
+            }
+        }
+        return Some(MeasurePoint {
+            position,
+            snap: MeasureSnap::Surface,
+        });
+    }
+
+    if !tool.snap_to_grid {
+        return None;
+    }
+    let (camera, camera_transform) = camera?;
+    let ray = camera
+        .viewport_to_world(camera_transform, user_input.mouse_pos)
+        .ok()?;
+    let distance = ray.intersect_plane(
+        active_grid.origin,
+        InfinitePlane3d::new(active_grid.plane.normal()),
+    )?;
+    Some(MeasurePoint {
+        position: active_grid.snap(ray.get_point(distance)),
+        snap: MeasureSnap::Grid,
+    })
+}
+
+// Closest vertex of the hovered mesh on screen, if the cursor is near enough to one
+fn nearest_mesh_vertex(
+    entity: Entity,
+    cursor: Vec2,
+    camera: Option<(&Camera, &GlobalTransform)>,
+    meshes: &Assets<Mesh>,
+    mesh_query: &Query<(&Mesh3d, &GlobalTransform)>,
+) -> Option<Vec3> {
+    let (camera, camera_transform) = camera?;
+    let (mesh_handle, mesh_transform) = mesh_query.get(entity).ok()?;
+    let mesh = meshes.get(&mesh_handle.0)?;
+    let positions = mesh.attribute(Mesh::ATTRIBUTE_POSITION)?.as_float3()?;
+
+    let mut nearest: Option<(f32, Vec3)> = None;
+    for position in positions {
+        let world = mesh_transform.transform_point(Vec3::from_array(*position));
+        let Ok(screen) = camera.world_to_viewport(camera_transform, world) else {
+            continue;
+        };
+        let pixels = screen.distance(cursor);
+        if pixels <= MEASURE_SNAP_PIXELS && nearest.is_none_or(|(best, _)| pixels < best) {
+            nearest = Some((pixels, world));
+        }
+    }
+    nearest.map(|(_, world)| world)
+}
+
+pub fn measure_overlay_system(
+    tool: Res<MeasureTool>,
+    mut gizmos: Gizmos<DebugRenderer>,
+    panes: Res<ViewportPanes>,
+    viewport_camera_state: Res<ViewportCameraState>,
+    camera_query: Query<&GlobalTransform, With<Camera>>,
+) {
+    if !tool.active {
+        return;
+    }
+    let Some(camera_position) = panes
+        .controlled_camera(&viewport_camera_state)
+        .and_then(|entity| camera_query.get(entity).ok())
+        .map(|transform| transform.translation())
+    else {
+        return;
+    };
+    let marker_radius = |point: Vec3| camera_position.distance(point) * POINT_MARKER_SCALE;
+
+    for measurement in tool.results.iter() {
+        draw_measurement(&mut gizmos, measurement, RESULT_COLOR, &marker_radius);
+    }
+    if let Some(preview) = tool.preview() {
+        draw_measurement(&mut gizmos, &preview, PREVIEW_COLOR, &marker_radius);
+    }
+    for point in tool.points.iter() {
+        gizmos.sphere(
+            Isometry3d::from_translation(point.position),
+            marker_radius(point.position),
+            PREVIEW_COLOR,
+        );
+    }
+    if let Some(hover) = tool.hover {
+        gizmos.sphere(
+            Isometry3d::from_translation(hover.position),
+            marker_radius(hover.position) * 1.5,
+            hover.snap.color(),
+        );
+    }
+}
+
+fn draw_measurement(
+    gizmos: &mut Gizmos<DebugRenderer>,
+    measurement: &Measurement,
+    color: Color,
+    marker_radius: &impl Fn(Vec3) -> f32,
+) {
+    for point in measurement.points.iter() {
+        gizmos.sphere(
+            Isometry3d::from_translation(*point),
+            marker_radius(*point),
+            color,
+        );
+    }
+    match measurement.mode {
+        MeasureMode::Distance => {
+            gizmos.line(measurement.first(), measurement.last(), color);
+        }
+        MeasureMode::Height => {
+            // Right triangle: across at the first point's height, then straight up
+            let (start, end) = (measurement.first(), measurement.last());
+            let corner = Vec3::new(end.x, start.y, end.z);
+            gizmos.line(start, corner, color.with_alpha(0.5));
+            gizmos.line(corner, end, color);
+            gizmos.line(start, end, color.with_alpha(0.3));
+        }
+        MeasureMode::Angle => {
+            for arm in measurement.points.windows(2) {
+                gizmos.line(arm[0], arm[1], color);
+            }
+            if measurement.points.len() >= 3 {
+                let corner = measurement.points[1];
+                let first_arm = measurement.points[0] - corner;
+                let second_arm = measurement.points[2] - corner;
+                let radius = first_arm.length().min(second_arm.length()) * 0.3;
+                if radius > f32::EPSILON {
+                    gizmos.short_arc_3d_between(
+                        corner,
+                        corner + first_arm.normalize() * radius,
+                        corner + second_arm.normalize() * radius,
+                        color,
+                    );
+                }
+            }
+        }
+    }
+}
+
+/// Labels next to each measurement plus the panel listing them
+pub fn measure_tool_ui_system(
+    mut contexts: EguiContexts,
+    mut tool: ResMut<MeasureTool>,
+    panes: Res<ViewportPanes>,
+    viewport_camera_state: Res<ViewportCameraState>,
+    camera_query: Query<(&Camera, &GlobalTransform)>,
+    window_query: Query<&Window, With<PrimaryWindow>>,
+) {
+    if !tool.active {
+        return;
+    }
+    let Ok(ctx) = contexts.ctx_mut() else {
+        return;
+    };
+
+    let camera = panes
+        .controlled_camera(&viewport_camera_state)
+        .and_then(|entity| camera_query.get(entity).ok());
+    // Camera projections come back in logical window pixels, egui may be zoomed on top
+    let logical_to_points = window_query
+        .single()
+        .map(|window| window.scale_factor() / ctx.pixels_per_point())
+        .unwrap_or(1.);
+
+    if let Some((camera, camera_transform)) = camera {
+        let painter = ctx.layer_painter(egui::LayerId::new(
+            egui::Order::Foreground,
+            egui::Id::new("measure_tool_labels"),
+        ));
+        let labels = tool
+            .results
+            .iter()
+            .map(|measurement| (measurement.clone(), RESULT_COLOR))
+            .chain(tool.preview().map(|preview| (preview, PREVIEW_COLOR)));
+        for (measurement, color) in labels {
+            let Ok(screen) = camera.world_to_viewport(camera_transform, measurement.label_anchor())
+            else {
+                continue;
+            };
+            let position = egui::pos2(screen.x, screen.y) * logical_to_points;
+            let [r, g, b, _] = color.to_srgba().to_u8_array();
+            let font = egui::FontId::proportional(14.);
+            // Dark copy underneath keeps the text readable on bright surfaces
+            painter.text(
+                position + egui::vec2(1., 1.),
+                egui::Align2::CENTER_BOTTOM,
+                measurement.short_label(),
+                font.clone(),
+                egui::Color32::from_black_alpha(200),
+            );
+            painter.text(
+                position,
+                egui::Align2::CENTER_BOTTOM,
+                measurement.short_label(),
+                font,
+                egui::Color32::from_rgb(r, g, b),
+            );
+        }
+    }
+
+    let mut open = true;
+    egui::Window::new("Measure")
+        .open(&mut open)
+        .resizable(true)
+        .default_width(320.)
+        .show(ctx, |ui| {
+            ui.horizontal(|ui| {
+                for mode in MeasureMode::all() {
+                    if ui
+                        .selectable_label(tool.mode == mode, mode.label())
+                        .clicked()
+                    {
+                        tool.set_mode(mode);
+                    }
+                }
+            });
+            ui.horizontal(|ui| {
+                ui.checkbox(&mut tool.snap_to_vertices, "Snap To Vertices");
+                ui.checkbox(&mut tool.snap_to_grid, "Grid Fallback");
+            });
+            ui.separator();
+
+            ui.label(tool.mode.hint());
+            ui.weak(format!(
+                "Point {} of {}  (Esc restarts)",
+                tool.points.len() + 1,
+                tool.mode.point_count()
+            ));
+            match tool.hover {
+                Some(hover) => ui.weak(format!(
+                    "Cursor: {} ({:.3}, {:.3}, {:.3})",
+                    hover.snap.label(),
+                    hover.position.x,
+                    hover.position.y,
+                    hover.position.z
+                )),
+                None => ui.weak("Cursor: nothing to measure"),
+            };
+            ui.separator();
+
+            if tool.results.is_empty() {
+                ui.weak("No measurements yet");
+                return;
+            }
+            let mut remove = None;
+            egui::ScrollArea::vertical()
+                .max_height(240.)
+                .show(ui, |ui| {
+                    for (index, measurement) in tool.results.iter().enumerate() {
+                        ui.horizontal(|ui| {
+                            if ui.small_button("x").on_hover_text("Remove").clicked() {
+                                remove = Some(index);
+                            }
+                            if ui.small_button("Copy").clicked() {
+                                ui.ctx().copy_text(measurement.summary());
+                            }
+                            ui.label(format!(
+                                "{}: {}",
+                                measurement.mode.label(),
+                                measurement.summary()
+                            ));
+                        });
+                    }
+                });
+            if let Some(index) = remove {
+                tool.results.remove(index);
+            }
+            if ui.button("Clear All").clicked() {
+                tool.results.clear();
+            }
+        });
+
+    if !open {
+        tool.set_active(false);
+    }
+}
@@ -1,12 +1,21 @@
+pub mod bookmarks;
 pub mod camera;
 pub mod config;
 pub mod debug;
 pub mod grid;
+pub mod handles;
 pub mod icons;
+pub mod measure;
+pub mod panes;
 pub mod plugin;
+pub mod preview;
 pub mod state;
 pub mod viewmode;
 
+pub use bookmarks::{
+    camera_bookmark_system, camera_transition_system, CameraBookmark, CameraBookmarkAction,
+    CameraTransition, CAMERA_BOOKMARK_SLOTS, CAMERA_TRANSITION_SECONDS,
+};
 pub use camera::{
     add_editor_camera,
     add_ui_camera,
@@ -20,18 +29,42 @@ pub use camera::{
     EditorViewportCamera,
     InputState,
     ViewportCameraState,
+    ViewportPaneCamera,
 };
 pub use state::ViewportState;
 
 pub use config::VisualizationConfig;
 pub use debug::{
     relationship_line_system, show_active_selection_bounds_system, show_camera_forward_system,
-    show_directional_light_forward_system, show_empty_origin_system, show_point_light_range_system,
-    show_selected_entities_bounds_system, DebugRenderer, SelectionRenderer,
+    show_directional_light_cascades_system, show_directional_light_forward_system,
+    show_empty_origin_system, show_light_probe_bounds_system, show_point_light_range_system,
+    show_rect_light_panel_system, show_selected_entities_bounds_system,
+    show_spot_light_cone_system, show_spot_light_cookie_system, DebugRenderer, SelectionRenderer,
+};
+pub use grid::{update_grid_system, ActiveGrid, GridPlane};
+pub use handles::{
+    class_handle_system, class_handles, distance_along_axis, set_class_handle_value, ClassHandle,
+    ClassHandleDrag, ClassHandleState, CLASS_HANDLE_PICK_PIXELS,
 };
-pub use grid::update_grid_system;
 pub use icons::{
     cleanup_icon_entities_system, spawn_icon_entities_system, update_icon_entities_system,
 };
+pub use measure::{
+    measure_overlay_system, measure_tool_system, measure_tool_ui_system, MeasureMode,
+    MeasurePoint, MeasureSnap, MeasureTool, Measurement, MEASURE_SNAP_PIXELS,
+};
+pub use panes::{
+    cleanup_viewport_pane_cameras_system, select_active_viewport_pane_system,
+    sync_viewport_pane_cameras_system, viewport_pane_headers_ui_system, PaneView, ViewportLayout,
+    ViewportPaneLayout, ViewportPanes, MAX_VIEWPORT_PANES,
+};
 pub use plugin::ViewportPlugin;
-pub use viewmode::{cleanup_scene_light_system, scene_light_system, SceneLightState};
+pub use preview::{
+    camera_preview_ui_system, cleanup_camera_preview_system, update_camera_preview_system,
+    CameraPreview, CameraPreviewCamera,
+};
+pub use viewmode::{
+    cleanup_scene_light_system, cleanup_view_mode_system, scene_light_system,
+    view_mode_bounds_system, view_mode_lights_system, view_mode_system, SceneLightState, ViewMode,
+    ViewModeMaterial, ViewModeProxy, ViewModeState,
+};
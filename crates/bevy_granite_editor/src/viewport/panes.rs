@@ -0,0 +1,542 @@
+use super::bookmarks::CameraTransition;
+use super::camera::{
+    scene_layers, CameraTarget, EditorViewportCamera, ViewportCameraState, ViewportPaneCamera,
+};
+use bevy::{
+    camera::{
+        Camera, Camera3d, OrthographicProjection, PerspectiveProjection, Projection, ScalingMode,
+    },
+    core_pipeline::tonemapping::Tonemapping,
+    ecs::{
+        component::Component,
+        entity::Entity,
+        resource::Resource,
+        system::{Commands, Query, Res, ResMut},
+    },
+    math::{Quat, Rect, Vec2, Vec3},
+    picking::Pickable,
+    prelude::{Name, Transform, Window, With, Without},
+    window::PrimaryWindow,
+};
+use bevy_egui::{egui, EguiContexts};
+use bevy_granite_core::{EditorIgnore, TreeHiddenEntity, UICamera, UserInput};
+use bevy_granite_gizmos::DragState;
+use bevy_granite_logging::{log, LogCategory, LogLevel, LogType};
+use serde::{Deserialize, Serialize};
+
+pub const MAX_VIEWPORT_PANES: usize = 4;
+
+// Ortho panes size themselves from the distance to their target so zoom and gizmo scaling
+// behave the same as in a perspective pane
+const ORTHO_CLIP_DEPTH: f32 = 10000.;
+const DEFAULT_PANE_DISTANCE: f32 = 10.;
+
+/// How the viewport area is split into panes
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Serialize, Deserialize, Default)]
+pub enum ViewportLayout {
+    #[default]
+    Single,
+    SideBySide,
+    Stacked,
+    Quad,
+}
+
+impl ViewportLayout {
+    pub fn all() -> Vec<Self> {
+        vec![Self::Single, Self::SideBySide, Self::Stacked, Self::Quad]
+    }
+
+    pub fn label(&self) -> &'static str {
+        match self {
+            Self::Single => "Single",
+            Self::SideBySide => "Two Side By Side",
+            Self::Stacked => "Two Stacked",
+            Self::Quad => "Four",
+        }
+    }
+
+    pub fn pane_count(&self) -> usize {
+        match self {
+            Self::Single => 1,
+            Self::SideBySide | Self::Stacked => 2,
+            Self::Quad => 4,
+        }
+    }
+
+    /// Pane rects inside the viewport area, left to right then top to bottom
+    pub fn split(&self, rect: Rect) -> Vec<Rect> {
+        let center = rect.center();
+        match self {
+            Self::Single => vec![rect],
+            Self::SideBySide => vec![
+                Rect::from_corners(rect.min, Vec2::new(center.x, rect.max.y)),
+                Rect::from_corners(Vec2::new(center.x, rect.min.y), rect.max),
+            ],
+            Self::Stacked => vec![
+                Rect::from_corners(rect.min, Vec2::new(rect.max.x, center.y)),
+                Rect::from_corners(Vec2::new(rect.min.x, center.y), rect.max),
+            ],
+            Self::Quad => vec![
+                Rect::from_corners(rect.min, center),
+                Rect::from_corners(
+                    Vec2::new(center.x, rect.min.y),
+                    Vec2::new(rect.max.x, center.y),
+                ),
+                Rect::from_corners(
+                    Vec2::new(rect.min.x, center.y),
+                    Vec2::new(center.x, rect.max.y),
+                ),
+                Rect::from_corners(center, rect.max),
+            ],
+        }
+    }
+}
+
+/// Projection and direction a pane looks with
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Serialize, Deserialize, Default)]
+pub enum PaneView {
+    #[default]
+    Perspective,
+    Top,
+    Front,
+    Right,
+    /// Orthographic projection that can still orbit
+    Orthographic,
+}
+
+impl PaneView {
+    pub fn all() -> Vec<Self> {
+        vec![
+            Self::Perspective,
+            Self::Top,
+            Self::Front,
+            Self::Right,
+            Self::Orthographic,
+        ]
+    }
+
+    pub fn label(&self) -> &'static str {
+        match self {
+            Self::Perspective => "Perspective",
+            Self::Top => "Top",
+            Self::Front => "Front",
+            Self::Right => "Right",
+            Self::Orthographic => "Orthographic",
+        }
+    }
+
+    pub fn is_orthographic(&self) -> bool {
+        !matches!(self, Self::Perspective)
+    }
+
+    /// Forward and up for views locked to an axis
+    pub fn fixed_direction(&self) -> Option<(Vec3, Vec3)> {
+        match self {
+            Self::Top => Some((Vec3::NEG_Y, Vec3::NEG_Z)),
+            Self::Front => Some((Vec3::NEG_Z, Vec3::Y)),
+            Self::Right => Some((Vec3::NEG_X, Vec3::Y)),
+            _ => None,
+        }
+    }
+
+    /// Axis locked views can only pan and zoom
+    pub fn is_axis_locked(&self) -> bool {
+        self.fixed_direction().is_some()
+    }
+
+    fn fixed_rotation(&self) -> Option<Quat> {
+        self.fixed_direction()
+            .map(|(forward, up)| Transform::default().looking_to(forward, up).rotation)
+    }
+}
+
+/// Saved with the dock layout
+#[derive(Clone, Debug, PartialEq, Serialize, Deserialize)]
+pub struct ViewportPaneLayout {
+    pub layout: ViewportLayout,
+    pub views: [PaneView; MAX_VIEWPORT_PANES],
+}
+
+impl Default for ViewportPaneLayout {
+    fn default() -> Self {
+        Self {
+            layout: ViewportLayout::Single,
+            views: [
+                PaneView::Perspective,
+                PaneView::Top,
+                PaneView::Front,
+                PaneView::Right,
+            ],
+        }
+    }
+}
+
+/// View last applied to a pane camera
+#[derive(Component)]
+pub struct AppliedPaneView(pub PaneView);
+
+/// Split viewport state. The first pane draws the main viewport camera (editor camera or
+/// the scene camera overriding it), the others get their own editor cameras
+#[derive(Resource, Default)]
+pub struct ViewportPanes {
+    pub layout: ViewportPaneLayout,
+    /// Pane the camera controls and gizmos follow
+    pub active: usize,
+    cameras: [Option<Entity>; MAX_VIEWPORT_PANES],
+    /// Orbit target per pane, the active pane's target lives in CameraTarget
+    targets: [Vec3; MAX_VIEWPORT_PANES],
+    /// Physical pixel rects from the last layout pass
+    pub rects: Vec<Rect>,
+}
+
+impl ViewportPanes {
+    pub fn pane_count(&self) -> usize {
+        self.layout.layout.pane_count()
+    }
+
+    pub fn camera(
+        &self,
+        index: usize,
+        viewport_camera_state: &ViewportCameraState,
+    ) -> Option<Entity> {
+        if index == 0 {
+            viewport_camera_state.active_camera()
+        } else {
+            self.cameras.get(index).copied().flatten()
+        }
+    }
+
+    /// Camera driven by the editor camera controls
+    pub fn controlled_camera(&self, viewport_camera_state: &ViewportCameraState) -> Option<Entity> {
+        self.camera(self.active, viewport_camera_state)
+    }
+
+    /// Scene cameras overriding the first pane keep their own projection
+    pub fn view(&self, index: usize, viewport_camera_state: &ViewportCameraState) -> PaneView {
+        if index == 0 && !viewport_camera_state.is_using_editor() {
+            return PaneView::Perspective;
+        }
+        self.layout.views[index.min(MAX_VIEWPORT_PANES - 1)]
+    }
+
+    pub fn active_view(&self, viewport_camera_state: &ViewportCameraState) -> PaneView {
+        self.view(self.active, viewport_camera_state)
+    }
+
+    pub fn set_layout(&mut self, layout: ViewportPaneLayout) {
+        self.layout = layout;
+    }
+
+    /// Pane under a physical pixel position
+    pub fn pane_at(&self, physical_position: Vec2) -> Option<usize> {
+        self.rects
+            .iter()
+            .position(|rect| rect.contains(physical_position))
+    }
+}
+
+fn pane_projection(view: PaneView, distance: f32) -> Projection {
+    if !view.is_orthographic() {
+        return Projection::Perspective(PerspectiveProjection::default());
+    }
+    // Match the height a default perspective camera sees at this distance
+    let fov = PerspectiveProjection::default().fov;
+    Projection::Orthographic(OrthographicProjection {
+        near: -ORTHO_CLIP_DEPTH,
+        far: ORTHO_CLIP_DEPTH,
+        scaling_mode: ScalingMode::FixedVertical {
+            viewport_height: 2.0 * distance.max(0.01) * (fov * 0.5).tan(),
+        },
+        ..OrthographicProjection::default_3d()
+    })
+}
+
+/// Place a camera for an axis locked view, keeping its distance from the target
+fn apply_fixed_view(transform: &mut Transform, view: PaneView, target: Vec3) {
+    let Some(rotation) = view.fixed_rotation() else {
+        return;
+    };
+    let distance = transform.translation.distance(target).max(0.01);
+    transform.rotation = rotation;
+    transform.translation = target - (rotation * Vec3::NEG_Z) * distance;
+}
+
+/// Switch which pane the camera controls drive
+fn activate_pane(
+    panes: &mut ViewportPanes,
+    index: usize,
+    viewport_camera_state: &ViewportCameraState,
+    camera_target: &mut CameraTarget,
+    ui_camera: &mut Transform,
+    cameras: &Query<&Transform, (With<Camera3d>, Without<UICamera>)>,
+) {
+    let previous = panes.active;
+    panes.targets[previous] = camera_target.position;
+    panes.active = index;
+    camera_target.position = panes.targets[index];
+
+    if let Some(transform) = panes
+        .camera(index, viewport_camera_state)
+        .and_then(|camera| cameras.get(camera).ok())
+    {
+        ui_camera.translation = transform.translation;
+        ui_camera.rotation = transform.rotation;
+    }
+}
+
+/// Clicking in a pane makes it the one the camera controls and gizmos use
+pub fn select_active_viewport_pane_system(
+    user_input: Res<UserInput>,
+    drag_state: Res<DragState>,
+    mut panes: ResMut<ViewportPanes>,
+    viewport_camera_state: Res<ViewportCameraState>,
+    mut camera_target: ResMut<CameraTarget>,
+    mut transition: ResMut<CameraTransition>,
+    mut ui_camera_query: Query<&mut Transform, With<UICamera>>,
+    cameras: Query<&Transform, (With<Camera3d>, Without<UICamera>)>,
+    window_query: Query<&Window, With<PrimaryWindow>>,
+) {
+    let Ok(mut ui_camera) = ui_camera_query.single_mut() else {
+        return;
+    };
+
+    if panes.active >= panes.pane_count() {
+        activate_pane(
+            &mut panes,
+            0,
+            &viewport_camera_state,
+            &mut camera_target,
+            &mut ui_camera,
+            &cameras,
+        );
+        return;
+    }
+
+    let pressed = user_input.mouse_left.just_pressed
+        || user_input.mouse_right.just_pressed
+        || user_input.mouse_middle.just_pressed;
+    if !pressed || user_input.mouse_over_egui || drag_state.dragging {
+        return;
+    }
+    let Ok(window) = window_query.single() else {
+        return;
+    };
+    let Some(index) = panes.pane_at(user_input.mouse_pos * window.scale_factor()) else {
+        return;
+    };
+    if index != panes.active {
+        // A bookmark or frame in flight belongs to the pane being left
+        transition.cancel();
+        activate_pane(
+            &mut panes,
+            index,
+            &viewport_camera_state,
+            &mut camera_target,
+            &mut ui_camera,
+            &cameras,
+        );
+    }
+}
+
+/// Spawn and remove pane cameras for the layout and keep their projections up to date
+pub fn sync_viewport_pane_cameras_system(
+    mut commands: Commands,
+    mut panes: ResMut<ViewportPanes>,
+    viewport_camera_state: Res<ViewportCameraState>,
+    camera_target: Res<CameraTarget>,
+    mut ui_camera_query: Query<&mut Transform, With<UICamera>>,
+    mut pane_cameras: Query<
+        (
+            &mut Transform,
+            Option<&mut Projection>,
+            Option<&AppliedPaneView>,
+        ),
+        (With<Camera3d>, Without<UICamera>),
+    >,
+    existing: Query<Entity, With<ViewportPaneCamera>>,
+    editor_camera: Query<Entity, With<EditorViewportCamera>>,
+) {
+    let pane_count = panes.pane_count();
+
+    for index in 1..MAX_VIEWPORT_PANES {
+        let camera = panes.cameras[index].filter(|camera| existing.contains(*camera));
+        match (index < pane_count, camera) {
+            (true, None) => {
+                let view = panes.layout.views[index];
+                let target = panes.targets[index];
+                let mut transform = Transform::from_translation(
+                    target + Vec3::new(2.0, 2.5, 5.0).normalize() * DEFAULT_PANE_DISTANCE,
+                )
+                .looking_at(target, Vec3::Y);
+                apply_fixed_view(&mut transform, view, target);
+                let camera = commands
+                    .spawn((
+                        transform,
+                        Camera3d::default(),
+                        Camera {
+                            // Below the main viewport camera so the overlays stay on top
+                            order: -(index as isize),
+                            ..Default::default()
+                        },
+                        pane_projection(view, DEFAULT_PANE_DISTANCE),
+                        AppliedPaneView(view),
+                        Name::new(format!("Editor Viewport Pane {}", index + 1)),
+                        Tonemapping::None,
+                        Pickable {
+                            should_block_lower: false,
+                            is_hoverable: false,
+                        },
+                        EditorIgnore::PICKING,
+                        TreeHiddenEntity,
+                        ViewportPaneCamera { index },
+                        scene_layers(),
+                    ))
+                    .id();
+                panes.cameras[index] = Some(camera);
+                log!(
+                    LogType::Editor,
+                    LogLevel::Info,
+                    LogCategory::System,
+                    "Spawned viewport pane {} camera ({})",
+                    index + 1,
+                    view.label()
+                );
+            }
+            (false, Some(camera)) => {
+                commands.entity(camera).despawn();
+                panes.cameras[index] = None;
+            }
+            (false, None) => panes.cameras[index] = None,
+            (true, Some(_)) => {}
+        }
+    }
+
+    // Only the editor camera takes a view in the first pane, overrides keep their own
+    let editor_camera = editor_camera.single().ok();
+    for index in 0..pane_count {
+        let Some(camera) = panes.camera(index, &viewport_camera_state) else {
+            continue;
+        };
+        if index == 0 && Some(camera) != editor_camera {
+            continue;
+        }
+        let Ok((mut transform, projection, applied)) = pane_cameras.get_mut(camera) else {
+            continue;
+        };
+        let view = panes.view(index, &viewport_camera_state);
+        let target = if index == panes.active {
+            camera_target.position
+        } else {
+            panes.targets[index]
+        };
+
+        if applied.map(|applied| applied.0) != Some(view) {
+            if index == panes.active {
+                // The ui camera drives this pane, move it instead so the sync keeps the view
+                if let Ok(mut ui_camera) = ui_camera_query.single_mut() {
+                    apply_fixed_view(&mut ui_camera, view, target);
+                }
+            }
+            apply_fixed_view(&mut transform, view, target);
+            commands.entity(camera).insert(AppliedPaneView(view));
+        }
+
+        // Framing and zoom move the ui camera freely, axis locked views stay on their axis
+        if index == panes.active && view.is_axis_locked() {
+            if let Ok(mut ui_camera) = ui_camera_query.single_mut() {
+                apply_fixed_view(&mut ui_camera, view, target);
+            }
+        }
+
+        let distance = transform.translation.distance(target);
+        let wanted = pane_projection(view, distance);
+        match projection {
+            Some(mut projection) => {
+                let same_kind = matches!(
+                    (&*projection, &wanted),
+                    (Projection::Perspective(_), Projection::Perspective(_))
+                        | (Projection::Orthographic(_), Projection::Orthographic(_))
+                );
+                // Leave perspective settings alone once set
+                if !same_kind || view.is_orthographic() {
+                    *projection = wanted;
+                }
+            }
+            None => {
+                commands.entity(camera).insert(wanted);
+            }
+        }
+    }
+}
+
+/// Pane cameras only exist while the editor is open
+pub fn cleanup_viewport_pane_cameras_system(
+    mut commands: Commands,
+    mut panes: ResMut<ViewportPanes>,
+    existing: Query<Entity, With<ViewportPaneCamera>>,
+) {
+    for camera in existing.iter() {
+        commands.entity(camera).despawn();
+    }
+    panes.cameras = [None; MAX_VIEWPORT_PANES];
+}
+
+/// Small view picker in the corner of each pane and an outline on the active one
+pub fn viewport_pane_headers_ui_system(
+    mut contexts: EguiContexts,
+    mut panes: ResMut<ViewportPanes>,
+    viewport_camera_state: Res<ViewportCameraState>,
+) {
+    let Ok(ctx) = contexts.ctx_mut() else { return };
+    let scale = ctx.pixels_per_point();
+    let pane_count = panes.pane_count();
+    let rects: Vec<egui::Rect> = panes
+        .rects
+        .iter()
+        .take(pane_count)
+        .map(|rect| {
+            egui::Rect::from_min_max(
+                egui::pos2(rect.min.x / scale, rect.min.y / scale),
+                egui::pos2(rect.max.x / scale, rect.max.y / scale),
+            )
+        })
+        .collect();
+
+    for (index, rect) in rects.iter().enumerate() {
+        if pane_count > 1 {
+            let stroke = if index == panes.active {
+                ctx.style().visuals.selection.stroke
+            } else {
+                ctx.style().visuals.widgets.noninteractive.bg_stroke
+            };
+            ctx.layer_painter(egui::LayerId::background()).rect_stroke(
+                *rect,
+                0.0,
+                stroke,
+                egui::StrokeKind::Inside,
+            );
+        }
+
+        egui::Area::new(egui::Id::new(("viewport_pane_header", index)))
+            .order(egui::Order::Middle)
+            .fixed_pos(rect.min + egui::vec2(6., 6.))
+            .show(ctx, |ui| {
+                if index == 0 && !viewport_camera_state.is_using_editor() {
+                    ui.weak("Scene Camera");
+                    return;
+                }
+                let mut view = panes.layout.views[index];
+                egui::ComboBox::from_id_salt(("viewport_pane_view", index))
+                    .selected_text(view.label())
+                    .width(110.)
+                    .show_ui(ui, |ui| {
+                        for option in PaneView::all() {
+                            ui.selectable_value(&mut view, option, option.label());
+                        }
+                    });
+                if view != panes.layout.views[index] {
+                    panes.layout.views[index] = view;
+                }
+            });
+    }
+}
@@ -1,3 +1,4 @@
+use super::bookmarks::{camera_bookmark_system, camera_transition_system, CameraTransition};
 use super::camera::{
     add_editor_camera, add_gizmo_overlay_camera, add_ui_camera, camera_frame_system,
     camera_sync_toggle_system, enforce_viewport_camera_state, gizmo_layers, grid_layers,
@@ -5,25 +6,46 @@ use super::camera::{
     sync_cameras_system, sync_gizmo_camera_state, update_viewport_camera_viewports_system,
     CameraSyncState, CameraTarget, InputState, ViewportCameraState,
 };
-use super::viewmode::{cleanup_scene_light_system, scene_light_system, SceneLightState};
+use super::handles::{class_handle_system, ClassHandleState};
+use super::measure::{
+    measure_overlay_system, measure_tool_system, measure_tool_ui_system, MeasureTool,
+};
+use super::panes::{
+    cleanup_viewport_pane_cameras_system, select_active_viewport_pane_system,
+    sync_viewport_pane_cameras_system, viewport_pane_headers_ui_system, ViewportPanes,
+};
+use super::preview::{
+    camera_preview_ui_system, cleanup_camera_preview_system, update_camera_preview_system,
+    CameraPreview,
+};
+use super::viewmode::{
+    cleanup_scene_light_system, cleanup_view_mode_system, scene_light_system,
+    view_mode_bounds_system, view_mode_lights_system, view_mode_system, SceneLightState,
+    ViewModeMaterial, ViewModeState,
+};
 use crate::{
     setup::is_editor_active,
     viewport::{
-        cleanup_icon_entities_system, grid::{spawn_viewport_grid, update_grid_system},
+        cleanup_icon_entities_system,
+        grid::{spawn_viewport_grid, update_grid_system, ActiveGrid},
         icons::register_embedded_class_icons, relationship_line_system,
         show_active_selection_bounds_system, show_camera_forward_system,
-        show_directional_light_forward_system, show_empty_origin_system,
-        show_point_light_range_system, show_selected_entities_bounds_system,
-        spawn_icon_entities_system, update_icon_entities_system, DebugRenderer, SelectionRenderer,
+        show_directional_light_cascades_system, show_directional_light_forward_system,
+        show_empty_origin_system, show_light_probe_bounds_system, show_point_light_range_system,
+        show_rect_light_panel_system, show_selected_entities_bounds_system,
+        show_spot_light_cone_system, show_spot_light_cookie_system, spawn_icon_entities_system,
+        update_icon_entities_system, DebugRenderer, SelectionRenderer,
     },
 };
 use bevy::{
     app::{PostUpdate, Startup},
+    asset::embedded_asset,
     ecs::schedule::{common_conditions::not, ApplyDeferred, IntoScheduleConfigs}, // from #78
     gizmos::{
         config::{DefaultGizmoConfigGroup, GizmoConfig},
         AppGizmoBuilder,
     },
+    pbr::MaterialPlugin,
     prelude::{App, Plugin, Update},
     transform::TransformSystems,
 };
@@ -32,7 +54,13 @@ use bevy_egui::EguiPrimaryContextPass;
 pub struct ViewportPlugin;
 impl Plugin for ViewportPlugin {
     fn build(&self, app: &mut App) {
+        embedded_asset!(app, "viewmode.wgsl");
+
         app
+            //
+            // View mode proxy shading
+            //
+            .add_plugins(MaterialPlugin::<ViewModeMaterial>::default())
             //
             // Resources
             //
@@ -41,6 +69,13 @@ impl Plugin for ViewportPlugin {
             .insert_resource(InputState::default()) // FIX: Use UserInput
             .insert_resource(ViewportCameraState::default())
             .insert_resource(SceneLightState::default())
+            .insert_resource(ViewportPanes::default())
+            .insert_resource(CameraTransition::default())
+            .insert_resource(ViewModeState::default())
+            .insert_resource(ActiveGrid::default())
+            .insert_resource(MeasureTool::default())
+            .insert_resource(ClassHandleState::default())
+            .insert_resource(CameraPreview::default())
             //
             // Debug gizmo groups/config
             //
@@ -86,11 +121,36 @@ impl Plugin for ViewportPlugin {
                     .chain(),
             )
             .add_systems(Update, update_grid_system.run_if(is_editor_active))
-            .add_systems(Update, mouse_button_iter.run_if(is_editor_active)) // FIX: Use UserInput
-            .add_systems(Update, camera_frame_system.run_if(is_editor_active))
+            .add_systems(
+                Update,
+                (
+                    select_active_viewport_pane_system,
+                    sync_viewport_pane_cameras_system,
+                    mouse_button_iter,
+                )
+                    .chain()
+                    .run_if(is_editor_active),
+            ) // FIX: Use UserInput
+            .add_systems(
+                Update,
+                cleanup_viewport_pane_cameras_system.run_if(not(is_editor_active)),
+            )
+            .add_systems(
+                Update,
+                (camera_frame_system, camera_bookmark_system, camera_transition_system)
+                    .chain()
+                    .run_if(is_editor_active),
+            )
             .add_systems(Update, camera_sync_toggle_system.run_if(is_editor_active))
+            .add_systems(
+                Update,
+                (measure_tool_system, class_handle_system, measure_overlay_system)
+                    .chain()
+                    .run_if(is_editor_active),
+            )
             .add_systems(Update, scene_light_system.run_if(is_editor_active))
             .add_systems(Update, cleanup_scene_light_system.run_if(not(is_editor_active)))
+            .add_systems(Update, cleanup_view_mode_system.run_if(not(is_editor_active)))
             .add_systems(
                 Update,
                 (handle_viewport_camera_override_requests, enforce_viewport_camera_state)
@@ -99,7 +159,14 @@ impl Plugin for ViewportPlugin {
             )
             .add_systems(
                 EguiPrimaryContextPass,
-                update_viewport_camera_viewports_system.run_if(is_editor_active),
+                (
+                    update_viewport_camera_viewports_system,
+                    viewport_pane_headers_ui_system,
+                    measure_tool_ui_system,
+                    camera_preview_ui_system,
+                )
+                    .chain()
+                    .run_if(is_editor_active),
             )
             .add_systems(
                 Update,
@@ -116,16 +183,34 @@ impl Plugin for ViewportPlugin {
                 PostUpdate,
                 (
                     show_directional_light_forward_system,
+                    show_directional_light_cascades_system,
                     show_camera_forward_system,
                     relationship_line_system,
                     show_point_light_range_system,
+                    show_rect_light_panel_system,
+                    show_spot_light_cone_system,
+                    show_spot_light_cookie_system,
+                    show_light_probe_bounds_system,
                     show_empty_origin_system,
                     show_active_selection_bounds_system,
                     show_selected_entities_bounds_system,
+                    view_mode_bounds_system,
+                    view_mode_system,
+                    view_mode_lights_system,
                 )
                     .after(TransformSystems::Propagate)
                     .run_if(is_editor_active),
             )
+            .add_systems(
+                PostUpdate,
+                update_camera_preview_system
+                    .after(TransformSystems::Propagate)
+                    .run_if(is_editor_active),
+            )
+            .add_systems(
+                Update,
+                cleanup_camera_preview_system.run_if(not(is_editor_active)),
+            )
             .add_systems(
                 PostUpdate,
                 (
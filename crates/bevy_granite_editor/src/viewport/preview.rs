@@ -0,0 +1,217 @@
+use super::{camera::ViewportCameraState, panes::ViewportPanes};
+use bevy::{
+    asset::{Assets, Handle, RenderAssetUsages},
+    camera::{visibility::RenderLayers, Camera, Camera3d, Projection, RenderTarget},
+    core_pipeline::tonemapping::{DebandDither, Tonemapping},
+    ecs::{
+        component::Component,
+        entity::Entity,
+        query::{With, Without},
+        system::{Commands, Query, Res, ResMut},
+        world::Ref,
+    },
+    image::Image,
+    light::VolumetricFog,
+    pbr::{Atmosphere, AtmosphereSettings, DistanceFog},
+    post_process::bloom::Bloom,
+    prelude::{Name, Resource, Transform},
+    render::{
+        render_resource::{Extent3d, TextureDimension, TextureFormat, TextureUsages},
+        view::Hdr,
+    },
+    transform::components::GlobalTransform,
+};
+use bevy_egui::{egui, EguiContexts, EguiTextureHandle};
+use bevy_granite_core::{EditorIgnore, GraniteTypes, IdentityData, TreeHiddenEntity};
+use bevy_granite_gizmos::ActiveSelection;
+
+const PREVIEW_WIDTH: u32 = 320;
+const PREVIEW_HEIGHT: u32 = 180;
+// Gap between the preview and the pane edges, in egui points
+const PREVIEW_MARGIN: f32 = 10.;
+// Class edits reach the camera's components through an event, so a change is copied for a
+// couple of frames to catch the ones applied after this runs
+const PREVIEW_SYNC_FRAMES: u32 = 2;
+
+/// Offscreen camera mirroring the selected scene camera
+#[derive(Component)]
+pub struct CameraPreviewCamera;
+
+/// Picture-in-picture view through the selected Camera3D, shown in the active pane's corner
+#[derive(Resource, Default)]
+pub struct CameraPreview {
+    camera: Option<Entity>,
+    image: Option<Handle<Image>>,
+    texture: Option<egui::TextureId>,
+    source: Option<(Entity, String)>,
+    sync_frames: u32,
+    /// False while the preview window is collapsed, the camera skips rendering then
+    pub visible: bool,
+}
+
+impl CameraPreview {
+    fn clear(&mut self, commands: &mut Commands, contexts: &mut EguiContexts) {
+        if let Some(camera) = self.camera.take() {
+            commands.entity(camera).despawn();
+        }
+        if let Some(image) = self.image.take() {
+            contexts.remove_image(&image);
+        }
+        self.texture = None;
+        self.source = None;
+    }
+}
+
+fn preview_target_image() -> Image {
+    let size = Extent3d {
+        width: PREVIEW_WIDTH,
+        height: PREVIEW_HEIGHT,
+        ..Default::default()
+    };
+    let mut image = Image::new_fill(
+        size,
+        TextureDimension::D2,
+        &[0, 0, 0, 0],
+        TextureFormat::Bgra8UnormSrgb,
+        RenderAssetUsages::default(),
+    );
+    image.texture_descriptor.usage =
+        TextureUsages::TEXTURE_BINDING | TextureUsages::COPY_DST | TextureUsages::RENDER_ATTACHMENT;
+    image
+}
+
+/// Follows the selected Camera3D with an offscreen camera using its projection and post effects
+/// Nothing is rendered when the selection is no camera or already drives the viewport
+pub fn update_camera_preview_system(
+    mut commands: Commands,
+    mut contexts: EguiContexts,
+    mut preview: ResMut<CameraPreview>,
+    mut images: ResMut<Assets<Image>>,
+    viewport_camera_state: Res<ViewportCameraState>,
+    active_query: Query<(Entity, Ref<IdentityData>, &GlobalTransform), With<ActiveSelection>>,
+    mut preview_query: Query<
+        (&mut Camera, &mut Transform, &mut GlobalTransform),
+        (With<CameraPreviewCamera>, Without<ActiveSelection>),
+    >,
+) {
+    let source = active_query.single().ok().filter(|(entity, identity, _)| {
+        matches!(identity.class, GraniteTypes::Camera3D(_))
+            && viewport_camera_state.active_override != Some(*entity)
+    });
+    let Some((entity, identity, source_transform)) = source else {
+        preview.clear(&mut commands, &mut contexts);
+        return;
+    };
+
+    let camera = match preview.camera {
+        Some(camera) => camera,
+        None => {
+            let image = images.add(preview_target_image());
+            let camera = commands
+                .spawn((
+                    Name::new("Camera Preview"),
+                    CameraPreviewCamera,
+                    Camera3d::default(),
+                    Camera {
+                        target: RenderTarget::Image(image.clone().into()),
+                        order: -5,
+                        ..Default::default()
+                    },
+                    *source_transform,
+                    source_transform.compute_transform(),
+                    EditorIgnore::PICKING,
+                    TreeHiddenEntity,
+                ))
+                .id();
+            preview.texture = Some(contexts.add_image(EguiTextureHandle::Strong(image.clone())));
+            preview.image = Some(image);
+            preview.camera = Some(camera);
+            camera
+        }
+    };
+
+    let switched = preview.source.as_ref().map(|(source, _)| *source) != Some(entity);
+    if switched || identity.is_changed() {
+        preview.source = Some((entity, identity.name.clone()));
+        preview.sync_frames = PREVIEW_SYNC_FRAMES;
+    }
+    if preview.sync_frames > 0 {
+        preview.sync_frames -= 1;
+        // Effects the camera no longer has are dropped before copying the current ones
+        commands.entity(camera).remove::<(
+            Hdr,
+            Bloom,
+            VolumetricFog,
+            DistanceFog,
+            Atmosphere,
+            AtmosphereSettings,
+            RenderLayers,
+        )>();
+        commands.entity(entity).clone_components::<(
+            Projection,
+            Tonemapping,
+            DebandDither,
+            Hdr,
+            Bloom,
+            VolumetricFog,
+            DistanceFog,
+            Atmosphere,
+            AtmosphereSettings,
+            RenderLayers,
+        )>(camera);
+    }
+
+    // Set both so the preview doesn't trail the camera by a frame
+    if let Ok((mut camera, mut transform, mut global_transform)) = preview_query.get_mut(camera) {
+        camera.is_active = preview.visible;
+        *transform = source_transform.compute_transform();
+        *global_transform = *source_transform;
+    }
+}
+
+/// Collapsible window in the bottom right corner of the active pane
+pub fn camera_preview_ui_system(
+    mut contexts: EguiContexts,
+    mut preview: ResMut<CameraPreview>,
+    panes: Res<ViewportPanes>,
+) {
+    let (Some(texture), Some((_, name))) = (preview.texture, preview.source.clone()) else {
+        return;
+    };
+    let Ok(ctx) = contexts.ctx_mut() else {
+        return;
+    };
+    let Some(rect) = panes.rects.get(panes.active) else {
+        return;
+    };
+    let scale = ctx.pixels_per_point();
+    let corner = egui::pos2(rect.max.x / scale, rect.max.y / scale)
+        - egui::vec2(PREVIEW_MARGIN, PREVIEW_MARGIN);
+
+    let mut visible = false;
+    egui::Window::new(format!("Preview: {}", name))
+        .id(egui::Id::new("camera_preview"))
+        .pivot(egui::Align2::RIGHT_BOTTOM)
+        .fixed_pos(corner)
+        .collapsible(true)
+        .resizable(false)
+        .show(ctx, |ui| {
+            visible = true;
+            ui.add(egui::Image::new((
+                texture,
+                egui::vec2(PREVIEW_WIDTH as f32, PREVIEW_HEIGHT as f32),
+            )));
+        });
+    preview.visible = visible;
+}
+
+/// The preview camera only exists while the editor is open
+pub fn cleanup_camera_preview_system(
+    mut commands: Commands,
+    mut contexts: EguiContexts,
+    mut preview: ResMut<CameraPreview>,
+) {
+    if preview.camera.is_some() {
+        preview.clear(&mut commands, &mut contexts);
+    }
+}
@@ -1,4 +1,4 @@
-use crate::viewport::VisualizationConfig;
+use crate::viewport::{grid::GridPlane, VisualizationConfig};
 use serde::{Deserialize, Serialize};
 
 #[derive(Clone, Debug, PartialEq, Serialize, Deserialize)]
@@ -8,6 +8,16 @@ pub struct ViewportState {
     pub grid_distance: f32,
     pub grid_color: [f32; 4],
     pub grid_size: f32,
+    #[serde(default)]
+    pub grid_plane: GridPlane,
+    #[serde(default)]
+    pub grid_origin: [f32; 3],
+    #[serde(default = "default_grid_subdivisions")]
+    pub grid_subdivisions: u32,
+    #[serde(default = "default_grid_align_to_ortho")]
+    pub grid_align_to_ortho: bool,
+    #[serde(default)]
+    pub grid_snap_new_entities: bool,
 
     #[serde(skip)]
     pub changed: bool,
@@ -19,8 +29,21 @@ impl Default for ViewportState {
             grid_distance: 100.,
             grid_color: [0.124, 0.124, 0.124, 1.0],
             grid_size: 1.,
+            grid_plane: GridPlane::XZ,
+            grid_origin: [0.; 3],
+            grid_subdivisions: default_grid_subdivisions(),
+            grid_align_to_ortho: default_grid_align_to_ortho(),
+            grid_snap_new_entities: false,
             visualizers: VisualizationConfig::default(),
             changed: true,
         }
     }
 }
+
+fn default_grid_subdivisions() -> u32 {
+    1
+}
+
+fn default_grid_align_to_ortho() -> bool {
+    true
+}
@@ -1,13 +1,36 @@
-use bevy::ecs::system::{Commands, Query, ResMut};
-use bevy::light::DirectionalLight;
-use bevy::prelude::{Entity, GlobalTransform, Name, Resource, Transform, With};
-use bevy_granite_core::EditorIgnore;
+use bevy::asset::{Asset, AssetEvent, AssetId, Assets, Handle, RenderAssetUsages};
+use bevy::camera::visibility::{InheritedVisibility, RenderLayers, Visibility};
+use bevy::color::{Alpha, Color, LinearRgba};
+use bevy::ecs::system::{Commands, Query, Res, ResMut};
+use bevy::gizmos::gizmos::Gizmos;
+use bevy::light::{DirectionalLight, NotShadowCaster, PointLight, SpotLight};
+use bevy::math::{Vec3, Vec4};
+use bevy::mesh::{Indices, Mesh, Mesh3d, PrimitiveTopology};
+use bevy::pbr::{Material, MeshMaterial3d, StandardMaterial};
+use bevy::picking::Pickable;
+use bevy::platform::collections::{HashMap, HashSet};
+use bevy::prelude::{
+    AlphaMode, Component, Entity, GlobalTransform, MessageReader, Name, Or, Resource, Transform,
+    With, Without,
+};
+use bevy::reflect::TypePath;
+use bevy::render::render_resource::{AsBindGroup, ShaderType};
+use bevy::shader::ShaderRef;
+use bevy_granite_core::{EditorIgnore, TreeHiddenEntity};
 use bevy_granite_logging::{
     config::{LogCategory, LogLevel, LogType},
     log,
 };
+use serde::{Deserialize, Serialize};
+
+use super::camera::{
+    grid_layers, scene_layers, EditorViewportCamera, ViewportPaneCamera, LAYER_GRID,
+    LAYER_SCENE, LAYER_VIEWMODE,
+};
+use super::DebugRenderer;
+use crate::entities::bounds::get_entity_bounds_world;
 
-use super::camera::EditorViewportCamera;
+pub const VIEW_MODE_SHADER_PATH: &str = "embedded://bevy_granite_editor/viewport/viewmode.wgsl";
 
 /// Scene light configuration - adds a directional light from the editor camera
 #[derive(Resource)]
@@ -115,3 +138,552 @@ pub fn cleanup_scene_light_system(
         );
     }
 }
+
+/// How the editor viewport cameras draw the scene. Runtime cameras always see it lit
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq, Hash, Serialize, Deserialize)]
+pub enum ViewMode {
+    #[default]
+    Lit,
+    Unlit,
+    Wireframe,
+    Normals,
+    LightingOnly,
+    Overdraw,
+    Bounds,
+}
+
+impl ViewMode {
+    pub fn all() -> Vec<Self> {
+        vec![
+            Self::Lit,
+            Self::Unlit,
+            Self::Wireframe,
+            Self::Normals,
+            Self::LightingOnly,
+            Self::Overdraw,
+            Self::Bounds,
+        ]
+    }
+
+    pub fn label(&self) -> &'static str {
+        match self {
+            Self::Lit => "Lit",
+            Self::Unlit => "Unlit Albedo",
+            Self::Wireframe => "Wireframe",
+            Self::Normals => "Normals",
+            Self::LightingOnly => "Lighting Only",
+            Self::Overdraw => "Overdraw",
+            Self::Bounds => "Bounds Only",
+        }
+    }
+
+    pub fn description(&self) -> &'static str {
+        match self {
+            Self::Lit => "Normal shaded view",
+            Self::Unlit => "Base color and textures without lighting",
+            Self::Wireframe => "Triangle edges only",
+            Self::Normals => "World space normals as color",
+            Self::LightingOnly => "Lighting on plain white surfaces",
+            Self::Overdraw => "Brighter where more surfaces stack up behind each other",
+            Self::Bounds => "Hide meshes and draw their bounding boxes",
+        }
+    }
+
+    // Lit draws the real scene and bounds draws nothing but gizmos
+    fn uses_proxies(&self) -> bool {
+        !matches!(self, Self::Lit | Self::Bounds)
+    }
+
+    fn camera_layers(&self) -> RenderLayers {
+        match self {
+            Self::Lit => scene_layers(),
+            Self::Bounds => grid_layers(),
+            _ => RenderLayers::from_layers(&[LAYER_VIEWMODE, LAYER_GRID]),
+        }
+    }
+}
+
+#[derive(Clone, Copy, Debug, ShaderType)]
+pub struct ViewModeSettings {
+    pub color: Vec4,
+    /// 1 draws world normals, 0 a flat color
+    pub mode: u32,
+}
+
+/// Flat color, normals and overdraw shading for view mode proxies
+#[derive(Asset, TypePath, AsBindGroup, Clone, Debug)]
+pub struct ViewModeMaterial {
+    #[uniform(0)]
+    pub settings: ViewModeSettings,
+    pub alpha_mode: AlphaMode,
+}
+
+impl Material for ViewModeMaterial {
+    fn fragment_shader() -> ShaderRef {
+        ShaderRef::Path(VIEW_MODE_SHADER_PATH.into())
+    }
+
+    fn alpha_mode(&self) -> AlphaMode {
+        self.alpha_mode
+    }
+}
+
+/// Stand-in for a scene mesh, drawn only on the view mode layer
+#[derive(Component)]
+pub struct ViewModeProxy {
+    pub source: Entity,
+}
+
+/// Scene meshes are copied onto their own render layer with mode specific materials so only
+/// the editor viewport cameras, which swap to that layer, see the change
+#[derive(Resource, Default)]
+pub struct ViewModeState {
+    pub mode: ViewMode,
+    applied: ViewMode,
+    /// Source entity to its proxy
+    proxies: HashMap<Entity, Entity>,
+    /// Source material to its unlit or lighting only copy
+    derived_materials: HashMap<AssetId<StandardMaterial>, Handle<StandardMaterial>>,
+    /// Derived from the default material for meshes using a registered material kind
+    fallback_material: Option<Handle<StandardMaterial>>,
+    /// Source mesh to its edge line list
+    wire_meshes: HashMap<AssetId<Mesh>, Handle<Mesh>>,
+    shared_materials: HashMap<ViewMode, Handle<ViewModeMaterial>>,
+    /// Scene lights moved onto the view mode layer, with the layers they had before
+    light_layers: HashMap<Entity, Option<RenderLayers>>,
+}
+
+impl ViewModeState {
+    pub fn set_mode(&mut self, mode: ViewMode) {
+        if self.mode != mode {
+            log!(
+                LogType::Editor,
+                LogLevel::Info,
+                LogCategory::System,
+                "View mode: {}",
+                mode.label()
+            );
+            self.mode = mode;
+        }
+    }
+
+    fn clear(
+        &mut self,
+        commands: &mut Commands,
+        standard_materials: &mut Assets<StandardMaterial>,
+    ) {
+        for (_, proxy) in self.proxies.drain() {
+            commands.entity(proxy).try_despawn();
+        }
+        for (_, derived) in self.derived_materials.drain() {
+            standard_materials.remove(&derived);
+        }
+        if let Some(fallback) = self.fallback_material.take() {
+            standard_materials.remove(&fallback);
+        }
+    }
+
+    fn restore_lights(&mut self, commands: &mut Commands) {
+        for (light, layers) in self.light_layers.drain() {
+            let Ok(mut light) = commands.get_entity(light) else {
+                continue;
+            };
+            match layers {
+                Some(layers) => light.insert(layers),
+                None => light.remove::<RenderLayers>(),
+            };
+        }
+    }
+}
+
+enum ProxyMaterial {
+    Standard(Handle<StandardMaterial>),
+    View(Handle<ViewModeMaterial>),
+}
+
+type ViewModeSourceQuery<'w, 's> = Query<
+    'w,
+    's,
+    (
+        Entity,
+        &'static Mesh3d,
+        Option<&'static MeshMaterial3d<StandardMaterial>>,
+        &'static GlobalTransform,
+        &'static InheritedVisibility,
+        Option<&'static RenderLayers>,
+    ),
+    Without<ViewModeProxy>,
+>;
+
+type ViewModeProxyQuery<'w, 's> = Query<
+    'w,
+    's,
+    (
+        &'static mut Transform,
+        &'static mut GlobalTransform,
+        &'static mut Visibility,
+        &'static Mesh3d,
+        Option<&'static MeshMaterial3d<StandardMaterial>>,
+        Option<&'static MeshMaterial3d<ViewModeMaterial>>,
+    ),
+    With<ViewModeProxy>,
+>;
+
+type SceneLightQuery<'w, 's> = Query<
+    'w,
+    's,
+    (Entity, Option<&'static RenderLayers>),
+    (
+        Or<(With<DirectionalLight>, With<PointLight>, With<SpotLight>)>,
+        Without<ViewModeProxy>,
+    ),
+>;
+
+type EditorViewportCameraQuery<'w, 's> = Query<
+    'w,
+    's,
+    (Entity, Option<&'static RenderLayers>),
+    Or<(With<EditorViewportCamera>, With<ViewportPaneCamera>)>,
+>;
+
+pub fn view_mode_system(
+    mut commands: Commands,
+    mut state: ResMut<ViewModeState>,
+    mut mesh_events: MessageReader<AssetEvent<Mesh>>,
+    mut material_events: MessageReader<AssetEvent<StandardMaterial>>,
+    mut meshes: ResMut<Assets<Mesh>>,
+    mut standard_materials: ResMut<Assets<StandardMaterial>>,
+    mut view_materials: ResMut<Assets<ViewModeMaterial>>,
+    sources: ViewModeSourceQuery,
+    mut proxies: ViewModeProxyQuery,
+    cameras: EditorViewportCameraQuery,
+) {
+    let mode = state.mode;
+
+    // Pane cameras come and go, so this is checked every frame
+    let layers = mode.camera_layers();
+    for (camera, current) in cameras.iter() {
+        if current != Some(&layers) {
+            commands.entity(camera).insert(layers.clone());
+        }
+    }
+
+    for event in mesh_events.read() {
+        if let AssetEvent::Modified { id } | AssetEvent::Removed { id } = event {
+            if let Some(wire) = state.wire_meshes.remove(id) {
+                meshes.remove(&wire);
+            }
+        }
+    }
+    for event in material_events.read() {
+        match event {
+            // Keep derived copies in step with live material edits
+            AssetEvent::Modified { id } => {
+                let Some(derived) = state.derived_materials.get(id).cloned() else {
+                    continue;
+                };
+                let Some(source) = standard_materials.get(*id).cloned() else {
+                    continue;
+                };
+                if let Some(material) = standard_materials.get_mut(&derived) {
+                    *material = derive_material(mode, &source);
+                }
+            }
+            AssetEvent::Removed { id } => {
+                if let Some(derived) = state.derived_materials.remove(id) {
+                    standard_materials.remove(&derived);
+                }
+            }
+            _ => {}
+        }
+    }
+
+    if state.applied != mode {
+        state.clear(&mut commands, &mut standard_materials);
+        state.applied = mode;
+    }
+    if !mode.uses_proxies() {
+        return;
+    }
+
+    let mut seen = HashSet::new();
+    for (source, mesh, material, global, visibility, source_layers) in sources.iter() {
+        if source_layers.is_some_and(|layers| !layers.intersects(&RenderLayers::layer(LAYER_SCENE)))
+        {
+            continue;
+        }
+
+        let mesh_handle = if mode == ViewMode::Wireframe {
+            match state.wire_meshes.get(&mesh.id()).cloned() {
+                Some(wire) => wire,
+                None => {
+                    let Some(wire) = meshes.get(&mesh.0).and_then(wireframe_mesh) else {
+                        continue;
+                    };
+                    let wire = meshes.add(wire);
+                    state.wire_meshes.insert(mesh.id(), wire.clone());
+                    wire
+                }
+            }
+        } else {
+            mesh.0.clone()
+        };
+
+        let proxy_material = match (mode, material) {
+            // Other material kinds have no standard material to copy, so they get a plain one
+            (ViewMode::Unlit | ViewMode::LightingOnly, None) => ProxyMaterial::Standard(
+                state
+                    .fallback_material
+                    .get_or_insert_with(|| {
+                        standard_materials.add(derive_material(mode, &StandardMaterial::default()))
+                    })
+                    .clone(),
+            ),
+            (ViewMode::Unlit | ViewMode::LightingOnly, Some(material)) => {
+                match state.derived_materials.get(&material.id()).cloned() {
+                    Some(derived) => ProxyMaterial::Standard(derived),
+                    None => {
+                        let Some(derived) = standard_materials
+                            .get(&material.0)
+                            .map(|source_material| derive_material(mode, source_material))
+                        else {
+                            continue;
+                        };
+                        let derived = standard_materials.add(derived);
+                        state.derived_materials.insert(material.id(), derived.clone());
+                        ProxyMaterial::Standard(derived)
+                    }
+                }
+            }
+            _ => {
+                let shared = state
+                    .shared_materials
+                    .entry(mode)
+                    .or_insert_with(|| view_materials.add(view_mode_material(mode)))
+                    .clone();
+                ProxyMaterial::View(shared)
+            }
+        };
+
+        seen.insert(source);
+        let proxy_visibility = if visibility.get() {
+            Visibility::Inherited
+        } else {
+            Visibility::Hidden
+        };
+
+        if let Some(proxy) = state.proxies.get(&source).copied() {
+            if let Ok((
+                mut transform,
+                mut proxy_global,
+                mut proxy_vis,
+                proxy_mesh,
+                proxy_standard,
+                proxy_view,
+            )) = proxies.get_mut(proxy)
+            {
+                // Written after propagation so proxies don't trail a frame behind a drag
+                let source_transform = global.compute_transform();
+                if *transform != source_transform {
+                    *transform = source_transform;
+                    *proxy_global = *global;
+                }
+                if *proxy_vis != proxy_visibility {
+                    *proxy_vis = proxy_visibility;
+                }
+                if proxy_mesh.0 != mesh_handle {
+                    commands.entity(proxy).insert(Mesh3d(mesh_handle));
+                }
+                match proxy_material {
+                    ProxyMaterial::Standard(handle) => {
+                        if proxy_standard.map(|current| &current.0) != Some(&handle) {
+                            commands.entity(proxy).insert(MeshMaterial3d(handle));
+                        }
+                    }
+                    ProxyMaterial::View(handle) => {
+                        if proxy_view.map(|current| &current.0) != Some(&handle) {
+                            commands.entity(proxy).insert(MeshMaterial3d(handle));
+                        }
+                    }
+                }
+                continue;
+            }
+        }
+
+        let mut proxy = commands.spawn((
+            global.compute_transform(),
+            *global,
+            proxy_visibility,
+            Mesh3d(mesh_handle),
+            ViewModeProxy { source },
+            RenderLayers::layer(LAYER_VIEWMODE),
+            NotShadowCaster,
+            Pickable::IGNORE,
+            Name::new("View Mode Proxy"),
+            EditorIgnore::SERIALIZE | EditorIgnore::PICKING,
+            TreeHiddenEntity,
+        ));
+        match proxy_material {
+            ProxyMaterial::Standard(handle) => proxy.insert(MeshMaterial3d(handle)),
+            ProxyMaterial::View(handle) => proxy.insert(MeshMaterial3d(handle)),
+        };
+        let proxy = proxy.id();
+        state.proxies.insert(source, proxy);
+    }
+
+    // Sources that were despawned or lost their mesh
+    let stale: Vec<Entity> = state
+        .proxies
+        .keys()
+        .filter(|source| !seen.contains(*source))
+        .copied()
+        .collect();
+    for source in stale {
+        if let Some(proxy) = state.proxies.remove(&source) {
+            commands.entity(proxy).try_despawn();
+        }
+    }
+}
+
+/// Lights only reach cameras they share a layer with, so lighting only mode puts the scene
+/// lights on the view mode layer too. They go back to their own layers in every other mode
+pub fn view_mode_lights_system(
+    mut commands: Commands,
+    mut state: ResMut<ViewModeState>,
+    lights: SceneLightQuery,
+) {
+    if state.mode != ViewMode::LightingOnly {
+        if !state.light_layers.is_empty() {
+            state.restore_lights(&mut commands);
+        }
+        return;
+    }
+
+    state.light_layers.retain(|light, _| lights.contains(*light));
+    for (light, layers) in lights.iter() {
+        if state.light_layers.contains_key(&light) {
+            continue;
+        }
+        let scene = layers.cloned().unwrap_or_default();
+        // Lights kept off the scene layer stay out of the viewport as they would when lit
+        if !scene.intersects(&RenderLayers::layer(LAYER_SCENE)) {
+            continue;
+        }
+        commands.entity(light).insert(scene.with(LAYER_VIEWMODE));
+        state.light_layers.insert(light, layers.cloned());
+    }
+}
+
+/// Bounds only mode draws every scene mesh as its world bounding box
+pub fn view_mode_bounds_system(
+    state: Res<ViewModeState>,
+    mut gizmos: Gizmos<DebugRenderer>,
+    meshes: Res<Assets<Mesh>>,
+    mesh_query: Query<&Mesh3d>,
+    sources: ViewModeSourceQuery,
+) {
+    if state.mode != ViewMode::Bounds {
+        return;
+    }
+    let color = Color::srgb(0.35, 0.8, 1.0);
+    for (entity, _, _, global, visibility, source_layers) in sources.iter() {
+        if !visibility.get()
+            || source_layers
+                .is_some_and(|layers| !layers.intersects(&RenderLayers::layer(LAYER_SCENE)))
+        {
+            continue;
+        }
+        if let Some((min, max)) = get_entity_bounds_world(entity, &meshes, &mesh_query, global) {
+            let size = (max - min).max(Vec3::splat(0.001));
+            gizmos.cuboid(
+                Transform::from_translation((min + max) * 0.5).with_scale(size),
+                color,
+            );
+        }
+    }
+}
+
+/// Despawns proxies when the editor turns off, they are rebuilt when it comes back
+pub fn cleanup_view_mode_system(
+    mut commands: Commands,
+    mut state: ResMut<ViewModeState>,
+    mut standard_materials: ResMut<Assets<StandardMaterial>>,
+) {
+    if state.proxies.is_empty()
+        && state.derived_materials.is_empty()
+        && state.fallback_material.is_none()
+        && state.light_layers.is_empty()
+    {
+        return;
+    }
+    state.clear(&mut commands, &mut standard_materials);
+    state.restore_lights(&mut commands);
+    state.applied = ViewMode::Lit;
+}
+
+fn derive_material(mode: ViewMode, source: &StandardMaterial) -> StandardMaterial {
+    let mut derived = source.clone();
+    match mode {
+        ViewMode::Unlit => {
+            derived.unlit = true;
+        }
+        _ => {
+            // Keep roughness, metal and normal maps, drop the color
+            derived.base_color = Color::WHITE.with_alpha(source.base_color.alpha());
+            derived.base_color_texture = None;
+            derived.emissive = LinearRgba::BLACK;
+            derived.emissive_texture = None;
+        }
+    }
+    derived
+}
+
+fn view_mode_material(mode: ViewMode) -> ViewModeMaterial {
+    let (color, shader_mode, alpha_mode) = match mode {
+        ViewMode::Normals => (Vec4::ONE, 1, AlphaMode::Opaque),
+        // Every stacked surface adds a little, so dense areas glow
+        ViewMode::Overdraw => (Vec4::new(0.12, 0.05, 0.02, 1.0), 0, AlphaMode::Add),
+        _ => (Vec4::new(0.85, 0.85, 0.85, 1.0), 0, AlphaMode::Opaque),
+    };
+    ViewModeMaterial {
+        settings: ViewModeSettings {
+            color,
+            mode: shader_mode,
+        },
+        alpha_mode,
+    }
+}
+
+/// Unique triangle edges of a mesh as a line list
+fn wireframe_mesh(mesh: &Mesh) -> Option<Mesh> {
+    if mesh.primitive_topology() != PrimitiveTopology::TriangleList {
+        return None;
+    }
+    let positions = mesh
+        .attribute(Mesh::ATTRIBUTE_POSITION)?
+        .as_float3()?
+        .to_vec();
+    let triangles: Vec<u32> = match mesh.indices() {
+        Some(indices) => indices.iter().map(|index| index as u32).collect(),
+        None => (0..positions.len() as u32).collect(),
+    };
+
+    let mut edges = HashSet::new();
+    let mut lines = Vec::new();
+    for triangle in triangles.chunks_exact(3) {
+        for (a, b) in [
+            (triangle[0], triangle[1]),
+            (triangle[1], triangle[2]),
+            (triangle[2], triangle[0]),
+        ] {
+            if edges.insert((a.min(b), a.max(b))) {
+                lines.extend([a, b]);
+            }
+        }
+    }
+
+    let mut wire = Mesh::new(PrimitiveTopology::LineList, RenderAssetUsages::default());
+    wire.insert_attribute(Mesh::ATTRIBUTE_POSITION, positions);
+    wire.insert_indices(Indices::U32(lines));
+    Some(wire)
+}
@@ -0,0 +1,17 @@
+#import bevy_pbr::forward_io::VertexOutput
+
+struct ViewModeSettings {
+    color: vec4<f32>,
+    mode: u32,
+}
+
+@group(#{MATERIAL_BIND_GROUP}) @binding(0) var<uniform> settings: ViewModeSettings;
+
+// mode 1 is world normals, everything else is a flat color
+@fragment
+fn fragment(in: VertexOutput) -> @location(0) vec4<f32> {
+    if settings.mode == 1u {
+        return vec4<f32>(normalize(in.world_normal) * 0.5 + 0.5, 1.0);
+    }
+    return settings.color;
+}
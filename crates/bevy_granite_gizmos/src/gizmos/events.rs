@@ -1,6 +1,6 @@
 use super::GizmoType;
 use bevy::prelude::{Entity, Message, Transform};
-use bevy_granite_core::TransformData;
+use bevy_granite_core::{BrushEditOperation, TransformData};
 
 #[derive(Message)]
 pub struct RotateInitDragEvent;
@@ -34,3 +34,11 @@ pub struct GizmoTransformAppliedEvent {
     pub old_transform: TransformData,
     pub new_transform: TransformData,
 }
+
+/// Event emitted when the user requests a mesh edit on a brush via the vertex gizmo
+/// The editor turns this into an undoable command that writes the new geometry into class data
+#[derive(Message, Clone)]
+pub struct RequestBrushEditEvent {
+    pub entity: Entity,
+    pub operation: BrushEditOperation,
+}
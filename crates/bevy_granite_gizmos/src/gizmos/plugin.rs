@@ -1,12 +1,12 @@
 use super::register_embedded_rotate_gizmo_mesh;
 use super::{
-    gizmo_changed_watcher, gizmo_events, handle_init_rotate_drag, handle_rotate_input,
-    handle_rotate_reset, scale_gizmo_by_camera_distance_system,
-    update_rotate_gizmo_rotation_for_mode, update_transform_gizmo_rotation_for_mode,
-    DespawnGizmoEvent, GizmoSnap, GizmoTransformAppliedEvent, GizmoType, LastSelectedGizmo,
-    NewGizmoConfig, PreviousTransformGizmo, RotateDraggingEvent, RotateInitDragEvent,
-    RotateResetDragEvent, SpawnGizmoEvent, TransformDraggingEvent, TransformInitDragEvent,
-    TransformResetDragEvent,
+    fade_plane_handles_system, gizmo_changed_watcher, gizmo_events, handle_init_rotate_drag,
+    handle_rotate_input, handle_rotate_reset, orient_view_ring_system,
+    scale_gizmo_by_camera_distance_system, update_rotate_gizmo_rotation_for_mode,
+    update_transform_gizmo_rotation_for_mode, DespawnGizmoEvent, GizmoSnap,
+    GizmoTransformAppliedEvent, GizmoType, LastSelectedGizmo, NewGizmoConfig,
+    PreviousTransformGizmo, RotateDraggingEvent, RotateInitDragEvent, RotateResetDragEvent,
+    SpawnGizmoEvent, TransformDraggingEvent, TransformInitDragEvent, TransformResetDragEvent,
 };
 use crate::gizmos::transform::{apply_transformations, TransitionDelta};
 use crate::gizmos::{GizmoMode, NewGizmoType};
@@ -62,6 +62,8 @@ impl Plugin for GizmoPlugin {
                     gizmo_events,
                     update_transform_gizmo_rotation_for_mode,
                     update_rotate_gizmo_rotation_for_mode,
+                    orient_view_ring_system.after(update_rotate_gizmo_rotation_for_mode),
+                    fade_plane_handles_system.after(update_transform_gizmo_rotation_for_mode),
                     apply_transformations.run_if(any_with_component::<TransitionDelta>),
                 )
                     .run_if(is_gizmos_active),
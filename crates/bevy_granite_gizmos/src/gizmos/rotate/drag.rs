@@ -4,9 +4,9 @@ use crate::{
     gizmos::{
         GizmoConfig, GizmoMesh, GizmoMode, GizmoOf, GizmoRoot, GizmoSnap, GizmoType, NewGizmoConfig, NewGizmoType,
         RotateDraggingEvent, RotateGizmo, RotateGizmoParent, RotateInitDragEvent,
-        RotateResetDragEvent,
+        RotateResetDragEvent, ROTATE_VISUAL_RADIUS,
     },
-    input::{DragState, GizmoAxis},
+    input::{DragState, GizmoAxis, NumericDragInput},
     selection::{
         ray::{raycast_at_cursor, HitType, RaycastCursorPos},
         ActiveSelection, RequestDuplicateAllSelectionEvent, Selected,
@@ -53,6 +53,12 @@ type GizmoMeshNameQuery<'w, 's> = Query<
     ),
 >;
 type ParentQuery<'w, 's> = Query<'w, 's, &'w ChildOf>;
+type PressedRotateHandleQuery<'w, 's> = Query<
+    'w,
+    's,
+    (Entity, &'w GizmoAxis, &'w GizmoRoot, &'w PickingInteraction),
+    With<RotateGizmo>,
+>;
 //
 // ------------------------------------------------------------------------
 
@@ -111,6 +117,7 @@ pub fn handle_init_rotate_drag(
         (Entity, Option<&GizmoMesh>, &Name, &PickingInteraction),
         Changed<PickingInteraction>,
     >,
+    pressed_handles: PressedRotateHandleQuery,
 ) {
     let (cursor_2d, raycast_cursor_pos) = resources;
 
@@ -135,10 +142,15 @@ pub fn handle_init_rotate_drag(
             return;
         };
 
-        let Some(raycast_target) = entity else {
+        let Some(mut raycast_target) = entity else {
             return;
         };
 
+        // Rings sit on the trackball sphere, so a ring press also presses the sphere. Rings win
+        if let Some(ring) = pressed_ring_over_trackball(raycast_target, &pressed_handles) {
+            raycast_target = ring;
+        }
+
         log!(
             LogType::Editor,
             LogLevel::Info,
@@ -169,12 +181,15 @@ pub fn handle_init_rotate_drag(
             drag_state.locked_axis = Some(gizmo_axis);
             drag_state.accumulated_angle = 0.0;
             drag_state.last_snapped = 0.0;
+            drag_state.rotation_readout = 0.0;
+            drag_state.trackball_start_dir = Vec3::NAN;
+            drag_state.trackball_applied = Quat::IDENTITY;
 
             drag_state.prev_hit_dir = match gizmo_axis {
-                GizmoAxis::All => {
+                GizmoAxis::All | GizmoAxis::Trackball => {
                     (raycast_cursor_pos.position - drag_state.gizmo_position).normalize()
                 }
-                GizmoAxis::X | GizmoAxis::Y | GizmoAxis::Z => {
+                GizmoAxis::X | GizmoAxis::Y | GizmoAxis::Z | GizmoAxis::View => {
                     (raycast_cursor_pos.position - drag_state.gizmo_position).normalize()
                 }
                 GizmoAxis::None => Vec3::ZERO,
@@ -238,6 +253,39 @@ fn hide_unselected_axes(
     }
 }
 
+/// If the trackball sphere was hit but a handle of the same gizmo is also pressed, return it
+fn pressed_ring_over_trackball(
+    hit: Entity,
+    pressed_handles: &PressedRotateHandleQuery,
+) -> Option<Entity> {
+    let Ok((_, GizmoAxis::Trackball, hit_root, _)) = pressed_handles.get(hit) else {
+        return None;
+    };
+    pressed_handles
+        .iter()
+        .find(|(_, axis, root, interaction)| {
+            root.0 == hit_root.0
+                && **axis != GizmoAxis::Trackball
+                && **interaction == PickingInteraction::Pressed
+        })
+        .map(|(entity, ..)| entity)
+}
+
+/// Point on the trackball sphere under a ray, as a unit direction from the center
+/// Rays that miss land on the silhouette so dragging outside the sphere keeps rotating
+fn trackball_direction(ray_origin: Vec3, ray_direction: Vec3, center: Vec3, radius: f32) -> Vec3 {
+    let closest = ray_origin + ray_direction * (center - ray_origin).dot(ray_direction);
+    let offset = closest - center;
+    let (distance_sq, radius_sq) = (offset.length_squared(), radius * radius);
+    if distance_sq <= radius_sq {
+        // Near side of the sphere
+        let back = (radius_sq - distance_sq).sqrt();
+        (closest - ray_direction * back - center).normalize_or_zero()
+    } else {
+        offset.normalize_or_zero()
+    }
+}
+
 /// ANGULAR movement for locked axis. We dont want pixel delta for locked axis.
 /// Free rotate can use mouse delta
 pub fn handle_rotate_dragging(
@@ -255,13 +303,18 @@ pub fn handle_rotate_dragging(
     gizmo_config_query: Query<&GizmoConfig>,
     mut drag_state: ResMut<DragState>,
     mut gizmo_visibility_query: Query<(&GizmoAxis, &mut Visibility, &GizmoRoot), With<RotateGizmo>>,
+    pressed_handles: PressedRotateHandleQuery,
+    numeric_input: Res<NumericDragInput>,
 ) {
-    if event.button != PointerButton::Primary {
+    if event.button != PointerButton::Primary || numeric_input.blocks_drag() {
         return;
     }
     let Ok((gizmo_axis, gizmo_root)) = gizmo_data.get(event.entity) else {
         return;
     };
+    if pressed_ring_over_trackball(event.entity, &pressed_handles).is_some() {
+        return;
+    }
     
     if !drag_state.dragging {
         drag_state.dragging = true;
@@ -387,19 +440,63 @@ pub fn handle_rotate_dragging(
                 (rotation, None)
             }
         }
-        GizmoAxis::X | GizmoAxis::Y | GizmoAxis::Z => {
+        GizmoAxis::Trackball => {
+            let Ok(ray) =
+                camera.viewport_to_world(camera_transform, event.pointer_location.position)
+            else {
+                return;
+            };
+            let sphere_scale = global_transforms
+                .get(event.entity)
+                .map(|global| global.to_scale_rotation_translation().0.x)
+                .unwrap_or(1.0);
+            let current_dir = trackball_direction(
+                ray.origin,
+                *ray.direction,
+                origin,
+                ROTATE_VISUAL_RADIUS * sphere_scale,
+            );
+            if drag_state.trackball_start_dir.is_nan() {
+                drag_state.trackball_start_dir = current_dir;
+                drag_state.trackball_applied = Quat::IDENTITY;
+                return;
+            }
+
+            // Snap the total rotation since the drag began, then apply only what changed
+            let (axis, angle) =
+                Quat::from_rotation_arc(drag_state.trackball_start_dir, current_dir)
+                    .to_axis_angle();
+            let snap_increment = _gizmo_snap.rotate_value.to_radians();
+            let angle = if snap_increment > f32::EPSILON {
+                (angle / snap_increment).round() * snap_increment
+            } else {
+                angle
+            };
+            let total = Quat::from_axis_angle(axis, angle);
+            let rotation_delta = total * drag_state.trackball_applied.inverse();
+            drag_state.trackball_applied = total;
+            drag_state.rotation_readout = angle.to_degrees();
+
+            if rotation_delta.angle_between(Quat::IDENTITY) < f32::EPSILON {
+                return;
+            }
+            (rotation_delta, None)
+        }
+        GizmoAxis::X | GizmoAxis::Y | GizmoAxis::Z | GizmoAxis::View => {
             let axis = match gizmo_axis {
                 GizmoAxis::X => Vec3::X,
                 GizmoAxis::Y => Vec3::Y,
                 GizmoAxis::Z => Vec3::Z,
-                _ => return,
+                _ => Vec3::ZERO,
             };
             
-            let world_axis = match mode {
-                GizmoMode::Local => {
+            // View ring spins around the camera direction regardless of mode
+            let world_axis = match (gizmo_axis, mode) {
+                (GizmoAxis::View, _) => camera_transform.forward().as_vec3(),
+                (_, GizmoMode::Local) => {
                     target_rotation * axis
                 }
-                GizmoMode::Global => {
+                (_, GizmoMode::Global) => {
                     axis
                 }
             };
@@ -463,8 +560,13 @@ pub fn handle_rotate_dragging(
             }
             
             let rotation_delta = Quat::from_axis_angle(world_axis, snapped_angle);
-            
-            (rotation_delta, Some((axis, snapped_angle)))
+            drag_state.rotation_readout += snapped_angle.to_degrees();
+
+            if *gizmo_axis == GizmoAxis::View {
+                (rotation_delta, None)
+            } else {
+                (rotation_delta, Some((axis, snapped_angle)))
+            }
         }
         GizmoAxis::None => {
             (Quat::IDENTITY, None)
@@ -572,6 +674,7 @@ pub fn handle_rotate_reset(
         drag_state.dragging = false;
         drag_state.locked_axis = None;
         drag_state.drag_ended = true;
+        drag_state.trackball_start_dir = Vec3::NAN;
 
         if let Some(position) = final_position {
             drag_state.raycast_position = position;
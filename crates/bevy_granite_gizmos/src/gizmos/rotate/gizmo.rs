@@ -16,7 +16,7 @@ use bevy_granite_logging::{
 };
 
 use crate::gizmos::{GizmoConfig, GizmoMode, GizmoOf, GizmoRoot};
-use crate::{gizmos::GizmoMesh, input::GizmoAxis};
+use crate::{gizmos::GizmoMesh, input::GizmoAxis, GizmoCamera};
 
 #[derive(Component)]
 pub struct RotateGizmo;
@@ -24,6 +24,10 @@ pub struct RotateGizmo;
 #[derive(Resource, Default, Component)]
 pub struct RotateGizmoParent;
 
+/// Outer ring that always faces the camera. Rotates around the view axis
+#[derive(Component)]
+pub struct RotateViewRing;
+
 #[derive(Resource, Default)]
 pub struct PreviousTransformGizmo {
     pub entity: Option<Entity>,
@@ -31,7 +35,8 @@ pub struct PreviousTransformGizmo {
 
 const GIZMO_SCALE: f32 = 0.85;
 const ROTATE_INNER_RADIUS: f32 = 0.12 * GIZMO_SCALE; // middle sphere of gizmo (free rotate)
-const ROTATE_VISUAL_RADIUS: f32 = 0.64 * GIZMO_SCALE; // middle sphere of gizmo (visual)
+pub const ROTATE_VISUAL_RADIUS: f32 = 0.64 * GIZMO_SCALE; // middle sphere of gizmo (trackball)
+const VIEW_RING_SCALE: f32 = 1.2; // outer screen space ring, relative to the axis rings
 const RING_MESH_HASH: uuid::Uuid = uuid::uuid!("3f6f4c2a-6e36-4ccf-81c4-f343f83c5f80"); // constantly random - doesnt matter the value
 
 pub fn register_embedded_rotate_gizmo_mesh(mut meshes: ResMut<Assets<Mesh>>) {
@@ -90,9 +95,17 @@ pub fn spawn_rotate_gizmo(
             .insert(RotateGizmo)
             .id();
 
-        // Build the visual sphere as a child
+        // Build the visual sphere as a child. Dragging it rotates like a trackball
         build_visual_sphere(parent, commands, materials, gizmo_entity, meshes);
 
+        build_view_ring(
+            parent,
+            commands,
+            materials,
+            gizmo_entity,
+            Color::srgba(0.9, 0.9, 0.9, 1.),
+        );
+
         build_free_sphere(
             parent,
             commands,
@@ -177,23 +190,63 @@ fn build_visual_sphere(
         ..Default::default()
     });
 
-    commands.spawn((
-        Mesh3d(sphere_handle),
-        MeshMaterial3d(material.clone()),
-        Transform::default(),
-        NotShadowCaster,
-        NotShadowReceiver,
-        Pickable {
-            is_hoverable: true,
-            should_block_lower: false,
-        },
-        Name::new("Gizmo Visual Sphere"),
-        GizmoAxis::None,
-        RotateGizmo,
-        ChildOf(parent),
-        GizmoOf(target),
-        GizmoRoot(parent),
-    ));
+    commands
+        .spawn((
+            Mesh3d(sphere_handle),
+            MeshMaterial3d(material.clone()),
+            Transform::default(),
+            NotShadowCaster,
+            NotShadowReceiver,
+            Pickable {
+                is_hoverable: true,
+                should_block_lower: false,
+            },
+            Name::new("Gizmo Visual Sphere"),
+            GizmoAxis::Trackball,
+            RotateGizmo,
+            GizmoMesh,
+            ChildOf(parent),
+            GizmoOf(target),
+            GizmoRoot(parent),
+        ))
+        .observe(super::drag::handle_rotate_dragging);
+}
+
+fn build_view_ring(
+    target: Entity,
+    commands: &mut Commands,
+    materials: &mut ResMut<Assets<StandardMaterial>>,
+    parent: Entity,
+    color: Color,
+) {
+    let material = materials.add(StandardMaterial {
+        base_color: color,
+        unlit: true,
+        alpha_mode: AlphaMode::AlphaToCoverage,
+        ..Default::default()
+    });
+
+    // Rotation is kept facing the camera by orient_view_ring_system
+    commands
+        .spawn((
+            Mesh3d(get_mesh_handle()),
+            MeshMaterial3d(material),
+            Transform {
+                scale: Vec3::ONE * GIZMO_SCALE * VIEW_RING_SCALE,
+                ..Default::default()
+            },
+            NotShadowCaster,
+            NotShadowReceiver,
+            Name::new("Gizmo Rotate View Ring"),
+            GizmoAxis::View,
+            RotateGizmo,
+            RotateViewRing,
+            GizmoMesh,
+            GizmoOf(target),
+            ChildOf(parent),
+            GizmoRoot(parent),
+        ))
+        .observe(super::drag::handle_rotate_dragging);
 }
 
 fn build_free_sphere(
@@ -311,3 +364,22 @@ pub fn update_gizmo_rotation_for_mode(
         }
     }
 }
+
+/// Keep the view ring facing the camera. The ring mesh lies in its local XY plane
+pub fn orient_view_ring_system(
+    camera_query: Query<&GlobalTransform, With<GizmoCamera>>,
+    parent_query: Query<&GlobalTransform, With<RotateGizmoParent>>,
+    mut ring_query: Query<(&mut Transform, &ChildOf), With<RotateViewRing>>,
+) {
+    let Ok(camera_transform) = camera_query.single() else {
+        return;
+    };
+    let camera_rotation = camera_transform.to_scale_rotation_translation().1;
+    for (mut ring_transform, child_of) in ring_query.iter_mut() {
+        let Ok(parent_global) = parent_query.get(child_of.parent()) else {
+            continue;
+        };
+        let parent_rotation = parent_global.to_scale_rotation_translation().1;
+        ring_transform.rotation = parent_rotation.inverse() * camera_rotation;
+    }
+}
@@ -1,7 +1,7 @@
 use super::TransformGizmo;
 use crate::{
     gizmos::{GizmoConfig, GizmoMode, GizmoOf, GizmoRoot, GizmoSnap, GizmoTransformAppliedEvent},
-    input::GizmoAxis,
+    input::{GizmoAxis, NumericDragInput},
     selection::{ActiveSelection, RequestDuplicateAllSelectionEvent, Selected},
     GizmoCamera,
 };
@@ -47,10 +47,14 @@ pub fn drag_transform_gizmo(
     gizmo_config_query: Query<&GizmoConfig>,
     user_input: Res<UserInput>,
     mut duplication_state: ResMut<TransformDuplicationState>,
+    numeric_input: Res<NumericDragInput>,
 ) {
     if event.button != bevy::picking::pointer::PointerButton::Primary {
         return;
     }
+    if numeric_input.blocks_drag() {
+        return;
+    }
 
     if duplication_state.just_duplicated {
         duplication_state.just_duplicated = false;
@@ -182,6 +186,10 @@ pub fn drag_transform_gizmo(
             };
             (plane_normal, plane_normal)
         }
+        TransformGizmo::Free => {
+            let view_normal = camera_transform.forward().as_vec3();
+            (view_normal, view_normal)
+        }
     };
 
     current_world_pos -= drag_offset.offset();
@@ -209,7 +217,7 @@ pub fn drag_transform_gizmo(
 
             axis_normalized * snapped_distance
         }
-        TransformGizmo::Plane => {
+        TransformGizmo::Plane | TransformGizmo::Free => {
             let plane_normal_normalized = normal.normalize_or_zero();
             let normal_component = raw_delta.dot(plane_normal_normalized);
             let projected = raw_delta - (plane_normal_normalized * normal_component);
@@ -410,6 +418,7 @@ pub fn draw_axis_lines(
             render_line(&mut asset, &a, origin, entity_rotation, gizmo_config.mode());
             render_line(&mut asset, &b, origin, entity_rotation, gizmo_config.mode());
         }
+        TransformGizmo::Free => return,
     }
 
     commands.spawn((
@@ -1,4 +1,5 @@
 use bevy::{
+    color::Alpha,
     ecs::hierarchy::{ChildOf, Children},
     light::{NotShadowCaster, NotShadowReceiver},
     mesh::Mesh3d,
@@ -18,12 +19,16 @@ use bevy_granite_logging::{
 use crate::{
     gizmos::{GizmoConfig, GizmoMesh, GizmoMode, GizmoOf, GizmoRoot},
     input::GizmoAxis,
+    GizmoCamera,
 };
 
 #[derive(Component, Debug)]
 pub enum TransformGizmo {
     Axis,
+    /// Square handle moving along the plane the axis is normal to
     Plane,
+    /// Center handle moving in the camera's view plane
+    Free,
 }
 
 #[derive(Resource, Default, Component)]
@@ -41,6 +46,11 @@ const TRANSFORM_LINE_LENGTH: f32 = 0.6 * GIZMO_SCALE; // length of line
 const TRANSFORM_LINE_WIDTH: f32 = 0.04 * GIZMO_SCALE; // width of line
 const TRANSFORM_HANDLE_LENGTH: f32 = 0.22 * GIZMO_SCALE; // cone handle length
 const TRANSFORM_HANDLE_WIDTH: f32 = 0.09 * GIZMO_SCALE; // cone handle width
+const TRANSFORM_PLANE_ALPHA: f32 = 0.5; // plane handle opacity when facing the camera
+
+// Plane handles fade out between these |normal . view| values, gone when edge-on
+const PLANE_FADE_HIDDEN: f32 = 0.12;
+const PLANE_FADE_FULL: f32 = 0.3;
 
 pub fn spawn_transform_gizmo(
     parent: Entity,
@@ -183,7 +193,7 @@ fn build_gizmo_sphere(
             NotShadowReceiver,
             Name::from("Gizmo Transform Sphere".to_string()),
             axis,
-            TransformGizmo::Axis,
+            TransformGizmo::Free,
             GizmoMesh,
             GizmoOf(root),
             ChildOf(parent),
@@ -279,7 +289,7 @@ fn build_axis_plane(
     )));
 
     let material = materials.add(StandardMaterial {
-        base_color: color,
+        base_color: color.with_alpha(TRANSFORM_PLANE_ALPHA),
         unlit: true,
         alpha_mode: AlphaMode::Blend,
         ..Default::default()
@@ -344,6 +354,52 @@ fn plane_rotation(axis: GizmoAxis) -> Quat {
     }
 }
 
+/// Plane handles seen nearly edge-on are hard to hit and turn a small mouse move into a huge
+/// jump, so they fade out and stop being pickable as they turn away from the camera
+pub fn fade_plane_handles_system(
+    camera_query: Query<&GlobalTransform, With<GizmoCamera>>,
+    mut handle_query: Query<(
+        &TransformGizmo,
+        &GlobalTransform,
+        &MeshMaterial3d<StandardMaterial>,
+        &mut Visibility,
+    )>,
+    mut materials: ResMut<Assets<StandardMaterial>>,
+) {
+    let Ok(camera_transform) = camera_query.single() else {
+        return;
+    };
+    let camera_position = camera_transform.translation();
+
+    for (gizmo, handle_transform, material, mut visibility) in handle_query.iter_mut() {
+        if !matches!(gizmo, TransformGizmo::Plane) {
+            continue;
+        }
+        // The handle slab is thin along its local Y
+        let normal = handle_transform.up().as_vec3();
+        let view = (handle_transform.translation() - camera_position).normalize_or_zero();
+        let facing = normal.dot(view).abs();
+        let fade =
+            ((facing - PLANE_FADE_HIDDEN) / (PLANE_FADE_FULL - PLANE_FADE_HIDDEN)).clamp(0., 1.);
+
+        visibility.set_if_neq(if fade > 0. {
+            Visibility::Inherited
+        } else {
+            Visibility::Hidden
+        });
+
+        let alpha = TRANSFORM_PLANE_ALPHA * fade;
+        let needs_update = materials
+            .get(&material.0)
+            .is_some_and(|current| (current.base_color.alpha() - alpha).abs() > 0.01);
+        if needs_update {
+            if let Some(current) = materials.get_mut(&material.0) {
+                current.base_color.set_alpha(alpha);
+            }
+        }
+    }
+}
+
 pub fn update_gizmo_rotation_for_mode(
     mut gizmo_query: Query<(&mut Transform, &GizmoOf, &GizmoConfig), With<TransformGizmoParent>>,
     parent_query: Query<&GlobalTransform>,
@@ -32,3 +32,19 @@ pub struct VertexSelectionState {
     pub selected_vertices: Vec<Entity>,
     pub midpoint_world: Option<Vec3>,
 }
+
+/// Values used by the mesh edit tools in the gizmo panel
+#[derive(Resource)]
+pub struct MeshEditSettings {
+    pub translate_offset: Vec3,
+    pub extrude_distance: f32,
+}
+
+impl Default for MeshEditSettings {
+    fn default() -> Self {
+        Self {
+            translate_offset: Vec3::ZERO,
+            extrude_distance: 0.5,
+        }
+    }
+}
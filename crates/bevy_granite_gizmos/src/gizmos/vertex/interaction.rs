@@ -8,6 +8,7 @@ use bevy::{
     picking::events::{Click, Pointer},
     prelude::{Commands, Entity, KeyCode, Query, Res, ResMut, StandardMaterial, With, Without},
 };
+use crate::selection::ClickSelectionState;
 use bevy_granite_core::UserInput;
 use bevy_granite_logging::{
     config::{LogCategory, LogLevel, LogType},
@@ -21,7 +22,11 @@ pub fn handle_vertex_click(
     vertex_query: Query<(Entity, &VertexMarker)>,
     selected_vertices: Query<Entity, With<SelectedVertex>>,
     mut selection_state: ResMut<VertexSelectionState>,
+    click_selection: Res<ClickSelectionState>,
 ) {
+    if !click_selection.enabled {
+        return;
+    }
     let clicked_entity = event.entity;
 
     let Ok((vertex_entity, vertex_marker)) = vertex_query.get(clicked_entity) else {
@@ -6,5 +6,5 @@ pub mod plugin;
 pub mod spawn;
 
 pub use components::{SelectedVertex, VertexMarker, VertexVisualizationParent};
-pub use config::{VertexSelectionState, VertexVisualizationConfig};
+pub use config::{MeshEditSettings, VertexSelectionState, VertexVisualizationConfig};
 pub use plugin::VertexVisualizationPlugin;
@@ -1,13 +1,13 @@
 use super::{
-    config::{VertexSelectionState, VertexVisualizationConfig},
+    config::{MeshEditSettings, VertexSelectionState, VertexVisualizationConfig},
     interaction::{deselect_all_vertices, handle_vertex_click, update_vertex_colors},
     midpoint::calculate_vertex_midpoint,
     spawn::{
         cleanup_deselected_entity_vertices, cull_vertices_by_distance, despawn_vertex_visualizations,
-        spawn_vertex_visualizations,
+        refresh_vertex_visualizations_on_geometry_change, spawn_vertex_visualizations,
     },
 };
-use crate::is_gizmos_active;
+use crate::{gizmos::RequestBrushEditEvent, is_gizmos_active};
 use bevy::{app::{App, Plugin, Update}, ecs::schedule::IntoScheduleConfigs};
 
 pub struct VertexVisualizationPlugin;
@@ -18,11 +18,15 @@ impl Plugin for VertexVisualizationPlugin {
             // Resources
             .insert_resource(VertexVisualizationConfig::default())
             .insert_resource(VertexSelectionState::default())
+            .insert_resource(MeshEditSettings::default())
+            // Events
+            .add_message::<RequestBrushEditEvent>()
             // Systems
             .add_systems(
                 Update,
                 (
                     spawn_vertex_visualizations,
+                    refresh_vertex_visualizations_on_geometry_change,
                     despawn_vertex_visualizations,
                     cleanup_deselected_entity_vertices,
                     cull_vertices_by_distance,
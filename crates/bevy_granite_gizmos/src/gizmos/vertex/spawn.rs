@@ -1,6 +1,6 @@
 use super::{
     components::{HasVertexVisualizations, VertexMarker, VertexVisualizationParent},
-    config::VertexVisualizationConfig,
+    config::{VertexSelectionState, VertexVisualizationConfig},
 };
 use crate::{
     gizmos::{GizmoType, NewGizmoType},
@@ -14,11 +14,11 @@ use bevy::{
     pbr::MeshMaterial3d,
     picking::Pickable,
     prelude::{
-        Assets, Camera, Children, Commands, Entity, Mesh, Meshable, Name, Query, Res, ResMut,
-        Sphere, StandardMaterial, Transform, Vec3, Visibility, With, Without,
+        Assets, Camera, Changed, Children, Commands, Entity, Mesh, Meshable, Name, Query, Res,
+        ResMut, Sphere, StandardMaterial, Transform, Vec3, Visibility, With, Without,
     },
 };
-use bevy_granite_core::{EditorIgnore, TreeHiddenEntity, UICamera};
+use bevy_granite_core::{EditorIgnore, GraniteType, IdentityData, TreeHiddenEntity, UICamera};
 use bevy_granite_logging::{
     config::{LogCategory, LogLevel, LogType},
     log,
@@ -31,18 +31,27 @@ pub fn spawn_vertex_visualizations(
     mut materials: ResMut<Assets<StandardMaterial>>,
     mut config: ResMut<VertexVisualizationConfig>,
     gizmo_type: Res<NewGizmoType>,
-    selected_entities: Query<(Entity, &Mesh3d), (With<Selected>, Without<HasVertexVisualizations>)>,
+    selected_entities: Query<
+        (Entity, &Mesh3d, Option<&IdentityData>),
+        (With<Selected>, Without<HasVertexVisualizations>),
+    >,
 ) {
     if !matches!(**gizmo_type, GizmoType::Pointer) || !config.enabled {
         return;
     }
 
-    for (entity, mesh3d) in selected_entities.iter() {
+    for (entity, mesh3d, identity) in selected_entities.iter() {
         let Some(mesh) = meshes.get(&mesh3d.0) else {
             continue;
         };
 
-        let Some(vertex_positions) = extract_vertex_positions(mesh) else {
+        // Brush classes expose their editable geometry, so marker indices line up with it
+        let brush_positions = identity
+            .and_then(|identity| identity.class.get_brush_geometry())
+            .map(|geometry| geometry.vertices);
+
+        let Some(vertex_positions) = brush_positions.or_else(|| extract_vertex_positions(mesh))
+        else {
             log!(
                 LogType::Editor,
                 LogLevel::Warning,
@@ -180,6 +189,62 @@ pub fn despawn_vertex_visualizations(
     }
 }
 
+/// System that keeps markers in sync after a brush's geometry changed (mesh edit, undo, redo)
+/// Same vertex count just moves markers, anything else respawns them
+pub fn refresh_vertex_visualizations_on_geometry_change(
+    mut commands: Commands,
+    changed_brushes: Query<
+        (Entity, &IdentityData),
+        (With<HasVertexVisualizations>, Changed<IdentityData>),
+    >,
+    vertex_parents: Query<(Entity, &VertexVisualizationParent, &Children)>,
+    mut markers: Query<(&mut VertexMarker, &mut Transform)>,
+    mut selection_state: ResMut<VertexSelectionState>,
+) {
+    for (entity, identity) in changed_brushes.iter() {
+        let Some(geometry) = identity.class.get_brush_geometry() else {
+            continue;
+        };
+
+        for (parent_entity, viz_parent, children) in vertex_parents.iter() {
+            if viz_parent.source_entity != entity {
+                continue;
+            }
+
+            if children.len() == geometry.vertices.len() {
+                for child in children.iter() {
+                    if let Ok((mut marker, mut transform)) = markers.get_mut(*child) {
+                        if let Some(position) = geometry.vertices.get(marker.vertex_index) {
+                            marker.local_position = *position;
+                            transform.translation = *position;
+                        }
+                    }
+                }
+                continue;
+            }
+
+            for child in children.iter() {
+                selection_state.selected_vertices.retain(|v| v != child);
+                if let Ok(mut entity_commands) = commands.get_entity(*child) {
+                    entity_commands.despawn();
+                }
+            }
+            if let Ok(mut entity_commands) = commands.get_entity(parent_entity) {
+                entity_commands.despawn();
+            }
+            commands.entity(entity).remove::<HasVertexVisualizations>();
+
+            log!(
+                LogType::Editor,
+                LogLevel::Info,
+                LogCategory::Entity,
+                "Brush topology changed on {:?}, respawning vertex markers",
+                entity
+            );
+        }
+    }
+}
+
 /// System that despawns vertex visualizations when an entity is deselected
 pub fn cleanup_deselected_entity_vertices(
     mut commands: Commands,
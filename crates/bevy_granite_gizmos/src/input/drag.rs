@@ -17,6 +17,11 @@ pub struct DragState {
     pub accumulated_angle: f32,
     pub last_snapped: f32,
     pub prev_hit_dir: Vec3,
+    /// Total applied rotation this drag in degrees, shown next to the cursor
+    pub rotation_readout: f32,
+    /// Trackball drags measure from where the drag started, not from the last frame
+    pub trackball_start_dir: Vec3,
+    pub trackball_applied: Quat,
 }
 
 impl Default for DragState {
@@ -33,6 +38,9 @@ impl Default for DragState {
             accumulated_angle: 0.,
             last_snapped: 0.,
             prev_hit_dir: Vec3::NAN,
+            rotation_readout: 0.,
+            trackball_start_dir: Vec3::NAN,
+            trackball_applied: Quat::IDENTITY,
         }
     }
 }
@@ -43,6 +51,10 @@ pub enum GizmoAxis {
     Y,
     Z,
     All,
+    /// Rotate gizmo sphere, rotates like a trackball
+    Trackball,
+    /// Around the camera view direction
+    View,
     #[default]
     None,
 }
@@ -53,8 +65,8 @@ impl GizmoAxis {
             GizmoAxis::X => Vec3::X,
             GizmoAxis::Y => Vec3::Y,
             GizmoAxis::Z => Vec3::Z,
-            GizmoAxis::All => Vec3::ONE,
-            GizmoAxis::None => Vec3::ZERO,
+            GizmoAxis::All | GizmoAxis::Trackball => Vec3::ONE,
+            GizmoAxis::None | GizmoAxis::View => Vec3::ZERO,
         }
     }
 
@@ -63,7 +75,9 @@ impl GizmoAxis {
             GizmoAxis::X => Quat::from_rotation_z((90f32).to_radians()),
             GizmoAxis::Y => Quat::IDENTITY,
             GizmoAxis::Z => Quat::from_rotation_x((90f32).to_radians()),
-            GizmoAxis::None | GizmoAxis::All => Quat::IDENTITY,
+            GizmoAxis::None | GizmoAxis::All | GizmoAxis::Trackball | GizmoAxis::View => {
+                Quat::IDENTITY
+            }
         }
     }
 
@@ -72,7 +86,8 @@ impl GizmoAxis {
             GizmoAxis::X => Color::linear_rgba(1., 0., 0., 1.),
             GizmoAxis::Y => Color::linear_rgba(0., 1., 0., 1.),
             GizmoAxis::Z => Color::linear_rgba(0., 0., 1., 1.),
-            GizmoAxis::All => Color::linear_rgba(1., 1., 1., 1.),
+            GizmoAxis::All | GizmoAxis::View => Color::linear_rgba(1., 1., 1., 1.),
+            GizmoAxis::Trackball => Color::linear_rgba(0.6, 0.6, 0.6, 1.),
             GizmoAxis::None => Color::linear_rgba(0., 0., 0., 1.),
         }
     }
@@ -82,7 +97,9 @@ impl GizmoAxis {
             GizmoAxis::X => (GizmoAxis::Y, GizmoAxis::Z),
             GizmoAxis::Y => (GizmoAxis::X, GizmoAxis::Z),
             GizmoAxis::Z => (GizmoAxis::X, GizmoAxis::Y),
-            GizmoAxis::All => (GizmoAxis::All, GizmoAxis::All),
+            GizmoAxis::All | GizmoAxis::Trackball | GizmoAxis::View => {
+                (GizmoAxis::All, GizmoAxis::All)
+            }
             GizmoAxis::None => (GizmoAxis::None, GizmoAxis::None),
         }
     }
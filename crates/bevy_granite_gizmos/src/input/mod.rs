@@ -1,7 +1,9 @@
 pub mod change_gizmo;
 pub mod drag;
+pub mod numeric;
 pub mod plugin;
 
 pub use plugin::InputPlugin;
 pub use drag::{DragState, GizmoAxis};
-pub use change_gizmo::{watch_gizmo_change};
\ No newline at end of file
+pub use change_gizmo::{watch_gizmo_change};
+pub use numeric::{numeric_drag_input_system, NumericDragInput};
\ No newline at end of file
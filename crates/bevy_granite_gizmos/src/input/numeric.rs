@@ -0,0 +1,289 @@
+use super::{DragState, GizmoAxis};
+use crate::{
+    gizmos::{
+        transform::InitialDragOffset, GizmoConfig, GizmoMode, GizmoOf, GizmoTransformAppliedEvent,
+        GizmoType, NewGizmoConfig, NewGizmoType, TransformGizmo,
+    },
+    selection::{array::selection_roots, ActiveSelection, Selected},
+    GizmoCamera,
+};
+use bevy::{
+    ecs::{entity::Entity, hierarchy::ChildOf, message::MessageWriter, resource::Resource},
+    input::{keyboard::KeyCode, ButtonInput},
+    platform::collections::HashMap,
+    prelude::{GlobalTransform, Quat, Query, Res, ResMut, Transform, With, Without},
+};
+use bevy_granite_core::{TransformData, UserInput};
+use bevy_granite_logging::{
+    config::{LogCategory, LogLevel, LogType},
+    log,
+};
+
+/// Typed value for the current gizmo drag. Press X/Y/Z to pick an axis, type a number and
+/// Enter to apply it as an exact offset (move) or angle in degrees (rotate) from the drag start
+#[derive(Resource, Default)]
+pub struct NumericDragInput {
+    pub axis: Option<GizmoAxis>,
+    pub text: String,
+    /// Set once a typed value is applied, the rest of the drag is ignored
+    pub applied: bool,
+    start_transforms: HashMap<Entity, Transform>,
+}
+
+impl NumericDragInput {
+    pub fn is_typing(&self) -> bool {
+        self.axis.is_some() || !self.text.is_empty()
+    }
+
+    /// Pointer motion must not fight the typed value
+    pub fn blocks_drag(&self) -> bool {
+        self.is_typing() || self.applied
+    }
+
+    pub fn value(&self) -> Option<f32> {
+        self.text
+            .parse::<f32>()
+            .ok()
+            .filter(|value| value.is_finite())
+    }
+
+    fn clear(&mut self) {
+        self.axis = None;
+        self.text.clear();
+        self.applied = false;
+        self.start_transforms.clear();
+    }
+}
+
+const DIGIT_KEYS: [(KeyCode, char); 24] = [
+    (KeyCode::Digit0, '0'),
+    (KeyCode::Digit1, '1'),
+    (KeyCode::Digit2, '2'),
+    (KeyCode::Digit3, '3'),
+    (KeyCode::Digit4, '4'),
+    (KeyCode::Digit5, '5'),
+    (KeyCode::Digit6, '6'),
+    (KeyCode::Digit7, '7'),
+    (KeyCode::Digit8, '8'),
+    (KeyCode::Digit9, '9'),
+    (KeyCode::Numpad0, '0'),
+    (KeyCode::Numpad1, '1'),
+    (KeyCode::Numpad2, '2'),
+    (KeyCode::Numpad3, '3'),
+    (KeyCode::Numpad4, '4'),
+    (KeyCode::Numpad5, '5'),
+    (KeyCode::Numpad6, '6'),
+    (KeyCode::Numpad7, '7'),
+    (KeyCode::Numpad8, '8'),
+    (KeyCode::Numpad9, '9'),
+    (KeyCode::Period, '.'),
+    (KeyCode::NumpadDecimal, '.'),
+    (KeyCode::Minus, '-'),
+    (KeyCode::NumpadSubtract, '-'),
+];
+
+type DraggedTransformGizmoQuery<'w, 's> =
+    Query<'w, 's, (&'w GizmoAxis, &'w TransformGizmo), With<InitialDragOffset>>;
+
+pub fn numeric_drag_input_system(
+    keys: Res<ButtonInput<KeyCode>>,
+    user_input: Res<UserInput>,
+    mut drag_state: ResMut<DragState>,
+    selected_option: Res<NewGizmoType>,
+    default_config: Res<NewGizmoConfig>,
+    mut numeric: ResMut<NumericDragInput>,
+    dragged_transform_gizmo: DraggedTransformGizmoQuery,
+    gizmo_configs: Query<(&GizmoOf, &GizmoConfig)>,
+    active_selection: Query<Entity, With<ActiveSelection>>,
+    selected: Query<Entity, With<Selected>>,
+    parents: Query<&ChildOf>,
+    global_transforms: Query<&GlobalTransform>,
+    camera_query: Query<&GlobalTransform, With<GizmoCamera>>,
+    mut transforms: Query<&mut Transform, Without<GizmoCamera>>,
+    mut applied_writer: MessageWriter<GizmoTransformAppliedEvent>,
+) {
+    let gizmo_type = **selected_option;
+    let roots = selection_roots(&selected, &parents);
+
+    // Nothing has moved on the press frame, so this is where the drag starts from
+    if user_input.mouse_left.just_pressed {
+        numeric.clear();
+        for &entity in &roots {
+            if let Ok(transform) = transforms.get(entity) {
+                numeric.start_transforms.insert(entity, *transform);
+            }
+        }
+    }
+
+    let dragging = user_input.mouse_left.pressed
+        && match gizmo_type {
+            GizmoType::Transform => !dragged_transform_gizmo.is_empty(),
+            GizmoType::Rotate => drag_state.dragging,
+            _ => false,
+        };
+    if !dragging {
+        if !user_input.mouse_left.pressed && (numeric.is_typing() || numeric.applied) {
+            numeric.clear();
+        }
+        return;
+    }
+    if numeric.applied {
+        return;
+    }
+
+    // Shift drag duplicates show up after the press, they start where they were spawned
+    for &entity in &roots {
+        if !numeric.start_transforms.contains_key(&entity) {
+            if let Ok(transform) = transforms.get(entity) {
+                numeric.start_transforms.insert(entity, *transform);
+            }
+        }
+    }
+
+    for (key, axis) in [
+        (KeyCode::KeyX, GizmoAxis::X),
+        (KeyCode::KeyY, GizmoAxis::Y),
+        (KeyCode::KeyZ, GizmoAxis::Z),
+    ] {
+        if keys.just_pressed(key) {
+            numeric.axis = Some(axis);
+        }
+    }
+    for (key, character) in DIGIT_KEYS {
+        if keys.just_pressed(key) {
+            let valid = match character {
+                '-' => numeric.text.is_empty(),
+                '.' => !numeric.text.contains('.'),
+                _ => true,
+            };
+            if valid {
+                numeric.text.push(character);
+            }
+        }
+    }
+    if keys.just_pressed(KeyCode::Backspace) {
+        numeric.text.pop();
+    }
+    if keys.just_pressed(KeyCode::Escape) {
+        numeric.axis = None;
+        numeric.text.clear();
+    }
+    if !(keys.just_pressed(KeyCode::Enter) || keys.just_pressed(KeyCode::NumpadEnter)) {
+        return;
+    }
+
+    let Some(value) = numeric.value() else {
+        return;
+    };
+    let Some(active) = active_selection.iter().next() else {
+        return;
+    };
+
+    // Without a typed axis use the handle being dragged
+    let axis = numeric.axis.or(match gizmo_type {
+        GizmoType::Transform => dragged_transform_gizmo
+            .iter()
+            .find(|(_, typ)| matches!(typ, TransformGizmo::Axis))
+            .map(|(axis, _)| *axis),
+        _ => match drag_state.locked_axis {
+            Some(GizmoAxis::Trackball) | Some(GizmoAxis::All) => Some(GizmoAxis::View),
+            locked => locked,
+        },
+    });
+    let Some(axis) = axis.filter(|axis| !matches!(axis, GizmoAxis::None | GizmoAxis::All)) else {
+        log!(
+            LogType::Editor,
+            LogLevel::Warning,
+            LogCategory::Input,
+            "Numeric input needs an axis, press X, Y or Z"
+        );
+        return;
+    };
+
+    let mode = gizmo_configs
+        .iter()
+        .find(|(gizmo_of, _)| gizmo_of.0 == active)
+        .map(|(_, config)| config.mode())
+        .unwrap_or(default_config.mode);
+    let parent_rotation = |entity: Entity| {
+        parents
+            .get(entity)
+            .ok()
+            .and_then(|parent| global_transforms.get(parent.parent()).ok())
+            .map(|global| global.to_scale_rotation_translation().1)
+            .unwrap_or(Quat::IDENTITY)
+    };
+    let Some(active_start) = numeric.start_transforms.get(&active).copied() else {
+        return;
+    };
+    let active_start_rotation = parent_rotation(active) * active_start.rotation;
+    let world_axis = match (axis, mode) {
+        (GizmoAxis::View, _) => match camera_query.single() {
+            Ok(camera) => camera.forward().as_vec3(),
+            Err(_) => return,
+        },
+        (_, GizmoMode::Local) => active_start_rotation * axis.to_vec3(),
+        (_, GizmoMode::Global) => axis.to_vec3(),
+    };
+
+    for &entity in &roots {
+        let Some(start) = numeric.start_transforms.get(&entity).copied() else {
+            continue;
+        };
+        let Ok(mut transform) = transforms.get_mut(entity) else {
+            continue;
+        };
+        let parent_rotation = parent_rotation(entity);
+
+        let mut result = start;
+        match gizmo_type {
+            GizmoType::Transform => {
+                result.translation += parent_rotation.inverse() * (world_axis * value);
+            }
+            _ => {
+                let angle = value.to_radians();
+                result.rotation = match (axis, mode) {
+                    (GizmoAxis::View, _) | (_, GizmoMode::Global) => {
+                        let start_global = parent_rotation * start.rotation;
+                        parent_rotation.inverse()
+                            * Quat::from_axis_angle(world_axis, angle)
+                            * start_global
+                    }
+                    (_, GizmoMode::Local) => {
+                        start.rotation * Quat::from_axis_angle(axis.to_vec3(), angle)
+                    }
+                };
+            }
+        }
+
+        // One entry for the whole drag, from where it started to the typed result
+        *transform = result;
+        applied_writer.write(GizmoTransformAppliedEvent {
+            entity,
+            old_transform: TransformData {
+                position: start.translation,
+                rotation: start.rotation,
+                scale: start.scale,
+            },
+            new_transform: TransformData {
+                position: result.translation,
+                rotation: result.rotation,
+                scale: result.scale,
+            },
+        });
+    }
+
+    log!(
+        LogType::Editor,
+        LogLevel::OK,
+        LogCategory::Input,
+        "Applied typed value {} on {:?}",
+        value,
+        axis
+    );
+    if gizmo_type == GizmoType::Rotate {
+        drag_state.rotation_readout = value;
+    }
+    numeric.axis = Some(axis);
+    numeric.applied = true;
+}
@@ -1,4 +1,4 @@
-use super::{watch_gizmo_change, DragState};
+use super::{numeric_drag_input_system, watch_gizmo_change, DragState, NumericDragInput};
 use crate::{is_gizmos_active, GizmoVisibilityState};
 use bevy::{
     app::{App, Plugin, Update},
@@ -14,9 +14,13 @@ impl Plugin for InputPlugin {
             //
             .insert_resource(GizmoVisibilityState::default())
             .insert_resource(DragState::default())
+            .insert_resource(NumericDragInput::default())
             //
             // Schedule system
             //
-            .add_systems(Update, (watch_gizmo_change,).run_if(is_gizmos_active));
+            .add_systems(
+                Update,
+                (watch_gizmo_change, numeric_drag_input_system).run_if(is_gizmos_active),
+            );
     }
 }
@@ -15,12 +15,14 @@ mod ui;
 pub use camera::GizmoCamera;
 pub use gizmos::{
     despawn_rotate_gizmo, GizmoChildren, GizmoMesh, GizmoSnap, GizmoTransformAppliedEvent,
-    GizmoType, NewGizmoConfig, RotateGizmo, TransformGizmo,
+    GizmoType, NewGizmoConfig, RequestBrushEditEvent, RotateGizmo, TransformGizmo,
 };
-pub use input::{watch_gizmo_change, DragState, GizmoAxis};
+pub use input::{watch_gizmo_change, DragState, GizmoAxis, NumericDragInput};
 pub use selection::{
-    ActiveSelection, EntityEvents, RequestDuplicateAllSelectionEvent, RequestDuplicateEntityEvent,
-    Selected,
+    ActiveSelection, ArrayLayout, ClickSelectionState, DuplicatedEntitiesEvent, EntityEvents,
+    RequestArrayDuplicateEvent, RequestDeleteSelectionSetEvent, RequestDuplicateAllSelectionEvent,
+    RequestDuplicateEntityEvent, RequestSaveSelectionSetEvent, RequestScatterEvent,
+    RequestSelectByEvent, Selected, SelectionQuery,
 };
 
 // Internal plugins
@@ -0,0 +1,102 @@
+use super::{
+    duplicate::duplicate_entity_recursive, DuplicatedEntitiesEvent, RequestArrayDuplicateEvent,
+};
+use crate::selection::Selected;
+use bevy::{
+    ecs::{
+        entity::Entity,
+        query::With,
+        system::{Commands, Query},
+    },
+    prelude::{AppTypeRegistry, ChildOf, GlobalTransform, MessageReader, Res, Transform, World},
+};
+use bevy_granite_logging::{
+    config::{LogCategory, LogLevel, LogType},
+    log,
+};
+
+/// Selected entities whose parent is not also selected
+/// Copying a parent already copies its children
+pub(crate) fn selection_roots(
+    selected: &Query<Entity, With<Selected>>,
+    parents: &Query<&ChildOf>,
+) -> Vec<Entity> {
+    let all: Vec<Entity> = selected.iter().collect();
+    all.iter()
+        .copied()
+        .filter(|entity| {
+            parents
+                .get(*entity)
+                .map(|parent| !all.contains(&parent.parent()))
+                .unwrap_or(true)
+        })
+        .collect()
+}
+
+/// Copy an entity under its original parent and give it a world transform
+pub fn duplicate_with_world_transform(
+    world: &mut World,
+    source: Entity,
+    world_transform: Transform,
+    registry: &AppTypeRegistry,
+) -> Option<Entity> {
+    let parent = world.get::<ChildOf>(source).map(|parent| parent.parent());
+    let parent_global = parent.and_then(|parent| world.get::<GlobalTransform>(parent).copied());
+
+    let copy = duplicate_entity_recursive(world, source, parent, registry)?;
+    let local = match parent_global {
+        Some(parent_global) => GlobalTransform::from(world_transform).reparented_to(&parent_global),
+        None => world_transform,
+    };
+    if let Some(mut transform) = world.get_mut::<Transform>(copy) {
+        *transform = local;
+    }
+    Some(copy)
+}
+
+pub fn array_duplicate_system(
+    mut commands: Commands,
+    mut array_reader: MessageReader<RequestArrayDuplicateEvent>,
+    type_registry: Res<AppTypeRegistry>,
+    selected: Query<Entity, With<Selected>>,
+    parents: Query<&ChildOf>,
+) {
+    for request in array_reader.read() {
+        let roots = selection_roots(&selected, &parents);
+        if roots.is_empty() || request.count == 0 {
+            continue;
+        }
+        log!(
+            LogType::Editor,
+            LogLevel::Info,
+            LogCategory::Entity,
+            "Array duplicating {} entities x{}",
+            roots.len(),
+            request.count
+        );
+
+        let request = request.clone();
+        let registry = type_registry.clone();
+        commands.queue(move |world: &mut World| {
+            let mut copies = Vec::new();
+            for entity in roots {
+                let Some(global) = world.get::<GlobalTransform>(entity).copied() else {
+                    continue;
+                };
+                let original = global.compute_transform();
+                for index in 1..=request.count {
+                    copies.extend(duplicate_with_world_transform(
+                        world,
+                        entity,
+                        request.copy_transform(index, original),
+                        &registry,
+                    ));
+                }
+            }
+            world.write_message(DuplicatedEntitiesEvent {
+                description: format!("Array {} copies", copies.len()),
+                entities: copies,
+            });
+        });
+    }
+}
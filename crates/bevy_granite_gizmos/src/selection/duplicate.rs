@@ -12,7 +12,7 @@ use bevy::{
     render::sync_world::SyncToRenderWorld,
 };
 use bevy_granite_core::{
-    entities::GraniteType, EditorIgnore, HasRuntimeData, IconProxy, IdentityData,
+    entities::GraniteType, EditorIgnore, HasRuntimeData, IconProxy, IdentityData, MirroredMeshes,
 };
 use bevy_granite_logging::{
     config::{LogCategory, LogLevel, LogType},
@@ -76,7 +76,7 @@ pub fn duplicate_all_selection_system(
     }
 }
 
-fn duplicate_entity_recursive(
+pub(crate) fn duplicate_entity_recursive(
     world: &mut World,
     entity_to_duplicate: Entity,
     new_parent: Option<Entity>,
@@ -94,6 +94,12 @@ fn duplicate_entity_recursive(
     if needs_unique {
         // Handle mesh cloning - for entities that need unique handles
         if let Some(mesh_handle) = world.get::<Mesh3d>(entity_to_duplicate).cloned() {
+            // Copy the source geometry, a mirrored copy gets its own reversed mesh later
+            let mesh_handle = world
+                .get_resource::<MirroredMeshes>()
+                .and_then(|mirrored| mirrored.original(mesh_handle.id()))
+                .map(Mesh3d)
+                .unwrap_or(mesh_handle);
             if let Some(mut mesh_assets) = world.get_resource_mut::<Assets<Mesh>>() {
                 if let Some(original_mesh) = mesh_assets.get(&mesh_handle) {
                     let cloned_mesh = original_mesh.clone();
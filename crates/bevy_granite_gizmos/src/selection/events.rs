@@ -1,6 +1,7 @@
+use super::SelectionQuery;
 use bevy::{
     ecs::event::Event,
-    prelude::{Entity, Message},
+    prelude::{Entity, EulerRot, Message, Quat, Transform, Vec3},
 };
 
 #[derive(Event)]
@@ -19,3 +20,164 @@ pub struct RequestDuplicateEntityEvent {
 
 #[derive(Message)]
 pub struct RequestDuplicateAllSelectionEvent;
+
+/// Select every entity matching the query. Additive keeps the current selection
+#[derive(Message, Clone)]
+pub struct RequestSelectByEvent {
+    pub query: SelectionQuery,
+    pub additive: bool,
+}
+
+/// Store the current selection as a named selection set inside its scene(s)
+#[derive(Message, Clone)]
+pub struct RequestSaveSelectionSetEvent {
+    pub name: String,
+}
+
+/// Remove a named selection set from every loaded scene
+#[derive(Message, Clone)]
+pub struct RequestDeleteSelectionSetEvent {
+    pub name: String,
+}
+
+/// How array copies are laid out
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub enum ArrayLayout {
+    /// Each copy steps by the offset from the one before
+    Linear,
+    /// Each copy also turns around a world space pivot and axis by the step angle
+    Radial {
+        pivot: Vec3,
+        axis: Vec3,
+        step_degrees: f32,
+    },
+}
+
+/// Make `count` copies of every selected entity, each stepped from the one before
+/// Offset is in world space, rotation is euler degrees around the copy's own axes
+#[derive(Message, Clone, Debug)]
+pub struct RequestArrayDuplicateEvent {
+    pub count: u32,
+    pub layout: ArrayLayout,
+    pub offset: Vec3,
+    pub rotation: Vec3,
+    /// Multiplied in once per step
+    pub scale: Vec3,
+}
+
+impl RequestArrayDuplicateEvent {
+    /// World transform of copy `index` (1 based) made from `original`
+    pub fn copy_transform(&self, index: u32, original: Transform) -> Transform {
+        let steps = index as f32;
+        let step_rotation = Quat::from_euler(
+            EulerRot::XYZ,
+            (self.rotation.x * steps).to_radians(),
+            (self.rotation.y * steps).to_radians(),
+            (self.rotation.z * steps).to_radians(),
+        );
+        let scale = original.scale * self.scale.powf(steps);
+        let offset = self.offset * steps;
+
+        match self.layout {
+            ArrayLayout::Linear => Transform {
+                translation: original.translation + offset,
+                rotation: original.rotation * step_rotation,
+                scale,
+            },
+            ArrayLayout::Radial {
+                pivot,
+                axis,
+                step_degrees,
+            } => {
+                let turn = Quat::from_axis_angle(
+                    axis.normalize_or(Vec3::Y),
+                    (step_degrees * steps).to_radians(),
+                );
+                Transform {
+                    translation: pivot + turn * (original.translation - pivot) + offset,
+                    rotation: turn * original.rotation * step_rotation,
+                    scale,
+                }
+            }
+        }
+    }
+}
+
+/// Sent once array or scatter has spawned its copies, with every copy it made
+/// The editor records these as one undo step
+#[derive(Message, Clone, Debug)]
+pub struct DuplicatedEntitiesEvent {
+    pub entities: Vec<Entity>,
+    pub description: String,
+}
+
+/// Scatter copies of the selection over the active entity's mesh surface
+/// The active entity is the surface, everything else selected is what gets placed
+#[derive(Message, Clone, Debug)]
+pub struct RequestScatterEvent {
+    /// Copies per square unit of surface
+    pub density: f32,
+    pub max_count: u32,
+    /// Point each copy's up axis along the surface normal
+    pub align_to_normal: bool,
+    /// Random euler degrees, picked in -range..range per axis
+    pub rotation_range: Vec3,
+    /// Random uniform scale multiplier, min to max
+    pub scale_range: (f32, f32),
+    pub seed: u64,
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn request(layout: ArrayLayout) -> RequestArrayDuplicateEvent {
+        RequestArrayDuplicateEvent {
+            count: 3,
+            layout,
+            offset: Vec3::new(2.0, 0.0, 0.0),
+            rotation: Vec3::ZERO,
+            scale: Vec3::ONE,
+        }
+    }
+
+    #[test]
+    fn test_linear_copy_steps_from_original() {
+        let original = Transform::from_xyz(1.0, 2.0, 3.0);
+        let copy = request(ArrayLayout::Linear).copy_transform(3, original);
+        assert!(copy.translation.abs_diff_eq(Vec3::new(7.0, 2.0, 3.0), 1e-5));
+        assert!(copy.rotation.abs_diff_eq(Quat::IDENTITY, 1e-5));
+    }
+
+    #[test]
+    fn test_copy_rotation_and_scale_compound() {
+        let mut request = request(ArrayLayout::Linear);
+        request.rotation = Vec3::new(0.0, 45.0, 0.0);
+        request.scale = Vec3::splat(2.0);
+        let copy = request.copy_transform(2, Transform::default());
+        assert!(copy
+            .rotation
+            .abs_diff_eq(Quat::from_rotation_y(90f32.to_radians()), 1e-5));
+        assert!(copy.scale.abs_diff_eq(Vec3::splat(4.0), 1e-5));
+    }
+
+    #[test]
+    fn test_radial_copy_turns_around_pivot() {
+        let mut request = request(ArrayLayout::Radial {
+            pivot: Vec3::ZERO,
+            axis: Vec3::Y,
+            step_degrees: 90.0,
+        });
+        request.offset = Vec3::ZERO;
+        let original = Transform::from_xyz(1.0, 0.0, 0.0);
+        let copy = request.copy_transform(2, original);
+        assert!(copy
+            .translation
+            .abs_diff_eq(Vec3::new(-1.0, 0.0, 0.0), 1e-5));
+        // Distance to the pivot stays the same for every copy
+        for index in 1..=4 {
+            let step = request.copy_transform(index, original);
+            assert!((step.translation.length() - 1.0).abs() < 1e-5);
+        }
+    }
+}
@@ -1,4 +1,4 @@
-use crate::selection::{events::EntityEvents, ActiveSelection, Selected};
+use crate::selection::{events::EntityEvents, ActiveSelection, ClickSelectionState, Selected};
 use bevy::{
     ecs::{lifecycle::Add, observer::On},
     prelude::{Component, Entity, Query, Res, With},
@@ -133,10 +133,14 @@ pub fn handle_picking_selection(
     ignored: Query<&EditorIgnore>,
     icon_proxy_query: Query<&IconProxy>,
     user_input: Res<UserInput>,
+    click_selection: Res<ClickSelectionState>,
 ) {
     if on_click.button != bevy::picking::pointer::PointerButton::Primary {
         return;
     }
+    if !click_selection.enabled {
+        return;
+    }
     match ignored.get(on_click.trigger().original_event_target) {
         Ok(to_ignore) => {
             if to_ignore.contains(EditorIgnore::PICKING) {
@@ -1,12 +1,16 @@
 use bevy::ecs::{
-    component::Component, lifecycle::HookContext, message::Messages, world::DeferredWorld,
+    component::Component, lifecycle::HookContext, message::Messages, resource::Resource,
+    world::DeferredWorld,
 };
 
+pub mod array;
 pub mod duplicate;
 pub mod events;
 pub mod manager;
 pub mod plugin;
+pub mod query;
 pub mod ray;
+pub mod scatter;
 
 /// Just the active selection marker
 #[derive(Component)]
@@ -38,10 +42,32 @@ impl ActiveSelection {
 #[derive(Component, Default)]
 pub struct Selected;
 
+/// Viewport tools that want left clicks for themselves (like measuring) turn this off
+/// so the same click doesn't also change the entity or vertex selection
+#[derive(Resource, Clone)]
+pub struct ClickSelectionState {
+    pub enabled: bool,
+}
+impl Default for ClickSelectionState {
+    fn default() -> Self {
+        Self { enabled: true }
+    }
+}
+
+pub use array::{array_duplicate_system, duplicate_with_world_transform};
 pub use duplicate::{duplicate_all_selection_system, duplicate_entity_system};
-pub use events::{EntityEvents, RequestDuplicateAllSelectionEvent, RequestDuplicateEntityEvent};
+pub use events::{
+    ArrayLayout, DuplicatedEntitiesEvent, EntityEvents, RequestArrayDuplicateEvent,
+    RequestDeleteSelectionSetEvent, RequestDuplicateAllSelectionEvent, RequestDuplicateEntityEvent,
+    RequestSaveSelectionSetEvent, RequestScatterEvent, RequestSelectByEvent,
+};
 pub use manager::{apply_pending_parents, handle_picking_selection, select_entity};
 pub use plugin::SelectionPlugin;
+pub use query::{
+    collect_query_matches, delete_selection_set_system, name_matches_pattern,
+    save_selection_set_system, select_by_query_system, SelectionQuery,
+};
 pub use ray::{RaycastCursorLast, RaycastCursorPos};
+pub use scatter::scatter_selection_system;
 
 use crate::gizmos::SpawnGizmoEvent;
@@ -1,7 +1,11 @@
+use super::ClickSelectionState;
 use super::{
-    apply_pending_parents, duplicate_all_selection_system, duplicate_entity_system,
-    handle_picking_selection, select_entity, RaycastCursorLast, RaycastCursorPos,
-    RequestDuplicateAllSelectionEvent, RequestDuplicateEntityEvent,
+    apply_pending_parents, array_duplicate_system, delete_selection_set_system,
+    duplicate_all_selection_system, duplicate_entity_system, handle_picking_selection,
+    save_selection_set_system, scatter_selection_system, select_by_query_system, select_entity,
+    DuplicatedEntitiesEvent, RaycastCursorLast, RaycastCursorPos, RequestArrayDuplicateEvent,
+    RequestDeleteSelectionSetEvent, RequestDuplicateAllSelectionEvent, RequestDuplicateEntityEvent,
+    RequestSaveSelectionSetEvent, RequestScatterEvent, RequestSelectByEvent,
 };
 use crate::{is_gizmos_active, selection::manager::deselect_entity};
 use bevy::{
@@ -19,6 +23,12 @@ impl Plugin for SelectionPlugin {
             //
             .add_message::<RequestDuplicateEntityEvent>()
             .add_message::<RequestDuplicateAllSelectionEvent>()
+            .add_message::<RequestSelectByEvent>()
+            .add_message::<RequestSaveSelectionSetEvent>()
+            .add_message::<RequestDeleteSelectionSetEvent>()
+            .add_message::<RequestArrayDuplicateEvent>()
+            .add_message::<RequestScatterEvent>()
+            .add_message::<DuplicatedEntitiesEvent>()
             //
             // Resources
             //
@@ -28,6 +38,7 @@ impl Plugin for SelectionPlugin {
             .insert_resource(RaycastCursorPos {
                 position: Vec3::ZERO,
             })
+            .insert_resource(ClickSelectionState::default())
             //
             // Events
             //
@@ -39,6 +50,11 @@ impl Plugin for SelectionPlugin {
                 (
                     duplicate_entity_system.after(handle_picking_selection),
                     duplicate_all_selection_system.after(handle_picking_selection),
+                    select_by_query_system,
+                    save_selection_set_system,
+                    delete_selection_set_system,
+                    array_duplicate_system,
+                    scatter_selection_system,
                 )
                     .run_if(is_gizmos_active),
             )
@@ -0,0 +1,280 @@
+use super::{
+    EntityEvents, RequestDeleteSelectionSetEvent, RequestSaveSelectionSetEvent,
+    RequestSelectByEvent, Selected,
+};
+use crate::selection::ActiveSelection;
+use bevy::{
+    ecs::{
+        entity::Entity,
+        query::With,
+        system::{Commands, Query, ResMut},
+    },
+    prelude::{AppTypeRegistry, MessageReader, Name, ReflectComponent, World},
+};
+use bevy_granite_core::{entities::GraniteType, IdentityData, SceneSelectionSets, SpawnSource};
+use bevy_granite_logging::{
+    config::{LogCategory, LogLevel, LogType},
+    log,
+};
+use std::collections::HashMap;
+use uuid::Uuid;
+
+/// What entities a RequestSelectByEvent should match
+#[derive(Debug, Clone, PartialEq)]
+pub enum SelectionQuery {
+    /// Matches the GraniteType friendly name, e.g. "Rectangle Brush"
+    Class(String),
+    /// Matches a component by full type path or short type name
+    Component(String),
+    /// Matches the relative material path stored in the class data
+    MaterialPath(String),
+    /// Matches the relative scene the entity was spawned from
+    SpawnSource(String),
+    /// Case insensitive name pattern. Supports '*' and '?' wildcards
+    NamePattern(String),
+    /// Matches a saved selection set by name
+    Set(String),
+    /// Matches explicit entity UUIDs
+    Uuids(Vec<Uuid>),
+}
+
+/// Case insensitive glob match that supports '*' (any run) and '?' (any single char)
+/// Patterns without wildcards match as a substring
+pub fn name_matches_pattern(name: &str, pattern: &str) -> bool {
+    let name: Vec<char> = name.to_lowercase().chars().collect();
+    let pattern: Vec<char> = pattern.to_lowercase().chars().collect();
+
+    if !pattern.iter().any(|c| *c == '*' || *c == '?') {
+        let pattern: String = pattern.into_iter().collect();
+        let name: String = name.into_iter().collect();
+        return name.contains(&pattern);
+    }
+
+    let (mut n, mut p) = (0, 0);
+    let mut star: Option<usize> = None;
+    let mut star_match = 0;
+
+    while n < name.len() {
+        if p < pattern.len() && (pattern[p] == '?' || pattern[p] == name[n]) {
+            n += 1;
+            p += 1;
+        } else if p < pattern.len() && pattern[p] == '*' {
+            star = Some(p);
+            star_match = n;
+            p += 1;
+        } else if let Some(star_p) = star {
+            p = star_p + 1;
+            star_match += 1;
+            n = star_match;
+        } else {
+            return false;
+        }
+    }
+
+    while p < pattern.len() && pattern[p] == '*' {
+        p += 1;
+    }
+    p == pattern.len()
+}
+
+/// Gather every entity with IdentityData that matches the query
+pub fn collect_query_matches(world: &mut World, query: &SelectionQuery) -> Vec<Entity> {
+    let set_uuids = match query {
+        SelectionQuery::Set(name) => world
+            .get_resource::<SceneSelectionSets>()
+            .map(|sets| sets.uuids_for(name))
+            .unwrap_or_default(),
+        SelectionQuery::Uuids(uuids) => uuids.clone(),
+        _ => Vec::new(),
+    };
+
+    // Resolve component name into its reflected component ahead of the entity loop
+    let reflect_component = if let SelectionQuery::Component(component_name) = query {
+        let registry = world.resource::<AppTypeRegistry>().clone();
+        let registry = registry.read();
+        let found = registry
+            .iter()
+            .find(|registration| {
+                let type_path = registration.type_info().type_path_table();
+                type_path.path() == component_name || type_path.short_path() == component_name
+            })
+            .and_then(|registration| registration.data::<ReflectComponent>().cloned());
+
+        if found.is_none() {
+            log!(
+                LogType::Editor,
+                LogLevel::Warning,
+                LogCategory::Entity,
+                "Select by component: '{}' is not a registered reflected component",
+                component_name
+            );
+            return Vec::new();
+        }
+        found
+    } else {
+        None
+    };
+
+    let mut entity_query =
+        world.query::<(Entity, &IdentityData, Option<&Name>, Option<&SpawnSource>)>();
+    let candidates: Vec<(Entity, IdentityData, Option<String>, Option<String>)> = entity_query
+        .iter(world)
+        .map(|(entity, identity, name, source)| {
+            (
+                entity,
+                identity.clone(),
+                name.map(|n| n.to_string()),
+                source.map(|s| s.str_ref().to_string()),
+            )
+        })
+        .collect();
+
+    candidates
+        .into_iter()
+        .filter(|(entity, identity, name, source)| match query {
+            SelectionQuery::Class(type_name) => identity.class.type_name() == *type_name,
+            SelectionQuery::Component(_) => reflect_component
+                .as_ref()
+                .and_then(|reflect| {
+                    world
+                        .get_entity(*entity)
+                        .ok()
+                        .map(|entity_ref| reflect.contains(entity_ref))
+                })
+                .unwrap_or(false),
+            SelectionQuery::MaterialPath(path) => identity
+                .class
+                .get_material_data()
+                .map(|material| material.path == path)
+                .unwrap_or(false),
+            SelectionQuery::SpawnSource(wanted) => source.as_deref() == Some(wanted.as_str()),
+            SelectionQuery::NamePattern(pattern) => {
+                let name = name.as_deref().unwrap_or(identity.name.as_str());
+                name_matches_pattern(name, pattern)
+            }
+            SelectionQuery::Set(_) | SelectionQuery::Uuids(_) => set_uuids.contains(&identity.uuid),
+        })
+        .map(|(entity, ..)| entity)
+        .collect()
+}
+
+/// Resolves RequestSelectByEvent queries and selects the matching entities
+pub fn select_by_query_system(
+    mut commands: Commands,
+    mut reader: MessageReader<RequestSelectByEvent>,
+) {
+    for RequestSelectByEvent { query, additive } in reader.read() {
+        let query = query.clone();
+        let additive = *additive;
+
+        commands.queue(move |world: &mut World| {
+            let mut matches = collect_query_matches(world, &query);
+
+            log!(
+                LogType::Editor,
+                LogLevel::Info,
+                LogCategory::Entity,
+                "Select by {:?}: {} matches",
+                query,
+                matches.len()
+            );
+
+            if matches.is_empty() {
+                if !additive {
+                    world.trigger(EntityEvents::DeselectAll);
+                }
+                return;
+            }
+
+            // Selecting the current active entity again would toggle it off,
+            // so make sure it is never the first entry of the range
+            let mut active_query = world.query_filtered::<Entity, With<ActiveSelection>>();
+            let current_active = active_query.iter(world).next();
+            if let Some(active) = current_active {
+                if matches.len() == 1 && matches[0] == active {
+                    return;
+                }
+                if matches[0] == active {
+                    matches.swap(0, 1);
+                }
+            }
+
+            world.trigger(EntityEvents::SelectRange {
+                range: matches,
+                additive,
+            });
+        });
+    }
+}
+
+/// Stores the current selection as a named set, grouped by the source scene of each entity
+pub fn save_selection_set_system(
+    mut reader: MessageReader<RequestSaveSelectionSetEvent>,
+    mut selection_sets: ResMut<SceneSelectionSets>,
+    selected: Query<(&IdentityData, Option<&SpawnSource>), With<Selected>>,
+) {
+    for RequestSaveSelectionSetEvent { name } in reader.read() {
+        let name = name.trim();
+        if name.is_empty() {
+            log!(
+                LogType::Editor,
+                LogLevel::Warning,
+                LogCategory::Entity,
+                "Cannot save a selection set without a name"
+            );
+            continue;
+        }
+
+        let mut by_source: HashMap<String, Vec<Uuid>> = HashMap::new();
+        for (identity, source) in selected.iter() {
+            let Some(source) = source else {
+                continue;
+            };
+            by_source
+                .entry(source.str_ref().to_string())
+                .or_default()
+                .push(identity.uuid);
+        }
+
+        if by_source.is_empty() {
+            log!(
+                LogType::Editor,
+                LogLevel::Warning,
+                LogCategory::Entity,
+                "Selection set '{}' not saved: no selected entity belongs to a scene",
+                name
+            );
+            continue;
+        }
+
+        // Overwrite semantics - drop the old set everywhere before writing the new members
+        selection_sets.remove(name);
+        for (source, uuids) in by_source {
+            selection_sets.upsert(&source, name, uuids);
+        }
+
+        log!(
+            LogType::Editor,
+            LogLevel::OK,
+            LogCategory::Entity,
+            "Saved selection set '{}'",
+            name
+        );
+    }
+}
+
+pub fn delete_selection_set_system(
+    mut reader: MessageReader<RequestDeleteSelectionSetEvent>,
+    mut selection_sets: ResMut<SceneSelectionSets>,
+) {
+    for RequestDeleteSelectionSetEvent { name } in reader.read() {
+        selection_sets.remove(name);
+        log!(
+            LogType::Editor,
+            LogLevel::OK,
+            LogCategory::Entity,
+            "Deleted selection set '{}'",
+            name
+        );
+    }
+}
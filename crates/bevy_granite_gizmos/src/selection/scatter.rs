@@ -0,0 +1,312 @@
+use super::{
+    array::{duplicate_with_world_transform, selection_roots},
+    ActiveSelection, DuplicatedEntitiesEvent, RequestScatterEvent, Selected,
+};
+use bevy::{
+    asset::Assets,
+    ecs::{
+        entity::Entity,
+        query::With,
+        system::{Commands, Query},
+    },
+    mesh::{Mesh, Mesh3d, VertexAttributeValues},
+    prelude::{
+        AppTypeRegistry, ChildOf, EulerRot, GlobalTransform, MessageReader, Quat, Res, Transform,
+        Vec3, World,
+    },
+};
+use bevy_granite_logging::{
+    config::{LogCategory, LogLevel, LogType},
+    log,
+};
+
+/// Small seeded generator so the same seed always scatters the same way
+struct ScatterRng(u64);
+
+impl ScatterRng {
+    // splitmix64
+    fn next_u64(&mut self) -> u64 {
+        self.0 = self.0.wrapping_add(0x9E37_79B9_7F4A_7C15);
+        let mut z = self.0;
+        z = (z ^ (z >> 30)).wrapping_mul(0xBF58_476D_1CE4_E5B9);
+        z = (z ^ (z >> 27)).wrapping_mul(0x94D0_49BB_1331_11EB);
+        z ^ (z >> 31)
+    }
+
+    /// 0..1
+    fn next_f32(&mut self) -> f32 {
+        (self.next_u64() >> 40) as f32 / (1u64 << 24) as f32
+    }
+
+    fn range(&mut self, min: f32, max: f32) -> f32 {
+        min + (max - min) * self.next_f32()
+    }
+}
+
+/// World space triangles of a mesh
+fn world_triangles(mesh: &Mesh, global: &GlobalTransform) -> Vec<[Vec3; 3]> {
+    let Some(VertexAttributeValues::Float32x3(positions)) =
+        mesh.attribute(Mesh::ATTRIBUTE_POSITION)
+    else {
+        return Vec::new();
+    };
+    let positions: Vec<Vec3> = positions
+        .iter()
+        .map(|position| global.transform_point(Vec3::from_array(*position)))
+        .collect();
+    let indices: Vec<usize> = match mesh.indices() {
+        Some(indices) => indices.iter().collect(),
+        None => (0..positions.len()).collect(),
+    };
+    indices
+        .chunks_exact(3)
+        .filter_map(|tri| {
+            Some([
+                *positions.get(tri[0])?,
+                *positions.get(tri[1])?,
+                *positions.get(tri[2])?,
+            ])
+        })
+        .collect()
+}
+
+/// Surface triangles with their running area, so spots are picked weighted by size
+struct ScatterSurface {
+    triangles: Vec<[Vec3; 3]>,
+    cumulative_area: Vec<f32>,
+}
+
+impl ScatterSurface {
+    fn new(triangles: Vec<[Vec3; 3]>) -> Self {
+        let mut cumulative_area = Vec::with_capacity(triangles.len());
+        let mut total_area = 0.0;
+        for [a, b, c] in &triangles {
+            total_area += (*b - *a).cross(*c - *a).length() * 0.5;
+            cumulative_area.push(total_area);
+        }
+        Self {
+            triangles,
+            cumulative_area,
+        }
+    }
+
+    fn area(&self) -> f32 {
+        self.cumulative_area.last().copied().unwrap_or(0.0)
+    }
+
+    /// How many copies the request asks for on this surface
+    fn count(&self, request: &RequestScatterEvent) -> u32 {
+        if self.area() <= f32::EPSILON {
+            return 0;
+        }
+        ((self.area() * request.density.max(0.0)).round() as u32).min(request.max_count)
+    }
+
+    /// Point and face normal picked uniformly over the whole surface
+    fn sample(&self, rng: &mut ScatterRng) -> (Vec3, Vec3) {
+        let pick = rng.next_f32() * self.area();
+        let index = self
+            .cumulative_area
+            .partition_point(|area| *area < pick)
+            .min(self.triangles.len() - 1);
+        let [a, b, c] = self.triangles[index];
+
+        // Uniform point on the triangle
+        let r1 = rng.next_f32().sqrt();
+        let r2 = rng.next_f32();
+        let point = a * (1.0 - r1) + b * (r1 * (1.0 - r2)) + c * (r1 * r2);
+        (point, (b - a).cross(c - a).normalize_or(Vec3::Y))
+    }
+}
+
+/// Which source each copy comes from and its world transform
+/// The same request always places the same way on the same surface
+fn scatter_placements(
+    request: &RequestScatterEvent,
+    surface: &ScatterSurface,
+    sources: &[(Entity, Transform)],
+) -> Vec<(Entity, Transform)> {
+    if sources.is_empty() {
+        return Vec::new();
+    }
+    let mut rng = ScatterRng(request.seed);
+    let (min_scale, max_scale) = request.scale_range;
+    (0..surface.count(request))
+        .map(|_| {
+            let (point, normal) = surface.sample(&mut rng);
+            let (source, base) = sources[(rng.next_u64() % sources.len() as u64) as usize];
+            let range = request.rotation_range;
+            let random_rotation = Quat::from_euler(
+                EulerRot::XYZ,
+                rng.range(-range.x, range.x).to_radians(),
+                rng.range(-range.y, range.y).to_radians(),
+                rng.range(-range.z, range.z).to_radians(),
+            );
+            let surface_rotation = if request.align_to_normal {
+                Quat::from_rotation_arc(Vec3::Y, normal)
+            } else {
+                Quat::IDENTITY
+            };
+
+            (
+                source,
+                Transform {
+                    translation: point,
+                    rotation: surface_rotation * base.rotation * random_rotation,
+                    scale: base.scale * rng.range(min_scale, max_scale),
+                },
+            )
+        })
+        .collect()
+}
+
+/// Place copies of the selected entities at random spots on the active entity's surface
+pub fn scatter_selection_system(
+    mut commands: Commands,
+    mut scatter_reader: MessageReader<RequestScatterEvent>,
+    type_registry: Res<AppTypeRegistry>,
+    target: Query<(Entity, &Mesh3d, &GlobalTransform), With<ActiveSelection>>,
+    selected: Query<Entity, With<Selected>>,
+    parents: Query<&ChildOf>,
+    globals: Query<&GlobalTransform>,
+    meshes: Res<Assets<Mesh>>,
+) {
+    for request in scatter_reader.read() {
+        let Ok((target_entity, mesh_handle, target_global)) = target.single() else {
+            log!(
+                LogType::Editor,
+                LogLevel::Warning,
+                LogCategory::Entity,
+                "Scatter needs an active entity with a mesh to scatter over"
+            );
+            continue;
+        };
+        let sources: Vec<(Entity, Transform)> = selection_roots(&selected, &parents)
+            .into_iter()
+            .filter(|entity| *entity != target_entity)
+            .filter_map(|entity| {
+                globals
+                    .get(entity)
+                    .ok()
+                    .map(|global| (entity, global.compute_transform()))
+            })
+            .collect();
+        if sources.is_empty() {
+            log!(
+                LogType::Editor,
+                LogLevel::Warning,
+                LogCategory::Entity,
+                "Scatter needs something selected to place, with the surface as the active entity"
+            );
+            continue;
+        }
+        let Some(mesh) = meshes.get(mesh_handle) else {
+            continue;
+        };
+
+        let surface = ScatterSurface::new(world_triangles(mesh, target_global));
+        // Work out every placement here, the world closure only spawns
+        let placements = scatter_placements(request, &surface, &sources);
+        if placements.is_empty() {
+            log!(
+                LogType::Editor,
+                LogLevel::Warning,
+                LogCategory::Entity,
+                "Scatter density is too low for this surface, nothing to place"
+            );
+            continue;
+        }
+
+        log!(
+            LogType::Editor,
+            LogLevel::Info,
+            LogCategory::Entity,
+            "Scattering {} copies over {:.2} square units",
+            placements.len(),
+            surface.area()
+        );
+
+        let registry = type_registry.clone();
+        commands.queue(move |world: &mut World| {
+            let copies: Vec<Entity> = placements
+                .into_iter()
+                .filter_map(|(source, transform)| {
+                    duplicate_with_world_transform(world, source, transform, &registry)
+                })
+                .collect();
+            world.write_message(DuplicatedEntitiesEvent {
+                description: format!("Scatter {} copies", copies.len()),
+                entities: copies,
+            });
+        });
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn request(density: f32, max_count: u32) -> RequestScatterEvent {
+        RequestScatterEvent {
+            density,
+            max_count,
+            align_to_normal: true,
+            rotation_range: Vec3::ZERO,
+            scale_range: (1.0, 1.0),
+            seed: 7,
+        }
+    }
+
+    // Two triangles making a 2x2 square on the XZ plane, facing up
+    fn floor() -> ScatterSurface {
+        let [a, b, c, d] = [
+            Vec3::new(-1.0, 0.0, -1.0),
+            Vec3::new(-1.0, 0.0, 1.0),
+            Vec3::new(1.0, 0.0, 1.0),
+            Vec3::new(1.0, 0.0, -1.0),
+        ];
+        ScatterSurface::new(vec![[a, b, c], [a, c, d]])
+    }
+
+    #[test]
+    fn test_scatter_count_from_density() {
+        let surface = floor();
+        assert!((surface.area() - 4.0).abs() < 1e-5);
+        assert_eq!(surface.count(&request(2.5, 100)), 10);
+        assert_eq!(surface.count(&request(2.5, 3)), 3);
+        assert_eq!(ScatterSurface::new(Vec::new()).count(&request(2.5, 100)), 0);
+    }
+
+    #[test]
+    fn test_scatter_places_on_surface_aligned_to_normal() {
+        let source = Entity::from_raw_u32(1).unwrap();
+        let placements = scatter_placements(
+            &request(5.0, 100),
+            &floor(),
+            &[(source, Transform::default())],
+        );
+        assert_eq!(placements.len(), 20);
+        for (entity, transform) in placements {
+            assert_eq!(entity, source);
+            let point = transform.translation;
+            assert!(point.y.abs() < 1e-5);
+            assert!(point.x.abs() <= 1.0 + 1e-5 && point.z.abs() <= 1.0 + 1e-5);
+            assert!((transform.rotation * Vec3::Y).abs_diff_eq(Vec3::Y, 1e-4));
+        }
+    }
+
+    #[test]
+    fn test_scatter_same_seed_same_placements() {
+        let source = Entity::from_raw_u32(1).unwrap();
+        let sources = [(source, Transform::default())];
+        let mut scattered = request(5.0, 100);
+        scattered.rotation_range = Vec3::splat(30.0);
+        scattered.scale_range = (0.5, 2.0);
+        let first = scatter_placements(&scattered, &floor(), &sources);
+        let second = scatter_placements(&scattered, &floor(), &sources);
+        assert_eq!(first, second);
+
+        scattered.seed += 1;
+        assert_ne!(first, scatter_placements(&scattered, &floor(), &sources));
+    }
+}
@@ -0,0 +1,146 @@
+use bevy::{
+    ecs::{message::MessageWriter, system::Query},
+    prelude::Entity,
+};
+use bevy_egui::egui;
+use bevy_granite_core::{BrushEditOperation, GraniteType, IdentityData};
+
+use crate::gizmos::{
+    vertex::{MeshEditSettings, VertexMarker},
+    RequestBrushEditEvent,
+};
+
+/// Mesh edit tools for the selected vertices of a brush
+/// Faces are considered selected when all of their vertices are selected
+pub fn mesh_edit_ui(
+    ui: &mut egui::Ui,
+    spacing: f32,
+    selected_markers: &[&VertexMarker],
+    settings: &mut MeshEditSettings,
+    identities: &Query<&IdentityData>,
+    brush_edit_writer: &mut MessageWriter<RequestBrushEditEvent>,
+) {
+    let Some(first) = selected_markers.first() else {
+        return;
+    };
+    let entity: Entity = first.parent_entity;
+    if selected_markers
+        .iter()
+        .any(|marker| marker.parent_entity != entity)
+    {
+        ui.weak("Mesh edit: select vertices on a single brush");
+        return;
+    }
+
+    let Some(geometry) = identities
+        .get(entity)
+        .ok()
+        .and_then(|identity| identity.class.get_brush_geometry())
+    else {
+        return;
+    };
+
+    let vertices: Vec<u32> = selected_markers
+        .iter()
+        .map(|marker| marker.vertex_index as u32)
+        .collect();
+    let faces = geometry.faces_from_vertices(&vertices);
+
+    ui.add_space(spacing);
+    ui.separator();
+    ui.label("Mesh Edit");
+    ui.add_space(spacing);
+
+    // Translate
+    ui.horizontal(|ui| {
+        let drag_width = 45.0;
+        ui.add_sized(
+            [drag_width, 20.0],
+            egui::DragValue::new(&mut settings.translate_offset.x)
+                .speed(0.05)
+                .fixed_decimals(2),
+        );
+        ui.add_sized(
+            [drag_width, 20.0],
+            egui::DragValue::new(&mut settings.translate_offset.y)
+                .speed(0.05)
+                .fixed_decimals(2),
+        );
+        ui.add_sized(
+            [drag_width, 20.0],
+            egui::DragValue::new(&mut settings.translate_offset.z)
+                .speed(0.05)
+                .fixed_decimals(2),
+        );
+    });
+    if ui
+        .add_enabled(
+            settings.translate_offset != bevy::prelude::Vec3::ZERO,
+            egui::Button::new("Move Verts"),
+        )
+        .on_hover_text("Offset is in the brush's local space")
+        .clicked()
+    {
+        brush_edit_writer.write(RequestBrushEditEvent {
+            entity,
+            operation: BrushEditOperation::TranslateVertices {
+                vertices: vertices.clone(),
+                delta: settings.translate_offset,
+            },
+        });
+    }
+    ui.add_space(spacing);
+
+    // Extrude
+    ui.horizontal(|ui| {
+        ui.add_sized(
+            [45.0, 20.0],
+            egui::DragValue::new(&mut settings.extrude_distance)
+                .speed(0.05)
+                .fixed_decimals(2),
+        );
+        if ui
+            .add_enabled(!faces.is_empty(), egui::Button::new("Extrude"))
+            .clicked()
+        {
+            brush_edit_writer.write(RequestBrushEditEvent {
+                entity,
+                operation: BrushEditOperation::ExtrudeFaces {
+                    faces: faces.clone(),
+                    distance: settings.extrude_distance,
+                },
+            });
+        }
+    });
+    ui.add_space(spacing);
+
+    // Split / delete
+    let can_split = vertices.len() == 2 && geometry.has_edge(vertices[0], vertices[1]);
+    ui.horizontal(|ui| {
+        if ui
+            .add_enabled(can_split, egui::Button::new("Split Edge"))
+            .clicked()
+        {
+            brush_edit_writer.write(RequestBrushEditEvent {
+                entity,
+                operation: BrushEditOperation::SplitEdge {
+                    a: vertices[0],
+                    b: vertices[1],
+                },
+            });
+        }
+        if ui
+            .add_enabled(!faces.is_empty(), egui::Button::new("Delete Faces"))
+            .clicked()
+        {
+            brush_edit_writer.write(RequestBrushEditEvent {
+                entity,
+                operation: BrushEditOperation::DeleteFaces {
+                    faces: faces.clone(),
+                },
+            });
+        }
+    });
+    ui.add_space(spacing);
+    ui.weak(format!("{} faces selected", faces.len()));
+}
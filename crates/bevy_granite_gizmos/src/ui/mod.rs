@@ -1,5 +1,10 @@
+pub mod mesh_edit;
+pub mod numeric_input;
 pub mod panel;
 pub mod plugin;
+pub mod rotate_readout;
 
 pub use plugin::UIPlugin;
-pub use panel::editor_gizmos_ui;
\ No newline at end of file
+pub use numeric_input::numeric_input_readout_ui;
+pub use panel::editor_gizmos_ui;
+pub use rotate_readout::rotate_angle_readout_ui;
\ No newline at end of file
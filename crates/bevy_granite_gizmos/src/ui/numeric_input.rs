@@ -0,0 +1,52 @@
+use bevy::prelude::Res;
+use bevy_egui::{egui, EguiContexts};
+use bevy_granite_core::CursorWindowPos;
+
+use crate::{
+    gizmos::{GizmoType, NewGizmoType},
+    input::{GizmoAxis, NumericDragInput},
+};
+
+/// Shows the value being typed during a gizmo drag next to the cursor
+pub fn numeric_input_readout_ui(
+    mut contexts: EguiContexts,
+    numeric: Res<NumericDragInput>,
+    selected_option: Res<NewGizmoType>,
+    cursor: Res<CursorWindowPos>,
+) {
+    if !numeric.is_typing() || numeric.applied {
+        return;
+    }
+    let (action, unit) = match **selected_option {
+        GizmoType::Transform => ("Move", ""),
+        GizmoType::Rotate => ("Rotate", "°"),
+        _ => return,
+    };
+    let axis = match numeric.axis {
+        Some(GizmoAxis::X) => " X",
+        Some(GizmoAxis::Y) => " Y",
+        Some(GizmoAxis::Z) => " Z",
+        _ => "",
+    };
+    let value = if numeric.text.is_empty() {
+        "_".to_string()
+    } else {
+        format!("{}{}", numeric.text, unit)
+    };
+
+    let ctx = contexts.ctx_mut().expect("Egui context to exist");
+    egui::Area::new(egui::Id::new("numeric_drag_input_readout"))
+        .order(egui::Order::Tooltip)
+        .interactable(false)
+        .fixed_pos(egui::pos2(cursor.position.x + 18., cursor.position.y + 18.))
+        .show(ctx, |ui| {
+            egui::Frame::popup(ui.style()).show(ui, |ui| {
+                ui.label(egui::RichText::new(format!("{}{}: {}", action, axis, value)).monospace());
+                ui.label(
+                    egui::RichText::new("Enter to apply, Esc to clear")
+                        .small()
+                        .weak(),
+                );
+            });
+        });
+}
@@ -1,15 +1,21 @@
 use bevy::{
-    ecs::{query::With, system::Query},
+    ecs::{message::MessageWriter, query::With, system::Query},
     prelude::{Res, ResMut},
 };
 use bevy_egui::{egui, EguiContexts};
+use bevy_granite_core::IdentityData;
 use bevy_granite_logging::{log, LogCategory, LogLevel, LogType};
 
 use crate::{
     gizmos::{
-        vertex::{SelectedVertex, VertexMarker, VertexSelectionState, VertexVisualizationConfig},
+        vertex::{
+            MeshEditSettings, SelectedVertex, VertexMarker, VertexSelectionState,
+            VertexVisualizationConfig,
+        },
         GizmoConfig, GizmoMode, GizmoSnap, GizmoType, Gizmos, NewGizmoConfig, NewGizmoType,
+        RequestBrushEditEvent,
     },
+    ui::mesh_edit::mesh_edit_ui,
     ActiveSelection,
 };
 
@@ -26,6 +32,9 @@ pub fn editor_gizmos_ui(
         (&VertexMarker, &bevy::prelude::GlobalTransform),
         With<SelectedVertex>,
     >,
+    mut mesh_edit_settings: ResMut<MeshEditSettings>,
+    identities: Query<&IdentityData>,
+    mut brush_edit_writer: MessageWriter<RequestBrushEditEvent>,
 ) {
     let small_spacing = 1.;
     let spacing = 4.;
@@ -219,6 +228,17 @@ pub fn editor_gizmos_ui(
                                     ));
                                 }
                             }
+
+                            let selected_markers: Vec<&VertexMarker> =
+                                selected_vertices.iter().map(|(marker, _)| marker).collect();
+                            mesh_edit_ui(
+                                ui,
+                                spacing,
+                                &selected_markers,
+                                &mut mesh_edit_settings,
+                                &identities,
+                                &mut brush_edit_writer,
+                            );
                             });
                         }
                     }
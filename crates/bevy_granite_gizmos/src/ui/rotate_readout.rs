@@ -0,0 +1,50 @@
+use bevy::prelude::Res;
+use bevy_egui::{egui, EguiContexts};
+use bevy_granite_core::CursorWindowPos;
+
+use crate::{
+    gizmos::{GizmoSnap, GizmoType, NewGizmoType},
+    input::{DragState, GizmoAxis, NumericDragInput},
+};
+
+/// Small label next to the cursor showing the angle of the current rotate drag
+pub fn rotate_angle_readout_ui(
+    mut contexts: EguiContexts,
+    drag_state: Res<DragState>,
+    selected_option: Res<NewGizmoType>,
+    gizmo_snap: Res<GizmoSnap>,
+    cursor: Res<CursorWindowPos>,
+    numeric: Res<NumericDragInput>,
+) {
+    // The typed value readout takes this spot while typing
+    if !drag_state.dragging
+        || !matches!(**selected_option, GizmoType::Rotate)
+        || (numeric.is_typing() && !numeric.applied)
+    {
+        return;
+    }
+    let axis_label = match drag_state.locked_axis {
+        Some(GizmoAxis::X) => "X",
+        Some(GizmoAxis::Y) => "Y",
+        Some(GizmoAxis::Z) => "Z",
+        Some(GizmoAxis::View) => "View",
+        Some(GizmoAxis::Trackball) => "Trackball",
+        _ => return,
+    };
+
+    let mut text = format!("{} {:.1}°", axis_label, drag_state.rotation_readout);
+    if gizmo_snap.rotate_value > 0.0 {
+        text.push_str(&format!("  (snap {:.1}°)", gizmo_snap.rotate_value));
+    }
+
+    let ctx = contexts.ctx_mut().expect("Egui context to exist");
+    egui::Area::new(egui::Id::new("rotate_angle_readout"))
+        .order(egui::Order::Tooltip)
+        .interactable(false)
+        .fixed_pos(egui::pos2(cursor.position.x + 18., cursor.position.y + 18.))
+        .show(ctx, |ui| {
+            egui::Frame::popup(ui.style()).show(ui, |ui| {
+                ui.label(egui::RichText::new(text).monospace());
+            });
+        });
+}
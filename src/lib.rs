@@ -139,7 +139,9 @@ pub mod prelude {
     pub use crate::{
         bevy_granite_core,
         bevy_granite_core::{
-            absolute_asset_to_rel, rel_asset_to_absolute, BridgeTag, MainCamera,
+            absolute_asset_to_rel, rel_asset_to_absolute, BridgeTag, ClassCategory, GraniteType,
+            GraniteTypes, MainCamera, RegisterGraniteClass, RegisterGraniteMaterial,
+            UserUpdatedCustomClassEvent,
             RequestDespawnBySource, RequestDespawnSerializableEntities, RequestLoadEvent,
             RequestReloadEvent, RequestSaveEvent, SaveSettings, SpawnSource, TreeHiddenEntity,
             UICamera, WorldLoadSuccessEvent, WorldSaveSuccessEvent, RequestLoadBatchEvent, WorldLoadBatchSuccessEvent
@@ -150,7 +152,9 @@ pub mod prelude {
 
     #[cfg(feature = "gizmos")]
     pub use crate::bevy_granite_gizmos::{
-        EntityEvents, RequestDuplicateAllSelectionEvent, RequestDuplicateEntityEvent,
+        EntityEvents, RequestDeleteSelectionSetEvent, RequestDuplicateAllSelectionEvent,
+        RequestDuplicateEntityEvent, RequestSaveSelectionSetEvent, RequestSelectByEvent,
+        SelectionQuery,
     };
 
     #[cfg(feature = "editor")]